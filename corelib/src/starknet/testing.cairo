@@ -1,4 +1,4 @@
-use starknet::ContractAddress;
+use starknet::{ClassHash, ContractAddress, SyscallResult};
 #[allow(unused_imports)]
 use core::array::ArrayTrait;
 #[allow(unused_imports)]
@@ -117,6 +117,84 @@ pub fn set_block_hash(block_number: u64, value: felt252) {
     cheatcode::<'set_block_hash'>([block_number.into(), value].span());
 }
 
+/// Declare the class of one of the contracts compiled with the tests.
+///
+/// Only declared classes may be deployed using `starknet::syscalls::deploy_syscall`, called using
+/// `starknet::syscalls::library_call_syscall` or replaced to using
+/// `starknet::syscalls::replace_class_syscall`.
+/// Fails with `CLASS_HASH_NOT_FOUND` if the class is unknown, and with `CLASS_ALREADY_DECLARED` if
+/// it was already declared.
+pub fn declare(class_hash: ClassHash) -> SyscallResult<()> {
+    let mut result = cheatcode::<'declare'>([class_hash.into()].span());
+    Serde::deserialize(ref result).unwrap()
+}
+
+/// Deploy a contract of a declared class at the given address, without running its constructor.
+///
+/// The storage of the contract is initialized with the given pairs of storage addresses and
+/// values.
+/// Fails with `CLASS_HASH_NOT_DECLARED` if the class was not declared, and with
+/// `CONTRACT_ALREADY_DEPLOYED` if a contract is already deployed at the address.
+pub fn deploy_at(
+    class_hash: ClassHash, contract_address: ContractAddress, storage: Span<(felt252, felt252)>,
+) -> SyscallResult<()> {
+    let mut input = array![class_hash.into(), contract_address.into()];
+    for entry in storage {
+        let (key, value) = *entry;
+        input.append(key);
+        input.append(value);
+    };
+    let mut result = cheatcode::<'deploy_at'>(input.span());
+    Serde::deserialize(ref result).unwrap()
+}
+
+/// Load `len` consecutive values from the storage of a contract, starting at storage address
+/// `key`.
+///
+/// Unset storage values are returned as 0. `len` must be at most 2^16.
+pub fn load(contract_address: ContractAddress, key: felt252, len: u32) -> Span<felt252> {
+    cheatcode::<'load'>([contract_address.into(), key, len.into()].span())
+}
+
+/// Store values to consecutive storage addresses of a contract, starting at storage address `key`.
+///
+/// The contract doesn't have to be deployed.
+pub fn store(contract_address: ContractAddress, key: felt252, values: Span<felt252>) {
+    let mut input = array![contract_address.into(), key];
+    input.append_span(values);
+    cheatcode::<'store'>(input.span());
+}
+
+/// Mock calls to an entry point of a contract.
+///
+/// The next `times` calls to the entry point with the given selector of the contract at
+/// `contract_address` using `starknet::syscalls::call_contract_syscall` will not run the entry
+/// point, and will return `return_data` instead.
+/// The contract doesn't have to be deployed.
+/// `times` must be positive. Mocking an already mocked entry point overrides the previous mock.
+pub fn mock_call(
+    contract_address: ContractAddress, selector: felt252, return_data: Span<felt252>, times: u32,
+) {
+    let mut input = array![contract_address.into(), selector, times.into()];
+    input.append_span(return_data);
+    cheatcode::<'mock_call'>(input.span());
+}
+
+/// Clear the mock of an entry point of a contract set by `mock_call`.
+pub fn clear_mock_call(contract_address: ContractAddress, selector: felt252) {
+    cheatcode::<'clear_mock_call'>([contract_address.into(), selector].span());
+}
+
+/// Expect a call to an entry point of a contract with the given calldata.
+///
+/// If no such call is made using `starknet::syscalls::call_contract_syscall` (mocked or not) after
+/// the call to `expect_call`, the test fails.
+pub fn expect_call(contract_address: ContractAddress, selector: felt252, calldata: Span<felt252>) {
+    let mut input = array![contract_address.into(), selector];
+    input.append_span(calldata);
+    cheatcode::<'expect_call'>(input.span());
+}
+
 /// Pop the earliest unpopped logged event for the contract.
 ///
 /// The value is returned as a tuple of two spans, the first for the keys and the second for the
@@ -182,3 +260,86 @@ pub fn pop_l2_to_l1_message(address: ContractAddress) -> Option<(felt252, Span<f
         (Serde::deserialize(ref l2_to_l1_message)?, Serde::deserialize(ref l2_to_l1_message)?),
     )
 }
+
+/// An event emitted during the test, as recorded by an `EventSpy`.
+#[derive(Drop, Clone, Debug, PartialEq, Serde)]
+pub struct EmittedEvent {
+    /// The address of the contract that emitted the event.
+    pub from_address: ContractAddress,
+    /// The keys of the event.
+    pub keys: Span<felt252>,
+    /// The data of the event.
+    pub data: Span<felt252>,
+}
+
+/// A spy on the events emitted by all contracts since its creation, in order of emission.
+///
+/// Unlike `pop_log`, spying does not consume the events, and events of reverted calls are not
+/// recorded.
+#[derive(Copy, Drop)]
+pub struct EventSpy {
+    start: usize,
+}
+
+/// Starts spying on the events emitted from this point on.
+///
+/// Example:
+/// ```
+/// let mut spy = starknet::testing::spy_events();
+/// token.transfer(recipient, 100);
+/// spy.assert_emitted(token.contract_address, @Event::Transfer(Transfer { from, to, amount }));
+/// ```
+pub fn spy_events() -> EventSpy {
+    let mut output = cheatcode::<'spy_events'>([].span());
+    EventSpy { start: Serde::deserialize(ref output).unwrap() }
+}
+
+#[generate_trait]
+pub impl EventSpyImpl of EventSpyTrait {
+    /// Returns all the events emitted since the spy was created.
+    fn get_events(self: @EventSpy) -> Array<EmittedEvent> {
+        let mut output = cheatcode::<'get_spied_events'>([(*self.start).into()].span());
+        Serde::deserialize(ref output).unwrap()
+    }
+
+    /// Asserts that the given event was emitted by the contract since the spy was created.
+    ///
+    /// On failure, panics with a message describing the expected event and the events the
+    /// contract emitted, decoded using the contract's ABI.
+    fn assert_emitted<T, +starknet::Event<T>>(
+        self: @EventSpy, from_address: ContractAddress, event: @T,
+    ) {
+        let input = event_assertion_input(*self.start, from_address, event);
+        panic_with_cheatcode_output(cheatcode::<'assert_event_emitted'>(input));
+    }
+
+    /// Asserts that the given event was not emitted by the contract since the spy was created.
+    fn assert_not_emitted<T, +starknet::Event<T>>(
+        self: @EventSpy, from_address: ContractAddress, event: @T,
+    ) {
+        let input = event_assertion_input(*self.start, from_address, event);
+        panic_with_cheatcode_output(cheatcode::<'assert_event_not_emitted'>(input));
+    }
+}
+
+/// Serializes the input of the event assertion cheatcodes.
+fn event_assertion_input<T, +starknet::Event<T>>(
+    start: usize, from_address: ContractAddress, event: @T,
+) -> Span<felt252> {
+    let mut keys = array![];
+    let mut data = array![];
+    starknet::Event::append_keys_and_data(event, ref keys, ref data);
+    let mut input = array![start.into(), from_address.into()];
+    keys.span().serialize(ref input);
+    data.span().serialize(ref input);
+    input.span()
+}
+
+/// Panics with the output of a cheatcode, if it is not empty.
+fn panic_with_cheatcode_output(output: Span<felt252>) {
+    if !output.is_empty() {
+        let mut panic_data = array![];
+        panic_data.append_span(output);
+        core::panics::panic(panic_data);
+    }
+}
@@ -1,9 +1,13 @@
-use std::fs;
+use std::fs::{self, File};
+use std::io;
 use std::path::PathBuf;
 
-use anyhow::Context;
+use anyhow::{Context, bail};
+use cairo_lang_compiler::diagnostics::{DiagnosticsReporter, MessageFormat};
 use cairo_lang_compiler::project::check_compiler_path;
 use cairo_lang_compiler::{CompilerConfig, compile_cairo_project_at_path};
+use cairo_lang_diagnostics::error_code_explanation;
+use cairo_lang_sierra::binary_format::write_program;
 use cairo_lang_utils::logging::init_logging;
 use clap::Parser;
 
@@ -32,18 +36,31 @@ impl From<crate::InliningStrategy> for cairo_lang_lowering::utils::InliningStrat
 #[clap(version, verbatim_doc_comment)]
 struct Args {
     /// The Cairo project path.
-    path: PathBuf,
+    #[arg(required_unless_present = "explain")]
+    path: Option<PathBuf>,
     /// Whether path is a single file.
     #[arg(short, long)]
     single_file: bool,
     /// The output file name (default: stdout).
     output: Option<String>,
+    /// Writes the output in the binary Sierra format, instead of the text one.
+    #[arg(long)]
+    binary: bool,
     /// Replaces sierra ids with human-readable ones.
     #[arg(short, long, default_value_t = false)]
     replace_ids: bool,
     /// Overrides inlining behavior.
     #[arg(short, long, default_value = "default")]
     inlining_strategy: InliningStrategy,
+    /// The format in which diagnostics are printed to stderr.
+    #[arg(long, default_value_t, value_enum)]
+    message_format: MessageFormat,
+    /// Applies the machine-applicable fixes suggested by the diagnostics to the source files.
+    #[arg(long)]
+    fix: bool,
+    /// Prints the long-form explanation of an error code, e.g. `E0001`, and exits.
+    #[arg(long, value_name = "CODE")]
+    explain: Option<String>,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -52,15 +69,41 @@ fn main() -> anyhow::Result<()> {
 
     let args = Args::parse();
 
-    // Check if args.path is a file or a directory.
-    check_compiler_path(args.single_file, &args.path)?;
+    if let Some(code) = args.explain {
+        let Some(explanation) = error_code_explanation(&code) else {
+            bail!("No explanation is available for error code `{code}`.");
+        };
+        print!("{explanation}");
+        return Ok(());
+    }
+    let path = args.path.expect("Path is required unless explaining an error code.");
+
+    // Check if path is a file or a directory.
+    check_compiler_path(args.single_file, &path)?;
 
-    let sierra_program = compile_cairo_project_at_path(&args.path, CompilerConfig {
+    let mut diagnostics_reporter = DiagnosticsReporter::stderr_with_format(args.message_format);
+    if args.fix {
+        diagnostics_reporter = diagnostics_reporter.apply_fixes();
+    }
+    let sierra_program = compile_cairo_project_at_path(&path, CompilerConfig {
+        diagnostics_reporter,
         replace_ids: args.replace_ids,
         inlining_strategy: args.inlining_strategy.into(),
         ..CompilerConfig::default()
     })?;
 
+    if args.binary {
+        let sierra_program = sierra_program.into_artifact();
+        match args.output {
+            Some(path) => write_program(
+                &sierra_program,
+                File::create(path).context("Failed to create output file.")?,
+            ),
+            None => write_program(&sierra_program, io::stdout().lock()),
+        }
+        .context("Failed to write output.")?;
+        return Ok(());
+    }
     match args.output {
         Some(path) => {
             fs::write(path, format!("{sierra_program}")).context("Failed to write output.")?
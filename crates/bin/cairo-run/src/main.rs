@@ -10,13 +10,35 @@ use cairo_lang_compiler::project::{check_compiler_path, setup_project};
 use cairo_lang_diagnostics::ToOption;
 use cairo_lang_runner::casm_run::format_next_item;
 use cairo_lang_runner::profiling::ProfilingInfoProcessor;
+use cairo_lang_runner::profiling_export::ProfileExportFormat;
 use cairo_lang_runner::{ProfilingInfoCollectionConfig, SierraCasmRunner, StarknetState};
+use cairo_lang_sierra::binary_format::read_program_auto;
+use cairo_lang_sierra::program::{Program, StatementIdx};
 use cairo_lang_sierra_generator::db::SierraGenGroup;
 use cairo_lang_sierra_generator::program_generator::SierraProgramWithDebug;
 use cairo_lang_sierra_generator::replace_ids::{DebugReplacer, SierraIdReplacer};
-use cairo_lang_starknet::contract::{find_contracts, get_contracts_info};
+use cairo_lang_starknet::contract::{ContractInfo, find_contracts, get_contracts_info};
 use cairo_lang_utils::Upcast;
-use clap::Parser;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
+use clap::{Parser, ValueEnum};
+use starknet_types_core::felt::Felt as Felt252;
+
+/// The clap-arg equivalent of [ProfileExportFormat].
+#[derive(ValueEnum, Clone, Copy, Default, Debug, PartialEq, Eq)]
+enum ProfileExportFormatArg {
+    #[default]
+    CollapsedStacks,
+    Pprof,
+}
+impl From<ProfileExportFormatArg> for ProfileExportFormat {
+    fn from(val: ProfileExportFormatArg) -> Self {
+        match val {
+            ProfileExportFormatArg::CollapsedStacks => ProfileExportFormat::CollapsedStacks,
+            ProfileExportFormatArg::Pprof => ProfileExportFormat::Pprof,
+        }
+    }
+}
 
 /// Compiles a Cairo project and runs the function `main`.
 /// Exits with 1 if the compilation or run fails, otherwise 0.
@@ -28,6 +50,9 @@ struct Args {
     /// Whether path is a single file.
     #[arg(short, long)]
     single_file: bool,
+    /// Whether path is an already compiled Sierra program, in the text or the binary format.
+    #[arg(long, conflicts_with = "single_file")]
+    sierra: bool,
     /// Allows the compilation to succeed with warnings.
     #[arg(long)]
     allow_warnings: bool,
@@ -40,46 +65,38 @@ struct Args {
     /// Whether to run the profiler.
     #[arg(long, default_value_t = false)]
     run_profiler: bool,
+    /// The path to write the profiled stack traces to, for opening in standard profile viewers.
+    #[arg(long, requires = "run_profiler")]
+    profile_output: Option<PathBuf>,
+    /// The format of the profiled stack traces written to `--profile-output`.
+    #[arg(long, default_value_t, value_enum)]
+    profile_format: ProfileExportFormatArg,
 }
 
+/// The compiled program to run, with the information collected while compiling it.
+type CompiledProgram =
+    (Program, OrderedHashMap<Felt252, ContractInfo>, UnorderedHashMap<StatementIdx, String>);
+
 fn main() -> anyhow::Result<()> {
     let args = Args::parse();
 
-    // Check if args.path is a file or a directory.
-    check_compiler_path(args.single_file, &args.path)?;
-
-    let mut db_builder = RootDatabase::builder();
-    db_builder.detect_corelib();
-    if args.available_gas.is_none() {
-        db_builder.skip_auto_withdraw_gas();
-    }
-    let db = &mut db_builder.build()?;
-
-    let main_crate_ids = setup_project(db, Path::new(&args.path))?;
-
-    let mut reporter = DiagnosticsReporter::stderr();
-    if args.allow_warnings {
-        reporter = reporter.allow_warnings();
-    }
-    if reporter.check(db) {
-        anyhow::bail!("failed to compile: {}", args.path.display());
-    }
-
-    let SierraProgramWithDebug { program: mut sierra_program, debug_info } = Arc::unwrap_or_clone(
-        db.get_sierra_program(main_crate_ids.clone())
-            .to_option()
-            .with_context(|| "Compilation failed without any diagnostics.")?,
-    );
-    let replacer = DebugReplacer { db };
-    replacer.enrich_function_names(&mut sierra_program);
+    let mut db = None;
+    let (sierra_program, contracts_info, statements_functions) = if args.sierra {
+        let program = read_program_auto(&args.path)
+            .with_context(|| format!("Failed to read Sierra program `{}`.", args.path.display()))?;
+        (program, Default::default(), Default::default())
+    } else {
+        let mut db_builder = RootDatabase::builder();
+        db_builder.detect_corelib();
+        if args.available_gas.is_none() {
+            db_builder.skip_auto_withdraw_gas();
+        }
+        compile_project(&args, db.insert(db_builder.build()?))?
+    };
     if args.available_gas.is_none() && sierra_program.requires_gas_counter() {
         anyhow::bail!("Program requires gas counter, please provide `--available-gas` argument.");
     }
 
-    let contracts = find_contracts((*db).upcast(), &main_crate_ids);
-    let contracts_info = get_contracts_info(db, contracts, &replacer)?;
-    let sierra_program = replacer.apply(&sierra_program);
-
     let runner = SierraCasmRunner::new(
         sierra_program.clone(),
         if args.available_gas.is_some() { Some(Default::default()) } else { None },
@@ -98,15 +115,23 @@ fn main() -> anyhow::Result<()> {
 
     if args.run_profiler {
         let profiling_info_processor = ProfilingInfoProcessor::new(
-            Some(db),
+            db.as_ref().map(|db| db as &dyn SierraGenGroup),
             sierra_program,
-            debug_info.statements_locations.get_statements_functions_map_for_tests(db),
+            statements_functions,
             Default::default(),
         );
         match result.profiling_info {
             Some(raw_profiling_info) => {
                 let profiling_info = profiling_info_processor.process(&raw_profiling_info);
                 println!("Profiling info:\n{}", profiling_info);
+                if let (Some(path), Some(stack_trace_weights)) =
+                    (&args.profile_output, profiling_info.stack_trace_weights.exportable())
+                {
+                    let format = ProfileExportFormat::from(args.profile_format);
+                    std::fs::write(path, format.export(stack_trace_weights)).with_context(
+                        || format!("Failed to write the profile to `{}`.", path.display()),
+                    )?;
+                }
             }
             None => println!("Warning: Profiling info not found."),
         }
@@ -145,3 +170,37 @@ fn main() -> anyhow::Result<()> {
     }
     Ok(())
 }
+
+/// Compiles the Cairo project at the path given in `args`.
+fn compile_project(args: &Args, db: &mut RootDatabase) -> anyhow::Result<CompiledProgram> {
+    // Check if args.path is a file or a directory.
+    check_compiler_path(args.single_file, &args.path)?;
+
+    let main_crate_ids = setup_project(db, Path::new(&args.path))?;
+
+    let mut reporter = DiagnosticsReporter::stderr();
+    if args.allow_warnings {
+        reporter = reporter.allow_warnings();
+    }
+    if reporter.check(db) {
+        anyhow::bail!("failed to compile: {}", args.path.display());
+    }
+
+    let SierraProgramWithDebug { program: mut sierra_program, debug_info } = Arc::unwrap_or_clone(
+        db.get_sierra_program(main_crate_ids.clone())
+            .to_option()
+            .with_context(|| "Compilation failed without any diagnostics.")?,
+    );
+    let replacer = DebugReplacer { db };
+    replacer.enrich_function_names(&mut sierra_program);
+
+    let contracts = find_contracts((*db).upcast(), &main_crate_ids);
+    let contracts_info = get_contracts_info(db, contracts, &replacer)?;
+    let sierra_program = replacer.apply(&sierra_program);
+    let statements_functions = if args.run_profiler {
+        debug_info.statements_locations.get_statements_functions_map_for_tests(db)
+    } else {
+        Default::default()
+    };
+    Ok((sierra_program, contracts_info, statements_functions))
+}
@@ -3,8 +3,13 @@
 use std::path::PathBuf;
 
 use anyhow::Ok;
+use cairo_lang_compiler::diagnostics::MessageFormat;
 use cairo_lang_compiler::project::check_compiler_path;
-use cairo_lang_test_runner::{RunProfilerConfig, TestRunConfig, TestRunner};
+use cairo_lang_runner::profiling_export::ProfileExportFormat;
+use cairo_lang_test_runner::{
+    GasSnapshotConfig, GasSnapshotMode, ProfileExportConfig, RunProfilerConfig, TestReportConfig,
+    TestReportFormat, TestRunConfig, TestRunner,
+};
 use clap::{Parser, ValueEnum};
 use serde::Serialize;
 
@@ -27,6 +32,39 @@ impl From<RunProfilerConfigArg> for RunProfilerConfig {
     }
 }
 
+/// The clap-arg equivalent of [TestReportFormat].
+#[derive(ValueEnum, Clone, Debug, Serialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "kebab-case")]
+enum TestReportFormatArg {
+    JsonLines,
+    Junit,
+}
+impl From<TestReportFormatArg> for TestReportFormat {
+    fn from(val: TestReportFormatArg) -> Self {
+        match val {
+            TestReportFormatArg::JsonLines => TestReportFormat::JsonLines,
+            TestReportFormatArg::Junit => TestReportFormat::JUnit,
+        }
+    }
+}
+
+/// The clap-arg equivalent of [ProfileExportFormat].
+#[derive(ValueEnum, Clone, Copy, Default, Debug, Serialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "kebab-case")]
+enum ProfileExportFormatArg {
+    #[default]
+    CollapsedStacks,
+    Pprof,
+}
+impl From<ProfileExportFormatArg> for ProfileExportFormat {
+    fn from(val: ProfileExportFormatArg) -> Self {
+        match val {
+            ProfileExportFormatArg::CollapsedStacks => ProfileExportFormat::CollapsedStacks,
+            ProfileExportFormatArg::Pprof => ProfileExportFormat::Pprof,
+        }
+    }
+}
+
 /// Compiles a Cairo project and runs all the functions marked as `#[test]`.
 /// Exits with 1 if the compilation or run fails, otherwise 0.
 #[derive(Parser, Debug)]
@@ -43,6 +81,9 @@ struct Args {
     /// The filter for the tests, running only tests containing the filter string.
     #[arg(short, long, default_value_t = String::default())]
     filter: String,
+    /// Whether the filter must match the full name of a test, instead of a part of it.
+    #[arg(long, default_value_t = false)]
+    exact: bool,
     /// Should we run ignored tests as well.
     #[arg(long, default_value_t = false)]
     include_ignored: bool,
@@ -62,6 +103,39 @@ struct Args {
     /// Whether to print resource usage after each test.
     #[arg(long, default_value_t = false)]
     print_resource_usage: bool,
+    /// The format of a machine-readable report of the test results to write.
+    #[arg(long, value_enum, requires = "report_path")]
+    report_format: Option<TestReportFormatArg>,
+    /// The path of the file to write the machine-readable report to.
+    #[arg(long, requires = "report_format")]
+    report_path: Option<PathBuf>,
+    /// The path to write an LCOV code coverage report of the tests to. The report only marks
+    /// whether each line was executed, with an execution count of 1.
+    #[arg(long)]
+    coverage: Option<PathBuf>,
+    /// The path to write the profiled stack traces of all the tests to, for opening in standard
+    /// profile viewers. Requires `--run-profiler cairo`.
+    #[arg(long)]
+    profile_output: Option<PathBuf>,
+    /// The format of the profiled stack traces written to `--profile-output`.
+    #[arg(long, default_value_t, value_enum)]
+    profile_format: ProfileExportFormatArg,
+    /// The path to write a snapshot of the gas and step counts of the passed tests to. The counts
+    /// of tests that are not run, such as filtered out tests, are kept from the existing snapshot.
+    #[arg(long, conflicts_with = "check_gas_snapshot")]
+    write_gas_snapshot: Option<PathBuf>,
+    /// The path of a gas snapshot to compare the gas and step counts of the passed tests against.
+    #[arg(long)]
+    check_gas_snapshot: Option<PathBuf>,
+    /// The allowed increase of a test's gas or step count over the snapshot, in percents.
+    #[arg(long, default_value_t = 0.0, requires = "check_gas_snapshot")]
+    gas_regression_threshold: f64,
+    /// Whether regressions over the gas snapshot only warn, instead of failing the run.
+    #[arg(long, default_value_t = false, requires = "check_gas_snapshot")]
+    allow_gas_regressions: bool,
+    /// The format in which compilation diagnostics are printed to stderr.
+    #[arg(long, default_value_t, value_enum)]
+    message_format: MessageFormat,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -69,14 +143,38 @@ fn main() -> anyhow::Result<()> {
 
     // Check if args.path is a file or a directory.
     check_compiler_path(args.single_file, &args.path)?;
+    if args.profile_output.is_some() && args.run_profiler != RunProfilerConfigArg::Cairo {
+        anyhow::bail!("`--profile-output` requires `--run-profiler cairo`.");
+    }
 
     let config = TestRunConfig {
         filter: args.filter,
+        exact: args.exact,
         ignored: args.ignored,
         include_ignored: args.include_ignored,
         run_profiler: args.run_profiler.into(),
         gas_enabled: !args.gas_disabled,
         print_resource_usage: args.print_resource_usage,
+        report: args
+            .report_format
+            .zip(args.report_path)
+            .map(|(format, path)| TestReportConfig { format: format.into(), path }),
+        coverage: args.coverage,
+        profile_export: args
+            .profile_output
+            .map(|path| ProfileExportConfig { format: args.profile_format.into(), path }),
+        gas_snapshot: match (args.write_gas_snapshot, args.check_gas_snapshot) {
+            (Some(path), _) => Some(GasSnapshotConfig { path, mode: GasSnapshotMode::Write }),
+            (None, Some(path)) => Some(GasSnapshotConfig {
+                path,
+                mode: GasSnapshotMode::Check {
+                    threshold_percent: args.gas_regression_threshold,
+                    allow_regressions: args.allow_gas_regressions,
+                },
+            }),
+            (None, None) => None,
+        },
+        message_format: args.message_format,
     };
 
     let runner = TestRunner::new(&args.path, args.starknet, args.allow_warnings, config)?;
@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+
+use anyhow::Context;
+use cairo_lang_casm::disassembler::disassemble_instruction;
+use cairo_lang_casm::hints::{Hint, PythonicHint};
+use cairo_lang_starknet_classes::casm_contract_class::{CasmContractClass, NestedIntList};
+use clap::Parser;
+use num_bigint::{BigInt, ToBigInt};
+
+/// Disassembles the bytecode of a CASM contract class, annotated with its entry points, hints and
+/// bytecode segments.
+/// Words that are not valid instructions are written as data.
+/// Exits with 0/1 if all the words disassemble successfully/otherwise.
+#[derive(Parser, Debug)]
+#[clap(version, verbatim_doc_comment)]
+struct Args {
+    /// The path of the file with the CASM contract class.
+    file: String,
+    /// The output file name (default: stdout).
+    output: Option<String>,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let casm_contract_class: CasmContractClass = serde_json::from_str(
+        &fs::read_to_string(&args.file)
+            .with_context(|| format!("Failed to read {}.", &args.file))?,
+    )
+    .with_context(|| "deserialization Failed.")?;
+    let invalid_words = match args.output {
+        Some(path) => write_disassembly(
+            &casm_contract_class,
+            &mut BufWriter::new(File::create(path).with_context(|| "Failed to create output.")?),
+        ),
+        None => write_disassembly(&casm_contract_class, &mut io::stdout().lock()),
+    }
+    .with_context(|| "Failed to write output.")?;
+    if invalid_words > 0 {
+        anyhow::bail!("Failed to disassemble {invalid_words} words.");
+    }
+    Ok(())
+}
+
+/// Writes the disassembly of the bytecode of `contract` to `out`, and returns the number of words
+/// that failed to disassemble.
+///
+/// Words that are not valid instructions are written as data, with the reason they failed to
+/// disassemble.
+fn write_disassembly(contract: &CasmContractClass, out: &mut impl Write) -> io::Result<usize> {
+    let prime = contract.prime.to_bigint().unwrap();
+    // Field elements above half the prime are shown as negative numbers, so that backward jumps
+    // have their natural form.
+    let bytecode: Vec<BigInt> = contract
+        .bytecode
+        .iter()
+        .map(|word| {
+            let value = word.value.to_bigint().unwrap();
+            if value > &prime / 2 { value - &prime } else { value }
+        })
+        .collect();
+
+    let mut annotations: HashMap<usize, Vec<String>> = HashMap::new();
+    add_segment_annotations(&contract.get_bytecode_segment_lengths(), "", &mut 0, &mut annotations);
+    let entry_points = &contract.entry_points_by_type;
+    for (kind, entry_points) in [
+        ("External", &entry_points.external),
+        ("L1 handler", &entry_points.l1_handler),
+        ("Constructor", &entry_points.constructor),
+    ] {
+        for entry_point in entry_points {
+            annotations.entry(entry_point.offset).or_default().push(format!(
+                "{kind} entry point {:#x}, builtins: [{}].",
+                entry_point.selector,
+                entry_point.builtins.join(", ")
+            ));
+        }
+    }
+    let hints: HashMap<_, _> = contract.hints.iter().map(|(pc, hints)| (*pc, hints)).collect();
+
+    let mut invalid_words = 0;
+    let mut pc = 0;
+    while pc < bytecode.len() {
+        write_annotations(out, &annotations, &hints, pc, "")?;
+        match disassemble_instruction(&bytecode, pc) {
+            Ok(instruction) => {
+                writeln!(out, "{pc}: {instruction};")?;
+                let end = pc + instruction.body.op_size();
+                // Annotations may also point into an instruction, e.g. at its immediate.
+                for offset in pc + 1..end {
+                    write_annotations(
+                        out,
+                        &annotations,
+                        &hints,
+                        offset,
+                        &format!("At {offset}, inside the previous instruction: "),
+                    )?;
+                }
+                pc = end;
+            }
+            Err(err) => {
+                writeln!(out, "{pc}: dw {}; // {err}", bytecode[pc])?;
+                invalid_words += 1;
+                pc += 1;
+            }
+        }
+    }
+    out.flush()?;
+    Ok(invalid_words)
+}
+
+/// Writes the annotations and hints at offset `pc` to `out`, with `prefix` before each
+/// annotation.
+fn write_annotations(
+    out: &mut impl Write,
+    annotations: &HashMap<usize, Vec<String>>,
+    hints: &HashMap<usize, &Vec<Hint>>,
+    pc: usize,
+    prefix: &str,
+) -> io::Result<()> {
+    for annotation in annotations.get(&pc).into_iter().flatten() {
+        writeln!(out, "// {prefix}{annotation}")?;
+    }
+    for hint in hints.get(&pc).into_iter().copied().flatten() {
+        if !prefix.is_empty() {
+            writeln!(out, "// {prefix}hint:")?;
+        }
+        let hint_str = hint.get_pythonic_hint();
+        // Skip leading and trailing space if hint starts with `\n`.
+        if hint_str.starts_with('\n') {
+            writeln!(out, "%{{{hint_str}%}}")?;
+        } else {
+            writeln!(out, "%{{ {hint_str} %}}")?;
+        }
+    }
+    Ok(())
+}
+
+/// Adds an annotation at the start of every leaf segment of `node`, which starts at `start`.
+fn add_segment_annotations(
+    node: &NestedIntList,
+    path: &str,
+    start: &mut usize,
+    annotations: &mut HashMap<usize, Vec<String>>,
+) {
+    match node {
+        NestedIntList::Leaf(length) => {
+            let name = if path.is_empty() { "Segment".into() } else { format!("Segment {path}") };
+            annotations.entry(*start).or_default().push(format!("{name}, length {length}."));
+            *start += length;
+        }
+        NestedIntList::Node(nodes) => {
+            for (index, node) in nodes.iter().enumerate() {
+                let path =
+                    if path.is_empty() { index.to_string() } else { format!("{path}.{index}") };
+                add_segment_annotations(node, &path, start, annotations);
+            }
+        }
+    }
+}
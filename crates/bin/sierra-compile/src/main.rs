@@ -1,7 +1,8 @@
 use std::fs;
+use std::path::Path;
 
 use anyhow::Context;
-use cairo_lang_sierra::ProgramParser;
+use cairo_lang_sierra::binary_format::read_program_auto;
 use cairo_lang_sierra_to_casm::compiler::SierraToCasmConfig;
 use cairo_lang_sierra_to_casm::metadata::calc_metadata;
 use cairo_lang_utils::logging::init_logging;
@@ -13,7 +14,7 @@ use indoc::indoc;
 #[derive(Parser, Debug)]
 #[clap(version, verbatim_doc_comment)]
 struct Args {
-    /// The path of the file to compile.
+    /// The path of the file to compile, in the text or the binary Sierra format.
     file: String,
     output: String,
 }
@@ -24,13 +25,12 @@ fn main() -> anyhow::Result<()> {
 
     let args = Args::parse();
 
-    let sierra_code = fs::read_to_string(args.file).with_context(|| "Could not read file!")?;
-    let Ok(program) = ProgramParser::new().parse(&sierra_code) else {
-        anyhow::bail!(indoc! {"
-            Failed to parse sierra program.
+    let program = read_program_auto(Path::new(&args.file)).with_context(|| {
+        indoc! {"
+            Failed to read sierra program.
             Note: StarkNet contracts should be compiled with `starknet-sierra-compile`."
-        })
-    };
+        }
+    })?;
 
     let cairo_program = cairo_lang_sierra_to_casm::compiler::compile(
         &program,
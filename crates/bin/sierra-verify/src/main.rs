@@ -0,0 +1,45 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::Context;
+use cairo_lang_sierra::binary_format::read_program_auto;
+use cairo_lang_sierra::verifier::verify;
+use cairo_lang_starknet_classes::contract_class::ContractClass;
+use clap::Parser;
+
+/// Verifies that a Sierra program is well-typed, without compiling it.
+/// Exits with 0/1 if the verification succeeds/fails.
+#[derive(Parser, Debug)]
+#[clap(version, verbatim_doc_comment)]
+struct Args {
+    /// The path of the file to verify, in the text or the binary Sierra format.
+    file: String,
+    /// Whether the file is a contract class, rather than a Sierra program.
+    #[arg(long)]
+    contract_class: bool,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+
+    let program = if args.contract_class {
+        let content = fs::read_to_string(&args.file)
+            .with_context(|| format!("Failed to read {}.", &args.file))?;
+        let contract_class: ContractClass =
+            serde_json::from_str(&content).with_context(|| "deserialization Failed.")?;
+        contract_class
+            .extract_sierra_program()
+            .with_context(|| "Failed parsing felt252s stream into Sierra program.")?
+    } else {
+        read_program_auto(Path::new(&args.file))
+            .with_context(|| format!("Failed to read sierra program {}.", &args.file))?
+    };
+
+    if let Err(errors) = verify(&program) {
+        for error in &errors {
+            eprintln!("{error}");
+        }
+        anyhow::bail!("Verification failed with {} errors.", errors.len());
+    }
+    Ok(())
+}
@@ -3,7 +3,7 @@ use std::path::PathBuf;
 
 use anyhow::Context;
 use cairo_lang_compiler::CompilerConfig;
-use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
+use cairo_lang_compiler::diagnostics::{DiagnosticsReporter, MessageFormat};
 use cairo_lang_compiler::project::check_compiler_path;
 use cairo_lang_starknet::compile::starknet_compile;
 use cairo_lang_starknet_classes::allowed_libfuncs::ListSelector;
@@ -36,6 +36,12 @@ struct Args {
     /// A file of the allowed libfuncs list to use.
     #[arg(long)]
     allowed_libfuncs_list_file: Option<String>,
+    /// The format in which diagnostics are printed to stderr.
+    #[arg(long, default_value_t, value_enum)]
+    message_format: MessageFormat,
+    /// Applies the machine-applicable fixes suggested by the diagnostics to the source files.
+    #[arg(long)]
+    fix: bool,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -47,10 +53,13 @@ fn main() -> anyhow::Result<()> {
     let list_selector =
         ListSelector::new(args.allowed_libfuncs_list_name, args.allowed_libfuncs_list_file)
             .expect("Both allowed libfunc list name and file were supplied.");
-    let mut diagnostics_reporter = DiagnosticsReporter::stderr();
+    let mut diagnostics_reporter = DiagnosticsReporter::stderr_with_format(args.message_format);
     if args.allow_warnings {
         diagnostics_reporter = diagnostics_reporter.allow_warnings();
     }
+    if args.fix {
+        diagnostics_reporter = diagnostics_reporter.apply_fixes();
+    }
     let res = starknet_compile(
         args.path,
         args.contract_path,
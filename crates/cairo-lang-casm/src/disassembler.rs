@@ -0,0 +1,213 @@
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+use core::fmt::Display;
+
+use cairo_lang_utils::bigint::BigIntAsHex;
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
+
+use crate::assembler::{ApUpdate, FpUpdate, InstructionRepr, Op1Addr, Opcode, PcUpdate, Res};
+use crate::encoder::{
+    AP_ADD_BIT, AP_ADD1_BIT, DST_REG_BIT, OFFSET_BITS, OP0_REG_BIT, OP1_AP_BIT, OP1_FP_BIT,
+    OP1_IMM_BIT, OPCODE_ASSERT_EQ_BIT, OPCODE_CALL_BIT, OPCODE_RET_BIT, PC_JNZ_BIT,
+    PC_JUMP_ABS_BIT, PC_JUMP_REL_BIT, RES_ADD_BIT, RES_MUL_BIT,
+};
+use crate::instructions::{
+    AddApInstruction, AssertEqInstruction, CallInstruction, Instruction, InstructionBody,
+    JnzInstruction, JumpInstruction, RetInstruction,
+};
+use crate::operand::{BinOpOperand, CellRef, DerefOrImmediate, Operation, Register, ResOperand};
+
+#[cfg(test)]
+#[path = "disassembler_test.rs"]
+mod test;
+
+/// Error in disassembling bytecode, with the offset of the failing instruction.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DisassemblyError {
+    /// The word is not a valid instruction encoding.
+    InvalidEncoding(usize),
+    /// The bytecode ends before the immediate of the instruction.
+    MissingImmediate(usize),
+    /// The instruction is valid, but is not an encoding of any [Instruction].
+    UnsupportedInstruction(usize),
+}
+impl Display for DisassemblyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DisassemblyError::InvalidEncoding(pc) => write!(f, "Invalid instruction at {pc}."),
+            DisassemblyError::MissingImmediate(pc) => {
+                write!(f, "Missing immediate of the instruction at {pc}.")
+            }
+            DisassemblyError::UnsupportedInstruction(pc) => {
+                write!(f, "Unsupported instruction at {pc}.")
+            }
+        }
+    }
+}
+
+/// Disassembles `bytecode`, which must consist of instructions only, into its instructions.
+///
+/// Immediates are decoded as is, so negative values should be given as negative numbers rather
+/// than as their field element representation.
+pub fn disassemble(bytecode: &[BigInt]) -> Result<Vec<Instruction>, DisassemblyError> {
+    let mut instructions = vec![];
+    let mut pc = 0;
+    while pc < bytecode.len() {
+        let instruction = disassemble_instruction(bytecode, pc)?;
+        pc += instruction.body.op_size();
+        instructions.push(instruction);
+    }
+    Ok(instructions)
+}
+
+/// Disassembles the instruction at offset `pc` of `bytecode`.
+///
+/// Only the encodings produced by [Instruction::assemble] are accepted, so the result always
+/// assembles back to the same bytecode.
+pub fn disassemble_instruction(
+    bytecode: &[BigInt],
+    pc: usize,
+) -> Result<Instruction, DisassemblyError> {
+    let repr = decode(bytecode, pc)?;
+    to_instruction(&repr)
+        .filter(|instruction| instruction.assemble() == repr)
+        .ok_or(DisassemblyError::UnsupportedInstruction(pc))
+}
+
+/// Decodes the flags, offsets and immediate of the instruction at offset `pc` of `bytecode`.
+fn decode(bytecode: &[BigInt], pc: usize) -> Result<InstructionRepr, DisassemblyError> {
+    let invalid = DisassemblyError::InvalidEncoding(pc);
+    let encoding = bytecode[pc].to_u64().ok_or(invalid)?;
+    let flags = encoding >> (3 * OFFSET_BITS);
+    if flags >> (OPCODE_ASSERT_EQ_BIT + 1) != 0 {
+        return Err(invalid);
+    }
+    let flag = |bit: i32| flags & (1 << bit) != 0;
+    // Convert the offsets back from the range [0, 2^16) to the range [-2^15, 2^15).
+    let offset = |index: u32| {
+        (((encoding >> (index * OFFSET_BITS)) & 0xffff) as i32 - (1 << (OFFSET_BITS - 1))) as i16
+    };
+    let register = |bit: i32| if flag(bit) { Register::FP } else { Register::AP };
+
+    let op1_addr = match (flag(OP1_IMM_BIT), flag(OP1_FP_BIT), flag(OP1_AP_BIT)) {
+        (false, false, false) => Op1Addr::Op0,
+        (true, false, false) => Op1Addr::Imm,
+        (false, true, false) => Op1Addr::FP,
+        (false, false, true) => Op1Addr::AP,
+        _ => return Err(invalid),
+    };
+    let pc_update = match (flag(PC_JUMP_ABS_BIT), flag(PC_JUMP_REL_BIT), flag(PC_JNZ_BIT)) {
+        (false, false, false) => PcUpdate::Regular,
+        (true, false, false) => PcUpdate::Jump,
+        (false, true, false) => PcUpdate::JumpRel,
+        (false, false, true) => PcUpdate::Jnz,
+        _ => return Err(invalid),
+    };
+    let res = match (flag(RES_ADD_BIT), flag(RES_MUL_BIT), pc_update == PcUpdate::Jnz) {
+        (false, false, true) => Res::Unconstrained,
+        (false, false, false) => Res::Op1,
+        (true, false, false) => Res::Add,
+        (false, true, false) => Res::Mul,
+        _ => return Err(invalid),
+    };
+    let opcode = match (flag(OPCODE_CALL_BIT), flag(OPCODE_RET_BIT), flag(OPCODE_ASSERT_EQ_BIT)) {
+        (false, false, false) => Opcode::Nop,
+        (true, false, false) => Opcode::Call,
+        (false, true, false) => Opcode::Ret,
+        (false, false, true) => Opcode::AssertEq,
+        _ => return Err(invalid),
+    };
+    let ap_update = match (flag(AP_ADD_BIT), flag(AP_ADD1_BIT), opcode == Opcode::Call) {
+        (false, false, true) => ApUpdate::Add2,
+        (false, false, false) => ApUpdate::Regular,
+        (true, false, false) => ApUpdate::Add,
+        (false, true, false) => ApUpdate::Add1,
+        _ => return Err(invalid),
+    };
+    let fp_update = match opcode {
+        Opcode::Nop | Opcode::AssertEq => FpUpdate::Regular,
+        Opcode::Call => FpUpdate::ApPlus2,
+        Opcode::Ret => FpUpdate::Dst,
+    };
+    let imm = if op1_addr == Op1Addr::Imm {
+        Some(bytecode.get(pc + 1).ok_or(DisassemblyError::MissingImmediate(pc))?.clone())
+    } else {
+        None
+    };
+    Ok(InstructionRepr {
+        off0: offset(0),
+        off1: offset(1),
+        off2: offset(2),
+        imm,
+        dst_register: register(DST_REG_BIT),
+        op0_register: register(OP0_REG_BIT),
+        op1_addr,
+        res,
+        pc_update,
+        ap_update,
+        fp_update,
+        opcode,
+    })
+}
+
+/// Returns the instruction matching the opcode and updates of `repr`, if there is one.
+///
+/// The offsets and registers are taken as is, so the result should be checked to assemble back to
+/// `repr`.
+fn to_instruction(repr: &InstructionRepr) -> Option<Instruction> {
+    let relative = repr.pc_update == PcUpdate::JumpRel;
+    let body = match (&repr.opcode, &repr.pc_update, &repr.ap_update) {
+        (Opcode::Ret, _, ApUpdate::Regular) => InstructionBody::Ret(RetInstruction {}),
+        (Opcode::Call, _, _) => {
+            InstructionBody::Call(CallInstruction { target: op1_operand(repr)?, relative })
+        }
+        (Opcode::AssertEq, _, _) => InstructionBody::AssertEq(AssertEqInstruction {
+            a: CellRef { register: repr.dst_register, offset: repr.off0 },
+            b: res_operand(repr)?,
+        }),
+        (Opcode::Nop, PcUpdate::Regular, ApUpdate::Add) => {
+            InstructionBody::AddAp(AddApInstruction { operand: res_operand(repr)? })
+        }
+        (Opcode::Nop, PcUpdate::Jump | PcUpdate::JumpRel, _) => {
+            InstructionBody::Jump(JumpInstruction { target: op1_operand(repr)?, relative })
+        }
+        (Opcode::Nop, PcUpdate::Jnz, _) => InstructionBody::Jnz(JnzInstruction {
+            jump_offset: op1_operand(repr)?,
+            condition: CellRef { register: repr.dst_register, offset: repr.off0 },
+        }),
+        _ => return None,
+    };
+    // Instructions that do not support `ap++` have a regular or fixed ap update here, so assembling
+    // them back does not panic.
+    Some(Instruction::new(body, repr.ap_update == ApUpdate::Add1))
+}
+
+/// Returns the res operand of `repr`.
+fn res_operand(repr: &InstructionRepr) -> Option<ResOperand> {
+    let a = CellRef { register: repr.op0_register, offset: repr.off1 };
+    let op = match repr.res {
+        Res::Op1 if repr.op1_addr == Op1Addr::Op0 => {
+            return Some(ResOperand::DoubleDeref(a, repr.off2));
+        }
+        Res::Op1 => return Some(op1_operand(repr)?.into()),
+        Res::Add => Operation::Add,
+        Res::Mul => Operation::Mul,
+        Res::Unconstrained => return None,
+    };
+    Some(ResOperand::BinOp(BinOpOperand { op, a, b: op1_operand(repr)? }))
+}
+
+/// Returns the op1 operand of `repr`, if it is not relative to op0.
+fn op1_operand(repr: &InstructionRepr) -> Option<DerefOrImmediate> {
+    Some(match repr.op1_addr {
+        Op1Addr::Imm => DerefOrImmediate::Immediate(BigIntAsHex { value: repr.imm.clone()? }),
+        Op1Addr::AP => {
+            DerefOrImmediate::Deref(CellRef { register: Register::AP, offset: repr.off2 })
+        }
+        Op1Addr::FP => {
+            DerefOrImmediate::Deref(CellRef { register: Register::FP, offset: repr.off2 })
+        }
+        Op1Addr::Op0 => return None,
+    })
+}
@@ -0,0 +1,90 @@
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+use num_bigint::BigInt;
+use test_case::test_case;
+use test_log::test;
+
+use super::{DisassemblyError, disassemble};
+use crate::casm;
+use crate::inline::CasmContext;
+
+#[test]
+fn test_disassemble_all_forms() {
+    let casm = casm! {
+        [ap + 0] = 1, ap++;
+        [fp + -3] = [ap + 2];
+        [ap + 1] = [fp + 4], ap++;
+        [ap + 0] = [[fp + -3] + 2], ap++;
+        [ap + 0] = [[ap + -1]];
+        [ap + 0] = [fp + -5] + [fp + -4], ap++;
+        [fp + -3] = [ap + 0] + 1, ap++;
+        [ap + 2] = [fp + 1] * [ap + -1];
+        [fp + 0] = [ap + -2] * 7;
+        ap += 205;
+        ap += [fp + -2];
+        ap += [[ap + 1] + 3];
+        call rel (-9);
+        call abs 3;
+        call rel [fp + -3];
+        call abs [ap + 1];
+        jmp rel (-5), ap++;
+        jmp abs 3;
+        jmp abs [ap + 1], ap++;
+        jmp rel 5 if [fp + -3] != 0;
+        jmp rel 2 if [ap + -1] != 0, ap++;
+        ret;
+    };
+    let bytecode: Vec<BigInt> =
+        casm.instructions.iter().flat_map(|inst| inst.assemble().encode()).collect();
+    assert_eq!(disassemble(&bytecode), Ok(casm.instructions));
+}
+
+#[test_case(
+    casm! {
+        [ap + 0] = 1, ap++;
+        [ap + 0] = 1, ap++;
+        [ap + 0] = 13, ap++;
+        call rel 3;
+        ret;
+        jmp rel 5 if [fp + -3] != 0;
+        [ap + 0] = [fp + -5], ap++;
+        jmp rel 8;
+        [ap + 0] = [fp + -4], ap++;
+        [ap + 0] = [fp + -5] + [fp + -4], ap++;
+        [fp + -3] = [ap + 0] + 1, ap++;
+        call rel (-9);
+        ret;
+    },
+    vec![
+        0x480680017fff8000, 1, 0x480680017fff8000, 1, 0x480680017fff8000, 13, 0x1104800180018000,
+        3, 0x208b7fff7fff7ffe, 0x20780017fff7ffd, 5, 0x480a7ffb7fff8000, 0x10780017fff7fff, 8,
+        0x480a7ffc7fff8000, 0x482a7ffc7ffb8000, 0x4825800180007ffd, 1, 0x1104800180018000, -9,
+        0x208b7fff7fff7ffe
+    ];
+    "fib(1, 1, 13)"
+)]
+fn test_disassemble_program(casm: CasmContext, bytecode: Vec<i128>) {
+    let bytecode: Vec<BigInt> = bytecode.into_iter().map(BigInt::from).collect();
+    assert_eq!(disassemble(&bytecode), Ok(casm.instructions));
+}
+
+#[test_case(vec![-1] => DisassemblyError::InvalidEncoding(0); "negative word")]
+#[test_case(vec![1 << 63] => DisassemblyError::InvalidEncoding(0); "flags out of range")]
+#[test_case(
+    vec![0x208b7fff7fff7ffe, 0x308b7fff7fff7ffe] => DisassemblyError::InvalidEncoding(1);
+    "two opcodes"
+)]
+#[test_case(vec![0x400680017fff8005] => DisassemblyError::MissingImmediate(0); "missing immediate")]
+#[test_case(
+    vec![0x8780017fff8000, 3] => DisassemblyError::UnsupportedInstruction(0);
+    "jump with a non-canonical dst"
+)]
+#[test_case(
+    vec![0x288b7fff7fff7ffe] => DisassemblyError::UnsupportedInstruction(0);
+    "ret with ap++"
+)]
+fn test_disassemble_errors(bytecode: Vec<i128>) -> DisassemblyError {
+    let bytecode: Vec<BigInt> = bytecode.into_iter().map(BigInt::from).collect();
+    disassemble(&bytecode).unwrap_err()
+}
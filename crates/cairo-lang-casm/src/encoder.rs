@@ -10,23 +10,23 @@ use crate::operand::Register;
 #[path = "encoder_test.rs"]
 mod test;
 
-const OFFSET_BITS: u32 = 16;
-
-const DST_REG_BIT: i32 = 0;
-const OP0_REG_BIT: i32 = 1;
-const OP1_IMM_BIT: i32 = 2;
-const OP1_FP_BIT: i32 = 3;
-const OP1_AP_BIT: i32 = 4;
-const RES_ADD_BIT: i32 = 5;
-const RES_MUL_BIT: i32 = 6;
-const PC_JUMP_ABS_BIT: i32 = 7;
-const PC_JUMP_REL_BIT: i32 = 8;
-const PC_JNZ_BIT: i32 = 9;
-const AP_ADD_BIT: i32 = 10;
-const AP_ADD1_BIT: i32 = 11;
-const OPCODE_CALL_BIT: i32 = 12;
-const OPCODE_RET_BIT: i32 = 13;
-const OPCODE_ASSERT_EQ_BIT: i32 = 14;
+pub(crate) const OFFSET_BITS: u32 = 16;
+
+pub(crate) const DST_REG_BIT: i32 = 0;
+pub(crate) const OP0_REG_BIT: i32 = 1;
+pub(crate) const OP1_IMM_BIT: i32 = 2;
+pub(crate) const OP1_FP_BIT: i32 = 3;
+pub(crate) const OP1_AP_BIT: i32 = 4;
+pub(crate) const RES_ADD_BIT: i32 = 5;
+pub(crate) const RES_MUL_BIT: i32 = 6;
+pub(crate) const PC_JUMP_ABS_BIT: i32 = 7;
+pub(crate) const PC_JUMP_REL_BIT: i32 = 8;
+pub(crate) const PC_JNZ_BIT: i32 = 9;
+pub(crate) const AP_ADD_BIT: i32 = 10;
+pub(crate) const AP_ADD1_BIT: i32 = 11;
+pub(crate) const OPCODE_CALL_BIT: i32 = 12;
+pub(crate) const OPCODE_RET_BIT: i32 = 13;
+pub(crate) const OPCODE_ASSERT_EQ_BIT: i32 = 14;
 
 impl InstructionRepr {
     pub fn encode(&self) -> Vec<BigInt> {
@@ -9,6 +9,7 @@ pub mod ap_change;
 pub mod assembler;
 pub mod builder;
 pub mod cell_expression;
+pub mod disassembler;
 pub mod encoder;
 pub mod hints;
 pub mod inline;
@@ -12,10 +12,12 @@ use cairo_lang_filesystem::detect::detect_corelib;
 use cairo_lang_filesystem::flag::Flag;
 use cairo_lang_filesystem::ids::{CrateId, FlagId, VirtualFile};
 use cairo_lang_lowering::db::{LoweringDatabase, LoweringGroup, init_lowering_group};
+use cairo_lang_lowering::lint_passes::{lowering_lint_suite, set_lowered_lint_suite};
 use cairo_lang_parser::db::{ParserDatabase, ParserGroup};
 use cairo_lang_project::ProjectConfig;
-use cairo_lang_semantic::db::{SemanticDatabase, SemanticGroup};
+use cairo_lang_semantic::db::{SemanticDatabase, SemanticGroup, init_semantic_group};
 use cairo_lang_semantic::inline_macros::get_default_plugin_suite;
+use cairo_lang_semantic::lint_passes::LintPass;
 use cairo_lang_semantic::plugin::{AnalyzerPlugin, PluginSuite};
 use cairo_lang_sierra_generator::db::SierraGenDatabase;
 use cairo_lang_syntax::node::db::{SyntaxDatabase, SyntaxGroup};
@@ -53,14 +55,18 @@ impl RootDatabase {
         plugins: Vec<Arc<dyn MacroPlugin>>,
         inline_macro_plugins: OrderedHashMap<String, Arc<dyn InlineMacroExprPlugin>>,
         analyzer_plugins: Vec<Arc<dyn AnalyzerPlugin>>,
+        lint_passes: Vec<Arc<dyn LintPass>>,
         inlining_strategy: InliningStrategy,
     ) -> Self {
         let mut res = Self { storage: Default::default() };
         init_files_group(&mut res);
+        init_semantic_group(&mut res);
         init_lowering_group(&mut res, inlining_strategy);
+        set_lowered_lint_suite(&mut res, lowering_lint_suite());
         res.set_macro_plugins(plugins);
         res.set_inline_macro_plugins(inline_macro_plugins.into());
         res.set_analyzer_plugins(analyzer_plugins);
+        res.set_lint_passes(lint_passes);
         res
     }
 
@@ -157,6 +163,7 @@ impl RootDatabaseBuilder {
             self.plugin_suite.plugins.clone(),
             self.plugin_suite.inline_macro_plugins.clone(),
             self.plugin_suite.analyzer_plugins.clone(),
+            self.plugin_suite.lint_passes.clone(),
             self.inlining_strategy,
         );
 
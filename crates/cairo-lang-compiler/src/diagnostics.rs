@@ -2,7 +2,10 @@ use std::fmt::Write;
 
 use cairo_lang_defs::db::DefsGroup;
 use cairo_lang_defs::ids::ModuleId;
-use cairo_lang_diagnostics::{DiagnosticEntry, Diagnostics, FormattedDiagnosticEntry, Severity};
+use cairo_lang_diagnostics::{
+    DiagnosticEntry, Diagnostics, FormattedDiagnosticEntry, Severity, StructuredDiagnostic,
+    StructuredFix, sarif_log,
+};
 use cairo_lang_filesystem::ids::{CrateId, FileLongId};
 use cairo_lang_lowering::db::LoweringGroup;
 use cairo_lang_parser::db::ParserGroup;
@@ -12,6 +15,7 @@ use cairo_lang_utils::unordered_hash_set::UnorderedHashSet;
 use thiserror::Error;
 
 use crate::db::RootDatabase;
+use crate::fix::apply_fixes_to_files;
 
 #[cfg(test)]
 #[path = "diagnostics_test.rs"]
@@ -21,16 +25,38 @@ mod test;
 #[error("Compilation failed.")]
 pub struct DiagnosticsError;
 
+/// The format in which diagnostics are presented.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum MessageFormat {
+    /// Human-readable text.
+    #[default]
+    Human,
+    /// A JSON object per diagnostic, one per line.
+    Json,
+    /// A single SARIF log of all the diagnostics, written once checking is done.
+    Sarif,
+}
+
 trait DiagnosticCallback {
-    fn on_diagnostic(&mut self, diagnostic: FormattedDiagnosticEntry);
+    fn on_diagnostic(&mut self, diagnostic: StructuredDiagnostic);
+
+    /// Called once all the diagnostics of a check were reported.
+    fn on_finish(&mut self) {}
 }
 
 impl DiagnosticCallback for Option<Box<dyn DiagnosticCallback + '_>> {
-    fn on_diagnostic(&mut self, diagnostic: FormattedDiagnosticEntry) {
+    fn on_diagnostic(&mut self, diagnostic: StructuredDiagnostic) {
         if let Some(callback) = self {
             callback.on_diagnostic(diagnostic)
         }
     }
+
+    fn on_finish(&mut self) {
+        if let Some(callback) = self {
+            callback.on_finish()
+        }
+    }
 }
 
 /// Collects compilation diagnostics and presents them in preconfigured way.
@@ -46,6 +72,9 @@ pub struct DiagnosticsReporter<'a> {
     allow_warnings: bool,
     /// If true, will ignore diagnostics from LoweringGroup during the ensure function.
     skip_lowering_diagnostics: bool,
+    /// The fixes suggested by the reported diagnostics, if the machine-applicable ones should be
+    /// applied to the source files once checking is done.
+    fixes: Option<Vec<StructuredFix>>,
 }
 
 impl DiagnosticsReporter<'static> {
@@ -57,6 +86,7 @@ impl DiagnosticsReporter<'static> {
             ignore_warnings_crate_ids: vec![],
             allow_warnings: false,
             skip_lowering_diagnostics: false,
+            fixes: None,
         }
     }
 
@@ -64,6 +94,33 @@ impl DiagnosticsReporter<'static> {
     pub fn stderr() -> Self {
         Self::callback(|diagnostic| eprint!("{diagnostic}"))
     }
+
+    /// Create a reporter which prints all diagnostics to [`std::io::Stderr`] in the given format.
+    pub fn stderr_with_format(format: MessageFormat) -> Self {
+        match format {
+            MessageFormat::Human => Self::stderr(),
+            MessageFormat::Json => Self::structured(|diagnostic| {
+                eprintln!("{}", serde_json::to_string(&diagnostic).unwrap())
+            }),
+            MessageFormat::Sarif => {
+                /// Collects the diagnostics, and prints them as a SARIF log when done.
+                struct SarifCollector(Vec<StructuredDiagnostic>);
+
+                impl DiagnosticCallback for SarifCollector {
+                    fn on_diagnostic(&mut self, diagnostic: StructuredDiagnostic) {
+                        self.0.push(diagnostic);
+                    }
+
+                    fn on_finish(&mut self) {
+                        let log = sarif_log(&std::mem::take(&mut self.0));
+                        eprintln!("{}", serde_json::to_string_pretty(&log).unwrap());
+                    }
+                }
+
+                Self::new(SarifCollector(vec![]))
+            }
+        }
+    }
 }
 
 impl<'a> DiagnosticsReporter<'a> {
@@ -78,7 +135,23 @@ impl<'a> DiagnosticsReporter<'a> {
         where
             F: FnMut(FormattedDiagnosticEntry),
         {
-            fn on_diagnostic(&mut self, diagnostic: FormattedDiagnosticEntry) {
+            fn on_diagnostic(&mut self, diagnostic: StructuredDiagnostic) {
+                self.0(diagnostic.into())
+            }
+        }
+
+        Self::new(Func(callback))
+    }
+
+    /// Create a reporter which calls `callback` for each diagnostic, in its structured form.
+    pub fn structured(callback: impl FnMut(StructuredDiagnostic) + 'a) -> Self {
+        struct Func<F>(F);
+
+        impl<F> DiagnosticCallback for Func<F>
+        where
+            F: FnMut(StructuredDiagnostic),
+        {
+            fn on_diagnostic(&mut self, diagnostic: StructuredDiagnostic) {
                 self.0(diagnostic)
             }
         }
@@ -101,6 +174,7 @@ impl<'a> DiagnosticsReporter<'a> {
             ignore_warnings_crate_ids: vec![],
             allow_warnings: false,
             skip_lowering_diagnostics: false,
+            fixes: None,
         }
     }
 
@@ -125,6 +199,13 @@ impl<'a> DiagnosticsReporter<'a> {
         self
     }
 
+    /// Applies the machine-applicable fixes suggested by the reported diagnostics to the source
+    /// files, once checking is done.
+    pub fn apply_fixes(mut self) -> Self {
+        self.fixes = Some(vec![]);
+        self
+    }
+
     /// Returns the crate ids for which the diagnostics will be checked.
     fn crates_of_interest(&self, db: &dyn LoweringGroup) -> Vec<CrateId> {
         if self.crate_ids.is_empty() { db.crates() } else { self.crate_ids.clone() }
@@ -139,9 +220,8 @@ impl<'a> DiagnosticsReporter<'a> {
         for crate_id in &crates {
             let Ok(module_file) = db.module_main_file(ModuleId::CrateRoot(*crate_id)) else {
                 found_diagnostics = true;
-                self.callback.on_diagnostic(FormattedDiagnosticEntry::new(
+                self.callback.on_diagnostic(StructuredDiagnostic::text_only(
                     Severity::Error,
-                    None,
                     "Failed to get main module file".to_string(),
                 ));
                 continue;
@@ -150,9 +230,8 @@ impl<'a> DiagnosticsReporter<'a> {
             if db.file_content(module_file).is_none() {
                 match module_file.lookup_intern(db) {
                     FileLongId::OnDisk(path) => {
-                        self.callback.on_diagnostic(FormattedDiagnosticEntry::new(
+                        self.callback.on_diagnostic(StructuredDiagnostic::text_only(
                             Severity::Error,
-                            None,
                             format!("{} not found\n", path.display()),
                         ))
                     }
@@ -193,6 +272,16 @@ impl<'a> DiagnosticsReporter<'a> {
                 }
             }
         }
+        if let Some(fixes) = &mut self.fixes {
+            if let Err(err) = apply_fixes_to_files(&std::mem::take(fixes)) {
+                self.callback.on_diagnostic(StructuredDiagnostic::text_only(
+                    Severity::Error,
+                    format!("Failed to apply fixes: {err:#}\n"),
+                ));
+                found_diagnostics = true;
+            }
+        }
+        self.callback.on_finish();
         found_diagnostics
     }
 
@@ -205,11 +294,14 @@ impl<'a> DiagnosticsReporter<'a> {
         skip_warnings: bool,
     ) -> bool {
         let mut found: bool = false;
-        for entry in group.format_with_severity(db) {
-            if skip_warnings && entry.severity() == Severity::Warning {
+        for entry in group.format_structured(db) {
+            if skip_warnings && entry.severity == Severity::Warning {
                 continue;
             }
-            if !entry.is_empty() {
+            if !entry.rendered.is_empty() {
+                if let Some(fixes) = &mut self.fixes {
+                    fixes.extend(entry.fixes.iter().cloned());
+                }
                 self.callback.on_diagnostic(entry);
                 found |= !self.allow_warnings || group.check_error_free().is_err();
             }
@@ -1,11 +1,12 @@
+use cairo_lang_diagnostics::{Severity, StructuredDiagnostic};
 use cairo_lang_filesystem::db::{CrateConfiguration, FilesGroupEx};
 use cairo_lang_filesystem::ids::{CrateId, Directory};
 
 use crate::db::RootDatabase;
-use crate::diagnostics::get_diagnostics_as_string;
+use crate::diagnostics::{DiagnosticsReporter, get_diagnostics_as_string};
 
-#[test]
-fn test_diagnostics() {
+/// Returns a database with a crate whose root does not exist.
+fn setup_bad_crate() -> RootDatabase {
     let mut db = RootDatabase::default();
 
     let crate_id = CrateId::plain(&db, "bad_create");
@@ -13,6 +14,24 @@ fn test_diagnostics() {
         crate_id,
         Some(CrateConfiguration::default_for_root(Directory::Real("no/such/path".into()))),
     );
+    db
+}
+
+#[test]
+fn test_diagnostics() {
+    let db = setup_bad_crate();
 
     assert_eq!(get_diagnostics_as_string(&db, &[]), "error: no/such/path/lib.cairo not found\n");
 }
+
+#[test]
+fn test_structured_diagnostics() {
+    let db = setup_bad_crate();
+
+    let mut diagnostics = vec![];
+    DiagnosticsReporter::structured(|diagnostic| diagnostics.push(diagnostic)).check(&db);
+    assert_eq!(diagnostics, vec![StructuredDiagnostic::text_only(
+        Severity::Error,
+        "no/such/path/lib.cairo not found\n".into()
+    )]);
+}
@@ -0,0 +1,96 @@
+//! Application of the fixes suggested by diagnostics to the source files.
+
+use std::fs;
+
+use anyhow::{Context, Result, bail};
+use cairo_lang_diagnostics::{Applicability, StructuredEdit, StructuredFix};
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+
+#[cfg(test)]
+#[path = "fix_test.rs"]
+mod test;
+
+/// Returns the edits of the machine-applicable fixes, grouped by the path of the edited file.
+///
+/// Duplicate fixes are taken once, and fixes with edits overlapping the edits of earlier fixes are
+/// skipped, as they can no longer be applied as intended.
+pub fn machine_applicable_edits(
+    fixes: &[StructuredFix],
+) -> OrderedHashMap<String, Vec<StructuredEdit>> {
+    let mut selected_fixes: Vec<&StructuredFix> = vec![];
+    let mut edits_by_file: OrderedHashMap<String, Vec<StructuredEdit>> = Default::default();
+    for fix in fixes {
+        if fix.applicability != Applicability::MachineApplicable || selected_fixes.contains(&fix) {
+            continue;
+        }
+        let overlaps = fix.edits.iter().any(|edit| {
+            edits_by_file
+                .get(&edit.span.file)
+                .is_some_and(|edits| edits.iter().any(|other| edits_overlap(edit, other)))
+        });
+        if overlaps {
+            continue;
+        }
+        selected_fixes.push(fix);
+        for edit in &fix.edits {
+            edits_by_file.entry(edit.span.file.clone()).or_default().push(edit.clone());
+        }
+    }
+    edits_by_file
+}
+
+/// Returns whether two edits of the same file overlap.
+///
+/// Insertions at the same offset are considered overlapping, as the order of the inserted texts is
+/// ambiguous.
+fn edits_overlap(a: &StructuredEdit, b: &StructuredEdit) -> bool {
+    a.span.byte_start == b.span.byte_start
+        || (a.span.byte_start < b.span.byte_end && b.span.byte_start < a.span.byte_end)
+}
+
+/// Returns `content` with the given edits applied.
+///
+/// Fails if the edits overlap, or if an edit does not match a range of `content`.
+pub fn apply_edits(content: &str, edits: &[StructuredEdit]) -> Result<String> {
+    let mut edits = edits.iter().collect::<Vec<_>>();
+    edits.sort_by_key(|edit| (edit.span.byte_start, edit.span.byte_end));
+    if let Some([a, b]) = edits.windows(2).find(|pair| edits_overlap(pair[0], pair[1])) {
+        bail!(
+            "Overlapping edits at bytes {}..{} and {}..{}.",
+            a.span.byte_start,
+            a.span.byte_end,
+            b.span.byte_start,
+            b.span.byte_end
+        );
+    }
+    let mut result = String::with_capacity(content.len());
+    let mut offset = 0;
+    for edit in edits {
+        let range = edit.span.byte_start..edit.span.byte_end;
+        let (Some(unchanged), Some(_)) =
+            (content.get(offset..range.start), content.get(range.clone()))
+        else {
+            bail!("Invalid edit range {}..{}.", range.start, range.end);
+        };
+        result.push_str(unchanged);
+        result.push_str(&edit.replacement);
+        offset = range.end;
+    }
+    result.push_str(&content[offset..]);
+    Ok(result)
+}
+
+/// Applies the machine-applicable fixes to the files they edit.
+///
+/// Returns the number of edited files.
+pub fn apply_fixes_to_files(fixes: &[StructuredFix]) -> Result<usize> {
+    let edits_by_file = machine_applicable_edits(fixes);
+    for (path, edits) in edits_by_file.iter() {
+        let content =
+            fs::read_to_string(path).with_context(|| format!("Failed to read `{path}`."))?;
+        let content =
+            apply_edits(&content, edits).with_context(|| format!("Failed to fix `{path}`."))?;
+        fs::write(path, content).with_context(|| format!("Failed to write `{path}`."))?;
+    }
+    Ok(edits_by_file.len())
+}
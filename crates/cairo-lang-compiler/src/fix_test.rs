@@ -0,0 +1,71 @@
+use cairo_lang_diagnostics::{Applicability, StructuredEdit, StructuredFix, StructuredSpan};
+
+use super::{apply_edits, machine_applicable_edits};
+
+/// Returns an edit replacing the given byte range of the first line of `lib.cairo`.
+fn edit(byte_start: usize, byte_end: usize, replacement: &str) -> StructuredEdit {
+    StructuredEdit {
+        span: StructuredSpan {
+            file: "lib.cairo".into(),
+            byte_start,
+            byte_end,
+            line_start: 1,
+            column_start: byte_start + 1,
+            line_end: 1,
+            column_end: byte_end + 1,
+        },
+        replacement: replacement.into(),
+    }
+}
+
+/// Returns a fix made of the given edits.
+fn fix(applicability: Applicability, edits: Vec<StructuredEdit>) -> StructuredFix {
+    StructuredFix { message: "Fix".into(), applicability, edits }
+}
+
+#[test]
+fn test_apply_edits() {
+    let content = "let x = a + b;";
+    assert_eq!(
+        apply_edits(content, &[edit(12, 13, "c"), edit(4, 4, "_"), edit(8, 12, "")]).unwrap(),
+        "let _x = c;"
+    );
+}
+
+#[test]
+fn test_apply_edits_failures() {
+    let content = "let x = a + b;";
+    assert_eq!(
+        apply_edits(content, &[edit(12, 13, "c"), edit(8, 13, "d")]).unwrap_err().to_string(),
+        "Overlapping edits at bytes 8..13 and 12..13."
+    );
+    assert_eq!(
+        apply_edits(content, &[edit(4, 4, "_"), edit(4, 4, "y")]).unwrap_err().to_string(),
+        "Overlapping edits at bytes 4..4 and 4..4."
+    );
+    assert_eq!(
+        apply_edits(content, &[edit(12, 20, "c")]).unwrap_err().to_string(),
+        "Invalid edit range 12..20."
+    );
+    assert_eq!(
+        apply_edits(content, &[edit(5, 4, "c")]).unwrap_err().to_string(),
+        "Invalid edit range 5..4."
+    );
+}
+
+#[test]
+fn test_machine_applicable_edits() {
+    let fixes = [
+        fix(Applicability::MachineApplicable, vec![edit(4, 4, "_")]),
+        // Duplicate of the first fix.
+        fix(Applicability::MachineApplicable, vec![edit(4, 4, "_")]),
+        // Overlaps the first fix.
+        fix(Applicability::MachineApplicable, vec![edit(10, 11, "c"), edit(4, 5, "y")]),
+        // Not machine-applicable.
+        fix(Applicability::MaybeIncorrect, vec![edit(8, 9, "()")]),
+        fix(Applicability::MachineApplicable, vec![edit(12, 13, "c")]),
+    ];
+    let edits = machine_applicable_edits(&fixes);
+    assert_eq!(edits.keys().collect::<Vec<_>>(), ["lib.cairo"]);
+    assert_eq!(edits["lib.cairo"], [edit(4, 4, "_"), edit(12, 13, "c")]);
+}
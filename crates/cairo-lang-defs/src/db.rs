@@ -1,14 +1,14 @@
 use std::collections::VecDeque;
 use std::sync::Arc;
 
-use cairo_lang_diagnostics::{Maybe, ToMaybe};
+use cairo_lang_diagnostics::{Maybe, ToMaybe, error_code};
 use cairo_lang_filesystem::db::FilesGroup;
 use cairo_lang_filesystem::ids::{CrateId, Directory, FileId, FileKind, FileLongId, VirtualFile};
 use cairo_lang_parser::db::ParserGroup;
 use cairo_lang_syntax::attribute::consts::{
-    ALLOW_ATTR, DEPRECATED_ATTR, FEATURE_ATTR, FMT_SKIP_ATTR, IMPLICIT_PRECEDENCE_ATTR,
+    ALLOW_ATTR, DENY_ATTR, DEPRECATED_ATTR, FEATURE_ATTR, FMT_SKIP_ATTR, IMPLICIT_PRECEDENCE_ATTR,
     INLINE_ATTR, INTERNAL_ATTR, MUST_USE_ATTR, PHANTOM_ATTR, STARKNET_INTERFACE_ATTR,
-    UNSTABLE_ATTR,
+    UNSTABLE_ATTR, WARN_ATTR,
 };
 use cairo_lang_syntax::node::ast::MaybeModuleBody;
 use cairo_lang_syntax::node::db::SyntaxGroup;
@@ -253,6 +253,8 @@ fn allowed_attributes(db: &dyn DefsGroup) -> Arc<OrderedHashSet<String>> {
         DEPRECATED_ATTR,
         INTERNAL_ATTR,
         ALLOW_ATTR,
+        WARN_ATTR,
+        DENY_ATTR,
         FEATURE_ATTR,
         PHANTOM_ATTR,
         IMPLICIT_PRECEDENCE_ATTR,
@@ -267,7 +269,7 @@ fn allowed_attributes(db: &dyn DefsGroup) -> Arc<OrderedHashSet<String>> {
 }
 
 fn allowed_statement_attributes(_db: &dyn DefsGroup) -> Arc<OrderedHashSet<String>> {
-    let all_attributes = [FMT_SKIP_ATTR, ALLOW_ATTR, FEATURE_ATTR];
+    let all_attributes = [FMT_SKIP_ATTR, ALLOW_ATTR, WARN_ATTR, DENY_ATTR, FEATURE_ATTR];
     Arc::new(OrderedHashSet::from_iter(all_attributes.map(|attr| attr.into())))
 }
 
@@ -547,7 +549,8 @@ fn priv_module_data(db: &dyn DefsGroup, module_id: ModuleId) -> Maybe<ModuleData
                             "Unknown inline item macro: '{}'.",
                             inline_macro_ast.name(db.upcast()).text(db.upcast())
                         ),
-                    ),
+                    )
+                    .with_error_code(error_code!(E3002)),
                 )),
                 ast::ModuleItem::HeaderDoc(_) => {}
                 ast::ModuleItem::Missing(_) => {}
@@ -693,8 +696,10 @@ pub fn validate_attributes_flat(
     for attr in item.attributes_elements(db) {
         if !allowed_attributes.contains(&attr.attr(db).as_syntax_node().get_text_without_trivia(db))
         {
-            plugin_diagnostics
-                .push(PluginDiagnostic::error(&attr, "Unsupported attribute.".to_string()));
+            plugin_diagnostics.push(
+                PluginDiagnostic::error(&attr, "Unsupported attribute.".to_string())
+                    .with_error_code(error_code!(E3003)),
+            );
         }
     }
 }
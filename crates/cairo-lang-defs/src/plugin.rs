@@ -2,7 +2,7 @@ use std::any::Any;
 use std::ops::Deref;
 use std::sync::Arc;
 
-use cairo_lang_diagnostics::Severity;
+use cairo_lang_diagnostics::{ErrorCode, Severity, error_code};
 use cairo_lang_filesystem::cfg::CfgSet;
 use cairo_lang_filesystem::db::Edition;
 use cairo_lang_filesystem::ids::CodeMapping;
@@ -68,13 +68,32 @@ pub struct PluginDiagnostic {
     pub stable_ptr: SyntaxStablePtrId,
     pub message: String,
     pub severity: Severity,
+    /// The stable error code of the diagnostic.
+    ///
+    /// Plugin diagnostics use the `E3xxx` codes, and default to the generic plugin diagnostic code,
+    /// `E3001`.
+    pub error_code: ErrorCode,
 }
 impl PluginDiagnostic {
     pub fn error(stable_ptr: impl Into<SyntaxStablePtrId>, message: String) -> PluginDiagnostic {
-        PluginDiagnostic { stable_ptr: stable_ptr.into(), message, severity: Severity::Error }
+        PluginDiagnostic {
+            stable_ptr: stable_ptr.into(),
+            message,
+            severity: Severity::Error,
+            error_code: error_code!(E3001),
+        }
     }
     pub fn warning(stable_ptr: impl Into<SyntaxStablePtrId>, message: String) -> PluginDiagnostic {
-        PluginDiagnostic { stable_ptr: stable_ptr.into(), message, severity: Severity::Warning }
+        PluginDiagnostic {
+            stable_ptr: stable_ptr.into(),
+            message,
+            severity: Severity::Warning,
+            error_code: error_code!(E3001),
+        }
+    }
+    /// Sets a specific error code for the diagnostic.
+    pub fn with_error_code(self, error_code: ErrorCode) -> PluginDiagnostic {
+        PluginDiagnostic { error_code, ..self }
     }
 }
 
@@ -1,3 +1,4 @@
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::helpers::WrappedArgListHelper;
 use cairo_lang_syntax::node::{SyntaxNode, TypedSyntaxNode, ast};
@@ -62,13 +63,34 @@ pub fn unsupported_bracket_diagnostic<CallAst: InlineMacroCall>(
     db: &dyn SyntaxGroup,
     macro_ast: &CallAst,
 ) -> CallAst::Result {
-    CallAst::Result::diagnostic_only(PluginDiagnostic::error(
-        macro_ast.arguments(db).left_bracket_stable_ptr(db),
-        format!(
-            "Macro `{}` does not support this bracket type.",
-            macro_ast.path(db).as_syntax_node().get_text_without_trivia(db)
-        ),
-    ))
+    CallAst::Result::diagnostic_only(
+        PluginDiagnostic::error(
+            macro_ast.arguments(db).left_bracket_stable_ptr(db),
+            format!(
+                "Macro `{}` does not support this bracket type.",
+                macro_ast.path(db).as_syntax_node().get_text_without_trivia(db)
+            ),
+        )
+        .with_error_code(error_code!(E3004)),
+    )
+}
+
+/// Returns diagnostics for a wrong number of unnamed arguments of an inline macro.
+pub fn wrong_number_of_args_diagnostic<CallAst: InlineMacroCall + TypedSyntaxNode>(
+    db: &dyn SyntaxGroup,
+    macro_ast: &CallAst,
+    n: usize,
+) -> CallAst::Result {
+    CallAst::Result::diagnostic_only(
+        PluginDiagnostic::error(
+            macro_ast.as_syntax_node().stable_ptr(),
+            format!(
+                "Macro `{}` must have exactly {n} unnamed arguments.",
+                macro_ast.path(db).as_syntax_node().get_text_without_trivia(db)
+            ),
+        )
+        .with_error_code(error_code!(E3005)),
+    )
 }
 
 /// Extracts a single unnamed argument.
@@ -135,18 +157,7 @@ macro_rules! extract_macro_unnamed_args {
 
         let args = $crate::plugin_utils::extract_unnamed_args($db, &macro_arg_list, $n);
         let Some(args) = args else {
-            return $crate::plugin_utils::PluginResultTrait::diagnostic_only(
-                PluginDiagnostic::error(
-                    $syntax,
-                    format!(
-                        "Macro `{}` must have exactly {} unnamed arguments.",
-                        $crate::plugin_utils::InlineMacroCall::path($syntax, $db)
-                            .as_syntax_node()
-                            .get_text_without_trivia($db),
-                        $n
-                    ),
-                ),
-            );
+            return $crate::plugin_utils::wrong_number_of_args_diagnostic($db, $syntax, $n);
         };
         let args: [ast::Expr; $n] = args.try_into().unwrap();
         args
@@ -8,6 +8,7 @@ use cairo_lang_filesystem::ids::FileId;
 use cairo_lang_filesystem::span::TextSpan;
 use cairo_lang_utils::Upcast;
 use itertools::Itertools;
+use serde::Serialize;
 
 use crate::error_code::{ErrorCode, OptionErrorCodeExt};
 use crate::location_marks::get_location_marks;
@@ -17,7 +18,8 @@ use crate::location_marks::get_location_marks;
 mod test;
 
 /// The severity of a diagnostic.
-#[derive(Eq, PartialEq, Hash, Ord, PartialOrd, Clone, Copy, Debug)]
+#[derive(Eq, PartialEq, Hash, Ord, PartialOrd, Clone, Copy, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Severity {
     Error,
     Warning,
@@ -46,6 +48,10 @@ pub trait DiagnosticEntry: Clone + fmt::Debug + Eq + Hash {
     fn error_code(&self) -> Option<ErrorCode> {
         None
     }
+    /// Returns the fixes suggested for the diagnostic.
+    fn fixes(&self, _db: &Self::DbType) -> Vec<DiagnosticFix> {
+        vec![]
+    }
     /// Returns true if the two should be regarded as the same kind when filtering duplicate
     /// diagnostics.
     fn is_same_kind(&self, other: &Self) -> bool;
@@ -129,6 +135,58 @@ impl DebugWithDb<dyn FilesGroup> for DiagnosticNote {
     }
 }
 
+/// How confident a suggested fix is, which decides whether tools may apply it unattended.
+#[derive(Eq, PartialEq, Hash, Clone, Copy, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Applicability {
+    /// The fix is definitely what the user intended, and may be applied automatically.
+    MachineApplicable,
+    /// The fix may not be what the user intended, or leaves placeholders to fill, and should be
+    /// reviewed before it is applied.
+    MaybeIncorrect,
+}
+
+/// A fix suggested for a diagnostic, made of text edits to the source files.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct DiagnosticFix {
+    pub message: String,
+    pub applicability: Applicability,
+    pub edits: Vec<DiagnosticEdit>,
+}
+
+/// A replacement of the text in a location.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct DiagnosticEdit {
+    pub location: DiagnosticLocation,
+    pub replacement: String,
+}
+impl DiagnosticEdit {
+    /// Creates an edit inserting `text` at the start of `location`.
+    pub fn insert_before(location: &DiagnosticLocation, text: impl Into<String>) -> Self {
+        Self {
+            location: DiagnosticLocation {
+                file_id: location.file_id,
+                span: location.span.start_only(),
+            },
+            replacement: text.into(),
+        }
+    }
+
+    /// Creates an edit removing the text in `location`.
+    pub fn remove(location: DiagnosticLocation) -> Self {
+        Self { location, replacement: String::new() }
+    }
+
+    /// Returns the location of the edit in the originating user code.
+    ///
+    /// Returns `None` if the edit is in generated code that was not copied as is from the user
+    /// code, as such an edit has no counterpart there.
+    pub fn user_location(&self, db: &dyn FilesGroup) -> Option<DiagnosticLocation> {
+        let user_location = self.location.user_location(db);
+        (user_location.span.width() == self.location.span.width()).then_some(user_location)
+    }
+}
+
 /// This struct is used to ensure that when an error occurs, a diagnostic is properly reported.
 ///
 /// It must not be constructed directly. Instead, it is returned by [DiagnosticsBuilder::add]
@@ -278,22 +336,7 @@ impl<TEntry: DiagnosticEntry> Diagnostics<TEntry> {
 
     /// Format entries to pairs of severity and message.
     pub fn format_with_severity(&self, db: &TEntry::DbType) -> Vec<FormattedDiagnosticEntry> {
-        let mut res: Vec<FormattedDiagnosticEntry> = Vec::new();
-
-        let files_db = db.upcast();
-        for entry in &self.get_diagnostics_without_duplicates(db) {
-            let mut msg = String::new();
-            msg += &format_diagnostics(files_db, &entry.format(db), entry.location(db));
-            for note in entry.notes(db) {
-                msg += &format!("note: {:?}\n", note.debug(files_db))
-            }
-            msg += "\n";
-
-            let formatted =
-                FormattedDiagnosticEntry::new(entry.severity(), entry.error_code(), msg);
-            res.push(formatted);
-        }
-        res
+        self.format_structured(db).into_iter().map(FormattedDiagnosticEntry::from).collect()
     }
 
     /// Format entries to a [`String`] with messages prefixed by severity.
@@ -1,5 +1,7 @@
 use std::fmt;
 
+use serde::{Serialize, Serializer};
+
 /// The unique and never-changing identifier of an error or warning.
 ///
 /// Valid error codes must start with capital `E` followed by 4 decimal digits, e.g.: `E0001`.
@@ -40,6 +42,12 @@ impl fmt::Display for ErrorCode {
     }
 }
 
+impl Serialize for ErrorCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.0)
+    }
+}
+
 /// Constructs an [`ErrorCode`].
 ///
 /// ```
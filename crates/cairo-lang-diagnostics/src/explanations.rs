@@ -0,0 +1,81 @@
+//! The registry of long-form explanations of error codes.
+//!
+//! Each explanation is a markdown file in the `explanations` directory, named after its error
+//! code. Every error code has an explanation, which is enforced by a test.
+
+use crate::ErrorCode;
+
+#[cfg(test)]
+#[path = "explanations_test.rs"]
+mod test;
+
+/// The URL of the directory holding the explanation files in the repository, at the release tag of
+/// this version, so the links match the explanations compiled into it.
+const EXPLANATIONS_URL: &str = concat!(
+    env!("CARGO_PKG_REPOSITORY"),
+    "blob/v",
+    env!("CARGO_PKG_VERSION"),
+    "/crates/cairo-lang-diagnostics/src/explanations"
+);
+
+/// Registers the explanations of the given error codes, read from their markdown files.
+macro_rules! register_explanations {
+    ($($code:ident),* $(,)?) => {
+        /// The registered explanations, keyed by error code and sorted by it.
+        static EXPLANATIONS: &[(&str, &str)] = &[$(
+            (stringify!($code), include_str!(concat!("explanations/", stringify!($code), ".md"))),
+        )*];
+    };
+}
+
+register_explanations! {
+    // Semantic diagnostics.
+    E0001, E0002, E0003, E0004, E0005, E0006, E0007, E0008, E0009, E0010, E0011, E0012, E0013,
+    E0014, E0015, E0016, E0017, E0018, E0019, E0020, E0021, E0022, E0023, E0024, E0025, E0026,
+    E0027, E0028, E0029, E0030, E0031, E0032, E0033, E0034, E0035, E0036, E0037, E0038, E0039,
+    E0040, E0041, E0042, E0043, E0044, E0045, E0046, E0047, E0048, E0049, E0050, E0051, E0052,
+    E0053, E0054, E0055, E0056, E0057, E0058, E0059, E0060, E0061, E0062, E0063, E0064, E0065,
+    E0066, E0067, E0068, E0069, E0070, E0071, E0072, E0073, E0074, E0075, E0076, E0077, E0078,
+    E0079, E0080, E0081, E0082, E0083, E0084, E0085, E0086, E0087, E0088, E0089, E0090, E0091,
+    E0092, E0093, E0094, E0095, E0096, E0097, E0098, E0099, E0100, E0101, E0102, E0103, E0104,
+    E0105, E0106, E0107, E0108, E0109, E0110, E0111, E0112, E0113, E0114, E0115, E0116, E0117,
+    E0118, E0119, E0120, E0121, E0122, E0123, E0124, E0125, E0126, E0127, E0128, E0129, E0130,
+    E0131, E0132, E0133, E0134, E0135, E0136, E0137, E0138, E0139, E0140, E0141, E0142, E0143,
+    E0144, E0145, E0146, E0147, E0148, E0149, E0150, E0151, E0152, E0153, E0154, E0155, E0156,
+    E0157, E0158, E0159, E0160, E0161, E0162, E0163, E0164, E0165, E0166, E0167, E0168, E0169,
+    E0170, E0171, E0172, E0173, E0174, E0175, E0176,
+    // Parser diagnostics.
+    E1001, E1002, E1003, E1004, E1005, E1006, E1007, E1008, E1009, E1010, E1011, E1012, E1013,
+    E1014, E1015, E1016, E1017, E1018, E1019, E1020, E1021, E1022, E1023, E1024, E1025, E1026,
+    // Lowering diagnostics.
+    E2001, E2002, E2003, E2004, E2005, E2006, E2007, E2008, E2009, E2010, E2011, E2012, E2013,
+    E2014, E2015, E2016, E2017, E2018, E2019, E2020, E2021, E2022, E2023, E2024,
+    // Plugin diagnostics.
+    E3001, E3002, E3003, E3004, E3005, E3006, E3007, E3008, E3009, E3010, E3011, E3012, E3013,
+    E3014, E3015, E3016, E3017, E3018, E3019,
+}
+
+/// Returns the long-form explanation of an error code, in markdown, if one is registered.
+///
+/// ```
+/// # use cairo_lang_diagnostics::error_code_explanation;
+/// assert!(error_code_explanation("E0001").unwrap().contains("never used"));
+/// assert_eq!(error_code_explanation("E9999"), None);
+/// ```
+pub fn error_code_explanation(code: &str) -> Option<&'static str> {
+    let index = EXPLANATIONS.binary_search_by_key(&code, |(code, _)| code).ok()?;
+    Some(EXPLANATIONS[index].1)
+}
+
+impl ErrorCode {
+    /// Returns the long-form explanation of this error code, in markdown, if one is registered.
+    pub fn explanation(self) -> Option<&'static str> {
+        error_code_explanation(self.as_str())
+    }
+
+    /// Returns the URL of the long-form explanation of this error code, if one is registered.
+    pub fn explanation_url(self) -> Option<String> {
+        self.explanation()?;
+        Some(format!("{EXPLANATIONS_URL}/{self}.md"))
+    }
+}
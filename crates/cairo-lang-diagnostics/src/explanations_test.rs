@@ -0,0 +1,72 @@
+use std::path::Path;
+
+use super::{EXPLANATIONS, error_code_explanation};
+use crate::ErrorCode;
+
+#[test]
+fn test_explanations_are_sorted_and_unique() {
+    for window in EXPLANATIONS.windows(2) {
+        let [(first, _), (second, _)] = window else { unreachable!() };
+        assert!(first < second, "`{first}` is not sorted before `{second}`.");
+    }
+}
+
+#[test]
+fn test_explanations_are_well_formed() {
+    for (code, explanation) in EXPLANATIONS {
+        // Panics on malformed error codes.
+        ErrorCode::new(code);
+        assert!(
+            explanation.contains("```cairo\n"),
+            "The explanation of `{code}` must contain a Cairo example."
+        );
+        assert!(
+            explanation.ends_with('\n'),
+            "The explanation of `{code}` must end with a newline."
+        );
+    }
+}
+
+#[test]
+fn test_error_code_explanation() {
+    assert_eq!(error_code_explanation("E0001"), Some(EXPLANATIONS[0].1));
+    assert_eq!(ErrorCode::new("E0001").explanation(), Some(EXPLANATIONS[0].1));
+    assert_eq!(
+        ErrorCode::new("E0001").explanation_url().unwrap(),
+        format!(
+            "https://github.com/starkware-libs/cairo/blob/v{}/crates/cairo-lang-diagnostics/src/\
+             explanations/E0001.md",
+            env!("CARGO_PKG_VERSION")
+        )
+    );
+    assert_eq!(ErrorCode::new("E9999").explanation_url(), None);
+}
+
+/// Collects the error codes used through `error_code!` in the Rust sources under `dir`.
+fn collect_used_error_codes(dir: &Path, codes: &mut Vec<String>) {
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collect_used_error_codes(&path, codes);
+        } else if path.extension().is_some_and(|extension| extension == "rs") {
+            let content = std::fs::read_to_string(&path).unwrap();
+            for (index, prefix) in content.match_indices("error_code!(") {
+                let rest = &content[index + prefix.len()..];
+                let code: String = rest.chars().take_while(|c| c.is_ascii_alphanumeric()).collect();
+                if !code.is_empty() {
+                    codes.push(code);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_every_error_code_is_explained() {
+    let mut codes = vec![];
+    collect_used_error_codes(&Path::new(env!("CARGO_MANIFEST_DIR")).join(".."), &mut codes);
+    assert!(!codes.is_empty(), "No usage of `error_code!` was found.");
+    for code in codes {
+        assert!(error_code_explanation(&code).is_some(), "Error code `{code}` has no explanation.");
+    }
+}
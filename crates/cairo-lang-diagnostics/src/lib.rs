@@ -2,13 +2,21 @@
 //! source files.
 
 pub use diagnostics::{
-    DiagnosticAdded, DiagnosticEntry, DiagnosticLocation, DiagnosticNote, Diagnostics,
-    DiagnosticsBuilder, FormattedDiagnosticEntry, Maybe, Severity, ToMaybe, ToOption,
-    format_diagnostics, skip_diagnostic,
+    Applicability, DiagnosticAdded, DiagnosticEdit, DiagnosticEntry, DiagnosticFix,
+    DiagnosticLocation, DiagnosticNote, Diagnostics, DiagnosticsBuilder, FormattedDiagnosticEntry,
+    Maybe, Severity, ToMaybe, ToOption, format_diagnostics, skip_diagnostic,
 };
 pub use error_code::{ErrorCode, OptionErrorCodeExt};
+pub use explanations::error_code_explanation;
 pub use location_marks::get_location_marks;
+pub use sarif::sarif_log;
+pub use structured::{
+    StructuredDiagnostic, StructuredEdit, StructuredFix, StructuredNote, StructuredSpan,
+};
 
 mod diagnostics;
 mod error_code;
+mod explanations;
 mod location_marks;
+mod sarif;
+mod structured;
@@ -0,0 +1,120 @@
+//! Emission of diagnostics as a [SARIF](https://sarifweb.azurewebsites.net/) 2.1.0 log, the format
+//! ingested by code scanning tools and code review bots.
+
+use itertools::Itertools;
+use serde_json::{Value, json};
+
+use crate::{Severity, StructuredDiagnostic, StructuredFix, StructuredSpan};
+
+#[cfg(test)]
+#[path = "sarif_test.rs"]
+mod test;
+
+/// The URL of the SARIF 2.1.0 JSON schema.
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Returns a SARIF log with a single run of the compiler, reporting the given diagnostics.
+pub fn sarif_log(diagnostics: &[StructuredDiagnostic]) -> Value {
+    let rules = diagnostics
+        .iter()
+        .filter_map(|diagnostic| diagnostic.code)
+        .sorted()
+        .dedup()
+        .map(|code| {
+            let mut rule = json!({ "id": code.as_str() });
+            if let Some(url) = code.explanation_url() {
+                rule["helpUri"] = url.into();
+            }
+            rule
+        })
+        .collect_vec();
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "cairo",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": env!("CARGO_PKG_REPOSITORY"),
+                    "rules": rules,
+                }
+            },
+            "columnKind": "unicodeCodePoints",
+            "results": diagnostics.iter().map(sarif_result).collect_vec(),
+        }]
+    })
+}
+
+/// Returns the SARIF result of a diagnostic.
+fn sarif_result(diagnostic: &StructuredDiagnostic) -> Value {
+    let level = match diagnostic.severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+    };
+    let mut result = json!({
+        "level": level,
+        "message": { "text": diagnostic.message },
+        "locations": diagnostic.span.iter().map(sarif_location).collect_vec(),
+        "relatedLocations": diagnostic
+            .notes
+            .iter()
+            .filter_map(|note| {
+                let mut location = sarif_location(note.span.as_ref()?);
+                location["message"] = json!({ "text": note.message });
+                Some(location)
+            })
+            .collect_vec(),
+        "fixes": diagnostic.fixes.iter().map(sarif_fix).collect_vec(),
+    });
+    if let Some(code) = diagnostic.code {
+        result["ruleId"] = code.as_str().into();
+    }
+    result
+}
+
+/// Returns the SARIF fix of a suggested fix, with its edits grouped by file.
+fn sarif_fix(fix: &StructuredFix) -> Value {
+    json!({
+        "description": { "text": fix.message },
+        "artifactChanges": fix
+            .edits
+            .iter()
+            .into_group_map_by(|edit| &edit.span.file)
+            .into_iter()
+            .sorted_by_key(|(file, _)| *file)
+            .map(|(file, edits)| json!({
+                "artifactLocation": { "uri": file },
+                "replacements": edits
+                    .into_iter()
+                    .map(|edit| json!({
+                        "deletedRegion": sarif_region(&edit.span),
+                        "insertedContent": { "text": edit.replacement },
+                    }))
+                    .collect_vec(),
+            }))
+            .collect_vec(),
+    })
+}
+
+/// Returns the SARIF location of a span.
+fn sarif_location(span: &StructuredSpan) -> Value {
+    json!({
+        "physicalLocation": {
+            "artifactLocation": { "uri": span.file },
+            "region": sarif_region(span),
+        }
+    })
+}
+
+/// Returns the SARIF region of a span.
+fn sarif_region(span: &StructuredSpan) -> Value {
+    json!({
+        "startLine": span.line_start,
+        "startColumn": span.column_start,
+        "endLine": span.line_end,
+        "endColumn": span.column_end,
+        "byteOffset": span.byte_start,
+        "byteLength": span.byte_end - span.byte_start,
+    })
+}
@@ -0,0 +1,97 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+use super::sarif_log;
+use crate::{
+    Applicability, Severity, StructuredDiagnostic, StructuredEdit, StructuredFix, StructuredNote,
+    StructuredSpan, error_code,
+};
+
+#[test]
+fn test_sarif_log() {
+    let span = StructuredSpan {
+        file: "lib.cairo".into(),
+        byte_start: 15,
+        byte_end: 16,
+        line_start: 2,
+        column_start: 9,
+        line_end: 2,
+        column_end: 10,
+    };
+    let diagnostics = [
+        StructuredDiagnostic {
+            severity: Severity::Warning,
+            code: Some(error_code!(E0001)),
+            message: "Unused variable.".into(),
+            span: Some(span.clone()),
+            notes: vec![
+                StructuredNote { message: "Declared here".into(), span: Some(span.clone()) },
+                StructuredNote { message: "Plain note".into(), span: None },
+            ],
+            fixes: vec![StructuredFix {
+                message: "Rename to `_x`.".into(),
+                applicability: Applicability::MachineApplicable,
+                edits: vec![StructuredEdit { span, replacement: "_x".into() }],
+            }],
+            rendered: String::new(),
+        },
+        StructuredDiagnostic::text_only(Severity::Error, "lib.cairo not found\n".into()),
+    ];
+    let region = json!({
+        "startLine": 2,
+        "startColumn": 9,
+        "endLine": 2,
+        "endColumn": 10,
+        "byteOffset": 15,
+        "byteLength": 1,
+    });
+    let location = json!({
+        "physicalLocation": {
+            "artifactLocation": { "uri": "lib.cairo" },
+            "region": region,
+        }
+    });
+    let mut related_location = location.clone();
+    related_location["message"] = json!({ "text": "Declared here" });
+
+    let log = sarif_log(&diagnostics);
+    assert_eq!(log["version"], "2.1.0");
+    let run = &log["runs"][0];
+    assert_eq!(run["tool"]["driver"]["name"], "cairo");
+    assert_eq!(
+        run["tool"]["driver"]["rules"],
+        json!([{
+            "id": "E0001",
+            "helpUri": error_code!(E0001).explanation_url().unwrap(),
+        }])
+    );
+    assert_eq!(
+        run["results"],
+        json!([
+            {
+                "ruleId": "E0001",
+                "level": "warning",
+                "message": { "text": "Unused variable." },
+                "locations": [location],
+                "relatedLocations": [related_location],
+                "fixes": [{
+                    "description": { "text": "Rename to `_x`." },
+                    "artifactChanges": [{
+                        "artifactLocation": { "uri": "lib.cairo" },
+                        "replacements": [{
+                            "deletedRegion": region,
+                            "insertedContent": { "text": "_x" },
+                        }],
+                    }],
+                }],
+            },
+            {
+                "level": "error",
+                "message": { "text": "lib.cairo not found" },
+                "locations": [],
+                "relatedLocations": [],
+                "fixes": [],
+            },
+        ])
+    );
+}
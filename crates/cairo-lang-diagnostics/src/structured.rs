@@ -0,0 +1,197 @@
+//! A structured model of diagnostics, for consumption by tools such as CI annotators.
+//!
+//! Unlike [FormattedDiagnosticEntry], which only holds pre-rendered text, a
+//! [StructuredDiagnostic] keeps the location of the diagnostic and of its notes as spans in the
+//! originating user files, and may be serialized to JSON.
+
+use cairo_lang_debug::debug::DebugWithDb;
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::span::TextOffset;
+use serde::Serialize;
+
+use crate::{
+    Applicability, DiagnosticEdit, DiagnosticEntry, DiagnosticFix, DiagnosticLocation,
+    DiagnosticNote, Diagnostics, ErrorCode, FormattedDiagnosticEntry, Severity, format_diagnostics,
+};
+
+#[cfg(test)]
+#[path = "structured_test.rs"]
+mod test;
+
+/// A diagnostic with its location and notes resolved to spans in the user files.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct StructuredDiagnostic {
+    /// The severity of the diagnostic.
+    pub severity: Severity,
+    /// The error code of the diagnostic, if any.
+    pub code: Option<ErrorCode>,
+    /// The message of the diagnostic.
+    pub message: String,
+    /// The location of the diagnostic, if it has one.
+    pub span: Option<StructuredSpan>,
+    /// The notes attached to the diagnostic.
+    pub notes: Vec<StructuredNote>,
+    /// The fixes suggested for the diagnostic.
+    pub fixes: Vec<StructuredFix>,
+    /// The diagnostic rendered as text, without the severity and code prefix.
+    pub rendered: String,
+}
+impl StructuredDiagnostic {
+    /// Creates a diagnostic without a location, rendered as the given text.
+    pub fn text_only(severity: Severity, rendered: String) -> Self {
+        Self {
+            severity,
+            code: None,
+            message: rendered.trim_end().to_string(),
+            span: None,
+            notes: vec![],
+            fixes: vec![],
+            rendered,
+        }
+    }
+
+    /// Creates the structured form of a diagnostic entry.
+    pub fn new<TEntry: DiagnosticEntry>(db: &TEntry::DbType, entry: &TEntry) -> Self {
+        let files_db = db.upcast();
+        let message = entry.format(db);
+        let location = entry.location(db);
+        let mut rendered = format_diagnostics(files_db, &message, location.clone());
+        for note in entry.notes(db) {
+            rendered += &format!("note: {:?}\n", note.debug(files_db))
+        }
+        rendered += "\n";
+        Self {
+            severity: entry.severity(),
+            code: entry.error_code(),
+            message,
+            span: Some(StructuredSpan::new(files_db, &location)),
+            notes: entry.notes(db).iter().map(|note| StructuredNote::new(files_db, note)).collect(),
+            fixes: entry
+                .fixes(db)
+                .iter()
+                .filter_map(|fix| StructuredFix::new(files_db, fix))
+                .collect(),
+            rendered,
+        }
+    }
+}
+
+impl From<StructuredDiagnostic> for FormattedDiagnosticEntry {
+    fn from(diagnostic: StructuredDiagnostic) -> Self {
+        Self::new(diagnostic.severity, diagnostic.code, diagnostic.rendered)
+    }
+}
+
+/// A span in a user file.
+///
+/// Lines and columns are 1-based, and columns are counted in characters. The end is exclusive.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize)]
+pub struct StructuredSpan {
+    /// The path of the file.
+    pub file: String,
+    /// The byte offset of the start of the span.
+    pub byte_start: usize,
+    /// The byte offset of the end of the span.
+    pub byte_end: usize,
+    /// The line of the start of the span.
+    pub line_start: usize,
+    /// The column of the start of the span.
+    pub column_start: usize,
+    /// The line of the end of the span.
+    pub line_end: usize,
+    /// The column of the end of the span.
+    pub column_end: usize,
+}
+impl StructuredSpan {
+    /// Creates the span of the user code a location originates from.
+    pub fn new(db: &dyn FilesGroup, location: &DiagnosticLocation) -> Self {
+        let DiagnosticLocation { file_id, span } = location.user_location(db);
+        let position = |offset: TextOffset| {
+            offset.position_in_file(db, file_id).map_or((1, 1), |pos| (pos.line + 1, pos.col + 1))
+        };
+        let (line_start, column_start) = position(span.start);
+        let (line_end, column_end) = position(span.end);
+        Self {
+            file: file_id.full_path(db),
+            byte_start: span.start.as_u32() as usize,
+            byte_end: span.end.as_u32() as usize,
+            line_start,
+            column_start,
+            line_end,
+            column_end,
+        }
+    }
+}
+
+/// A note attached to a diagnostic.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct StructuredNote {
+    /// The text of the note.
+    pub message: String,
+    /// The location the note refers to, if any.
+    pub span: Option<StructuredSpan>,
+}
+impl StructuredNote {
+    /// Creates the structured form of a diagnostic note.
+    pub fn new(db: &dyn FilesGroup, note: &DiagnosticNote) -> Self {
+        Self {
+            message: note.text.clone(),
+            span: note.location.as_ref().map(|location| StructuredSpan::new(db, location)),
+        }
+    }
+}
+
+/// A fix suggested for a diagnostic, made of text edits to the user files.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize)]
+pub struct StructuredFix {
+    /// A description of the fix.
+    pub message: String,
+    /// Whether the fix may be applied without the user's review.
+    pub applicability: Applicability,
+    /// The edits applying the fix.
+    pub edits: Vec<StructuredEdit>,
+}
+impl StructuredFix {
+    /// Creates the structured form of a diagnostic fix.
+    ///
+    /// Returns `None` if any of its edits has no counterpart in the user code.
+    pub fn new(db: &dyn FilesGroup, fix: &DiagnosticFix) -> Option<Self> {
+        Some(Self {
+            message: fix.message.clone(),
+            applicability: fix.applicability,
+            edits: fix
+                .edits
+                .iter()
+                .map(|edit| StructuredEdit::new(db, edit))
+                .collect::<Option<_>>()?,
+        })
+    }
+}
+
+/// A replacement of the text in a span.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize)]
+pub struct StructuredEdit {
+    /// The span to replace.
+    pub span: StructuredSpan,
+    /// The text to replace the span with.
+    pub replacement: String,
+}
+impl StructuredEdit {
+    /// Creates the structured form of a diagnostic edit, if it has a counterpart in the user code.
+    fn new(db: &dyn FilesGroup, edit: &DiagnosticEdit) -> Option<Self> {
+        Some(Self {
+            span: StructuredSpan::new(db, &edit.user_location(db)?),
+            replacement: edit.replacement.clone(),
+        })
+    }
+}
+
+impl<TEntry: DiagnosticEntry> Diagnostics<TEntry> {
+    /// Converts the entries, without duplicates, to their structured form.
+    pub fn format_structured(&self, db: &TEntry::DbType) -> Vec<StructuredDiagnostic> {
+        self.get_diagnostics_without_duplicates(db)
+            .iter()
+            .map(|entry| StructuredDiagnostic::new(db, entry))
+            .collect()
+    }
+}
@@ -0,0 +1,154 @@
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::ids::{FileId, FileKind, FileLongId, VirtualFile};
+use cairo_lang_filesystem::span::{TextOffset, TextSpan, TextWidth};
+use cairo_lang_filesystem::test_utils::FilesDatabaseForTesting;
+use cairo_lang_utils::Intern;
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use test_log::test;
+
+use super::{StructuredDiagnostic, StructuredEdit, StructuredFix, StructuredNote, StructuredSpan};
+use crate::{
+    Applicability, DiagnosticEdit, DiagnosticEntry, DiagnosticFix, DiagnosticLocation,
+    DiagnosticNote, DiagnosticsBuilder, ErrorCode, FormattedDiagnosticEntry, Severity, error_code,
+};
+
+// Test diagnostic, with a note pointing to the second line.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct NotedDiag {
+    file_id: FileId,
+    notes: Vec<DiagnosticNote>,
+    fixes: Vec<DiagnosticFix>,
+}
+impl DiagnosticEntry for NotedDiag {
+    type DbType = dyn FilesGroup;
+
+    fn format(&self, _db: &dyn FilesGroup) -> String {
+        "Noted diagnostic.".into()
+    }
+
+    fn location(&self, _db: &dyn FilesGroup) -> DiagnosticLocation {
+        location(self.file_id, 1, 3)
+    }
+
+    fn notes(&self, _db: &dyn FilesGroup) -> &[DiagnosticNote] {
+        &self.notes
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn error_code(&self) -> Option<ErrorCode> {
+        Some(error_code!(E0001))
+    }
+
+    fn fixes(&self, _db: &dyn FilesGroup) -> Vec<DiagnosticFix> {
+        self.fixes.clone()
+    }
+
+    fn is_same_kind(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+/// Returns the location of the given byte range in the file.
+fn location(file_id: FileId, start: u32, end: u32) -> DiagnosticLocation {
+    DiagnosticLocation {
+        file_id,
+        span: TextSpan {
+            start: TextOffset::default().add_width(TextWidth::new_for_testing(start)),
+            end: TextOffset::default().add_width(TextWidth::new_for_testing(end)),
+        },
+    }
+}
+
+fn setup() -> (FilesDatabaseForTesting, FileId) {
+    let db_val = FilesDatabaseForTesting::default();
+    let file_id = FileLongId::Virtual(VirtualFile {
+        parent: None,
+        name: "dummy_file.cairo".into(),
+        content: "abcd\nefg.\n".into(),
+        code_mappings: [].into(),
+        kind: FileKind::Module,
+    })
+    .intern(&db_val);
+    (db_val, file_id)
+}
+
+#[test]
+fn test_structured_diagnostics() {
+    let (db_val, file_id) = setup();
+    let notes = vec![
+        DiagnosticNote::with_location("Defined here".into(), location(file_id, 5, 8)),
+        DiagnosticNote::text_only("Plain note".into()),
+    ];
+
+    let fixes = vec![DiagnosticFix {
+        message: "Prefix with `_`.".into(),
+        applicability: Applicability::MachineApplicable,
+        edits: vec![
+            DiagnosticEdit::insert_before(&location(file_id, 1, 3), "_"),
+            DiagnosticEdit::remove(location(file_id, 8, 9)),
+        ],
+    }];
+
+    let mut diagnostics: DiagnosticsBuilder<NotedDiag> = DiagnosticsBuilder::default();
+    diagnostics.add(NotedDiag { file_id, notes, fixes });
+    let structured = diagnostics.build().format_structured(&db_val);
+
+    let span = |byte_start, byte_end, line, column_start, column_end| StructuredSpan {
+        file: "dummy_file.cairo".into(),
+        byte_start,
+        byte_end,
+        line_start: line,
+        column_start,
+        line_end: line,
+        column_end,
+    };
+    assert_eq!(structured, vec![StructuredDiagnostic {
+        severity: Severity::Warning,
+        code: Some(error_code!(E0001)),
+        message: "Noted diagnostic.".into(),
+        span: Some(span(1, 3, 1, 2, 4)),
+        notes: vec![
+            StructuredNote { message: "Defined here".into(), span: Some(span(5, 8, 2, 1, 4)) },
+            StructuredNote { message: "Plain note".into(), span: None },
+        ],
+        fixes: vec![StructuredFix {
+            message: "Prefix with `_`.".into(),
+            applicability: Applicability::MachineApplicable,
+            edits: vec![
+                StructuredEdit { span: span(1, 1, 1, 2, 2), replacement: "_".into() },
+                StructuredEdit { span: span(8, 9, 2, 4, 5), replacement: "".into() },
+            ],
+        }],
+        rendered: indoc! {"
+            Noted diagnostic.
+             --> dummy_file.cairo:1:2
+            abcd
+             ^^
+            note: Defined here:
+              --> dummy_file.cairo:2:1
+            efg.
+            ^*^
+            note: Plain note
+
+        "}
+        .into(),
+    }]);
+    assert_eq!(
+        FormattedDiagnosticEntry::from(structured[0].clone()).to_string(),
+        format!("warning[E0001]: {}", structured[0].rendered)
+    );
+}
+
+#[test]
+fn test_structured_diagnostic_json() {
+    let diagnostic =
+        StructuredDiagnostic::text_only(Severity::Error, "lib.cairo not found\n".into());
+    assert_eq!(
+        serde_json::to_string(&diagnostic).unwrap(),
+        r#"{"severity":"error","code":null,"message":"lib.cairo not found","span":null,"notes":[],"fixes":[],"rendered":"lib.cairo not found\n"}"#
+    );
+}
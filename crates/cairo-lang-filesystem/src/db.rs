@@ -85,6 +85,12 @@ pub struct CrateSettings {
 
     #[serde(default)]
     pub experimental_features: ExperimentalFeaturesConfig,
+
+    /// The crate-level lint levels, keyed by lint name.
+    ///
+    /// These are overridden by `#[allow(...)]`, `#[warn(...)]` and `#[deny(...)]` attributes.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub lints: BTreeMap<String, LintLevel>,
 }
 
 /// The Cairo edition of a crate.
@@ -134,6 +140,19 @@ impl Edition {
     }
 }
 
+/// The level of a lint, controlling how the warnings it covers are reported.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LintLevel {
+    /// The warnings are not reported.
+    Allow,
+    /// The warnings are reported as warnings.
+    #[default]
+    Warn,
+    /// The warnings are reported as errors.
+    Deny,
+}
+
 /// The settings for a dependency.
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DependencySettings {
@@ -239,6 +258,7 @@ pub fn init_dev_corelib(db: &mut (dyn FilesGroup + 'static), core_lib_dir: PathB
                     negative_impls: true,
                     coupons: true,
                 },
+                lints: Default::default(),
             },
         }),
     );
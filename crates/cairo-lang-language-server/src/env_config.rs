@@ -15,6 +15,7 @@ use tracing::debug;
 pub const CAIRO_LS_DB_REPLACE_INTERVAL: &'_ str = "CAIRO_LS_DB_REPLACE_INTERVAL";
 pub const CAIRO_LS_LOG: &'_ str = "CAIRO_LS_LOG";
 pub const CAIRO_LS_PROFILE: &'_ str = "CAIRO_LS_PROFILE";
+pub const CAIRO_TEST: &'_ str = "CAIRO_TEST";
 pub const SCARB: &'_ str = "SCARB";
 
 /// Interval between compiler database regenerations (to free unused memory).
@@ -38,6 +39,11 @@ pub fn tracing_profile() -> bool {
     env::var_os(CAIRO_LS_PROFILE).map(env_to_bool).unwrap_or_default()
 }
 
+/// Path to the `cairo-test` binary to run tests with, looked up in `PATH` by default.
+pub fn cairo_test_path() -> PathBuf {
+    env::var_os(CAIRO_TEST).map(PathBuf::from).unwrap_or_else(|| PathBuf::from("cairo-test"))
+}
+
 /// Path to the Scarb binary to call during analysis.
 pub fn scarb_path() -> Option<PathBuf> {
     env::var_os(SCARB).map(PathBuf::from)
@@ -48,6 +54,7 @@ pub fn report_to_logs() {
     debug!("{CAIRO_LS_DB_REPLACE_INTERVAL}={:?}", db_replace_interval());
     debug!("{CAIRO_LS_LOG}={}", log_env_filter());
     debug!("{CAIRO_LS_PROFILE}={}", tracing_profile());
+    debug!("{CAIRO_TEST}={}", cairo_test_path().display());
     debug!("{SCARB}={}", scarb_path().map(|p| p.display().to_string()).unwrap_or_default());
 }
 
@@ -1,19 +1,10 @@
-use cairo_lang_syntax::node::SyntaxNode;
-use cairo_lang_utils::ordered_hash_map::{Entry, OrderedHashMap};
-use itertools::Itertools;
-use lsp_types::{
-    CodeAction, CodeActionOrCommand, CodeActionParams, CodeActionResponse, Diagnostic,
-    NumberOrString,
-};
-use tracing::{debug, warn};
+use lsp_types::{CodeAction, CodeActionOrCommand, CodeActionParams, CodeActionResponse, Range};
+use tracing::warn;
 
 use crate::lang::db::{AnalysisDatabase, LsSyntaxGroup};
 use crate::lang::lsp::{LsProtoGroup, ToCairo};
 
-mod add_missing_trait;
 mod expand_macro;
-mod fill_struct_fields;
-mod rename_unused_variable;
 
 /// Compute commands for a given text document and range. These commands are typically code fixes to
 /// either fix problems or to beautify/refactor code.
@@ -22,87 +13,53 @@ pub fn code_actions(params: CodeActionParams, db: &AnalysisDatabase) -> Option<C
     let file_id = db.file_for_url(&params.text_document.uri)?;
     let node = db.find_syntax_node_at_position(file_id, params.range.start.to_cairo())?;
 
-    actions.extend(
-        get_code_actions_for_diagnostics(db, &node, &params)
-            .into_iter()
-            .map(CodeActionOrCommand::from),
-    );
+    actions.extend(get_diagnostic_fixes(&params).into_iter().map(CodeActionOrCommand::from));
 
-    actions.extend(
-        expand_macro::expand_macro(db, node.clone()).into_iter().map(CodeActionOrCommand::from),
-    );
+    actions.extend(expand_macro::expand_macro(db, node).into_iter().map(CodeActionOrCommand::from));
 
     Some(actions)
 }
 
-/// Generate code actions for a given diagnostics in context of [`CodeActionParams`].
+/// Returns the quick fixes suggested by the compiler for the diagnostics in the requested range.
 ///
-/// # Arguments
-///
-/// * `db` - A reference to the Salsa database.
-/// * `node` - The syntax node where the diagnostic is located.
-/// * `params` - The parameters for the code action request.
-///
-/// # Returns
-///
-/// A vector of [`CodeAction`] objects that can be applied to resolve the diagnostics.
-fn get_code_actions_for_diagnostics(
-    db: &AnalysisDatabase,
-    node: &SyntaxNode,
-    params: &CodeActionParams,
-) -> Vec<CodeAction> {
-    let mut diagnostic_groups_by_codes: OrderedHashMap<String, Vec<&Diagnostic>> =
-        OrderedHashMap::default();
-
-    for diagnostic in params.context.diagnostics.iter() {
-        if let Some(code) = extract_code(diagnostic) {
-            match diagnostic_groups_by_codes.entry(code.to_owned()) {
-                Entry::Occupied(mut entry) => {
-                    entry.get_mut().push(diagnostic);
+/// The fixes are passed to the client in the `data` of each diagnostic when diagnostics are
+/// published, and the client sends them back along with the diagnostics.
+fn get_diagnostic_fixes(params: &CodeActionParams) -> Vec<CodeAction> {
+    let mut actions: Vec<CodeAction> = vec![];
+    for diagnostic in &params.context.diagnostics {
+        let Some(data) = &diagnostic.data else {
+            continue;
+        };
+        if !ranges_intersect(diagnostic.range, params.range) {
+            continue;
+        }
+        let fixes: Vec<CodeAction> = match serde_json::from_value(data.clone()) {
+            Ok(fixes) => fixes,
+            Err(err) => {
+                warn!("diagnostic data is not a list of fixes: {err}");
+                continue;
+            }
+        };
+        for fix in fixes {
+            // Diagnostics of the same code, e.g. one per missing struct member, may suggest the
+            // same fix, which is offered once.
+            match actions
+                .iter_mut()
+                .find(|action| action.title == fix.title && action.edit == fix.edit)
+            {
+                Some(action) => {
+                    action.diagnostics.get_or_insert_with(Vec::new).push(diagnostic.clone())
                 }
-                Entry::Vacant(entry) => {
-                    entry.insert(vec![diagnostic]);
+                None => {
+                    actions.push(CodeAction { diagnostics: Some(vec![diagnostic.clone()]), ..fix })
                 }
             }
         }
     }
-
-    diagnostic_groups_by_codes
-        .into_iter()
-        .flat_map(|(code, diagnostics)| match code.as_str() {
-            "E0001" => diagnostics
-                .into_iter()
-                .map(|diagnostic| {
-                    rename_unused_variable::rename_unused_variable(
-                        db,
-                        node,
-                        diagnostic.clone(),
-                        params.text_document.uri.clone(),
-                    )
-                })
-                .collect_vec(),
-            "E0002" => {
-                add_missing_trait::add_missing_trait(db, node, params.text_document.uri.clone())
-            }
-            "E0003" => fill_struct_fields::fill_struct_fields(db, node.clone(), params)
-                .map(|result| vec![result])
-                .unwrap_or_default(),
-            _ => {
-                debug!("no code actions for diagnostic code: {code}");
-                vec![]
-            }
-        })
-        .collect_vec()
+    actions
 }
 
-/// Extracts [`Diagnostic`] code if it's given as a string, returns None otherwise.
-fn extract_code(diagnostic: &Diagnostic) -> Option<&str> {
-    match &diagnostic.code {
-        Some(NumberOrString::String(code)) => Some(code),
-        Some(NumberOrString::Number(code)) => {
-            warn!("diagnostic code is not a string: `{code}`");
-            None
-        }
-        None => None,
-    }
+/// Returns whether two ranges intersect, including when one ends where the other starts.
+fn ranges_intersect(a: Range, b: Range) -> bool {
+    a.start <= b.end && b.start <= a.end
 }
@@ -0,0 +1,97 @@
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::{ModuleId, ModuleItemId, TopLevelLanguageElementId};
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_syntax::node::TypedStablePtr;
+use cairo_lang_syntax::node::helpers::QueryAttrs;
+use cairo_lang_test_plugin::TEST_ATTR;
+use cairo_lang_utils::Upcast;
+use lsp_types::{CodeLens, CodeLensParams, Command, Url};
+
+use crate::lang::db::AnalysisDatabase;
+use crate::lang::inspect::names::definition_name_location;
+use crate::lang::lsp::{LsProtoGroup, ToLsp};
+use crate::server::commands::RunTestArguments;
+
+/// Get the code lenses of a text document: a lens running each test function, and a lens running
+/// all the tests of each module containing tests.
+pub fn code_lens(params: CodeLensParams, db: &AnalysisDatabase) -> Option<Vec<CodeLens>> {
+    let uri = params.text_document.uri;
+    let file = db.file_for_url(&uri)?;
+    // Modules are ordered from the crate roots downwards, so the first module is the one the file
+    // is the main file of, and any other modules are inline modules defined in it.
+    let module_id = *db.file_modules(file).ok()?.first()?;
+    let mut lenses = vec![];
+    test_lenses(db, file, &uri, module_id, &mut lenses);
+    Some(lenses)
+}
+
+/// Collects the lenses of the tests in a module and its submodules which are defined in the file.
+///
+/// Returns whether the module contains any tests.
+fn test_lenses(
+    db: &AnalysisDatabase,
+    file: FileId,
+    uri: &Url,
+    module_id: ModuleId,
+    lenses: &mut Vec<CodeLens>,
+) -> bool {
+    let defs_db: &dyn DefsGroup = db.upcast();
+    let syntax_db = db.upcast();
+    let Ok(items) = db.module_items(module_id) else { return false };
+    let mut has_tests = false;
+    for item in items.iter() {
+        match *item {
+            ModuleItemId::FreeFunction(id)
+                if id.stable_ptr(defs_db).lookup(syntax_db).has_attr(syntax_db, TEST_ATTR) =>
+            {
+                has_tests = true;
+                let filter = id.full_path(defs_db);
+                lenses.extend(run_test_lens(db, file, uri, id, "▶ Run test", filter, true));
+            }
+            ModuleItemId::Submodule(id) => {
+                let mut submodule_lenses = vec![];
+                if test_lenses(db, file, uri, ModuleId::Submodule(id), &mut submodule_lenses) {
+                    has_tests = true;
+                    // Test names are full paths, so the tests of the module share its path prefix.
+                    let filter = format!("{}::", id.full_path(defs_db));
+                    lenses.extend(run_test_lens(db, file, uri, id, "▶ Run tests", filter, false));
+                }
+                lenses.extend(submodule_lenses);
+            }
+            _ => {}
+        }
+    }
+    has_tests
+}
+
+/// Creates a lens running the tests matching the filter, located at the name of the given item.
+/// If `exact` is set, only the test whose full name is the filter is run.
+///
+/// Returns `None` if the name of the item is not in the file.
+fn run_test_lens(
+    db: &AnalysisDatabase,
+    file: FileId,
+    uri: &Url,
+    item: impl TopLevelLanguageElementId,
+    title: &str,
+    filter: String,
+    exact: bool,
+) -> Option<CodeLens> {
+    let defs_db: &dyn DefsGroup = db.upcast();
+    let name = item.name(defs_db);
+    let (name_file, name_span) =
+        definition_name_location(db, item.untyped_stable_ptr(defs_db), &name)?;
+    if name_file != file {
+        return None;
+    }
+    let arguments = RunTestArguments { uri: uri.clone(), filter, exact };
+    Some(CodeLens {
+        range: name_span.position_in_file(db.upcast(), file)?.to_lsp(),
+        command: Some(Command {
+            title: title.to_string(),
+            command: "cairo.runTest".to_string(),
+            arguments: Some(vec![serde_json::to_value(arguments).ok()?]),
+        }),
+        data: None,
+    })
+}
@@ -14,6 +14,7 @@ use cairo_lang_semantic::items::function_with_body::SemanticExprLookup;
 use cairo_lang_semantic::items::us::SemanticUseEx;
 use cairo_lang_semantic::items::visibility::peek_visible_in;
 use cairo_lang_semantic::lookup_item::{HasResolverData, LookupItemEx};
+use cairo_lang_semantic::lsp_helpers::find_methods_for_type;
 use cairo_lang_semantic::resolve::{ResolvedConcreteItem, ResolvedGenericItem, Resolver};
 use cairo_lang_semantic::types::peel_snapshots;
 use cairo_lang_semantic::{ConcreteTypeId, Pattern, TypeLongId};
@@ -24,7 +25,6 @@ use lsp_types::{CompletionItem, CompletionItemKind, InsertTextFormat, Position,
 use tracing::debug;
 
 use crate::lang::db::{AnalysisDatabase, LsSemanticGroup};
-use crate::lang::inspect::methods::find_methods_for_type;
 use crate::lang::lsp::ToLsp;
 
 pub fn generic_completions(
@@ -199,7 +199,7 @@ pub fn dot_completions(
     let function_with_body = lookup_item_id.function_with_body()?;
     let module_id = function_with_body.module_file_id(db.upcast()).0;
     let resolver_data = lookup_item_id.resolver_data(db).ok()?;
-    let resolver = Resolver::with_data(
+    let mut resolver = Resolver::with_data(
         db,
         resolver_data.as_ref().clone_with_inference_id(db, InferenceId::NoContext),
     );
@@ -231,7 +231,7 @@ pub fn dot_completions(
         TextOffset::default()
     };
     let position = offset.position_in_file(db.upcast(), file_id).unwrap().to_lsp();
-    let relevant_methods = find_methods_for_type(db, resolver, ty, stable_ptr);
+    let relevant_methods = find_methods_for_type(db, &mut resolver, ty, stable_ptr);
 
     let mut completions = Vec::new();
     for trait_function in relevant_methods {
@@ -0,0 +1,182 @@
+use cairo_lang_diagnostics::ToOption;
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::TextOffset;
+use cairo_lang_parser::db::ParserGroup;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::function_with_body::SemanticExprLookup;
+use cairo_lang_semantic::lookup_item::LookupItemEx;
+use cairo_lang_semantic::{Expr, ExprFunctionCallArg, Pattern};
+use cairo_lang_syntax::node::ast::{self, ArgClause, OptionTypeClause};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, Terminal, TypedSyntaxNode};
+use cairo_lang_utils::Upcast;
+use lsp_types::{InlayHint, InlayHintKind, InlayHintLabel, InlayHintParams};
+
+use crate::lang::db::{AnalysisDatabase, LsSemanticGroup};
+use crate::lang::inspect::calls::CallSite;
+use crate::lang::lsp::{LsProtoGroup, ToCairo, ToLsp};
+
+/// Get the inlay hints in a range of a text document: inferred types of variables, parameter names
+/// of call arguments and implicit snapshots and references of method receivers.
+pub fn inlay_hints(params: InlayHintParams, db: &AnalysisDatabase) -> Option<Vec<InlayHint>> {
+    let file = db.file_for_url(&params.text_document.uri)?;
+    let range = params.range.to_cairo().offset_in_file(db.upcast(), file)?;
+    let syntax = db.file_syntax(file).to_option()?;
+
+    let syntax_db = db.upcast();
+    let mut hints = vec![];
+    for node in syntax.descendants(syntax_db) {
+        let span = node.span_without_trivia(syntax_db);
+        if span.end < range.start || range.end < span.start {
+            continue;
+        }
+        match node.kind(syntax_db) {
+            SyntaxKind::StatementLet => variable_type_hints(db, file, node, &mut hints),
+            SyntaxKind::ExprFunctionCall => call_hints(db, file, node, &mut hints),
+            _ => {}
+        }
+    }
+    Some(hints)
+}
+
+/// Adds hints of the types of the variables declared by a `let` statement without a type clause.
+fn variable_type_hints(
+    db: &AnalysisDatabase,
+    file: FileId,
+    node: SyntaxNode,
+    hints: &mut Vec<InlayHint>,
+) {
+    let syntax_db = db.upcast();
+    let statement = ast::StatementLet::from_syntax_node(syntax_db, node.clone());
+    if !matches!(statement.type_clause(syntax_db), OptionTypeClause::Empty(_)) {
+        return;
+    }
+    let Some(function_id) = db.find_lookup_item(&node).and_then(|item| item.function_with_body())
+    else {
+        return;
+    };
+    for pattern in statement.pattern(syntax_db).as_syntax_node().descendants(syntax_db) {
+        if !ast::Pattern::is_variant(pattern.kind(syntax_db)) {
+            continue;
+        }
+        let pattern_ptr = ast::Pattern::from_syntax_node(syntax_db, pattern.clone()).stable_ptr();
+        let Ok(pattern_id) = db.lookup_pattern_by_ptr(function_id, pattern_ptr) else { continue };
+        let Pattern::Variable(variable) = db.pattern_semantic(function_id, pattern_id) else {
+            continue;
+        };
+        if variable.var.ty.is_missing(db) {
+            continue;
+        }
+        let label = format!(": {}", short_type_name(&variable.var.ty.format(db)));
+        let offset = pattern.span_without_trivia(syntax_db).end;
+        hints.extend(inlay_hint(db, file, offset, label, Some(InlayHintKind::TYPE)));
+    }
+}
+
+/// Adds hints of the parameter names of the arguments of a call, and of the implicit snapshot or
+/// reference of the receiver of a method call.
+fn call_hints(db: &AnalysisDatabase, file: FileId, node: SyntaxNode, hints: &mut Vec<InlayHint>) {
+    let syntax_db = db.upcast();
+    let call = CallSite::from_node(db, node);
+    let Some(signature) = call.signature(db) else { return };
+
+    if let Some(method_call) = &call.method_call {
+        if let Some(coercion) = receiver_coercion(db, &call) {
+            let offset = method_call.lhs(syntax_db).as_syntax_node().span_without_trivia(syntax_db);
+            hints.extend(inlay_hint(db, file, offset.start, coercion, None));
+        }
+    }
+
+    // The receiver of a method call is passed as the first parameter.
+    let params = signature.params.iter().skip(if call.is_method_call() { 1 } else { 0 });
+    let args = call.call.arguments(syntax_db).arguments(syntax_db).elements(syntax_db);
+    for (arg, param) in args.into_iter().zip(params) {
+        let ArgClause::Unnamed(clause) = arg.arg_clause(syntax_db) else { continue };
+        // Hints that repeat what is already written are omitted.
+        let value = clause.value(syntax_db);
+        if param.name.starts_with('_') || is_identifier_named(db, &value, &param.name) {
+            continue;
+        }
+        let offset = arg.as_syntax_node().span_without_trivia(syntax_db).start;
+        let label = format!("{}:", param.name);
+        hints.extend(inlay_hint(db, file, offset, label, Some(InlayHintKind::PARAMETER)));
+    }
+}
+
+/// Returns the implicit coercion of the receiver of a method call to the `self` parameter - either
+/// `ref` or snapshots.
+fn receiver_coercion(db: &AnalysisDatabase, call: &CallSite) -> Option<String> {
+    let (function_id, expr) = call.semantic(db)?;
+    let mut receiver = match expr.args.first()? {
+        ExprFunctionCallArg::Reference(_) => return Some("ref ".into()),
+        ExprFunctionCallArg::Value(expr_id) => db.expr_semantic(function_id, *expr_id),
+    };
+    // Implicit snapshots are located at the receiver itself, unlike snapshots written in code.
+    let mut snapshots = 0;
+    while let Expr::Snapshot(snapshot) = &receiver {
+        let inner = db.expr_semantic(function_id, snapshot.inner);
+        if inner.stable_ptr() != snapshot.stable_ptr {
+            break;
+        }
+        snapshots += 1;
+        receiver = inner;
+    }
+    (snapshots > 0).then(|| "@".repeat(snapshots))
+}
+
+/// Checks whether the expression is a single identifier with the given name.
+fn is_identifier_named(db: &AnalysisDatabase, expr: &ast::Expr, name: &str) -> bool {
+    let syntax_db = db.upcast();
+    let ast::Expr::Path(path) = expr else { return false };
+    match &path.elements(syntax_db)[..] {
+        [ast::PathSegment::Simple(segment)] => segment.ident(syntax_db).text(syntax_db) == name,
+        _ => false,
+    }
+}
+
+/// Creates an inlay hint at an offset in the file.
+fn inlay_hint(
+    db: &AnalysisDatabase,
+    file: FileId,
+    offset: TextOffset,
+    label: String,
+    kind: Option<InlayHintKind>,
+) -> Option<InlayHint> {
+    Some(InlayHint {
+        position: offset.position_in_file(db.upcast(), file)?.to_lsp(),
+        label: InlayHintLabel::String(label),
+        kind,
+        text_edits: None,
+        tooltip: None,
+        padding_left: None,
+        padding_right: Some(kind == Some(InlayHintKind::PARAMETER)),
+        data: None,
+    })
+}
+
+/// Shortens a formatted type to the names of the types, without their module paths, e.g.
+/// `core::array::Array::<core::integer::u32>` to `Array::<u32>`.
+fn short_type_name(ty: &str) -> String {
+    let mut short = String::new();
+    let mut path_segment = String::new();
+    let mut chars = ty.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c.is_alphanumeric() || c == '_' {
+            path_segment.push(c);
+        } else if c == ':' && chars.peek() == Some(&':') && !path_segment.is_empty() {
+            chars.next();
+            if chars.peek() == Some(&'<') {
+                // Generic arguments of the path.
+                short += &std::mem::take(&mut path_segment);
+                short += "::";
+            } else {
+                // A module of the path.
+                path_segment.clear();
+            }
+        } else {
+            short += &std::mem::take(&mut path_segment);
+            short.push(c);
+        }
+    }
+    short + &path_segment
+}
@@ -1,7 +1,12 @@
 pub mod code_actions;
+pub mod code_lens;
 pub mod completion;
 pub mod formatter;
 pub mod hover;
+pub mod inlay_hints;
 pub mod macros;
 pub mod navigation;
+pub mod rename;
 pub mod semantic_highlighting;
+pub mod signature_help;
+pub mod symbols;
@@ -0,0 +1,151 @@
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::TextSpan;
+use cairo_lang_utils::Upcast;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use lsp_types::{
+    CallHierarchyIncomingCall, CallHierarchyIncomingCallsParams, CallHierarchyItem,
+    CallHierarchyOutgoingCall, CallHierarchyOutgoingCallsParams, CallHierarchyPrepareParams, Range,
+};
+
+use crate::lang::db::AnalysisDatabase;
+use crate::lang::inspect::functions::FunctionDef;
+use crate::lang::inspect::names::{NameLocation, definition_name_location};
+use crate::lang::lsp::{LsProtoGroup, ToCairo, ToLsp};
+
+/// Get the call hierarchy item of the function defined or called at a given text document
+/// position.
+pub fn prepare_call_hierarchy(
+    params: CallHierarchyPrepareParams,
+    db: &AnalysisDatabase,
+) -> Option<Vec<CallHierarchyItem>> {
+    let file = db.file_for_url(&params.text_document_position_params.text_document.uri)?;
+    let position = params.text_document_position_params.position.to_cairo();
+    let function = FunctionDef::at_position(db, file, position)?;
+    Some(vec![call_hierarchy_item(db, function)?.0])
+}
+
+/// Get the functions calling the function of a call hierarchy item, with the locations of the
+/// calls in them.
+///
+/// The calls are searched for in the bodies of the functions of all the analyzed crates.
+pub fn incoming_calls(
+    params: CallHierarchyIncomingCallsParams,
+    db: &AnalysisDatabase,
+) -> Option<Vec<CallHierarchyIncomingCall>> {
+    let function = item_function(db, &params.item)?;
+    let name = function.name(db);
+    let syntax_db = db.upcast();
+
+    let mut callers = OrderedHashMap::<FunctionDef, Vec<NameLocation>>::default();
+    for crate_id in db.crates() {
+        for module_id in db.crate_modules(crate_id).iter() {
+            // Avoid analyzing modules that can not call the function.
+            let Ok(files) = db.module_files(*module_id) else { continue };
+            if !files.iter().any(|file| {
+                db.file_content(*file).is_some_and(|content| content.contains(name.as_str()))
+            }) {
+                continue;
+            }
+            for caller in FunctionDef::module_functions(db, *module_id) {
+                let definition = caller.untyped_stable_ptr(db).lookup(syntax_db);
+                if !definition.get_text(syntax_db).contains(name.as_str()) {
+                    continue;
+                }
+                for (callee, location) in caller.calls(db) {
+                    if callee == function {
+                        callers.entry(caller).or_default().push(location);
+                    }
+                }
+            }
+        }
+    }
+
+    let calls = callers
+        .into_iter()
+        .filter_map(|(caller, locations)| {
+            let (from, file) = call_hierarchy_item(db, caller)?;
+            let from_ranges = ranges_in_file(db, file, locations);
+            Some(CallHierarchyIncomingCall { from, from_ranges })
+        })
+        .collect();
+    Some(calls)
+}
+
+/// Get the functions called by the function of a call hierarchy item, with the locations of the
+/// calls in its body.
+pub fn outgoing_calls(
+    params: CallHierarchyOutgoingCallsParams,
+    db: &AnalysisDatabase,
+) -> Option<Vec<CallHierarchyOutgoingCall>> {
+    let file = db.file_for_url(&params.item.uri)?;
+    let function = item_function(db, &params.item)?;
+
+    let mut callees = OrderedHashMap::<FunctionDef, Vec<NameLocation>>::default();
+    for (callee, location) in function.calls(db) {
+        callees.entry(callee).or_default().push(location);
+    }
+
+    let calls = callees
+        .into_iter()
+        .filter_map(|(callee, locations)| {
+            let (to, _) = call_hierarchy_item(db, callee)?;
+            let from_ranges = ranges_in_file(db, file, locations);
+            Some(CallHierarchyOutgoingCall { to, from_ranges })
+        })
+        .collect();
+    Some(calls)
+}
+
+/// Finds the function of a call hierarchy item by the name at the start of its selection range.
+fn item_function(db: &AnalysisDatabase, item: &CallHierarchyItem) -> Option<FunctionDef> {
+    let file = db.file_for_url(&item.uri)?;
+    FunctionDef::at_position(db, file, item.selection_range.start.to_cairo())
+}
+
+/// Creates the call hierarchy item of a function, together with the file it is defined in.
+///
+/// Returns `None` for functions with names that are not in user code.
+fn call_hierarchy_item(
+    db: &AnalysisDatabase,
+    function: FunctionDef,
+) -> Option<(CallHierarchyItem, FileId)> {
+    let syntax_db = db.upcast();
+    let name = function.name(db);
+    let definition = function.untyped_stable_ptr(db);
+    let (file, name_span) = definition_name_location(db, definition, &name)?;
+    let mut span = name_span;
+    if definition.file_id(syntax_db) == file {
+        let definition_span = definition.lookup(syntax_db).span_without_trivia(syntax_db);
+        span = TextSpan {
+            start: span.start.min(definition_span.start),
+            end: span.end.max(definition_span.end),
+        };
+    }
+
+    let files_db = db.upcast();
+    let item = CallHierarchyItem {
+        name: name.into(),
+        kind: function.symbol_kind(),
+        tags: None,
+        detail: Some(function.full_path(db)),
+        uri: db.url_for_file(file)?,
+        range: span.position_in_file(files_db, file)?.to_lsp(),
+        selection_range: name_span.position_in_file(files_db, file)?.to_lsp(),
+        data: None,
+    };
+    Some((item, file))
+}
+
+/// Converts the locations in the file to ranges, dropping locations in other files.
+/// The ranges are sorted by their position in the file.
+fn ranges_in_file(db: &AnalysisDatabase, file: FileId, locations: Vec<NameLocation>) -> Vec<Range> {
+    let mut ranges = locations
+        .into_iter()
+        .filter(|(location_file, _)| *location_file == file)
+        .filter_map(|(_, span)| Some(span.position_in_file(db.upcast(), file)?.to_lsp()))
+        .collect::<Vec<_>>();
+    ranges.sort_by_key(|range| range.start);
+    ranges
+}
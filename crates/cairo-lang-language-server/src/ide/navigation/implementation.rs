@@ -0,0 +1,121 @@
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::{ModuleId, TopLevelLanguageElementId, TraitFunctionId, TraitId};
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::TextPosition;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::resolve::{ResolvedConcreteItem, ResolvedGenericItem};
+use cairo_lang_syntax::node::TypedSyntaxNode;
+use cairo_lang_utils::Upcast;
+use lsp_types::request::{GotoImplementationParams, GotoImplementationResponse};
+
+use crate::lang::db::{AnalysisDatabase, LsSemanticGroup, LsSyntaxGroup};
+use crate::lang::inspect::defs::{ResolvedItem, find_definition};
+use crate::lang::inspect::functions::FunctionDef;
+use crate::lang::inspect::names::{NameLocation, definition_name_location};
+use crate::lang::lsp::{LsProtoGroup, ToCairo};
+
+/// Get the locations of all the impls of a trait, or of all the impl functions implementing a trait
+/// function, at a given text document position.
+///
+/// The impls are searched for in all the analyzed crates, and include the impl aliases of the
+/// impls of the trait, e.g. embedded component impls.
+pub fn goto_implementation(
+    params: GotoImplementationParams,
+    db: &AnalysisDatabase,
+) -> Option<GotoImplementationResponse> {
+    let file = db.file_for_url(&params.text_document_position_params.text_document.uri)?;
+    let position = params.text_document_position_params.position.to_cairo();
+
+    let locations = match FunctionDef::at_position(db, file, position) {
+        Some(FunctionDef::Trait(trait_function)) => trait_function_impls(db, trait_function),
+        Some(FunctionDef::Impl(impl_function)) => {
+            trait_function_impls(db, db.impl_function_trait_function(impl_function).ok()?)
+        }
+        Some(_) => return None,
+        None => trait_impls(db, trait_at_position(db, file, position)?),
+    };
+    let mut locations =
+        locations.into_iter().filter_map(|location| db.lsp_location(location)).collect::<Vec<_>>();
+    locations.sort_by(|a, b| (a.uri.as_str(), a.range.start).cmp(&(b.uri.as_str(), b.range.start)));
+    locations.dedup();
+    Some(GotoImplementationResponse::Array(locations))
+}
+
+/// Finds the trait referred by the identifier at the given position.
+fn trait_at_position(
+    db: &AnalysisDatabase,
+    file: FileId,
+    position: TextPosition,
+) -> Option<TraitId> {
+    let identifier = db.find_identifier_at_position(file, position)?;
+    let lookup_items = db.collect_lookup_items_stack(&identifier.as_syntax_node())?;
+    match find_definition(db, &identifier, &lookup_items)?.0 {
+        ResolvedItem::Generic(ResolvedGenericItem::Trait(trait_id)) => Some(trait_id),
+        ResolvedItem::Concrete(ResolvedConcreteItem::Trait(concrete_trait)) => {
+            Some(concrete_trait.trait_id(db))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the locations of the names of the impls of the trait and of their impl aliases.
+fn trait_impls(db: &AnalysisDatabase, trait_id: TraitId) -> Vec<NameLocation> {
+    let mut locations = vec![];
+    for_each_module(db, |module_id| {
+        for impl_def in db.module_impls_ids(module_id).iter().flat_map(|ids| ids.iter()) {
+            if db.impl_def_trait(*impl_def) == Ok(trait_id) {
+                locations.extend(name_location(db, *impl_def));
+            }
+        }
+        for impl_alias in db.module_impl_aliases_ids(module_id).iter().flat_map(|ids| ids.iter()) {
+            let Ok(impl_def) = db.impl_alias_impl_def(*impl_alias) else { continue };
+            if db.impl_def_trait(impl_def) == Ok(trait_id) {
+                locations.extend(name_location(db, *impl_alias));
+            }
+        }
+    });
+    locations
+}
+
+/// Returns the locations of the names of the impl functions implementing the trait function.
+///
+/// Impls using the default implementation of the trait function are omitted.
+fn trait_function_impls(
+    db: &AnalysisDatabase,
+    trait_function: TraitFunctionId,
+) -> Vec<NameLocation> {
+    let trait_id = trait_function.trait_id(db.upcast());
+    let mut locations = vec![];
+    for_each_module(db, |module_id| {
+        for impl_def in db.module_impls_ids(module_id).iter().flat_map(|ids| ids.iter()) {
+            if db.impl_def_trait(*impl_def) != Ok(trait_id) {
+                continue;
+            }
+            if let Ok(Some(impl_function)) =
+                db.impl_function_by_trait_function(*impl_def, trait_function)
+            {
+                locations.extend(name_location(db, impl_function));
+            }
+        }
+    });
+    locations
+}
+
+/// Calls the function on every module of the analyzed crates.
+fn for_each_module(db: &AnalysisDatabase, mut f: impl FnMut(ModuleId)) {
+    for crate_id in db.crates() {
+        for module_id in db.crate_modules(crate_id).iter() {
+            f(*module_id);
+        }
+    }
+}
+
+/// Returns the location of the name of the item in its definition.
+fn name_location(
+    db: &AnalysisDatabase,
+    item: impl TopLevelLanguageElementId,
+) -> Option<NameLocation> {
+    let defs_db: &dyn DefsGroup = db.upcast();
+    definition_name_location(db, item.untyped_stable_ptr(defs_db), &item.name(defs_db))
+}
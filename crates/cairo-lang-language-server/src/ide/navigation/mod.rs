@@ -1 +1,4 @@
+pub mod call_hierarchy;
 pub mod goto_definition;
+pub mod implementation;
+pub mod references;
@@ -0,0 +1,22 @@
+use lsp_types::{Location, ReferenceParams};
+
+use crate::lang::db::AnalysisDatabase;
+use crate::lang::inspect::usages::SearchedSymbol;
+use crate::lang::lsp::{LsProtoGroup, ToCairo};
+
+/// Get the locations of all references to a symbol at a given text document position.
+pub fn references(params: ReferenceParams, db: &AnalysisDatabase) -> Option<Vec<Location>> {
+    let file = db.file_for_url(&params.text_document_position.text_document.uri)?;
+    let position = params.text_document_position.position.to_cairo();
+    let symbol = SearchedSymbol::at_position(db, file, position)?;
+
+    let include_declaration = params.context.include_declaration;
+    let mut locations = symbol
+        .usages(db)
+        .into_iter()
+        .filter(|location| include_declaration || *location != symbol.definition)
+        .filter_map(|location| db.lsp_location(location))
+        .collect::<Vec<_>>();
+    locations.sort_by(|a, b| (a.uri.as_str(), a.range.start).cmp(&(b.uri.as_str(), b.range.start)));
+    Some(locations)
+}
@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+
+use anyhow::anyhow;
+use cairo_lang_filesystem::db::CORELIB_CRATE_NAME;
+use cairo_lang_parser::lexer::Lexer;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_utils::Upcast;
+use lsp_server::ErrorCode;
+use lsp_types::{RenameParams, TextEdit, WorkspaceEdit};
+
+use crate::lang::db::AnalysisDatabase;
+use crate::lang::inspect::usages::SearchedSymbol;
+use crate::lang::lsp::{LsProtoGroup, ToCairo};
+use crate::lsp::result::{LSPError, LSPResult};
+
+/// Rename a symbol at a given text document position, together with all its usages in the
+/// analyzed crates.
+pub fn rename(params: RenameParams, db: &AnalysisDatabase) -> LSPResult<Option<WorkspaceEdit>> {
+    let new_name = params.new_name;
+    if !is_identifier(db, &new_name) {
+        return Err(LSPError::new(
+            anyhow!("`{new_name}` is not a valid identifier"),
+            ErrorCode::InvalidParams,
+        ));
+    }
+
+    let Some(file) = db.file_for_url(&params.text_document_position.text_document.uri) else {
+        return Ok(None);
+    };
+    let position = params.text_document_position.position.to_cairo();
+    let Some(symbol) = SearchedSymbol::at_position(db, file, position) else {
+        return Ok(None);
+    };
+    if symbol.definition_crate_name(db).is_none_or(|name| name == CORELIB_CRATE_NAME) {
+        return Err(LSPError::new(
+            anyhow!("`{}` is not defined in the analyzed project", symbol.name),
+            ErrorCode::RequestFailed,
+        ));
+    }
+
+    let mut changes = HashMap::<_, Vec<_>>::new();
+    for location in symbol.usages(db) {
+        let Some(location) = db.lsp_location(location) else { continue };
+        changes
+            .entry(location.uri)
+            .or_default()
+            .push(TextEdit { range: location.range, new_text: new_name.clone() });
+    }
+    for edits in changes.values_mut() {
+        edits.sort_by_key(|edit| edit.range.start);
+    }
+
+    Ok(Some(WorkspaceEdit {
+        changes: Some(changes),
+        document_changes: None,
+        change_annotations: None,
+    }))
+}
+
+/// Checks whether the given text is a single identifier, and not a keyword.
+fn is_identifier(db: &AnalysisDatabase, text: &str) -> bool {
+    let mut lexer = Lexer::from_text(db.upcast(), text);
+    matches!(
+        (lexer.next(), lexer.next()),
+        (Some(terminal), Some(end))
+            if terminal.kind == SyntaxKind::TerminalIdentifier
+                && terminal.text == text
+                && end.kind == SyntaxKind::TerminalEndOfFile
+    )
+}
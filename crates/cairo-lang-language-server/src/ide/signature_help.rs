@@ -0,0 +1,89 @@
+use cairo_lang_syntax::node::ast::ArgClause;
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{Terminal, TypedSyntaxNode};
+use cairo_lang_utils::Upcast;
+use lsp_types::{
+    ParameterInformation, ParameterLabel, SignatureHelp, SignatureHelpParams, SignatureInformation,
+};
+
+use crate::lang::db::{AnalysisDatabase, LsSyntaxGroup};
+use crate::lang::inspect::calls::{CallSite, format_param};
+use crate::lang::lsp::{LsProtoGroup, ToCairo};
+
+/// Get the signature of the function called at a given text document position, with the parameter
+/// of the argument at the position highlighted.
+pub fn signature_help(params: SignatureHelpParams, db: &AnalysisDatabase) -> Option<SignatureHelp> {
+    let file = db.file_for_url(&params.text_document_position_params.text_document.uri)?;
+    let position = params.text_document_position_params.position.to_cairo();
+    let offset = position.offset_in_file(db.upcast(), file)?;
+    let node = db.find_syntax_node_at_position(file, position)?;
+
+    let syntax_db = db.upcast();
+    let call = CallSite::enclosing(db, node)?;
+    let arguments = call.call.arguments(syntax_db);
+    let rparen = arguments.rparen(syntax_db).as_syntax_node();
+    let rparen_missing = rparen.width(syntax_db).as_u32() == 0;
+    if offset < arguments.lparen(syntax_db).as_syntax_node().span_without_trivia(syntax_db).end
+        || (!rparen_missing && offset > rparen.span_without_trivia(syntax_db).start)
+    {
+        return None;
+    }
+    let signature = call.signature(db)?;
+
+    // The receiver of a method call is passed as the first parameter.
+    let first_arg_param = if call.is_method_call() { 1 } else { 0 };
+    let arg_list = arguments.arguments(syntax_db);
+    let arg_index = db
+        .get_children(arg_list.as_syntax_node())
+        .iter()
+        .filter(|node| {
+            node.kind(syntax_db) == SyntaxKind::TerminalComma
+                && node.span_without_trivia(syntax_db).end <= offset
+        })
+        .count();
+    // A named argument refers to its parameter by name, regardless of its position.
+    let named_param = arg_list.elements(syntax_db).get(arg_index).and_then(|arg| {
+        let name = match arg.arg_clause(syntax_db) {
+            ArgClause::Unnamed(_) => return None,
+            ArgClause::Named(clause) => clause.name(syntax_db).text(syntax_db),
+            ArgClause::FieldInitShorthand(clause) => {
+                clause.name(syntax_db).name(syntax_db).text(syntax_db)
+            }
+        };
+        signature.params.iter().position(|param| param.name == name)
+    });
+    let active_parameter = named_param.unwrap_or(first_arg_param + arg_index);
+
+    let mut label = format!("fn {}(", call.name(db));
+    let mut parameters = vec![];
+    for (i, param) in signature.params.iter().enumerate() {
+        if i > 0 {
+            label += ", ";
+        }
+        let start = label.encode_utf16().count() as u32;
+        label += &format_param(db, param);
+        let end = label.encode_utf16().count() as u32;
+        parameters.push(ParameterInformation {
+            label: ParameterLabel::LabelOffsets([start, end]),
+            documentation: None,
+        });
+    }
+    label += ")";
+    if !signature.return_type.is_unit(db) {
+        label += &format!(" -> {}", signature.return_type.format(db));
+    }
+
+    let active_parameter =
+        (active_parameter < signature.params.len()).then_some(active_parameter as u32);
+    Some(SignatureHelp {
+        signatures: vec![SignatureInformation {
+            label,
+            documentation: None,
+            parameters: Some(parameters),
+            active_parameter,
+        }],
+        active_signature: Some(0),
+        active_parameter,
+    })
+}
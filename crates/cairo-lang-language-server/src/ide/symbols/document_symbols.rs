@@ -0,0 +1,76 @@
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::TextSpan;
+use cairo_lang_utils::Upcast;
+use lsp_types::{DocumentSymbol, DocumentSymbolParams, DocumentSymbolResponse};
+
+use super::{Symbol, module_symbols};
+use crate::lang::db::AnalysisDatabase;
+use crate::lang::inspect::names::definition_name_location;
+use crate::lang::lsp::{LsProtoGroup, ToLsp};
+
+/// Get the hierarchical outline of the items defined in a text document.
+pub fn document_symbols(
+    params: DocumentSymbolParams,
+    db: &AnalysisDatabase,
+) -> Option<DocumentSymbolResponse> {
+    let file = db.file_for_url(&params.text_document.uri)?;
+    // Modules are ordered from the crate roots downwards, so the first module is the one the file
+    // is the main file of, and any other modules are inline modules defined in it.
+    let module_id = *db.file_modules(file).ok()?.first()?;
+    let symbols = module_symbols(db, module_id)
+        .into_iter()
+        .filter_map(|symbol| Some(document_symbol(db, file, symbol)?.0))
+        .collect();
+    Some(DocumentSymbolResponse::Nested(symbols))
+}
+
+/// Converts a symbol to a document symbol, together with the span it covers in the file.
+///
+/// Symbols with names which are not in the file, e.g. items generated by compiler plugins, are
+/// dropped.
+/// The returned span is used to cover the children of the symbol by its range, even when the
+/// definition of the symbol itself is generated.
+fn document_symbol(
+    db: &AnalysisDatabase,
+    file: FileId,
+    symbol: Symbol,
+) -> Option<(DocumentSymbol, TextSpan)> {
+    let (name_file, name_span) = definition_name_location(db, symbol.definition, &symbol.name)?;
+    if name_file != file {
+        return None;
+    }
+    let syntax_db = db.upcast();
+    let mut span = name_span;
+    if symbol.definition.file_id(syntax_db) == file {
+        span = cover(span, symbol.definition.lookup(syntax_db).span_without_trivia(syntax_db));
+    }
+
+    let mut children = vec![];
+    for child in symbol.children {
+        let Some((child, child_span)) = document_symbol(db, file, child) else { continue };
+        span = cover(span, child_span);
+        children.push(child);
+    }
+
+    let files_db = db.upcast();
+    let selection_range = name_span.position_in_file(files_db, file)?.to_lsp();
+    let range = span.position_in_file(files_db, file)?.to_lsp();
+    #[allow(deprecated)]
+    let document_symbol = DocumentSymbol {
+        name: symbol.name.into(),
+        detail: symbol.detail,
+        kind: symbol.kind,
+        tags: None,
+        deprecated: None,
+        range,
+        selection_range,
+        children: (!children.is_empty()).then_some(children),
+    };
+    Some((document_symbol, span))
+}
+
+/// Returns the smallest span covering both spans.
+fn cover(a: TextSpan, b: TextSpan) -> TextSpan {
+    TextSpan { start: a.start.min(b.start), end: a.end.max(b.end) }
+}
@@ -0,0 +1,141 @@
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::{ModuleId, ModuleItemId, TopLevelLanguageElementId};
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_starknet::plugin::consts::{EVENT_ATTR, STORAGE_ATTR};
+use cairo_lang_syntax::node::TypedStablePtr;
+use cairo_lang_syntax::node::helpers::QueryAttrs;
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_utils::Upcast;
+use lsp_types::SymbolKind;
+use smol_str::SmolStr;
+
+use crate::lang::db::AnalysisDatabase;
+
+pub mod document_symbols;
+pub mod workspace_symbols;
+
+/// An item definition presented as a symbol, together with the items nested in it.
+struct Symbol {
+    name: SmolStr,
+    kind: SymbolKind,
+    /// A short description of the symbol, shown next to its name.
+    detail: Option<String>,
+    /// The definition of the item.
+    definition: SyntaxStablePtrId,
+    /// The full path of the item.
+    full_path: String,
+    children: Vec<Symbol>,
+}
+
+impl Symbol {
+    fn new(
+        db: &AnalysisDatabase,
+        item: impl TopLevelLanguageElementId,
+        kind: SymbolKind,
+        children: Vec<Symbol>,
+    ) -> Self {
+        let defs_db: &dyn DefsGroup = db.upcast();
+        Self {
+            name: item.name(defs_db),
+            kind,
+            detail: None,
+            definition: item.untyped_stable_ptr(defs_db),
+            full_path: item.full_path(defs_db),
+            children,
+        }
+    }
+}
+
+/// Returns the symbols of the items of a module, in the order of their definitions.
+fn module_symbols(db: &AnalysisDatabase, module_id: ModuleId) -> Vec<Symbol> {
+    let Ok(items) = db.module_items(module_id) else { return vec![] };
+    items.iter().filter_map(|item| module_item_symbol(db, *item)).collect()
+}
+
+/// Returns the symbol of a module item, or `None` for items which are not definitions.
+fn module_item_symbol(db: &AnalysisDatabase, item: ModuleItemId) -> Option<Symbol> {
+    let defs_db: &dyn DefsGroup = db.upcast();
+    let syntax_db = db.upcast();
+    Some(match item {
+        ModuleItemId::Submodule(id) => {
+            Symbol::new(db, id, SymbolKind::MODULE, module_symbols(db, ModuleId::Submodule(id)))
+        }
+        ModuleItemId::Struct(id) => {
+            let members = db.struct_members(id).map(|members| {
+                members
+                    .values()
+                    .map(|member| Symbol::new(db, member.id, SymbolKind::FIELD, vec![]))
+                    .collect()
+            });
+            let mut symbol = Symbol::new(db, id, SymbolKind::STRUCT, members.unwrap_or_default());
+            if id.stable_ptr(defs_db).lookup(syntax_db).has_attr(syntax_db, STORAGE_ATTR) {
+                symbol.detail = Some(format!("#[{STORAGE_ATTR}]"));
+            }
+            symbol
+        }
+        ModuleItemId::Enum(id) => {
+            let variants = db.enum_variants(id).map(|variants| {
+                variants
+                    .values()
+                    .map(|variant| Symbol::new(db, *variant, SymbolKind::ENUM_MEMBER, vec![]))
+                    .collect()
+            });
+            let mut symbol = Symbol::new(db, id, SymbolKind::ENUM, variants.unwrap_or_default());
+            if id.stable_ptr(defs_db).lookup(syntax_db).has_attr(syntax_db, EVENT_ATTR) {
+                symbol.detail = Some(format!("#[{EVENT_ATTR}]"));
+            }
+            symbol
+        }
+        ModuleItemId::Trait(id) => {
+            let mut items = vec![];
+            if let Ok(functions) = db.trait_functions(id) {
+                items.extend(
+                    functions.values().map(|f| Symbol::new(db, *f, SymbolKind::METHOD, vec![])),
+                );
+            }
+            if let Ok(types) = db.trait_types(id) {
+                items.extend(
+                    types.values().map(|t| Symbol::new(db, *t, SymbolKind::TYPE_PARAMETER, vec![])),
+                );
+            }
+            if let Ok(constants) = db.trait_constants(id) {
+                items.extend(
+                    constants.values().map(|c| Symbol::new(db, *c, SymbolKind::CONSTANT, vec![])),
+                );
+            }
+            Symbol::new(db, id, SymbolKind::INTERFACE, sorted_by_definition(db, items))
+        }
+        ModuleItemId::Impl(id) => {
+            let mut items = vec![];
+            if let Ok(functions) = db.impl_functions(id) {
+                items.extend(
+                    functions.values().map(|f| Symbol::new(db, *f, SymbolKind::METHOD, vec![])),
+                );
+            }
+            if let Ok(types) = db.impl_type_ids(id) {
+                items.extend(
+                    types.iter().map(|t| Symbol::new(db, *t, SymbolKind::TYPE_PARAMETER, vec![])),
+                );
+            }
+            if let Ok(constants) = db.impl_constants(id) {
+                items.extend(
+                    constants.keys().map(|c| Symbol::new(db, *c, SymbolKind::CONSTANT, vec![])),
+                );
+            }
+            Symbol::new(db, id, SymbolKind::OBJECT, sorted_by_definition(db, items))
+        }
+        ModuleItemId::FreeFunction(id) => Symbol::new(db, id, SymbolKind::FUNCTION, vec![]),
+        ModuleItemId::ExternFunction(id) => Symbol::new(db, id, SymbolKind::FUNCTION, vec![]),
+        ModuleItemId::Constant(id) => Symbol::new(db, id, SymbolKind::CONSTANT, vec![]),
+        ModuleItemId::TypeAlias(id) => Symbol::new(db, id, SymbolKind::TYPE_PARAMETER, vec![]),
+        ModuleItemId::ExternType(id) => Symbol::new(db, id, SymbolKind::STRUCT, vec![]),
+        ModuleItemId::ImplAlias(id) => Symbol::new(db, id, SymbolKind::OBJECT, vec![]),
+        ModuleItemId::Use(_) => return None,
+    })
+}
+
+/// Sorts symbols of items of different kinds by the positions of their definitions.
+fn sorted_by_definition(db: &AnalysisDatabase, mut symbols: Vec<Symbol>) -> Vec<Symbol> {
+    symbols.sort_by_key(|symbol| symbol.definition.lookup(db.upcast()).offset());
+    symbols
+}
@@ -0,0 +1,113 @@
+use std::cmp::Reverse;
+
+use cairo_lang_defs::ids::ModuleId;
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_utils::Upcast;
+use itertools::Itertools;
+use lsp_types::{SymbolInformation, WorkspaceSymbolParams, WorkspaceSymbolResponse};
+
+use super::{Symbol, module_symbols};
+use crate::lang::db::AnalysisDatabase;
+use crate::lang::inspect::names::definition_name_location;
+use crate::lang::lsp::LsProtoGroup;
+
+/// The maximal number of symbols returned for a query.
+const MAX_SYMBOLS: usize = 128;
+
+/// Search for symbols fuzzily matching a query in all the analyzed crates.
+pub fn workspace_symbols(
+    params: WorkspaceSymbolParams,
+    db: &AnalysisDatabase,
+) -> Option<WorkspaceSymbolResponse> {
+    let query = params.query.to_lowercase();
+
+    let mut matches = vec![];
+    for crate_id in db.crates() {
+        let crate_name = crate_id.name(db.upcast()).to_string();
+        for symbol in module_symbols(db, ModuleId::CrateRoot(crate_id)) {
+            collect_matches(&query, symbol, Some(crate_name.clone()), &mut matches);
+        }
+    }
+    // Best matches first, and within the same score, in alphabetical order of names and paths.
+    let symbols = matches
+        .into_iter()
+        .sorted_by(|(a_score, a, _), (b_score, b, _)| {
+            (Reverse(a_score), &a.name, &a.full_path).cmp(&(
+                Reverse(b_score),
+                &b.name,
+                &b.full_path,
+            ))
+        })
+        .filter_map(|(_, symbol, container_name)| symbol_information(db, symbol, container_name))
+        .take(MAX_SYMBOLS)
+        .collect();
+    Some(WorkspaceSymbolResponse::Flat(symbols))
+}
+
+/// Collects the symbol and its children which match the query, with their match scores and the
+/// full paths of their containers.
+fn collect_matches(
+    query: &str,
+    mut symbol: Symbol,
+    container_name: Option<String>,
+    matches: &mut Vec<(MatchScore, Symbol, Option<String>)>,
+) {
+    for child in std::mem::take(&mut symbol.children) {
+        collect_matches(query, child, Some(symbol.full_path.clone()), matches);
+    }
+    if let Some(score) = fuzzy_match(query, &symbol.name.to_lowercase()) {
+        matches.push((score, symbol, container_name));
+    }
+}
+
+/// How well a name matches a query, from the worst to the best.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum MatchScore {
+    /// The characters of the query appear in the name in order.
+    Subsequence,
+    /// The query appears in the name.
+    Substring,
+    /// The name starts with the query.
+    Prefix,
+    /// The name is the query.
+    Exact,
+}
+
+/// Matches a lowercase query against a lowercase name.
+fn fuzzy_match(query: &str, name: &str) -> Option<MatchScore> {
+    if name == query {
+        Some(MatchScore::Exact)
+    } else if name.starts_with(query) {
+        Some(MatchScore::Prefix)
+    } else if name.contains(query) {
+        Some(MatchScore::Substring)
+    } else {
+        let mut name_chars = name.chars();
+        query
+            .chars()
+            .all(|c| name_chars.any(|name_char| name_char == c))
+            .then_some(MatchScore::Subsequence)
+    }
+}
+
+/// Converts a symbol to a symbol information, located at the name in its definition.
+///
+/// Returns `None` for symbols with names that are not in user code, e.g. items generated by
+/// compiler plugins.
+fn symbol_information(
+    db: &AnalysisDatabase,
+    symbol: Symbol,
+    container_name: Option<String>,
+) -> Option<SymbolInformation> {
+    let location = definition_name_location(db, symbol.definition, &symbol.name)?;
+    let location = db.lsp_location(location)?;
+    #[allow(deprecated)]
+    Some(SymbolInformation {
+        name: symbol.name.into(),
+        kind: symbol.kind,
+        tags: None,
+        deprecated: None,
+        location,
+        container_name,
+    })
+}
@@ -6,9 +6,10 @@ use cairo_lang_filesystem::db::{
 };
 use cairo_lang_filesystem::ids::VirtualFile;
 use cairo_lang_lowering::db::{LoweringDatabase, LoweringGroup, init_lowering_group};
+use cairo_lang_lowering::lint_passes::{lowering_lint_suite, set_lowered_lint_suite};
 use cairo_lang_lowering::utils::InliningStrategy;
 use cairo_lang_parser::db::{ParserDatabase, ParserGroup};
-use cairo_lang_semantic::db::{SemanticDatabase, SemanticGroup};
+use cairo_lang_semantic::db::{SemanticDatabase, SemanticGroup, init_semantic_group};
 use cairo_lang_semantic::inline_macros::get_default_plugin_suite;
 use cairo_lang_semantic::plugin::PluginSuite;
 use cairo_lang_starknet::starknet_plugin_suite;
@@ -45,6 +46,7 @@ impl AnalysisDatabase {
         let mut db = Self { storage: Default::default() };
 
         init_files_group(&mut db);
+        init_semantic_group(&mut db);
         init_lowering_group(&mut db, InliningStrategy::Default);
 
         db.set_cfg_set(Self::initial_cfg_set().into());
@@ -58,6 +60,7 @@ impl AnalysisDatabase {
                     acc
                 });
         db.apply_plugin_suite(plugin_suite);
+        set_lowered_lint_suite(&mut db, lowering_lint_suite());
 
         db
     }
@@ -82,6 +85,7 @@ impl AnalysisDatabase {
         self.set_macro_plugins(plugin_suite.plugins);
         self.set_inline_macro_plugins(plugin_suite.inline_macro_plugins.into());
         self.set_analyzer_plugins(plugin_suite.analyzer_plugins);
+        self.set_lint_passes(plugin_suite.lint_passes);
     }
 }
 
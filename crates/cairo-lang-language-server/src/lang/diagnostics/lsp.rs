@@ -1,9 +1,14 @@
-use cairo_lang_diagnostics::{DiagnosticEntry, DiagnosticLocation, Diagnostics, Severity};
+use std::collections::HashMap;
+
+use cairo_lang_diagnostics::{
+    Applicability, DiagnosticEntry, DiagnosticFix, DiagnosticLocation, Diagnostics, Severity,
+};
 use cairo_lang_filesystem::db::FilesGroup;
 use cairo_lang_filesystem::ids::FileId;
 use cairo_lang_utils::{LookupIntern, Upcast};
 use lsp_types::{
-    Diagnostic, DiagnosticRelatedInformation, DiagnosticSeverity, Location, NumberOrString, Range,
+    CodeAction, CodeActionKind, CodeDescription, Diagnostic, DiagnosticRelatedInformation,
+    DiagnosticSeverity, Location, NumberOrString, Range, TextEdit, Url, WorkspaceEdit,
 };
 use tracing::{error, trace};
 
@@ -59,6 +64,11 @@ pub fn map_cairo_diagnostics_to_lsp<T: DiagnosticEntry>(
         if mapped_file_id != processed_file_id {
             continue;
         }
+        let fixes = diagnostic
+            .fixes(db)
+            .iter()
+            .filter_map(|fix| fix_code_action(db, fix))
+            .collect::<Vec<_>>();
         diags.push(Diagnostic {
             range,
             message,
@@ -68,11 +78,42 @@ pub fn map_cairo_diagnostics_to_lsp<T: DiagnosticEntry>(
                 Severity::Warning => DiagnosticSeverity::WARNING,
             }),
             code: diagnostic.error_code().map(|code| NumberOrString::String(code.to_string())),
+            code_description: diagnostic
+                .error_code()
+                .and_then(|code| code.explanation_url())
+                .and_then(|url| Some(CodeDescription { href: Url::parse(&url).ok()? })),
+            // The client sends the data back when requesting code actions for the diagnostic.
+            data: (!fixes.is_empty()).then(|| serde_json::to_value(fixes).unwrap()),
             ..Diagnostic::default()
         });
     }
 }
 
+/// Converts a fix suggested for a diagnostic to a quick fix code action.
+///
+/// Returns `None` if any of the edits of the fix is not in a user file.
+fn fix_code_action(
+    db: &(impl Upcast<dyn FilesGroup> + ?Sized),
+    fix: &DiagnosticFix,
+) -> Option<CodeAction> {
+    let mut changes: HashMap<Url, Vec<TextEdit>> = HashMap::new();
+    for edit in &fix.edits {
+        let location = edit.user_location(db.upcast())?;
+        let range = get_lsp_range(db.upcast(), &location)?;
+        changes
+            .entry(db.url_for_file(location.file_id)?)
+            .or_default()
+            .push(TextEdit { range, new_text: edit.replacement.clone() });
+    }
+    Some(CodeAction {
+        title: fix.message.clone(),
+        kind: Some(CodeActionKind::QUICKFIX),
+        edit: Some(WorkspaceEdit::new(changes)),
+        is_preferred: Some(fix.applicability == Applicability::MachineApplicable),
+        ..Default::default()
+    })
+}
+
 /// Returns the mapped range of a location, optionally adds a note about the mapping of the
 /// location.
 fn get_mapped_range_and_add_mapping_note(
@@ -0,0 +1,130 @@
+use cairo_lang_defs::ids::FunctionWithBodyId;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::function_with_body::SemanticExprLookup;
+use cairo_lang_semantic::lookup_item::LookupItemEx;
+use cairo_lang_semantic::resolve::ResolvedConcreteItem;
+use cairo_lang_semantic::{Expr, ExprFunctionCall, Mutability, Parameter, Signature};
+use cairo_lang_syntax::node::ast::{self, BinaryOperator};
+use cairo_lang_syntax::node::helpers::PathSegmentEx;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, Terminal, TypedStablePtr, TypedSyntaxNode};
+use cairo_lang_utils::Upcast;
+use smol_str::SmolStr;
+
+use crate::lang::db::{AnalysisDatabase, LsSemanticGroup};
+
+/// A call of a function or a method, as written in code.
+pub struct CallSite {
+    /// The call expression, which for method calls is the right-hand side of the `.` operator.
+    pub call: ast::ExprFunctionCall,
+    /// The `.` expression of a method call, whose left-hand side is the receiver of the method.
+    pub method_call: Option<ast::ExprBinary>,
+}
+
+impl CallSite {
+    /// Creates a call site from an [`SyntaxKind::ExprFunctionCall`] node.
+    pub fn from_node(db: &AnalysisDatabase, node: SyntaxNode) -> Self {
+        let syntax_db = db.upcast();
+        let call = ast::ExprFunctionCall::from_syntax_node(syntax_db, node.clone());
+        let method_call = node
+            .parent()
+            .filter(|parent| parent.kind(syntax_db) == SyntaxKind::ExprBinary)
+            .map(|parent| ast::ExprBinary::from_syntax_node(syntax_db, parent))
+            .filter(|binary| {
+                matches!(binary.op(syntax_db), BinaryOperator::Dot(_))
+                    && binary.rhs(syntax_db).stable_ptr().untyped() == node.stable_ptr()
+            });
+        Self { call, method_call }
+    }
+
+    /// Finds the innermost call whose argument list contains the given node.
+    pub fn enclosing(db: &AnalysisDatabase, node: SyntaxNode) -> Option<Self> {
+        let syntax_db = db.upcast();
+        let mut node = Some(node);
+        while let Some(current) = node {
+            if current.kind(syntax_db) == SyntaxKind::ArgListParenthesized {
+                let parent = current.parent()?;
+                if parent.kind(syntax_db) == SyntaxKind::ExprFunctionCall {
+                    return Some(Self::from_node(db, parent));
+                }
+            }
+            node = current.parent();
+        }
+        None
+    }
+
+    /// Whether this is a method call, which passes the receiver as the first parameter.
+    pub fn is_method_call(&self) -> bool {
+        self.method_call.is_some()
+    }
+
+    /// The name of the called function, as written in code.
+    pub fn name(&self, db: &AnalysisDatabase) -> SmolStr {
+        self.name_identifier(db).map(|identifier| identifier.text(db.upcast())).unwrap_or_default()
+    }
+
+    /// The expression of the whole call, including the receiver of a method call.
+    pub fn expr_ptr(&self) -> ast::ExprPtr {
+        match &self.method_call {
+            Some(method_call) => ast::Expr::Binary(method_call.clone()).stable_ptr(),
+            None => ast::Expr::FunctionCall(self.call.clone()).stable_ptr(),
+        }
+    }
+
+    /// Returns the semantic model of the call, if it is a valid call, together with the function it
+    /// is in.
+    pub fn semantic(
+        &self,
+        db: &AnalysisDatabase,
+    ) -> Option<(FunctionWithBodyId, ExprFunctionCall)> {
+        let lookup_item = db.find_lookup_item(&self.call.as_syntax_node())?;
+        let function_id = lookup_item.function_with_body()?;
+        let expr_id = db.lookup_expr_by_ptr(function_id, self.expr_ptr()).ok()?;
+        match db.expr_semantic(function_id, expr_id) {
+            Expr::FunctionCall(call) => Some((function_id, call)),
+            _ => None,
+        }
+    }
+
+    /// Returns the signature of the called function.
+    ///
+    /// The generic arguments of the function are substituted if they were inferred, and otherwise
+    /// the function is resolved by its name, e.g. when arguments are still being written.
+    pub fn signature(&self, db: &AnalysisDatabase) -> Option<Signature> {
+        let concrete_signature = self
+            .semantic(db)
+            .and_then(|(_, call)| db.concrete_function_signature(call.function).ok())
+            .filter(|signature| {
+                !signature.return_type.is_missing(db)
+                    && signature.params.iter().all(|param| !param.ty.is_missing(db))
+            });
+        if concrete_signature.is_some() {
+            return concrete_signature;
+        }
+        let lookup_item = db.find_lookup_item(&self.call.as_syntax_node())?;
+        let identifier = self.name_identifier(db)?;
+        match db.lookup_resolved_concrete_item_by_ptr(lookup_item, identifier.stable_ptr())? {
+            ResolvedConcreteItem::Function(function_id) => {
+                function_id.get_concrete(db).generic_function.generic_signature(db).ok()
+            }
+            _ => None,
+        }
+    }
+
+    /// The identifier of the called function name.
+    pub fn name_identifier(&self, db: &AnalysisDatabase) -> Option<ast::TerminalIdentifier> {
+        let syntax_db = db.upcast();
+        let segment = self.call.path(syntax_db).elements(syntax_db).pop()?;
+        Some(segment.identifier_ast(syntax_db))
+    }
+}
+
+/// Formats a function parameter as written in a function signature.
+pub fn format_param(db: &AnalysisDatabase, param: &Parameter) -> String {
+    let mutability = match param.mutability {
+        Mutability::Immutable => "",
+        Mutability::Mutable => "mut ",
+        Mutability::Reference => "ref ",
+    };
+    format!("{mutability}{}: {}", param.name, param.ty.format(db))
+}
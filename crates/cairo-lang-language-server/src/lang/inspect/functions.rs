@@ -0,0 +1,190 @@
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::{
+    ExternFunctionId, FreeFunctionId, FunctionWithBodyId, ImplFunctionId, ImplItemId,
+    LanguageElementId, LookupItemId, ModuleId, NamedLanguageElementId, TopLevelLanguageElementId,
+    TraitFunctionId,
+};
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::TextPosition;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::functions::GenericFunctionId;
+use cairo_lang_semantic::resolve::{ResolvedConcreteItem, ResolvedGenericItem};
+use cairo_lang_semantic::{Expr, FunctionId};
+use cairo_lang_syntax::node::ast::{self, BinaryOperator};
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use cairo_lang_utils::Upcast;
+use lsp_types::SymbolKind;
+use smol_str::SmolStr;
+
+use crate::lang::db::{AnalysisDatabase, LsSemanticGroup, LsSyntaxGroup};
+use crate::lang::inspect::calls::CallSite;
+use crate::lang::inspect::defs::{ResolvedItem, find_definition};
+use crate::lang::inspect::names::{NameLocation, name_node_location};
+
+/// A function definition, which calls can be resolved to.
+///
+/// Calls of trait functions are resolved to the implementing impl functions when the impl is
+/// known, e.g. in non-generic code, and to the trait functions otherwise.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FunctionDef {
+    Free(FreeFunctionId),
+    Extern(ExternFunctionId),
+    Impl(ImplFunctionId),
+    Trait(TraitFunctionId),
+}
+
+impl FunctionDef {
+    /// Finds the function defined or called by the identifier at the given position.
+    pub fn at_position(
+        db: &AnalysisDatabase,
+        file: FileId,
+        position: TextPosition,
+    ) -> Option<Self> {
+        let syntax_db = db.upcast();
+        let identifier = db.find_identifier_at_position(file, position)?;
+        let lookup_items = db.collect_lookup_items_stack(&identifier.as_syntax_node())?;
+
+        // Names of impl functions in their declarations resolve to the whole impl.
+        if let Some(LookupItemId::ImplItem(ImplItemId::Function(impl_function))) =
+            lookup_items.first()
+        {
+            let declaration = impl_function.stable_ptr(db).lookup(syntax_db).declaration(syntax_db);
+            if declaration.name(syntax_db).stable_ptr() == identifier.stable_ptr() {
+                return Some(Self::Impl(*impl_function));
+            }
+        }
+
+        match find_definition(db, &identifier, &lookup_items)?.0 {
+            ResolvedItem::Generic(ResolvedGenericItem::GenericFunction(function)) => {
+                Self::from_generic(db, function)
+            }
+            ResolvedItem::Generic(ResolvedGenericItem::TraitFunction(function)) => {
+                Some(Self::Trait(function))
+            }
+            ResolvedItem::Concrete(ResolvedConcreteItem::Function(function)) => {
+                Self::from_function_id(db, function)
+            }
+            ResolvedItem::Concrete(ResolvedConcreteItem::TraitFunction(function)) => {
+                Some(Self::Trait(function.trait_function(db)))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the definition of the function called by a semantic call.
+    pub fn from_function_id(db: &AnalysisDatabase, function: FunctionId) -> Option<Self> {
+        Self::from_generic(db, function.get_concrete(db).generic_function)
+    }
+
+    fn from_generic(db: &AnalysisDatabase, function: GenericFunctionId) -> Option<Self> {
+        Some(match function {
+            GenericFunctionId::Free(id) => Self::Free(id),
+            GenericFunctionId::Extern(id) => Self::Extern(id),
+            GenericFunctionId::Impl(id) => match id.impl_function(db).ok()? {
+                Some(impl_function) => Self::Impl(impl_function),
+                None => Self::Trait(id.function),
+            },
+            GenericFunctionId::Trait(id) => Self::Trait(id.trait_function(db)),
+        })
+    }
+
+    /// Returns all the functions with bodies in a module, including the functions of its traits
+    /// and impls.
+    pub fn module_functions(db: &AnalysisDatabase, module_id: ModuleId) -> Vec<Self> {
+        let mut functions = vec![];
+        if let Ok(free_functions) = db.module_free_functions_ids(module_id) {
+            functions.extend(free_functions.iter().map(|id| Self::Free(*id)));
+        }
+        for impl_def in db.module_impls_ids(module_id).iter().flat_map(|ids| ids.iter()) {
+            if let Ok(impl_functions) = db.impl_functions(*impl_def) {
+                functions.extend(impl_functions.values().map(|id| Self::Impl(*id)));
+            }
+        }
+        for trait_id in db.module_traits_ids(module_id).iter().flat_map(|ids| ids.iter()) {
+            if let Ok(trait_functions) = db.trait_functions(*trait_id) {
+                functions.extend(trait_functions.values().map(|id| Self::Trait(*id)));
+            }
+        }
+        functions
+    }
+
+    pub fn name(&self, db: &AnalysisDatabase) -> SmolStr {
+        let defs_db: &dyn DefsGroup = db.upcast();
+        match self {
+            Self::Free(id) => id.name(defs_db),
+            Self::Extern(id) => id.name(defs_db),
+            Self::Impl(id) => id.name(defs_db),
+            Self::Trait(id) => id.name(defs_db),
+        }
+    }
+
+    pub fn full_path(&self, db: &AnalysisDatabase) -> String {
+        let defs_db: &dyn DefsGroup = db.upcast();
+        match self {
+            Self::Free(id) => id.full_path(defs_db),
+            Self::Extern(id) => id.full_path(defs_db),
+            Self::Impl(id) => id.full_path(defs_db),
+            Self::Trait(id) => id.full_path(defs_db),
+        }
+    }
+
+    /// The definition of the function.
+    pub fn untyped_stable_ptr(&self, db: &AnalysisDatabase) -> SyntaxStablePtrId {
+        let defs_db: &dyn DefsGroup = db.upcast();
+        match self {
+            Self::Free(id) => id.untyped_stable_ptr(defs_db),
+            Self::Extern(id) => id.untyped_stable_ptr(defs_db),
+            Self::Impl(id) => id.untyped_stable_ptr(defs_db),
+            Self::Trait(id) => id.untyped_stable_ptr(defs_db),
+        }
+    }
+
+    /// The kind of the function as a symbol: functions of traits and impls are methods.
+    pub fn symbol_kind(&self) -> SymbolKind {
+        match self {
+            Self::Free(_) | Self::Extern(_) => SymbolKind::FUNCTION,
+            Self::Impl(_) | Self::Trait(_) => SymbolKind::METHOD,
+        }
+    }
+
+    /// Finds the calls written in the body of the function, with the locations of the names of the
+    /// called functions.
+    ///
+    /// Calls of operators and calls which can not be mapped to the user code are omitted.
+    pub fn calls(&self, db: &AnalysisDatabase) -> Vec<(FunctionDef, NameLocation)> {
+        let function_id = match *self {
+            Self::Free(id) => FunctionWithBodyId::Free(id),
+            Self::Impl(id) => FunctionWithBodyId::Impl(id),
+            Self::Trait(id) => FunctionWithBodyId::Trait(id),
+            Self::Extern(_) => return vec![],
+        };
+        let Ok(body) = db.function_body(function_id) else { return vec![] };
+        body.arenas
+            .exprs
+            .iter()
+            .filter_map(|(_, expr)| {
+                let Expr::FunctionCall(call) = expr else { return None };
+                let callee = Self::from_function_id(db, call.function)?;
+                let call_site = call_site(db, call.stable_ptr)?;
+                let name = call_site.name_identifier(db)?.as_syntax_node();
+                Some((callee, name_node_location(db, &name, &callee.name(db))?))
+            })
+            .collect()
+    }
+}
+
+/// Returns the call written in code of a semantic call expression, or `None` if the call is
+/// implicit, e.g. of an operator.
+fn call_site(db: &AnalysisDatabase, expr_ptr: ast::ExprPtr) -> Option<CallSite> {
+    let syntax_db = db.upcast();
+    let call = match expr_ptr.lookup(syntax_db) {
+        ast::Expr::FunctionCall(call) => call,
+        ast::Expr::Binary(binary) => match (binary.op(syntax_db), binary.rhs(syntax_db)) {
+            (BinaryOperator::Dot(_), ast::Expr::FunctionCall(call)) => call,
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Some(CallSite::from_node(db, call.as_syntax_node()))
+}
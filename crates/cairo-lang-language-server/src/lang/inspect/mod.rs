@@ -1,5 +1,8 @@
 //! High-level constructs for inspecting language elements from the analysis database.
 
+pub mod calls;
 pub mod crates;
 pub mod defs;
-pub mod methods;
+pub mod functions;
+pub mod names;
+pub mod usages;
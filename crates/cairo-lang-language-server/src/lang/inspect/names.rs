@@ -0,0 +1,50 @@
+use cairo_lang_filesystem::db::{FilesGroup, get_originating_location};
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::TextSpan;
+use cairo_lang_syntax::node::ast::TerminalIdentifier;
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, Terminal, TypedSyntaxNode};
+use cairo_lang_utils::Upcast;
+
+use crate::lang::db::AnalysisDatabase;
+
+/// A location of a symbol name in user code.
+pub type NameLocation = (FileId, TextSpan);
+
+/// Returns the location in user code of the name in the given definition.
+///
+/// The name is the first identifier with the given text in the definition.
+/// Returns `None` if there is no such identifier, or if it cannot be mapped to the name in user
+/// code.
+pub fn definition_name_location(
+    db: &AnalysisDatabase,
+    definition: SyntaxStablePtrId,
+    name: &str,
+) -> Option<NameLocation> {
+    let syntax_db = db.upcast();
+    let name_node = definition.lookup(syntax_db).descendants(syntax_db).find(|node| {
+        node.kind(syntax_db) == SyntaxKind::TerminalIdentifier
+            && TerminalIdentifier::from_syntax_node(syntax_db, node.clone()).text(syntax_db) == name
+    })?;
+    name_node_location(db, &name_node, name)
+}
+
+/// Returns the location in user code of the given name node, which may be generated by a compiler
+/// plugin.
+///
+/// Returns `None` if the node cannot be mapped to the name in user code.
+pub fn name_node_location(
+    db: &AnalysisDatabase,
+    node: &SyntaxNode,
+    name: &str,
+) -> Option<NameLocation> {
+    let syntax_db = db.upcast();
+    let span = node.span_without_trivia(syntax_db);
+    let width = span.width();
+    let file = node.stable_ptr().file_id(syntax_db);
+    let (file, mut span) = get_originating_location(db.upcast(), file, span.start_only());
+    span.end = span.end.add_width(width);
+    let content = db.file_content(file)?;
+    (content.get(span.to_str_range())? == name).then_some((file, span))
+}
@@ -0,0 +1,187 @@
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::{ImplItemId, LookupItemId, ModuleId};
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::TextPosition;
+use cairo_lang_parser::db::ParserGroup;
+use cairo_lang_semantic::Pattern;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::function_with_body::SemanticExprLookup;
+use cairo_lang_semantic::lookup_item::LookupItemEx;
+use cairo_lang_semantic::resolve::{ResolvedConcreteItem, ResolvedGenericItem};
+use cairo_lang_syntax::node::ast::{PatternPtr, TerminalIdentifier};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, Terminal, TypedStablePtr, TypedSyntaxNode};
+use cairo_lang_utils::Upcast;
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+use smol_str::SmolStr;
+
+use crate::lang::db::{AnalysisDatabase, LsSemanticGroup, LsSyntaxGroup};
+use crate::lang::inspect::defs::{ResolvedItem, find_definition};
+use crate::lang::inspect::names::{NameLocation, definition_name_location, name_node_location};
+
+/// A symbol whose usages are searched for.
+///
+/// The symbol is identified by the location of the name in its definition, so that all identifiers
+/// resolving to the same definition are usages of the same symbol.
+pub struct SearchedSymbol {
+    /// The name of the symbol.
+    pub name: SmolStr,
+    /// The location of the name in the definition of the symbol.
+    pub definition: NameLocation,
+    /// Whether the symbol is a local variable, thus can only be used in its own module.
+    is_local: bool,
+    /// The module the search started from.
+    module: Option<ModuleId>,
+}
+
+impl SearchedSymbol {
+    /// Finds the symbol referred by the identifier at the given position.
+    pub fn at_position(
+        db: &AnalysisDatabase,
+        file: FileId,
+        position: TextPosition,
+    ) -> Option<Self> {
+        let identifier = db.find_identifier_at_position(file, position)?;
+        let (definition, is_local) = resolve_definition(db, &identifier)?;
+        Some(Self {
+            name: identifier.text(db.upcast()),
+            definition,
+            is_local,
+            module: db.find_module_containing_node(&identifier.as_syntax_node()),
+        })
+    }
+
+    /// Returns the crate the symbol is defined in, if the definition is in an analyzed module.
+    pub fn definition_crate_name(&self, db: &AnalysisDatabase) -> Option<SmolStr> {
+        let (file, _) = self.definition;
+        let module = db.file_modules(file).ok()?.first().copied()?;
+        Some(module.owning_crate(db.upcast()).name(db.upcast()))
+    }
+
+    /// Finds the deduplicated locations of all usages of the symbol, including the definition
+    /// itself.
+    ///
+    /// Usages in code generated by compiler plugins are mapped back to the user code which led to
+    /// them, and are dropped if they can not be mapped to the symbol name.
+    pub fn usages(&self, db: &AnalysisDatabase) -> Vec<NameLocation> {
+        let mut usages = OrderedHashSet::<NameLocation>::from_iter([self.definition]);
+        for file in self.search_files(db) {
+            // Avoid parsing files that can not refer to the symbol.
+            if !db.file_content(file).is_some_and(|content| content.contains(self.name.as_str())) {
+                continue;
+            }
+            let Ok(syntax) = db.file_syntax(file) else { continue };
+            for node in syntax.descendants(db.upcast()) {
+                let Some(identifier) = self.identifier_with_name(db, node) else { continue };
+                if resolve_definition(db, &identifier)
+                    .is_some_and(|(definition, _)| definition == self.definition)
+                {
+                    let node = identifier.as_syntax_node();
+                    if let Some(location) = name_node_location(db, &node, &self.name) {
+                        usages.insert(location);
+                    }
+                }
+            }
+        }
+        usages.into_iter().collect()
+    }
+
+    /// Returns the files which may contain usages of the symbol, including files generated by
+    /// compiler plugins.
+    fn search_files(&self, db: &AnalysisDatabase) -> OrderedHashSet<FileId> {
+        let modules = match self.module {
+            Some(module) if self.is_local => vec![module],
+            _ => db
+                .crates()
+                .into_iter()
+                .flat_map(|crate_id| db.crate_modules(crate_id).iter().copied().collect::<Vec<_>>())
+                .collect(),
+        };
+        modules
+            .into_iter()
+            .filter_map(|module| db.module_files(module).ok())
+            .flat_map(|files| files.iter().copied().collect::<Vec<_>>())
+            .collect()
+    }
+
+    /// Returns the node as an identifier, if it is one with the name of the symbol.
+    fn identifier_with_name(
+        &self,
+        db: &AnalysisDatabase,
+        node: SyntaxNode,
+    ) -> Option<TerminalIdentifier> {
+        if node.kind(db.upcast()) != SyntaxKind::TerminalIdentifier {
+            return None;
+        }
+        let identifier = TerminalIdentifier::from_syntax_node(db.upcast(), node);
+        (identifier.text(db.upcast()) == self.name).then_some(identifier)
+    }
+}
+
+/// Returns the location of the name in the definition the given identifier resolves to, and
+/// whether the definition is of a local variable.
+fn resolve_definition(
+    db: &AnalysisDatabase,
+    identifier: &TerminalIdentifier,
+) -> Option<(NameLocation, bool)> {
+    let syntax_db = db.upcast();
+    let node = identifier.as_syntax_node();
+    let name = identifier.text(syntax_db);
+
+    // Names of variables in their declarations are not resolved, as they are definitions.
+    if is_variable_declaration(db, &node) {
+        return Some((name_node_location(db, &node, &name)?, true));
+    }
+
+    let lookup_items = db.collect_lookup_items_stack(&node)?;
+
+    // Names of impl functions are identified with the names of the trait functions they implement,
+    // as usages of impl functions are resolved to the trait functions.
+    if let Some(LookupItemId::ImplItem(ImplItemId::Function(impl_function))) = lookup_items.first()
+    {
+        let declaration = impl_function.stable_ptr(db).lookup(syntax_db).declaration(syntax_db);
+        if declaration.name(syntax_db).stable_ptr() == identifier.stable_ptr() {
+            let trait_function = db.impl_function_trait_function(*impl_function).ok()?;
+            let definition = trait_function.stable_ptr(db).untyped();
+            return Some((definition_name_location(db, definition, &name)?, false));
+        }
+    }
+
+    let (resolved_item, definition) = find_definition(db, identifier, &lookup_items)?;
+    let definition = match &resolved_item {
+        // The definition of a submodule is its body or its file, which do not contain its name.
+        ResolvedItem::Generic(ResolvedGenericItem::Module(module_id))
+        | ResolvedItem::Concrete(ResolvedConcreteItem::Module(module_id)) => match module_id {
+            ModuleId::Submodule(submodule_id) => submodule_id.stable_ptr(db).untyped(),
+            ModuleId::CrateRoot(_) => return None,
+        },
+        _ => definition,
+    };
+    let is_local = matches!(resolved_item, ResolvedItem::Generic(ResolvedGenericItem::Variable(_)));
+    Some((definition_name_location(db, definition, &name)?, is_local))
+}
+
+/// Checks whether the identifier node is the name of a declared variable - a function parameter or
+/// a variable pattern.
+fn is_variable_declaration(db: &AnalysisDatabase, node: &SyntaxNode) -> bool {
+    let syntax_db = db.upcast();
+    let Some(parent) = node.parent() else { return false };
+    let pattern = match parent.kind(syntax_db) {
+        SyntaxKind::Param => return true,
+        SyntaxKind::PatternIdentifier => parent,
+        // A single identifier pattern is parsed as a path, which may also be an enum variant.
+        SyntaxKind::PathSegmentSimple => match parent.parent() {
+            Some(path) if path.kind(syntax_db) == SyntaxKind::ExprPath => path,
+            _ => return false,
+        },
+        _ => return false,
+    };
+    let Some(function_id) = db.find_lookup_item(node).and_then(|item| item.function_with_body())
+    else {
+        return false;
+    };
+    db.lookup_pattern_by_ptr(function_id, PatternPtr(pattern.stable_ptr())).is_ok_and(
+        |pattern_id| matches!(db.pattern_semantic(function_id, pattern_id), Pattern::Variable(_)),
+    )
+}
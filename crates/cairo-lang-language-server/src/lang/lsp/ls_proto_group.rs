@@ -1,10 +1,13 @@
 use cairo_lang_filesystem::db::FilesGroup;
 use cairo_lang_filesystem::ids::{FileId, FileLongId};
+use cairo_lang_filesystem::span::TextSpan;
 use cairo_lang_utils::Upcast;
-use lsp_types::Url;
+use lsp_types::{Location, Url};
 use salsa::InternKey;
 use tracing::error;
 
+use crate::lang::lsp::ToLsp;
+
 #[cfg(test)]
 #[path = "ls_proto_group_test.rs"]
 mod test;
@@ -54,6 +57,13 @@ pub trait LsProtoGroup: Upcast<dyn FilesGroup> {
         url.path_segments_mut().unwrap().push(&format!("{}.cairo", vf.name));
         Some(url)
     }
+
+    /// Get the LSP [`Location`] of a [`TextSpan`] in a file.
+    fn lsp_location(&self, (file_id, span): (FileId, TextSpan)) -> Option<Location> {
+        let uri = self.url_for_file(file_id)?;
+        let range = span.position_in_file(self.upcast(), file_id)?.to_lsp();
+        Some(Location { uri, range })
+    }
 }
 
 impl<T> LsProtoGroup for T where T: Upcast<dyn FilesGroup> + ?Sized {}
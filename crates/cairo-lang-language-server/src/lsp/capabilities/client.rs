@@ -38,6 +38,33 @@ pub trait ClientCapabilitiesExt {
 
     /// The client supports dynamic registration for code action capabilities.
     fn code_action_dynamic_registration(&self) -> bool;
+
+    /// The client supports dynamic registration for code lens capabilities.
+    fn code_lens_dynamic_registration(&self) -> bool;
+
+    /// The client supports dynamic registration for implementation capabilities.
+    fn implementation_dynamic_registration(&self) -> bool;
+
+    /// The client supports dynamic registration for call hierarchy capabilities.
+    fn call_hierarchy_dynamic_registration(&self) -> bool;
+
+    /// The client supports dynamic registration for references capabilities.
+    fn references_dynamic_registration(&self) -> bool;
+
+    /// The client supports dynamic registration for rename capabilities.
+    fn rename_dynamic_registration(&self) -> bool;
+
+    /// The client supports dynamic registration for document symbol capabilities.
+    fn document_symbol_dynamic_registration(&self) -> bool;
+
+    /// The client supports dynamic registration for workspace symbol capabilities.
+    fn workspace_symbol_dynamic_registration(&self) -> bool;
+
+    /// The client supports dynamic registration for signature help capabilities.
+    fn signature_help_dynamic_registration(&self) -> bool;
+
+    /// The client supports dynamic registration for inlay hint capabilities.
+    fn inlay_hint_dynamic_registration(&self) -> bool;
 }
 
 impl ClientCapabilitiesExt for ClientCapabilities {
@@ -88,4 +115,42 @@ impl ClientCapabilitiesExt for ClientCapabilities {
     fn code_action_dynamic_registration(&self) -> bool {
         try_or_default!(self.text_document.as_ref()?.code_action.as_ref()?.dynamic_registration?)
     }
+
+    fn code_lens_dynamic_registration(&self) -> bool {
+        try_or_default!(self.text_document.as_ref()?.code_lens.as_ref()?.dynamic_registration?)
+    }
+
+    fn implementation_dynamic_registration(&self) -> bool {
+        try_or_default!(self.text_document.as_ref()?.implementation.as_ref()?.dynamic_registration?)
+    }
+
+    fn call_hierarchy_dynamic_registration(&self) -> bool {
+        try_or_default!(self.text_document.as_ref()?.call_hierarchy.as_ref()?.dynamic_registration?)
+    }
+
+    fn references_dynamic_registration(&self) -> bool {
+        try_or_default!(self.text_document.as_ref()?.references.as_ref()?.dynamic_registration?)
+    }
+
+    fn rename_dynamic_registration(&self) -> bool {
+        try_or_default!(self.text_document.as_ref()?.rename.as_ref()?.dynamic_registration?)
+    }
+
+    fn document_symbol_dynamic_registration(&self) -> bool {
+        try_or_default!(
+            self.text_document.as_ref()?.document_symbol.as_ref()?.dynamic_registration?
+        )
+    }
+
+    fn workspace_symbol_dynamic_registration(&self) -> bool {
+        try_or_default!(self.workspace.as_ref()?.symbol.as_ref()?.dynamic_registration?)
+    }
+
+    fn signature_help_dynamic_registration(&self) -> bool {
+        try_or_default!(self.text_document.as_ref()?.signature_help.as_ref()?.dynamic_registration?)
+    }
+
+    fn inlay_hint_dynamic_registration(&self) -> bool {
+        try_or_default!(self.text_document.as_ref()?.inlay_hint.as_ref()?.dynamic_registration?)
+    }
 }
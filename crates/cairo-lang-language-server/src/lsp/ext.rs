@@ -59,3 +59,39 @@ impl Notification for ScarbMetadataFailed {
     type Params = ();
     const METHOD: &'static str = "cairo/scarb-metadata-failed";
 }
+
+/// Notifies about the results of a test run started by the `cairo.runTest` command.
+#[derive(Debug)]
+pub struct TestRunFinished;
+
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+pub struct TestRunFinishedParams {
+    /// The filter of the names of the tests that were run.
+    pub filter: String,
+    /// The results of the tests, in the order they finished.
+    pub results: Vec<TestResult>,
+    /// The reason the tests could not be run, e.g. compilation errors.
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+pub struct TestResult {
+    /// The full name of the test.
+    pub name: String,
+    pub outcome: TestOutcome,
+    /// The description of the failure of a failed test.
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TestOutcome {
+    Passed,
+    Failed,
+    Ignored,
+}
+
+impl Notification for TestRunFinished {
+    type Params = TestRunFinishedParams;
+    const METHOD: &'static str = "cairo/testRunFinished";
+}
@@ -160,6 +160,7 @@ pub fn update_crate_roots(metadata: &Metadata, db: &mut AnalysisDatabase) {
                 dependencies,
                 cfg_set,
                 experimental_features,
+                lints: Default::default(),
             };
 
             let custom_main_file_stems = (file_stem != "lib").then_some(vec![file_stem.into()]);
@@ -1,7 +1,10 @@
 use anyhow::bail;
+use lsp_types::Url;
+use serde::{Deserialize, Serialize};
 
 pub enum ServerCommands {
     Reload,
+    RunTest,
 }
 
 impl TryFrom<String> for ServerCommands {
@@ -10,7 +13,20 @@ impl TryFrom<String> for ServerCommands {
     fn try_from(value: String) -> anyhow::Result<Self> {
         match value.as_str() {
             "cairo.reload" => Ok(ServerCommands::Reload),
+            "cairo.runTest" => Ok(ServerCommands::RunTest),
             _ => bail!("Unrecognized command: {value}"),
         }
     }
 }
+
+/// The argument of the `cairo.runTest` command.
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+pub struct RunTestArguments {
+    /// The document the tests are defined in, used to find the project to run them in.
+    pub uri: Url,
+    /// The filter of the names of the tests to run.
+    pub filter: String,
+    /// Whether the filter must match the full name of a test, instead of a part of it.
+    #[serde(default)]
+    pub exact: bool,
+}
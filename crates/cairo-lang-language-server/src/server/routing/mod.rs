@@ -15,8 +15,11 @@ use lsp_types::notification::{
     Notification as NotificationTrait, SetTrace,
 };
 use lsp_types::request::{
-    CodeActionRequest, Completion, ExecuteCommand, Formatting, GotoDefinition, HoverRequest,
-    Request as RequestTrait, SemanticTokensFullRequest,
+    CallHierarchyIncomingCalls, CallHierarchyOutgoingCalls, CallHierarchyPrepare,
+    CodeActionRequest, CodeLensRequest, Completion, DocumentSymbolRequest, ExecuteCommand,
+    Formatting, GotoDefinition, GotoImplementation, HoverRequest, InlayHintRequest, References,
+    Rename, Request as RequestTrait, SemanticTokensFullRequest, SignatureHelpRequest,
+    WorkspaceSymbolRequest,
 };
 use tracing::{error, trace, warn};
 
@@ -33,13 +36,36 @@ pub fn request<'a>(request: Request) -> Task<'a> {
     let id = request.id.clone();
 
     match request.method.as_str() {
+        CallHierarchyIncomingCalls::METHOD => {
+            background_request_task::<CallHierarchyIncomingCalls>(
+                request,
+                BackgroundSchedule::Worker,
+            )
+        }
+        CallHierarchyOutgoingCalls::METHOD => {
+            background_request_task::<CallHierarchyOutgoingCalls>(
+                request,
+                BackgroundSchedule::Worker,
+            )
+        }
+        CallHierarchyPrepare::METHOD => background_request_task::<CallHierarchyPrepare>(
+            request,
+            BackgroundSchedule::LatencySensitive,
+        ),
         CodeActionRequest::METHOD => background_request_task::<CodeActionRequest>(
             request,
             BackgroundSchedule::LatencySensitive,
         ),
+        CodeLensRequest::METHOD => {
+            background_request_task::<CodeLensRequest>(request, BackgroundSchedule::Worker)
+        }
         Completion::METHOD => {
             background_request_task::<Completion>(request, BackgroundSchedule::LatencySensitive)
         }
+        DocumentSymbolRequest::METHOD => background_request_task::<DocumentSymbolRequest>(
+            request,
+            BackgroundSchedule::LatencySensitive,
+        ),
         ExecuteCommand::METHOD => local_request_task::<ExecuteCommand>(request),
         ExpandMacro::METHOD => {
             background_request_task::<ExpandMacro>(request, BackgroundSchedule::Worker)
@@ -50,20 +76,37 @@ pub fn request<'a>(request: Request) -> Task<'a> {
         GotoDefinition::METHOD => {
             background_request_task::<GotoDefinition>(request, BackgroundSchedule::LatencySensitive)
         }
+        GotoImplementation::METHOD => {
+            background_request_task::<GotoImplementation>(request, BackgroundSchedule::Worker)
+        }
         HoverRequest::METHOD => {
             background_request_task::<HoverRequest>(request, BackgroundSchedule::LatencySensitive)
         }
+        InlayHintRequest::METHOD => {
+            background_request_task::<InlayHintRequest>(request, BackgroundSchedule::Worker)
+        }
         ProvideVirtualFile::METHOD => background_request_task::<ProvideVirtualFile>(
             request,
             BackgroundSchedule::LatencySensitive,
         ),
+        References::METHOD => {
+            background_request_task::<References>(request, BackgroundSchedule::Worker)
+        }
+        Rename::METHOD => background_request_task::<Rename>(request, BackgroundSchedule::Worker),
         SemanticTokensFullRequest::METHOD => background_request_task::<SemanticTokensFullRequest>(
             request,
             BackgroundSchedule::Worker,
         ),
+        SignatureHelpRequest::METHOD => background_request_task::<SignatureHelpRequest>(
+            request,
+            BackgroundSchedule::LatencySensitive,
+        ),
         ViewAnalyzedCrates::METHOD => {
             background_request_task::<ViewAnalyzedCrates>(request, BackgroundSchedule::Worker)
         }
+        WorkspaceSymbolRequest::METHOD => {
+            background_request_task::<WorkspaceSymbolRequest>(request, BackgroundSchedule::Worker)
+        }
 
         method => {
             warn!("received request {method} which does not have a handler");
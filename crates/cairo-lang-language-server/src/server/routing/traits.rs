@@ -5,24 +5,34 @@
 // | Commit: 46a457318d8d259376a2b458b3f814b9b795fe69    |
 // +-----------------------------------------------------+
 
+use anyhow::{Context, anyhow};
 use cairo_lang_filesystem::db::{
     AsFilesGroupMut, FilesGroup, FilesGroupEx, PrivRawFileContentQuery,
 };
+use lsp_server::ErrorCode;
 use lsp_types::notification::{
     DidChangeConfiguration, DidChangeTextDocument, DidChangeWatchedFiles, DidCloseTextDocument,
     DidOpenTextDocument, DidSaveTextDocument, Notification,
 };
 use lsp_types::request::{
-    CodeActionRequest, Completion, ExecuteCommand, Formatting, GotoDefinition, HoverRequest,
-    Request, SemanticTokensFullRequest,
+    CallHierarchyIncomingCalls, CallHierarchyOutgoingCalls, CallHierarchyPrepare,
+    CodeActionRequest, CodeLensRequest, Completion, DocumentSymbolRequest, ExecuteCommand,
+    Formatting, GotoDefinition, GotoImplementation, GotoImplementationParams,
+    GotoImplementationResponse, HoverRequest, InlayHintRequest, References, Rename, Request,
+    SemanticTokensFullRequest, SignatureHelpRequest, WorkspaceSymbolRequest,
 };
 use lsp_types::{
-    CodeActionParams, CodeActionResponse, CompletionParams, CompletionResponse,
-    DidChangeConfigurationParams, DidChangeTextDocumentParams, DidChangeWatchedFilesParams,
-    DidCloseTextDocumentParams, DidOpenTextDocumentParams, DidSaveTextDocumentParams,
-    DocumentFormattingParams, ExecuteCommandParams, GotoDefinitionParams, GotoDefinitionResponse,
-    Hover, HoverParams, SemanticTokensParams, SemanticTokensResult, TextDocumentContentChangeEvent,
-    TextDocumentPositionParams, TextEdit, Url,
+    CallHierarchyIncomingCall, CallHierarchyIncomingCallsParams, CallHierarchyItem,
+    CallHierarchyOutgoingCall, CallHierarchyOutgoingCallsParams, CallHierarchyPrepareParams,
+    CodeActionParams, CodeActionResponse, CodeLens, CodeLensParams, CompletionParams,
+    CompletionResponse, DidChangeConfigurationParams, DidChangeTextDocumentParams,
+    DidChangeWatchedFilesParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
+    DidSaveTextDocumentParams, DocumentFormattingParams, DocumentSymbolParams,
+    DocumentSymbolResponse, ExecuteCommandParams, GotoDefinitionParams, GotoDefinitionResponse,
+    Hover, HoverParams, InlayHint, InlayHintParams, Location, ReferenceParams, RenameParams,
+    SemanticTokensParams, SemanticTokensResult, SignatureHelp, SignatureHelpParams,
+    TextDocumentContentChangeEvent, TextDocumentPositionParams, TextEdit, Url, WorkspaceEdit,
+    WorkspaceSymbolParams, WorkspaceSymbolResponse,
 };
 use serde_json::Value;
 use tracing::error;
@@ -32,10 +42,11 @@ use crate::lsp::ext::{
     ExpandMacro, ProvideVirtualFile, ProvideVirtualFileRequest, ProvideVirtualFileResponse,
     ViewAnalyzedCrates,
 };
-use crate::lsp::result::{LSPError, LSPResult};
+use crate::lsp::result::{LSPError, LSPResult, LSPResultEx};
 use crate::server::client::{Notifier, Requester};
-use crate::server::commands::ServerCommands;
+use crate::server::commands::{RunTestArguments, ServerCommands};
 use crate::state::{State, StateSnapshot};
+use crate::toolchain::cairo_test::{TestRunOptions, run_tests_in_background, uses_starknet_plugin};
 use crate::{Backend, ide, lang};
 
 /// A request handler that needs mutable access to the session.
@@ -84,6 +95,17 @@ impl BackgroundDocumentRequestHandler for CodeActionRequest {
     }
 }
 
+impl BackgroundDocumentRequestHandler for CodeLensRequest {
+    #[tracing::instrument(name = "textDocument/codeLens", skip_all)]
+    fn run_with_snapshot(
+        snapshot: StateSnapshot,
+        _notifier: Notifier,
+        params: CodeLensParams,
+    ) -> LSPResult<Option<Vec<CodeLens>>> {
+        Ok(ide::code_lens::code_lens(params, &snapshot.db))
+    }
+}
+
 impl SyncRequestHandler for ExecuteCommand {
     #[tracing::instrument(
         name = "workspace/executeCommand",
@@ -103,6 +125,30 @@ impl SyncRequestHandler for ExecuteCommand {
                 ServerCommands::Reload => {
                     Backend::reload(state, &notifier, requester)?;
                 }
+                ServerCommands::RunTest => {
+                    let arguments: RunTestArguments = params
+                        .arguments
+                        .into_iter()
+                        .next()
+                        .context("missing `cairo.runTest` arguments")
+                        .and_then(|value| Ok(serde_json::from_value(value)?))
+                        .with_failure_code(ErrorCode::InvalidParams)?;
+                    let file_path = arguments
+                        .uri
+                        .to_file_path()
+                        .map_err(|()| anyhow!("tests can only be run in files on disk"))
+                        .with_failure_code(ErrorCode::InvalidParams)?;
+                    let starknet = state
+                        .db
+                        .file_for_url(&arguments.uri)
+                        .is_some_and(|file| uses_starknet_plugin(&state.db, file));
+                    let options = TestRunOptions {
+                        filter: arguments.filter,
+                        exact: arguments.exact,
+                        starknet,
+                    };
+                    run_tests_in_background(file_path, options, notifier);
+                }
             }
         }
 
@@ -294,6 +340,116 @@ impl BackgroundDocumentRequestHandler for GotoDefinition {
     }
 }
 
+impl BackgroundDocumentRequestHandler for GotoImplementation {
+    #[tracing::instrument(name = "textDocument/implementation", skip_all)]
+    fn run_with_snapshot(
+        snapshot: StateSnapshot,
+        _notifier: Notifier,
+        params: GotoImplementationParams,
+    ) -> LSPResult<Option<GotoImplementationResponse>> {
+        Ok(ide::navigation::implementation::goto_implementation(params, &snapshot.db))
+    }
+}
+
+impl BackgroundDocumentRequestHandler for CallHierarchyPrepare {
+    #[tracing::instrument(name = "textDocument/prepareCallHierarchy", skip_all)]
+    fn run_with_snapshot(
+        snapshot: StateSnapshot,
+        _notifier: Notifier,
+        params: CallHierarchyPrepareParams,
+    ) -> LSPResult<Option<Vec<CallHierarchyItem>>> {
+        Ok(ide::navigation::call_hierarchy::prepare_call_hierarchy(params, &snapshot.db))
+    }
+}
+
+impl BackgroundDocumentRequestHandler for CallHierarchyIncomingCalls {
+    #[tracing::instrument(name = "callHierarchy/incomingCalls", skip_all)]
+    fn run_with_snapshot(
+        snapshot: StateSnapshot,
+        _notifier: Notifier,
+        params: CallHierarchyIncomingCallsParams,
+    ) -> LSPResult<Option<Vec<CallHierarchyIncomingCall>>> {
+        Ok(ide::navigation::call_hierarchy::incoming_calls(params, &snapshot.db))
+    }
+}
+
+impl BackgroundDocumentRequestHandler for CallHierarchyOutgoingCalls {
+    #[tracing::instrument(name = "callHierarchy/outgoingCalls", skip_all)]
+    fn run_with_snapshot(
+        snapshot: StateSnapshot,
+        _notifier: Notifier,
+        params: CallHierarchyOutgoingCallsParams,
+    ) -> LSPResult<Option<Vec<CallHierarchyOutgoingCall>>> {
+        Ok(ide::navigation::call_hierarchy::outgoing_calls(params, &snapshot.db))
+    }
+}
+
+impl BackgroundDocumentRequestHandler for References {
+    #[tracing::instrument(name = "textDocument/references", skip_all)]
+    fn run_with_snapshot(
+        snapshot: StateSnapshot,
+        _notifier: Notifier,
+        params: ReferenceParams,
+    ) -> LSPResult<Option<Vec<Location>>> {
+        Ok(ide::navigation::references::references(params, &snapshot.db))
+    }
+}
+
+impl BackgroundDocumentRequestHandler for Rename {
+    #[tracing::instrument(name = "textDocument/rename", skip_all)]
+    fn run_with_snapshot(
+        snapshot: StateSnapshot,
+        _notifier: Notifier,
+        params: RenameParams,
+    ) -> LSPResult<Option<WorkspaceEdit>> {
+        ide::rename::rename(params, &snapshot.db)
+    }
+}
+
+impl BackgroundDocumentRequestHandler for SignatureHelpRequest {
+    #[tracing::instrument(name = "textDocument/signatureHelp", skip_all)]
+    fn run_with_snapshot(
+        snapshot: StateSnapshot,
+        _notifier: Notifier,
+        params: SignatureHelpParams,
+    ) -> LSPResult<Option<SignatureHelp>> {
+        Ok(ide::signature_help::signature_help(params, &snapshot.db))
+    }
+}
+
+impl BackgroundDocumentRequestHandler for InlayHintRequest {
+    #[tracing::instrument(name = "textDocument/inlayHint", skip_all)]
+    fn run_with_snapshot(
+        snapshot: StateSnapshot,
+        _notifier: Notifier,
+        params: InlayHintParams,
+    ) -> LSPResult<Option<Vec<InlayHint>>> {
+        Ok(ide::inlay_hints::inlay_hints(params, &snapshot.db))
+    }
+}
+
+impl BackgroundDocumentRequestHandler for DocumentSymbolRequest {
+    #[tracing::instrument(name = "textDocument/documentSymbol", skip_all)]
+    fn run_with_snapshot(
+        snapshot: StateSnapshot,
+        _notifier: Notifier,
+        params: DocumentSymbolParams,
+    ) -> LSPResult<Option<DocumentSymbolResponse>> {
+        Ok(ide::symbols::document_symbols::document_symbols(params, &snapshot.db))
+    }
+}
+
+impl BackgroundDocumentRequestHandler for WorkspaceSymbolRequest {
+    #[tracing::instrument(name = "workspace/symbol", skip_all)]
+    fn run_with_snapshot(
+        snapshot: StateSnapshot,
+        _notifier: Notifier,
+        params: WorkspaceSymbolParams,
+    ) -> LSPResult<Option<WorkspaceSymbolResponse>> {
+        Ok(ide::symbols::workspace_symbols::workspace_symbols(params, &snapshot.db))
+    }
+}
+
 impl BackgroundDocumentRequestHandler for Completion {
     #[tracing::instrument(name = "textDocument/completion", skip_all)]
     fn run_with_snapshot(
@@ -0,0 +1,157 @@
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+
+use anyhow::{Context, Result, bail};
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_diagnostics::ToOption;
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_parser::db::ParserGroup;
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{TypedSyntaxNode, ast};
+use cairo_lang_utils::Upcast;
+use itertools::Itertools;
+use serde::Deserialize;
+use tempfile::NamedTempFile;
+use tracing::error;
+
+use crate::env_config;
+use crate::lang::db::AnalysisDatabase;
+use crate::lsp::ext::{TestOutcome, TestResult, TestRunFinished, TestRunFinishedParams};
+use crate::project::ProjectManifestPath;
+use crate::server::client::Notifier;
+
+#[cfg(test)]
+#[path = "cairo_test_test.rs"]
+mod test;
+
+/// The options of a `cairo-test` run.
+#[derive(Debug, Clone)]
+pub struct TestRunOptions {
+    /// The filter of the names of the tests to run.
+    pub filter: String,
+    /// Whether the filter must match the full name of a test, instead of a part of it.
+    pub exact: bool,
+    /// Whether to run the tests with the Starknet plugin.
+    pub starknet: bool,
+}
+
+/// Runs the tests matching the filter with `cairo-test` on a background thread, and notifies the
+/// language client about the results once they finish.
+///
+/// The tests are run in the project of the given file, as saved on disk.
+pub fn run_tests_in_background(file_path: PathBuf, options: TestRunOptions, notifier: Notifier) {
+    let spawned = thread::Builder::new().name("cairo-test".into()).spawn(move || {
+        let (results, error) = match run_tests(&file_path, &options) {
+            Ok(results) => (results, None),
+            Err(err) => (vec![], Some(format!("{err:#}"))),
+        };
+        let filter = options.filter;
+        notifier.notify::<TestRunFinished>(TestRunFinishedParams { filter, results, error });
+    });
+    if let Err(err) = spawned {
+        error!("failed to spawn a thread running tests: {err:?}");
+    }
+}
+
+/// Returns whether the crate of the given file uses the Starknet plugin, i.e. has any of its
+/// attributes, in which case its tests can only be compiled with the plugin.
+pub fn uses_starknet_plugin(db: &AnalysisDatabase, file: FileId) -> bool {
+    let defs_db: &dyn DefsGroup = db.upcast();
+    let syntax_db: &dyn SyntaxGroup = db.upcast();
+    let Some(module_id) = db.file_modules(file).ok().and_then(|modules| modules.first().copied())
+    else {
+        return false;
+    };
+    db.crate_modules(module_id.owning_crate(defs_db))
+        .iter()
+        .filter_map(|module_id| db.module_main_file(*module_id).ok())
+        .unique()
+        .filter_map(|file| db.file_syntax(file).to_option())
+        .flat_map(|syntax| syntax.descendants(syntax_db))
+        .filter(|node| node.kind(syntax_db) == SyntaxKind::Attribute)
+        .any(|node| {
+            ast::Attribute::from_syntax_node(syntax_db, node)
+                .attr(syntax_db)
+                .as_syntax_node()
+                .get_text_without_trivia(syntax_db)
+                .starts_with("starknet::")
+        })
+}
+
+/// Runs the tests matching the filter in the project of the given file, and collects their
+/// results.
+///
+/// Warnings are not allowed, as when running `cairo-test` on the project directly, so the
+/// compilation errors of a project with warnings are reported instead.
+fn run_tests(file_path: &Path, options: &TestRunOptions) -> Result<Vec<TestResult>> {
+    let mut command = Command::new(env_config::cairo_test_path());
+    match ProjectManifestPath::discover(file_path) {
+        Some(ProjectManifestPath::CairoProject(manifest_path)) => {
+            command.arg(manifest_path.parent().context("project manifest has no parent")?);
+        }
+        Some(ProjectManifestPath::Scarb(_)) => {
+            bail!("running tests of Scarb projects is not supported, use `scarb cairo-test`")
+        }
+        None => {
+            command.arg(file_path).arg("--single-file");
+        }
+    }
+
+    command.args(["--filter", &options.filter]);
+    if options.exact {
+        command.arg("--exact");
+    }
+    if options.starknet {
+        command.arg("--starknet");
+    }
+
+    let report = NamedTempFile::new().context("failed to create a test report file")?;
+    let output = command
+        .args(["--report-format", "json-lines", "--report-path"])
+        .arg(report.path())
+        .stdin(Stdio::null())
+        .output()
+        .context("failed to execute: cairo-test")?;
+
+    let report = std::fs::read_to_string(report.path()).unwrap_or_default();
+    let results = parse_report(&report)?;
+    // A failed run with no tests finished means the tests could not be compiled.
+    if results.is_empty() && !output.status.success() {
+        bail!("{}", String::from_utf8_lossy(&output.stderr).trim());
+    }
+    Ok(results)
+}
+
+/// An event of the JSON-lines report written by `cairo-test`.
+#[derive(Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+enum ReportEvent {
+    Started,
+    Passed { name: String },
+    Failed { name: String, message: String },
+    Ignored { name: String },
+}
+
+/// Parses the results of the finished tests from a JSON-lines test report.
+fn parse_report(report: &str) -> Result<Vec<TestResult>> {
+    let mut results = vec![];
+    for line in report.lines().filter(|line| !line.trim().is_empty()) {
+        let event = serde_json::from_str(line)
+            .with_context(|| format!("failed to parse test report event: {line}"))?;
+        results.push(match event {
+            ReportEvent::Started => continue,
+            ReportEvent::Passed { name } => {
+                TestResult { name, outcome: TestOutcome::Passed, message: None }
+            }
+            ReportEvent::Failed { name, message } => {
+                TestResult { name, outcome: TestOutcome::Failed, message: Some(message) }
+            }
+            ReportEvent::Ignored { name } => {
+                TestResult { name, outcome: TestOutcome::Ignored, message: None }
+            }
+        });
+    }
+    Ok(results)
+}
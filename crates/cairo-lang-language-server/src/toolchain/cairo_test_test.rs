@@ -0,0 +1,42 @@
+use indoc::indoc;
+
+use super::parse_report;
+use crate::lsp::ext::{TestOutcome, TestResult};
+
+#[test]
+fn parse_finished_tests() {
+    let report = indoc! {r#"
+        {"event":"started","name":"hello::tests::test_add"}
+        {"event":"started","name":"hello::tests::test_sub"}
+        {"event":"passed","name":"hello::tests::test_add","gas_usage":100,"steps":12}
+        {"event":"failed","name":"hello::tests::test_sub","message":"Panicked with 'oops'."}
+        {"event":"ignored","name":"hello::tests::test_mul"}
+    "#};
+    assert_eq!(parse_report(report).unwrap(), vec![
+        TestResult {
+            name: "hello::tests::test_add".into(),
+            outcome: TestOutcome::Passed,
+            message: None,
+        },
+        TestResult {
+            name: "hello::tests::test_sub".into(),
+            outcome: TestOutcome::Failed,
+            message: Some("Panicked with 'oops'.".into()),
+        },
+        TestResult {
+            name: "hello::tests::test_mul".into(),
+            outcome: TestOutcome::Ignored,
+            message: None,
+        },
+    ]);
+}
+
+#[test]
+fn parse_empty_report() {
+    assert_eq!(parse_report("").unwrap(), vec![]);
+}
+
+#[test]
+fn parse_malformed_report() {
+    assert!(parse_report("test result: ok.").is_err());
+}
@@ -1 +1,2 @@
+pub mod cairo_test;
 pub mod scarb;
@@ -57,6 +57,7 @@ fn cairo_projects() {
                         negative_impls: true,
                         coupons: true,
                     },
+                    lints: {},
                 }
                 ```
             - `project1`: `["[ROOT]/project1/src/lib.cairo"]`
@@ -71,6 +72,7 @@ fn cairo_projects() {
                         negative_impls: false,
                         coupons: false,
                     },
+                    lints: {},
                 }
                 ```
             - `project2`: `["[ROOT]/project2/src/lib.cairo"]`
@@ -85,6 +87,7 @@ fn cairo_projects() {
                         negative_impls: false,
                         coupons: false,
                     },
+                    lints: {},
                 }
                 ```
             - `subproject`: `["[ROOT]/project2/subproject/src/lib.cairo"]`
@@ -99,6 +102,7 @@ fn cairo_projects() {
                         negative_impls: false,
                         coupons: false,
                     },
+                    lints: {},
                 }
                 ```
         "#});
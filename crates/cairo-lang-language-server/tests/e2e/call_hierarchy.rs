@@ -0,0 +1,119 @@
+use cairo_lang_test_utils::parse_test_file::TestRunnerResult;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use lsp_types::{
+    CallHierarchyClientCapabilities, CallHierarchyIncomingCallsParams, CallHierarchyItem,
+    CallHierarchyOutgoingCallsParams, CallHierarchyPrepareParams, ClientCapabilities,
+    TextDocumentClientCapabilities, TextDocumentIdentifier, TextDocumentPositionParams,
+    lsp_request,
+};
+
+use crate::support::cursor::{peek_caret, peek_selection};
+use crate::support::{MockClient, cursors, sandbox};
+
+cairo_lang_test_utils::test_file_test!(
+    call_hierarchy,
+    "tests/test_data/call_hierarchy",
+    {
+        fns: "fns.txt",
+        methods: "methods.txt",
+    },
+    test_call_hierarchy
+);
+
+fn caps(base: ClientCapabilities) -> ClientCapabilities {
+    ClientCapabilities {
+        text_document: base.text_document.or_else(Default::default).map(|it| {
+            TextDocumentClientCapabilities {
+                call_hierarchy: Some(CallHierarchyClientCapabilities {
+                    dynamic_registration: Some(false),
+                }),
+                ..it
+            }
+        }),
+        ..base
+    }
+}
+
+/// Perform call hierarchy test.
+///
+/// This function spawns a sandbox language server with the given code in the `src/lib.cairo` file.
+/// The Cairo source code is expected to contain caret markers.
+/// The function then prepares the call hierarchy at each caret position, and reports the prepared
+/// item together with its incoming and outgoing calls.
+fn test_call_hierarchy(
+    inputs: &OrderedHashMap<String, String>,
+    _args: &OrderedHashMap<String, String>,
+) -> TestRunnerResult {
+    let (cairo, cursors) = cursors(&inputs["cairo_code"]);
+
+    let mut ls = sandbox! {
+        files {
+            "cairo_project.toml" => inputs["cairo_project.toml"].clone(),
+            "src/lib.cairo" => cairo.clone(),
+        }
+        client_capabilities = caps;
+    };
+    ls.open_and_wait_for_diagnostics("src/lib.cairo");
+
+    let mut hierarchies = OrderedHashMap::default();
+
+    for (n, position) in cursors.carets().into_iter().enumerate() {
+        let mut report = String::new();
+
+        report.push_str(&peek_caret(&cairo, position));
+        let params = CallHierarchyPrepareParams {
+            text_document_position_params: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri: ls.doc_id("src/lib.cairo").uri },
+                position,
+            },
+            work_done_progress_params: Default::default(),
+        };
+        let items = ls.send_request::<lsp_request!("textDocument/prepareCallHierarchy")>(params);
+
+        match items.as_deref() {
+            Some([item]) => report_calls(&mut ls, &cairo, item, &mut report),
+            Some(items) => panic!("Unexpected call hierarchy items: {items:?}"),
+            None => report.push_str("none response"),
+        }
+        hierarchies.insert(format!("Call hierarchy #{n}"), report);
+    }
+
+    TestRunnerResult::success(hierarchies)
+}
+
+/// Reports the incoming and outgoing calls of a call hierarchy item.
+fn report_calls(ls: &mut MockClient, cairo: &str, item: &CallHierarchyItem, report: &mut String) {
+    assert_eq!(item.uri, ls.doc_id("src/lib.cairo").uri);
+    report.push_str(&format!("item: {} ({:?})\n", item.detail.as_ref().unwrap(), item.kind));
+    report.push_str(&peek_selection(cairo, &item.selection_range));
+
+    let params = CallHierarchyIncomingCallsParams {
+        item: item.clone(),
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+    let incoming_calls = ls
+        .send_request::<lsp_request!("callHierarchy/incomingCalls")>(params)
+        .expect("Incoming calls request failed.");
+    for call in incoming_calls {
+        report.push_str(&format!("incoming: {}\n", call.from.detail.unwrap()));
+        for range in call.from_ranges {
+            report.push_str(&peek_selection(cairo, &range));
+        }
+    }
+
+    let params = CallHierarchyOutgoingCallsParams {
+        item: item.clone(),
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+    let outgoing_calls = ls
+        .send_request::<lsp_request!("callHierarchy/outgoingCalls")>(params)
+        .expect("Outgoing calls request failed.");
+    for call in outgoing_calls {
+        report.push_str(&format!("outgoing: {}\n", call.to.detail.unwrap()));
+        for range in call.from_ranges {
+            report.push_str(&peek_selection(cairo, &range));
+        }
+    }
+}
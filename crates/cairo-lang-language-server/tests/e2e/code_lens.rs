@@ -0,0 +1,80 @@
+use cairo_lang_test_utils::parse_test_file::TestRunnerResult;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use lsp_types::{
+    ClientCapabilities, CodeLensClientCapabilities, CodeLensParams, TextDocumentClientCapabilities,
+    TextDocumentIdentifier, lsp_request,
+};
+
+use crate::support::cursor::peek_selection;
+use crate::support::sandbox;
+
+cairo_lang_test_utils::test_file_test!(
+    code_lens,
+    "tests/test_data/code_lens",
+    {
+        tests: "tests.txt",
+    },
+    test_code_lens
+);
+
+fn caps(base: ClientCapabilities) -> ClientCapabilities {
+    ClientCapabilities {
+        text_document: base.text_document.or_else(Default::default).map(|it| {
+            TextDocumentClientCapabilities {
+                code_lens: Some(CodeLensClientCapabilities { dynamic_registration: Some(false) }),
+                ..it
+            }
+        }),
+        ..base
+    }
+}
+
+/// Perform code lens test.
+///
+/// This function spawns a sandbox language server with the given code in the `src/lib.cairo` file.
+/// The function then requests the code lenses of the file and reports the title, the selected
+/// range and the command arguments of each lens.
+fn test_code_lens(
+    inputs: &OrderedHashMap<String, String>,
+    _args: &OrderedHashMap<String, String>,
+) -> TestRunnerResult {
+    let cairo = &inputs["cairo_code"];
+
+    let mut ls = sandbox! {
+        files {
+            "cairo_project.toml" => inputs["cairo_project.toml"].clone(),
+            "src/lib.cairo" => cairo.clone(),
+        }
+        client_capabilities = caps;
+    };
+    ls.open_and_wait_for_diagnostics("src/lib.cairo");
+
+    let uri = ls.doc_id("src/lib.cairo").uri;
+    let params = CodeLensParams {
+        text_document: TextDocumentIdentifier { uri: uri.clone() },
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+    let lenses = ls
+        .send_request::<lsp_request!("textDocument/codeLens")>(params)
+        .expect("Code lens request failed.");
+
+    let mut report = String::new();
+    for lens in lenses {
+        let command = lens.command.expect("Code lenses must be resolved.");
+        let [argument] = &command.arguments.unwrap_or_default()[..] else {
+            panic!("Expected a single argument of the `{}` command.", command.command);
+        };
+        assert_eq!(argument["uri"], uri.as_str());
+        report.push_str(&format!(
+            "{} ({} {} exact: {})\n{}",
+            command.title,
+            command.command,
+            argument["filter"],
+            argument["exact"],
+            peek_selection(cairo, &lens.range)
+        ));
+    }
+
+    TestRunnerResult::success(OrderedHashMap::from([("Code lenses".to_string(), report)]))
+}
@@ -0,0 +1,43 @@
+use indoc::indoc;
+use lsp_types::NumberOrString;
+use pretty_assertions::assert_eq;
+
+use crate::support::sandbox;
+
+#[test]
+fn error_codes() {
+    let mut ls = sandbox! {
+        files {
+            "cairo_project.toml" => indoc! {r#"
+                [crate_roots]
+                hello = "src"
+            "#},
+            "src/lib.cairo" => indoc! {r#"
+                fn main() {
+                    let x = 5;
+                    let y: felt252 = 1;
+                    y.foo();
+                }
+            "#},
+        }
+    };
+
+    let diagnostics = ls.open_and_wait_for_diagnostics("src/lib.cairo").diagnostics;
+    let codes = diagnostics
+        .iter()
+        .map(|diagnostic| {
+            let Some(NumberOrString::String(code)) = &diagnostic.code else {
+                panic!("Diagnostic has no error code: {diagnostic:?}");
+            };
+            let href = diagnostic.code_description.as_ref().map(|description| {
+                description.href.as_str().rsplit('/').next().unwrap().to_string()
+            });
+            (code.as_str(), href)
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(codes, [
+        ("E0002", Some("E0002.md".to_string())),
+        ("E0001", Some("E0001.md".to_string()))
+    ]);
+}
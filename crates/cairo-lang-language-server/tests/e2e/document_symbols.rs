@@ -0,0 +1,94 @@
+use cairo_lang_test_utils::parse_test_file::TestRunnerResult;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use lsp_types::{
+    ClientCapabilities, DocumentSymbol, DocumentSymbolClientCapabilities, DocumentSymbolParams,
+    DocumentSymbolResponse, TextDocumentClientCapabilities, TextDocumentIdentifier, lsp_request,
+};
+
+use crate::support::cursor::index_in_text;
+use crate::support::sandbox;
+
+cairo_lang_test_utils::test_file_test!(
+    document_symbols,
+    "tests/test_data/document_symbols",
+    {
+        items: "items.txt",
+        starknet: "starknet.txt",
+    },
+    test_document_symbols
+);
+
+fn caps(base: ClientCapabilities) -> ClientCapabilities {
+    ClientCapabilities {
+        text_document: base.text_document.or_else(Default::default).map(|it| {
+            TextDocumentClientCapabilities {
+                document_symbol: Some(DocumentSymbolClientCapabilities {
+                    dynamic_registration: Some(false),
+                    hierarchical_document_symbol_support: Some(true),
+                    ..Default::default()
+                }),
+                ..it
+            }
+        }),
+        ..base
+    }
+}
+
+/// Perform document symbols test.
+///
+/// This function spawns a sandbox language server with the given code in the `src/lib.cairo` file.
+/// The function then requests the symbols of the file and renders them as an indented outline,
+/// with the lines covered by each symbol.
+fn test_document_symbols(
+    inputs: &OrderedHashMap<String, String>,
+    _args: &OrderedHashMap<String, String>,
+) -> TestRunnerResult {
+    let cairo = &inputs["cairo_code"];
+
+    let mut ls = sandbox! {
+        files {
+            "cairo_project.toml" => inputs["cairo_project.toml"].clone(),
+            "src/lib.cairo" => cairo.clone(),
+        }
+        client_capabilities = caps;
+    };
+    ls.open_and_wait_for_diagnostics("src/lib.cairo");
+
+    let params = DocumentSymbolParams {
+        text_document: TextDocumentIdentifier { uri: ls.doc_id("src/lib.cairo").uri },
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+    let response = ls
+        .send_request::<lsp_request!("textDocument/documentSymbol")>(params)
+        .expect("Document symbols request failed.");
+    let DocumentSymbolResponse::Nested(symbols) = response else {
+        panic!("Expected nested document symbols, got: {response:?}");
+    };
+
+    let mut outline = String::new();
+    render_symbols(cairo, &symbols, 0, &mut outline);
+
+    TestRunnerResult::success(OrderedHashMap::from([("Symbols".to_string(), outline)]))
+}
+
+/// Renders the symbols and their children as an indented outline.
+fn render_symbols(cairo: &str, symbols: &[DocumentSymbol], depth: usize, outline: &mut String) {
+    for symbol in symbols {
+        let selection = &cairo[index_in_text(cairo, symbol.selection_range.start)
+            ..index_in_text(cairo, symbol.selection_range.end)];
+        assert_eq!(selection, symbol.name, "The selection range must be the symbol name.");
+        assert!(
+            symbol.range.start <= symbol.selection_range.start
+                && symbol.selection_range.end <= symbol.range.end,
+            "The range of `{}` must contain its selection range.",
+            symbol.name
+        );
+
+        let indent = "    ".repeat(depth);
+        let detail = symbol.detail.as_ref().map(|detail| format!(" {detail}")).unwrap_or_default();
+        let lines = format!("{}-{}", symbol.range.start.line + 1, symbol.range.end.line + 1);
+        outline.push_str(&format!("{indent}{:?} {}{detail} @ {lines}\n", symbol.kind, symbol.name));
+        render_symbols(cairo, symbol.children.as_deref().unwrap_or_default(), depth + 1, outline);
+    }
+}
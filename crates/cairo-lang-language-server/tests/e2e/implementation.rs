@@ -0,0 +1,88 @@
+use cairo_lang_test_utils::parse_test_file::TestRunnerResult;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use lsp_types::request::GotoImplementationResponse;
+use lsp_types::{
+    ClientCapabilities, GotoCapability, GotoDefinitionParams, TextDocumentClientCapabilities,
+    TextDocumentIdentifier, TextDocumentPositionParams, lsp_request,
+};
+
+use crate::support::cursor::{peek_caret, peek_selection};
+use crate::support::{cursors, sandbox};
+
+cairo_lang_test_utils::test_file_test!(
+    implementation,
+    "tests/test_data/implementation",
+    {
+        traits: "traits.txt",
+        trait_fns: "trait_fns.txt",
+    },
+    test_implementation
+);
+
+fn caps(base: ClientCapabilities) -> ClientCapabilities {
+    ClientCapabilities {
+        text_document: base.text_document.or_else(Default::default).map(|it| {
+            TextDocumentClientCapabilities {
+                implementation: Some(GotoCapability {
+                    dynamic_registration: Some(false),
+                    link_support: None,
+                }),
+                ..it
+            }
+        }),
+        ..base
+    }
+}
+
+/// Perform go to implementation test.
+///
+/// This function spawns a sandbox language server with the given code in the `src/lib.cairo` file.
+/// The Cairo source code is expected to contain caret markers.
+/// The function then requests the implementations at each caret position and compares the result
+/// with the expected implementations from the snapshot file.
+fn test_implementation(
+    inputs: &OrderedHashMap<String, String>,
+    _args: &OrderedHashMap<String, String>,
+) -> TestRunnerResult {
+    let (cairo, cursors) = cursors(&inputs["cairo_code"]);
+
+    let mut ls = sandbox! {
+        files {
+            "cairo_project.toml" => inputs["cairo_project.toml"].clone(),
+            "src/lib.cairo" => cairo.clone(),
+        }
+        client_capabilities = caps;
+    };
+    ls.open_and_wait_for_diagnostics("src/lib.cairo");
+
+    let mut implementations = OrderedHashMap::default();
+
+    for (n, position) in cursors.carets().into_iter().enumerate() {
+        let mut report = String::new();
+
+        report.push_str(&peek_caret(&cairo, position));
+        let params = GotoDefinitionParams {
+            text_document_position_params: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri: ls.doc_id("src/lib.cairo").uri },
+                position,
+            },
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        };
+        let response = ls.send_request::<lsp_request!("textDocument/implementation")>(params);
+
+        match response {
+            Some(GotoImplementationResponse::Array(locations)) => {
+                for location in locations {
+                    assert_eq!(location.uri, ls.doc_id("src/lib.cairo").uri);
+                    report.push_str(&peek_selection(&cairo, &location.range));
+                }
+            }
+            Some(response) => panic!("Unexpected implementation response: {response:?}"),
+            None => report.push_str("none response"),
+        }
+        implementations.insert(format!("Implementations #{n}"), report);
+    }
+
+    TestRunnerResult::success(implementations)
+}
@@ -0,0 +1,78 @@
+use cairo_lang_test_utils::parse_test_file::TestRunnerResult;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use lsp_types::{
+    ClientCapabilities, InlayHintClientCapabilities, InlayHintLabel, InlayHintParams, Position,
+    Range, TextDocumentClientCapabilities, TextDocumentIdentifier, lsp_request,
+};
+
+use crate::support::cursor::index_in_text;
+use crate::support::sandbox;
+
+cairo_lang_test_utils::test_file_test!(
+    inlay_hints,
+    "tests/test_data/inlay_hints",
+    {
+        calls: "calls.txt",
+        variables: "variables.txt",
+    },
+    test_inlay_hints
+);
+
+fn caps(base: ClientCapabilities) -> ClientCapabilities {
+    ClientCapabilities {
+        text_document: base.text_document.or_else(Default::default).map(|it| {
+            TextDocumentClientCapabilities {
+                inlay_hint: Some(InlayHintClientCapabilities {
+                    dynamic_registration: Some(false),
+                    resolve_support: None,
+                }),
+                ..it
+            }
+        }),
+        ..base
+    }
+}
+
+/// Perform inlay hints test.
+///
+/// This function spawns a sandbox language server with the given code in the `src/lib.cairo` file.
+/// The function then requests the inlay hints of the whole file and reports the code with the
+/// hints inserted at their positions.
+fn test_inlay_hints(
+    inputs: &OrderedHashMap<String, String>,
+    _args: &OrderedHashMap<String, String>,
+) -> TestRunnerResult {
+    let cairo = &inputs["cairo_code"];
+
+    let mut ls = sandbox! {
+        files {
+            "cairo_project.toml" => inputs["cairo_project.toml"].clone(),
+            "src/lib.cairo" => cairo.clone(),
+        }
+        client_capabilities = caps;
+    };
+    ls.open_and_wait_for_diagnostics("src/lib.cairo");
+
+    let params = InlayHintParams {
+        text_document: TextDocumentIdentifier { uri: ls.doc_id("src/lib.cairo").uri },
+        range: Range {
+            start: Position { line: 0, character: 0 },
+            end: Position { line: cairo.lines().count() as u32, character: 0 },
+        },
+        work_done_progress_params: Default::default(),
+    };
+    let hints = ls
+        .send_request::<lsp_request!("textDocument/inlayHint")>(params)
+        .expect("Inlay hints request failed.");
+
+    let mut code = cairo.clone();
+    // Inserting from the last hint keeps the positions of the preceding hints valid.
+    for hint in hints.iter().rev() {
+        let InlayHintLabel::String(label) = &hint.label else {
+            panic!("Expected a string inlay hint label.");
+        };
+        code.insert_str(index_in_text(cairo, hint.position), &format!("<hint>{label}</hint>"));
+    }
+
+    TestRunnerResult::success(OrderedHashMap::from([("Inlay hints".to_string(), code)]))
+}
@@ -1,9 +1,19 @@
 mod analysis;
+mod call_hierarchy;
 mod code_actions;
+mod code_lens;
 mod completions;
+mod diagnostics;
+mod document_symbols;
 mod goto;
 mod hover;
+mod implementation;
+mod inlay_hints;
 mod macro_expand;
+mod references;
+mod rename;
 mod semantic_tokens;
+mod signature_help;
 mod support;
 mod workspace_configuration;
+mod workspace_symbols;
@@ -0,0 +1,86 @@
+use cairo_lang_test_utils::parse_test_file::TestRunnerResult;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use lsp_types::{
+    ClientCapabilities, DynamicRegistrationClientCapabilities, ReferenceContext, ReferenceParams,
+    TextDocumentClientCapabilities, TextDocumentIdentifier, TextDocumentPositionParams,
+    lsp_request,
+};
+
+use crate::support::cursor::{peek_caret, peek_selection};
+use crate::support::{cursors, sandbox};
+
+cairo_lang_test_utils::test_file_test!(
+    references,
+    "tests/test_data/references",
+    {
+        fns: "fns.txt",
+        items: "items.txt",
+        variables: "variables.txt",
+    },
+    test_references
+);
+
+fn caps(base: ClientCapabilities) -> ClientCapabilities {
+    ClientCapabilities {
+        text_document: base.text_document.or_else(Default::default).map(|it| {
+            TextDocumentClientCapabilities {
+                references: Some(DynamicRegistrationClientCapabilities {
+                    dynamic_registration: Some(false),
+                }),
+                ..it
+            }
+        }),
+        ..base
+    }
+}
+
+/// Perform references test.
+///
+/// This function spawns a sandbox language server with the given code in the `src/lib.cairo` file.
+/// The Cairo source code is expected to contain caret markers.
+/// The function then requests references at each caret position and compares the result with the
+/// expected references from the snapshot file.
+fn test_references(
+    inputs: &OrderedHashMap<String, String>,
+    args: &OrderedHashMap<String, String>,
+) -> TestRunnerResult {
+    let (cairo, cursors) = cursors(&inputs["cairo_code"]);
+    let include_declaration = args.get("include_declaration").is_none_or(|arg| arg == "true");
+
+    let mut ls = sandbox! {
+        files {
+            "cairo_project.toml" => inputs["cairo_project.toml"].clone(),
+            "src/lib.cairo" => cairo.clone(),
+        }
+        client_capabilities = caps;
+    };
+    ls.open_and_wait_for_diagnostics("src/lib.cairo");
+
+    let mut references = OrderedHashMap::default();
+
+    for (n, position) in cursors.carets().into_iter().enumerate() {
+        let mut report = String::new();
+
+        report.push_str(&peek_caret(&cairo, position));
+        let params = ReferenceParams {
+            text_document_position: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri: ls.doc_id("src/lib.cairo").uri },
+                position,
+            },
+            context: ReferenceContext { include_declaration },
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        };
+        let locations = ls
+            .send_request::<lsp_request!("textDocument/references")>(params)
+            .expect("References request failed.");
+
+        for location in locations {
+            assert_eq!(location.uri, ls.doc_id("src/lib.cairo").uri);
+            report.push_str(&peek_selection(&cairo, &location.range));
+        }
+        references.insert(format!("References #{n}"), report);
+    }
+
+    TestRunnerResult::success(references)
+}
@@ -0,0 +1,94 @@
+use cairo_lang_test_utils::parse_test_file::TestRunnerResult;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use lsp_types::{
+    ClientCapabilities, RenameClientCapabilities, RenameParams, TextDocumentClientCapabilities,
+    TextDocumentIdentifier, TextDocumentPositionParams, lsp_request,
+};
+
+use crate::support::cursor::{index_in_text, peek_caret};
+use crate::support::{cursors, sandbox};
+
+cairo_lang_test_utils::test_file_test!(
+    rename,
+    "tests/test_data/rename",
+    {
+        items: "items.txt",
+        variables: "variables.txt",
+    },
+    test_rename
+);
+
+fn caps(base: ClientCapabilities) -> ClientCapabilities {
+    ClientCapabilities {
+        text_document: base.text_document.or_else(Default::default).map(|it| {
+            TextDocumentClientCapabilities {
+                rename: Some(RenameClientCapabilities {
+                    dynamic_registration: Some(false),
+                    ..Default::default()
+                }),
+                ..it
+            }
+        }),
+        ..base
+    }
+}
+
+/// Perform rename test.
+///
+/// This function spawns a sandbox language server with the given code in the `src/lib.cairo` file.
+/// The Cairo source code is expected to contain caret markers.
+/// The function then requests renaming the symbol at each caret position to the name given in the
+/// `new_name` argument, and compares the renamed code with the expected code from the snapshot
+/// file.
+fn test_rename(
+    inputs: &OrderedHashMap<String, String>,
+    args: &OrderedHashMap<String, String>,
+) -> TestRunnerResult {
+    let (cairo, cursors) = cursors(&inputs["cairo_code"]);
+    let new_name = &args["new_name"];
+
+    let mut ls = sandbox! {
+        files {
+            "cairo_project.toml" => inputs["cairo_project.toml"].clone(),
+            "src/lib.cairo" => cairo.clone(),
+        }
+        client_capabilities = caps;
+    };
+    ls.open_and_wait_for_diagnostics("src/lib.cairo");
+
+    let mut renames = OrderedHashMap::default();
+
+    for (n, position) in cursors.carets().into_iter().enumerate() {
+        let mut report = String::new();
+
+        report.push_str(&peek_caret(&cairo, position));
+        let params = RenameParams {
+            text_document_position: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri: ls.doc_id("src/lib.cairo").uri },
+                position,
+            },
+            new_name: new_name.clone(),
+            work_done_progress_params: Default::default(),
+        };
+        let edit = ls
+            .send_request::<lsp_request!("textDocument/rename")>(params)
+            .expect("Rename request failed.");
+
+        let mut changes = edit.changes.unwrap_or_default();
+        let mut edits = changes.remove(&ls.doc_id("src/lib.cairo").uri).unwrap_or_default();
+        assert!(changes.is_empty(), "Unexpected edits outside of `src/lib.cairo`.");
+
+        // Apply the edits from the end, so that the positions of the other edits stay valid.
+        edits.sort_by_key(|edit| edit.range.start);
+        let mut renamed = cairo.clone();
+        for edit in edits.into_iter().rev() {
+            let start = index_in_text(&cairo, edit.range.start);
+            let end = index_in_text(&cairo, edit.range.end);
+            renamed.replace_range(start..end, &edit.new_text);
+        }
+        report.push_str(&renamed);
+        renames.insert(format!("Rename #{n}"), report);
+    }
+
+    TestRunnerResult::success(renames)
+}
@@ -0,0 +1,97 @@
+use cairo_lang_test_utils::parse_test_file::TestRunnerResult;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use lsp_types::{
+    ClientCapabilities, ParameterLabel, SignatureHelpClientCapabilities, SignatureHelpParams,
+    TextDocumentClientCapabilities, TextDocumentIdentifier, TextDocumentPositionParams,
+    lsp_request,
+};
+
+use crate::support::cursor::peek_caret;
+use crate::support::{cursors, sandbox};
+
+cairo_lang_test_utils::test_file_test!(
+    signature_help,
+    "tests/test_data/signature_help",
+    {
+        fns: "fns.txt",
+        methods: "methods.txt",
+    },
+    test_signature_help
+);
+
+fn caps(base: ClientCapabilities) -> ClientCapabilities {
+    ClientCapabilities {
+        text_document: base.text_document.or_else(Default::default).map(|it| {
+            TextDocumentClientCapabilities {
+                signature_help: Some(SignatureHelpClientCapabilities {
+                    dynamic_registration: Some(false),
+                    ..Default::default()
+                }),
+                ..it
+            }
+        }),
+        ..base
+    }
+}
+
+/// Perform signature help test.
+///
+/// This function spawns a sandbox language server with the given code in the `src/lib.cairo` file.
+/// The Cairo source code is expected to contain caret markers.
+/// The function then requests signature help at each caret position and reports the signature
+/// label, with the active parameter selected.
+fn test_signature_help(
+    inputs: &OrderedHashMap<String, String>,
+    _args: &OrderedHashMap<String, String>,
+) -> TestRunnerResult {
+    let (cairo, cursors) = cursors(&inputs["cairo_code"]);
+
+    let mut ls = sandbox! {
+        files {
+            "cairo_project.toml" => inputs["cairo_project.toml"].clone(),
+            "src/lib.cairo" => cairo.clone(),
+        }
+        client_capabilities = caps;
+    };
+    ls.open_and_wait_for_diagnostics("src/lib.cairo");
+
+    let mut signatures = OrderedHashMap::default();
+
+    for (n, position) in cursors.carets().into_iter().enumerate() {
+        let mut report = String::new();
+
+        report.push_str(&peek_caret(&cairo, position));
+        let params = SignatureHelpParams {
+            context: None,
+            text_document_position_params: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri: ls.doc_id("src/lib.cairo").uri },
+                position,
+            },
+            work_done_progress_params: Default::default(),
+        };
+        let signature_help = ls.send_request::<lsp_request!("textDocument/signatureHelp")>(params);
+
+        match signature_help {
+            Some(signature_help) => {
+                let signature = &signature_help.signatures[0];
+                let mut label = signature.label.clone();
+                let active_parameter = signature_help
+                    .active_parameter
+                    .and_then(|idx| signature.parameters.as_ref()?.get(idx as usize));
+                if let Some(parameter) = active_parameter {
+                    let ParameterLabel::LabelOffsets([start, end]) = parameter.label else {
+                        panic!("Expected parameter label offsets.");
+                    };
+                    // Labels in tests are ASCII, so UTF-16 offsets are byte offsets.
+                    label.insert_str(end as usize, "</sel>");
+                    label.insert_str(start as usize, "<sel>");
+                }
+                report.push_str(&label);
+            }
+            None => report.push_str("No signature help."),
+        }
+        signatures.insert(format!("Signature help #{n}"), report);
+    }
+
+    TestRunnerResult::success(signatures)
+}
@@ -0,0 +1,82 @@
+use cairo_lang_test_utils::parse_test_file::TestRunnerResult;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use lsp_types::{
+    ClientCapabilities, WorkspaceClientCapabilities, WorkspaceSymbolClientCapabilities,
+    WorkspaceSymbolParams, WorkspaceSymbolResponse, lsp_request,
+};
+
+use crate::support::cursor::peek_selection;
+use crate::support::sandbox;
+
+cairo_lang_test_utils::test_file_test!(
+    workspace_symbols,
+    "tests/test_data/workspace_symbols",
+    {
+        queries: "queries.txt",
+    },
+    test_workspace_symbols
+);
+
+fn caps(base: ClientCapabilities) -> ClientCapabilities {
+    ClientCapabilities {
+        workspace: base.workspace.or_else(Default::default).map(|it| WorkspaceClientCapabilities {
+            symbol: Some(WorkspaceSymbolClientCapabilities {
+                dynamic_registration: Some(false),
+                ..Default::default()
+            }),
+            ..it
+        }),
+        ..base
+    }
+}
+
+/// Perform workspace symbols test.
+///
+/// This function spawns a sandbox language server with the given code in the `src/lib.cairo` file.
+/// The function then searches for symbols matching each of the `queries` argument,
+/// space-separated, and reports the symbols found in the file, in the order they are returned.
+/// Symbols found in the core library are skipped, to keep the reports stable.
+fn test_workspace_symbols(
+    inputs: &OrderedHashMap<String, String>,
+    args: &OrderedHashMap<String, String>,
+) -> TestRunnerResult {
+    let cairo = &inputs["cairo_code"];
+
+    let mut ls = sandbox! {
+        files {
+            "cairo_project.toml" => inputs["cairo_project.toml"].clone(),
+            "src/lib.cairo" => cairo.clone(),
+        }
+        client_capabilities = caps;
+    };
+    ls.open_and_wait_for_diagnostics("src/lib.cairo");
+
+    let mut symbols = OrderedHashMap::default();
+
+    for query in args["queries"].split_whitespace() {
+        let params = WorkspaceSymbolParams {
+            query: query.to_string(),
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        };
+        let response = ls
+            .send_request::<lsp_request!("workspace/symbol")>(params)
+            .expect("Workspace symbols request failed.");
+        let WorkspaceSymbolResponse::Flat(found) = response else {
+            panic!("Expected flat workspace symbols, got: {response:?}");
+        };
+
+        let mut report = String::new();
+        for symbol in found {
+            if symbol.location.uri != ls.doc_id("src/lib.cairo").uri {
+                continue;
+            }
+            let container = symbol.container_name.unwrap_or_default();
+            report.push_str(&format!("{:?} {container}::{}\n", symbol.kind, symbol.name));
+            report.push_str(&peek_selection(cairo, &symbol.location.range));
+        }
+        symbols.insert(format!("Symbols for `{query}`"), report);
+    }
+
+    TestRunnerResult::success(symbols)
+}
@@ -1,11 +1,13 @@
 use std::sync::Arc;
 
 use cairo_lang_defs as defs;
+use cairo_lang_defs::diagnostic_utils::StableLocation;
 use cairo_lang_defs::ids::{LanguageElementId, ModuleId, ModuleItemId, NamedLanguageElementLongId};
 use cairo_lang_diagnostics::{Diagnostics, DiagnosticsBuilder, Maybe};
 use cairo_lang_filesystem::ids::FileId;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::items::enm::SemanticEnumEx;
+use cairo_lang_semantic::lints::apply_lint_levels;
 use cairo_lang_semantic::{self as semantic, ConcreteTypeId, TypeId, TypeLongId, corelib};
 use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
 use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
@@ -23,6 +25,7 @@ use crate::diagnostic::{LoweringDiagnostic, LoweringDiagnosticKind};
 use crate::graph_algorithms::feedback_set::flag_add_withdraw_gas;
 use crate::ids::{FunctionId, FunctionLongId};
 use crate::inline::get_inline_diagnostics;
+use crate::lint_passes::{LoweredLintContext, LoweredLintPass};
 use crate::lower::{MultiLowering, lower_semantic_function};
 use crate::optimizations::config::OptimizationConfig;
 use crate::optimizations::scrub_units::scrub_units;
@@ -334,6 +337,11 @@ pub trait LoweringGroup: SemanticGroup + Upcast<dyn SemanticGroup> {
     #[salsa::input]
     fn optimization_config(&self) -> Arc<OptimizationConfig>;
 
+    /// The lint passes run on the lowered bodies of functions. Set with
+    /// [crate::lint_passes::set_lowered_lint_suite], which declares their lints as well.
+    #[salsa::input]
+    fn lowered_lint_passes(&self) -> Vec<Arc<dyn LoweredLintPass>>;
+
     /// Returns the final optimization strategy that is applied on top of
     /// inlined_function_optimization_strategy.
     #[salsa::invoke(crate::optimizations::strategy::final_optimization_strategy)]
@@ -367,6 +375,7 @@ pub fn init_lowering_group(
             .with_moveable_functions(moveable_functions)
             .with_inlining_strategy(inlining_strategy),
     ));
+    db.set_lowered_lint_passes(vec![]);
 }
 
 #[derive(Debug, Eq, PartialEq, Clone, Hash)]
@@ -678,6 +687,7 @@ fn function_with_body_lowering_diagnostics(
             diagnostics.add(LoweringDiagnostic {
                 location,
                 kind: LoweringDiagnosticKind::NoPanicFunctionCycle,
+                denied: false,
             });
         }
     }
@@ -699,6 +709,7 @@ fn semantic_function_with_body_lowering_diagnostics(
         let function_id = ids::FunctionWithBodyLongId::Semantic(semantic_function_id).intern(db);
         diagnostics
             .extend(db.function_with_body_lowering_diagnostics(function_id).unwrap_or_default());
+        add_lint_pass_diagnostics(db, semantic_function_id, function_id, &mut diagnostics);
         for (key, _) in multi_lowering.generated_lowerings.iter() {
             let function_id =
                 ids::FunctionWithBodyLongId::Generated { parent: semantic_function_id, key: *key }
@@ -712,6 +723,38 @@ fn semantic_function_with_body_lowering_diagnostics(
     Ok(diagnostics.build())
 }
 
+/// Adds the diagnostics reported by the lowered lint passes on the lowered form of a function.
+///
+/// Only the main lowering of the function is checked, not the functions generated for its loops.
+fn add_lint_pass_diagnostics(
+    db: &dyn LoweringGroup,
+    semantic_function_id: defs::ids::FunctionWithBodyId,
+    function_id: ids::FunctionWithBodyId,
+    diagnostics: &mut DiagnosticsBuilder<LoweringDiagnostic>,
+) {
+    let lint_passes = db.lowered_lint_passes();
+    if lint_passes.is_empty() {
+        return;
+    }
+    let Ok(lowered) = db.function_with_body_lowering(function_id) else {
+        return;
+    };
+    let mut cx = LoweredLintContext::new(db, semantic_function_id);
+    for lint_pass in lint_passes.iter() {
+        lint_pass.check_lowered(&mut cx, &lowered);
+    }
+    for report in cx.into_reports() {
+        diagnostics.add(LoweringDiagnostic {
+            location: Location::new(StableLocation::new(report.stable_ptr)),
+            kind: LoweringDiagnosticKind::LintPassDiagnostic {
+                lint: report.lint,
+                message: report.message,
+            },
+            denied: false,
+        });
+    }
+}
+
 fn module_lowering_diagnostics(
     db: &dyn LoweringGroup,
     module_id: ModuleId,
@@ -731,7 +774,22 @@ fn module_lowering_diagnostics(
             ModuleItemId::Enum(_) => {}
             ModuleItemId::TypeAlias(_) => {}
             ModuleItemId::ImplAlias(_) => {}
-            ModuleItemId::Trait(_) => {}
+            ModuleItemId::Trait(trait_id) => {
+                for trait_func in db.trait_functions(*trait_id)?.values() {
+                    if !matches!(db.trait_function_body(*trait_func), Ok(Some(_))) {
+                        continue;
+                    }
+                    let semantic_function_id = defs::ids::FunctionWithBodyId::Trait(*trait_func);
+                    let function_id =
+                        ids::FunctionWithBodyLongId::Semantic(semantic_function_id).intern(db);
+                    add_lint_pass_diagnostics(
+                        db,
+                        semantic_function_id,
+                        function_id,
+                        &mut diagnostics,
+                    );
+                }
+            }
             ModuleItemId::Impl(impl_def_id) => {
                 for impl_func in db.impl_functions(*impl_def_id)?.values() {
                     let function_id = defs::ids::FunctionWithBodyId::Impl(*impl_func);
@@ -743,7 +801,7 @@ fn module_lowering_diagnostics(
             ModuleItemId::ExternFunction(_) => {}
         }
     }
-    Ok(diagnostics.build())
+    Ok(apply_lint_levels(db.upcast(), module_id, diagnostics.build()))
 }
 
 fn file_lowering_diagnostics(
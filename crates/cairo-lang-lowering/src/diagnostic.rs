@@ -1,12 +1,14 @@
 use cairo_lang_defs::diagnostic_utils::StableLocation;
 use cairo_lang_diagnostics::{
-    DiagnosticAdded, DiagnosticEntry, DiagnosticLocation, DiagnosticNote, DiagnosticsBuilder,
-    Severity,
+    Applicability, DiagnosticAdded, DiagnosticEdit, DiagnosticEntry, DiagnosticFix,
+    DiagnosticLocation, DiagnosticNote, DiagnosticsBuilder, ErrorCode, Severity, error_code,
 };
 use cairo_lang_semantic as semantic;
 use cairo_lang_semantic::corelib::LiteralError;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::expr::inference::InferenceError;
+use cairo_lang_semantic::lint_passes::Lint;
+use cairo_lang_semantic::lints::{self, LintDiagnostic};
 use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
 
 use crate::Location;
@@ -32,7 +34,7 @@ impl LoweringDiagnosticsBuilder for LoweringDiagnostics {
         location: Location,
         kind: LoweringDiagnosticKind,
     ) -> DiagnosticAdded {
-        self.add(LoweringDiagnostic { location, kind })
+        self.add(LoweringDiagnostic { location, kind, denied: false })
     }
 }
 
@@ -40,6 +42,9 @@ impl LoweringDiagnosticsBuilder for LoweringDiagnostics {
 pub struct LoweringDiagnostic {
     pub location: Location,
     pub kind: LoweringDiagnosticKind,
+    /// true if the lint covering the diagnostic is denied, in which case it is reported as an
+    /// error.
+    pub denied: bool,
 }
 
 impl DiagnosticEntry for LoweringDiagnostic {
@@ -82,12 +87,17 @@ impl DiagnosticEntry for LoweringDiagnostic {
             LoweringDiagnosticKind::EmptyRepeatedElementFixedSizeArray => {
                 "Fixed size array repeated element size must be greater than 0.".into()
             }
+            LoweringDiagnosticKind::LintPassDiagnostic { message, .. } => message.clone(),
         }
     }
 
     fn severity(&self) -> Severity {
+        if self.denied {
+            return Severity::Error;
+        }
         match self.kind {
-            LoweringDiagnosticKind::Unreachable { .. } => Severity::Warning,
+            LoweringDiagnosticKind::Unreachable { .. }
+            | LoweringDiagnosticKind::LintPassDiagnostic { .. } => Severity::Warning,
             _ => Severity::Error,
         }
     }
@@ -96,6 +106,10 @@ impl DiagnosticEntry for LoweringDiagnostic {
         &self.location.notes
     }
 
+    fn error_code(&self) -> Option<ErrorCode> {
+        Some(self.kind.error_code())
+    }
+
     fn location(&self, db: &Self::DbType) -> DiagnosticLocation {
         if let LoweringDiagnosticKind::Unreachable { last_statement_ptr } = &self.kind {
             return self
@@ -106,11 +120,41 @@ impl DiagnosticEntry for LoweringDiagnostic {
         self.location.stable_location.diagnostic_location(db.upcast())
     }
 
+    fn fixes(&self, db: &Self::DbType) -> Vec<DiagnosticFix> {
+        match &self.kind {
+            LoweringDiagnosticKind::Unreachable { .. } => vec![DiagnosticFix {
+                message: "Remove unreachable code".into(),
+                // Removing the code may leave variables and imports it used unused.
+                applicability: Applicability::MaybeIncorrect,
+                edits: vec![DiagnosticEdit::remove(self.location(db))],
+            }],
+            _ => vec![],
+        }
+    }
+
     fn is_same_kind(&self, other: &Self) -> bool {
         other.kind == self.kind
     }
 }
 
+impl LintDiagnostic for LoweringDiagnostic {
+    fn lint(&self) -> Option<&'static str> {
+        match self.kind {
+            LoweringDiagnosticKind::Unreachable { .. } => Some(lints::UNREACHABLE_CODE),
+            LoweringDiagnosticKind::LintPassDiagnostic { lint, .. } => Some(lint.name),
+            _ => None,
+        }
+    }
+
+    fn stable_location(&self) -> StableLocation {
+        self.location.stable_location
+    }
+
+    fn deny(&mut self) {
+        self.denied = true;
+    }
+}
+
 impl MatchError {
     fn format(&self) -> String {
         match (&self.error, &self.kind) {
@@ -212,6 +256,44 @@ pub enum LoweringDiagnosticKind {
     EmptyRepeatedElementFixedSizeArray,
     UnsupportedPattern,
     Unsupported,
+    LintPassDiagnostic { lint: &'static Lint, message: String },
+}
+
+impl LoweringDiagnosticKind {
+    /// Returns the stable error code of the diagnostic kind.
+    ///
+    /// Lowering diagnostics use the `E2xxx` codes. Codes are never reused, so new kinds must get
+    /// new codes.
+    pub fn error_code(&self) -> ErrorCode {
+        match self {
+            Self::Unreachable { .. } => error_code!(E2001),
+            Self::VariableMoved { .. } => error_code!(E2002),
+            Self::VariableNotDropped { .. } => error_code!(E2003),
+            Self::MatchError(match_error) => match match_error.error {
+                MatchDiagnostic::UnsupportedMatchedType(_) => error_code!(E2004),
+                MatchDiagnostic::UnsupportedMatchedValueTuple => error_code!(E2005),
+                MatchDiagnostic::UnsupportedMatchArmNotAVariant => error_code!(E2006),
+                MatchDiagnostic::UnsupportedMatchArmNotATuple => error_code!(E2007),
+                MatchDiagnostic::UnreachableMatchArm => error_code!(E2008),
+                MatchDiagnostic::MissingMatchArm(_) => error_code!(E2009),
+                MatchDiagnostic::UnsupportedMatchArmNotALiteral => error_code!(E2010),
+                MatchDiagnostic::UnsupportedMatchArmNonSequential => error_code!(E2011),
+                MatchDiagnostic::NonExhaustiveMatchFelt252 => error_code!(E2012),
+                MatchDiagnostic::UnsupportedNumericInLetCondition => error_code!(E2013),
+            },
+            Self::DesnappingANonCopyableType { .. } => error_code!(E2014),
+            Self::UnexpectedError => error_code!(E2015),
+            Self::CannotInlineFunctionThatMightCallItself => error_code!(E2016),
+            Self::MemberPathLoop => error_code!(E2017),
+            Self::NoPanicFunctionCycle => error_code!(E2018),
+            Self::LiteralError(_) => error_code!(E2019),
+            Self::FixedSizeArrayNonCopyableType => error_code!(E2020),
+            Self::EmptyRepeatedElementFixedSizeArray => error_code!(E2021),
+            Self::UnsupportedPattern => error_code!(E2022),
+            Self::Unsupported => error_code!(E2023),
+            Self::LintPassDiagnostic { .. } => error_code!(E2024),
+        }
+    }
 }
 
 /// Error in a match-like construct.
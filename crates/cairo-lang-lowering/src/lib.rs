@@ -12,6 +12,7 @@ pub mod graph_algorithms;
 pub mod ids;
 pub mod implicits;
 pub mod inline;
+pub mod lint_passes;
 pub mod lower;
 pub mod objects;
 pub mod optimizations;
@@ -0,0 +1,105 @@
+//! Lint passes on lowered functions, and the lint passes of the lowering phase.
+//!
+//! A lowered lint pass is registered in a [LoweredLintSuite], and is given the lowered form of the
+//! bodies of the functions of the user code. See [cairo_lang_semantic::lint_passes] for the lint
+//! passes on the semantic form, and for the [Lint]s both kinds of passes report.
+
+use std::sync::Arc;
+
+use cairo_lang_defs::ids::FunctionWithBodyId;
+use cairo_lang_semantic::lint_passes::{Lint, LintReport};
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+
+use crate::FlatLowered;
+use crate::db::LoweringGroup;
+
+pub mod unconditional_recursion;
+
+#[cfg(test)]
+mod test;
+
+/// A trait for a lowered lint pass: a plugin that reports lints on lowered function bodies.
+pub trait LoweredLintPass: std::fmt::Debug + Sync + Send {
+    /// The lints this pass may report.
+    ///
+    /// Lints reported by the pass must be declared here, so their names are accepted by the lint
+    /// attributes.
+    fn lints(&self) -> Vec<&'static Lint>;
+    /// Checks the lowered form of a function body.
+    fn check_lowered(&self, cx: &mut LoweredLintContext<'_>, lowered: &FlatLowered);
+}
+
+/// The context of a lowered lint pass checking a function body.
+pub struct LoweredLintContext<'a> {
+    /// The lowering database.
+    pub db: &'a dyn LoweringGroup,
+    /// The checked function.
+    pub function_id: FunctionWithBodyId,
+    /// The lints reported so far.
+    reports: Vec<LintReport>,
+}
+impl<'a> LoweredLintContext<'a> {
+    /// Creates a context for checking the lowered body of `function_id`.
+    pub fn new(db: &'a dyn LoweringGroup, function_id: FunctionWithBodyId) -> Self {
+        Self { db, function_id, reports: vec![] }
+    }
+
+    /// Reports `lint` at `stable_ptr`.
+    pub fn report(
+        &mut self,
+        stable_ptr: impl Into<SyntaxStablePtrId>,
+        lint: &'static Lint,
+        message: impl Into<String>,
+    ) {
+        self.reports.push(LintReport {
+            lint,
+            stable_ptr: stable_ptr.into(),
+            message: message.into(),
+        });
+    }
+
+    /// Returns the lints reported in this context.
+    pub fn into_reports(self) -> Vec<LintReport> {
+        self.reports
+    }
+}
+
+/// A suite of lowered lint passes.
+#[derive(Clone, Debug, Default)]
+pub struct LoweredLintSuite {
+    /// The lowered lint passes, running on all lowered function bodies.
+    pub lint_passes: Vec<Arc<dyn LoweredLintPass>>,
+}
+impl LoweredLintSuite {
+    /// Adds a lowered lint pass.
+    pub fn add_lint_pass_ex(&mut self, lint_pass: Arc<dyn LoweredLintPass>) -> &mut Self {
+        self.lint_passes.push(lint_pass);
+        self
+    }
+
+    /// Adds a lowered lint pass.
+    pub fn add_lint_pass<T: LoweredLintPass + Default + 'static>(&mut self) -> &mut Self {
+        self.add_lint_pass_ex(Arc::new(T::default()))
+    }
+
+    /// Merges the lowered lint passes of another suite into this one.
+    pub fn add(&mut self, suite: LoweredLintSuite) -> &mut Self {
+        self.lint_passes.extend(suite.lint_passes);
+        self
+    }
+}
+
+/// Sets the lowered lint passes of `db` to the passes of `suite`, and declares their lints.
+pub fn set_lowered_lint_suite(db: &mut (dyn LoweringGroup + 'static), suite: LoweredLintSuite) {
+    db.set_external_lints(Arc::new(
+        suite.lint_passes.iter().flat_map(|lint_pass| lint_pass.lints()).collect(),
+    ));
+    db.set_lowered_lint_passes(suite.lint_passes);
+}
+
+/// Gets the suite of the lint passes of the lowering phase.
+pub fn lowering_lint_suite() -> LoweredLintSuite {
+    let mut suite = LoweredLintSuite::default();
+    suite.add_lint_pass::<unconditional_recursion::UnconditionalRecursionLintPass>();
+    suite
+}
@@ -0,0 +1,32 @@
+use cairo_lang_semantic::test_utils::setup_test_module;
+use cairo_lang_test_utils::parse_test_file::TestRunnerResult;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+
+use super::{lowering_lint_suite, set_lowered_lint_suite};
+use crate::db::LoweringGroup;
+use crate::test_utils::LoweringDatabaseForTesting;
+
+cairo_lang_test_utils::test_file_test!(
+    lint_passes,
+    "src/lint_passes/test_data",
+    {
+        unconditional_recursion: "unconditional_recursion",
+    },
+    test_lint_passes
+);
+
+fn test_lint_passes(
+    inputs: &OrderedHashMap<String, String>,
+    _args: &OrderedHashMap<String, String>,
+) -> TestRunnerResult {
+    let mut db = LoweringDatabaseForTesting::new();
+    set_lowered_lint_suite(&mut db, lowering_lint_suite());
+    let (test_module, semantic_diagnostics) =
+        setup_test_module(&db, inputs["module_code"].as_str()).split();
+    let lowering_diagnostics = db.module_lowering_diagnostics(test_module.module_id).unwrap();
+
+    TestRunnerResult::success(OrderedHashMap::from([
+        ("semantic_diagnostics".into(), semantic_diagnostics),
+        ("lowering_diagnostics".into(), lowering_diagnostics.format(&db)),
+    ]))
+}
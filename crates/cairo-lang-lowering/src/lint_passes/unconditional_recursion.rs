@@ -0,0 +1,93 @@
+use cairo_lang_defs::ids::LanguageElementId;
+use cairo_lang_filesystem::db::LintLevel;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::lint_passes::Lint;
+use cairo_lang_utils::{LookupIntern, Upcast};
+
+use super::{LoweredLintContext, LoweredLintPass};
+use crate::ids::{FunctionId, FunctionLongId};
+use crate::{BlockId, FlatBlockEnd, FlatLowered, Statement};
+
+/// Functions that cannot return without calling themselves.
+pub static UNCONDITIONAL_RECURSION: Lint = Lint {
+    name: "unconditional_recursion",
+    default_level: LintLevel::Warn,
+    description: "Functions that cannot return without calling themselves.",
+};
+
+/// Lint pass reporting [UNCONDITIONAL_RECURSION].
+#[derive(Debug, Default)]
+pub struct UnconditionalRecursionLintPass;
+
+impl LoweredLintPass for UnconditionalRecursionLintPass {
+    fn lints(&self) -> Vec<&'static Lint> {
+        vec![&UNCONDITIONAL_RECURSION]
+    }
+
+    fn check_lowered(&self, cx: &mut LoweredLintContext<'_>, lowered: &FlatLowered) {
+        if lowered.blocks.has_root().is_err() {
+            return;
+        }
+        let mut always_recurses = vec![None; lowered.blocks.len()];
+        if block_always_recurses(cx, lowered, BlockId::root(), &mut always_recurses) {
+            cx.report(
+                cx.function_id.untyped_stable_ptr(cx.db.upcast()),
+                &UNCONDITIONAL_RECURSION,
+                "Function cannot return without calling itself.",
+            );
+        }
+    }
+}
+
+/// Returns whether all the paths starting at `block_id` call the checked function.
+///
+/// `always_recurses` caches the results of the visited blocks.
+fn block_always_recurses(
+    cx: &LoweredLintContext<'_>,
+    lowered: &FlatLowered,
+    block_id: BlockId,
+    always_recurses: &mut [Option<bool>],
+) -> bool {
+    if let Some(result) = always_recurses[block_id.0] {
+        return result;
+    }
+    // Loops are lowered into separate functions, so blocks should not form cycles. If they do,
+    // paths back to a block being visited are considered as not recursing.
+    always_recurses[block_id.0] = Some(false);
+    let block = &lowered.blocks[block_id];
+    let calls_itself = block.statements.iter().any(|statement| {
+        let Statement::Call(call) = statement else {
+            return false;
+        };
+        is_recursive_call(cx, call.function)
+    });
+    let result = calls_itself
+        || match &block.end {
+            FlatBlockEnd::Goto(target, _) => {
+                block_always_recurses(cx, lowered, *target, always_recurses)
+            }
+            FlatBlockEnd::Match { info } => {
+                !info.arms().is_empty()
+                    && info.arms().iter().all(|arm| {
+                        block_always_recurses(cx, lowered, arm.block_id, always_recurses)
+                    })
+            }
+            FlatBlockEnd::Return(..) | FlatBlockEnd::Panic(_) | FlatBlockEnd::NotSet => false,
+        };
+    always_recurses[block_id.0] = Some(result);
+    result
+}
+
+/// Returns whether calling `function` calls the checked function.
+fn is_recursive_call(cx: &LoweredLintContext<'_>, function: FunctionId) -> bool {
+    let FunctionLongId::Semantic(function) = function.lookup_intern(cx.db) else {
+        return false;
+    };
+    let db: &dyn SemanticGroup = cx.db.upcast();
+    function
+        .get_concrete(db)
+        .body(db)
+        .ok()
+        .flatten()
+        .is_some_and(|body| body.function_with_body_id(db) == cx.function_id)
+}
@@ -148,10 +148,11 @@ a = a * 3
     builder.add(LoweringDiagnostic {
         location,
         kind: LoweringDiagnosticKind::CannotInlineFunctionThatMightCallItself,
+        denied: false,
     });
 
     assert_eq!(builder.build().format(db), indoc::indoc! {"
-error: Cannot inline a function that might call itself.
+error[E2016]: Cannot inline a function that might call itself.
  --> lib.cairo:1:1
 fn test_func() { let mut a = 5; {
 ^*******************************^
@@ -1,4 +1,4 @@
-use cairo_lang_diagnostics::DiagnosticEntry;
+use cairo_lang_diagnostics::{DiagnosticEntry, ErrorCode, error_code};
 use cairo_lang_filesystem::db::FilesGroup;
 use cairo_lang_filesystem::ids::FileId;
 use cairo_lang_filesystem::span::TextSpan;
@@ -55,6 +55,42 @@ pub enum ParserDiagnosticKind {
     DisallowedTrailingSeparatorOr,
     ConsecutiveMathOperators { first_op: SyntaxKind, second_op: SyntaxKind },
 }
+impl ParserDiagnosticKind {
+    /// Returns the stable error code of the diagnostic kind.
+    ///
+    /// Parser diagnostics use the `E1xxx` codes. Codes are never reused, so new kinds must get new
+    /// codes.
+    pub fn error_code(&self) -> ErrorCode {
+        match self {
+            Self::SkippedElement { .. } => error_code!(E1001),
+            Self::MissingToken(_) => error_code!(E1002),
+            Self::MissingExpression => error_code!(E1003),
+            Self::MissingPathSegment => error_code!(E1004),
+            Self::MissingTypeClause => error_code!(E1005),
+            Self::MissingTypeExpression => error_code!(E1006),
+            Self::MissingWrappedArgList => error_code!(E1007),
+            Self::MissingPatteren => error_code!(E1008),
+            Self::ExpectedInToken => error_code!(E1009),
+            Self::ItemInlineMacroWithoutBang { .. } => error_code!(E1010),
+            Self::ReservedIdentifier { .. } => error_code!(E1011),
+            Self::UnderscoreNotAllowedAsIdentifier => error_code!(E1012),
+            Self::MissingLiteralSuffix => error_code!(E1013),
+            Self::InvalidNumericLiteralValue => error_code!(E1014),
+            Self::IllegalStringEscaping => error_code!(E1015),
+            Self::ShortStringMustBeAscii => error_code!(E1016),
+            Self::StringMustBeAscii => error_code!(E1017),
+            Self::UnterminatedShortString => error_code!(E1018),
+            Self::UnterminatedString => error_code!(E1019),
+            Self::VisibilityWithoutItem => error_code!(E1020),
+            Self::AttributesWithoutItem => error_code!(E1021),
+            Self::AttributesWithoutTraitItem => error_code!(E1022),
+            Self::AttributesWithoutImplItem => error_code!(E1023),
+            Self::AttributesWithoutStatement => error_code!(E1024),
+            Self::DisallowedTrailingSeparatorOr => error_code!(E1025),
+            Self::ConsecutiveMathOperators { .. } => error_code!(E1026),
+        }
+    }
+}
 impl DiagnosticEntry for ParserDiagnostic {
     type DbType = dyn FilesGroup;
 
@@ -153,6 +189,10 @@ Did you mean to write `{identifier}!{left}...{right}'?",
         cairo_lang_diagnostics::DiagnosticLocation { file_id: self.file_id, span: self.span }
     }
 
+    fn error_code(&self) -> Option<ErrorCode> {
+        Some(self.kind.error_code())
+    }
+
     fn is_same_kind(&self, other: &Self) -> bool {
         other.kind == self.kind
     }
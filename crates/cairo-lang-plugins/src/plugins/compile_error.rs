@@ -1,6 +1,7 @@
 use cairo_lang_defs::extract_macro_single_unnamed_arg;
 use cairo_lang_defs::plugin::{MacroPlugin, MacroPluginMetadata, PluginDiagnostic, PluginResult};
 use cairo_lang_defs::plugin_utils::PluginResultTrait;
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::{Terminal, TypedSyntaxNode, ast};
 
@@ -25,11 +26,14 @@ impl MacroPlugin for CompileErrorPlugin {
                     ast::WrappedArgList::ParenthesizedArgList(_)
                 );
                 let ast::Expr::String(err_message) = compilation_error_arg else {
-                    return PluginResult::diagnostic_only(PluginDiagnostic::error(
-                        &compilation_error_arg,
-                        "`compiler_error!` argument must be an unnamed string argument."
-                            .to_string(),
-                    ));
+                    return PluginResult::diagnostic_only(
+                        PluginDiagnostic::error(
+                            &compilation_error_arg,
+                            "`compiler_error!` argument must be an unnamed string argument."
+                                .to_string(),
+                        )
+                        .with_error_code(error_code!(E3005)),
+                    );
                 };
                 return PluginResult::diagnostic_only(PluginDiagnostic::error(
                     &inline_macro_ast,
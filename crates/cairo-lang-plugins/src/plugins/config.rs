@@ -4,6 +4,7 @@ use cairo_lang_defs::patcher::PatchBuilder;
 use cairo_lang_defs::plugin::{
     MacroPlugin, MacroPluginMetadata, PluginDiagnostic, PluginGeneratedFile, PluginResult,
 };
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_filesystem::cfg::{Cfg, CfgSet};
 use cairo_lang_syntax::attribute::structured::{
     Attribute, AttributeArg, AttributeArgVariant, AttributeStructurize,
@@ -201,10 +202,13 @@ fn parse_predicate_item(
 ) -> Option<Cfg> {
     match arg.variant {
         AttributeArgVariant::FieldInitShorthand(_) => {
-            diagnostics.push(PluginDiagnostic::error(
-                &arg.arg,
-                "This attribute does not support field initialization shorthands.".into(),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    &arg.arg,
+                    "This attribute does not support field initialization shorthands.".into(),
+                )
+                .with_error_code(error_code!(E3008)),
+            );
             None
         }
         AttributeArgVariant::Named { name, value } => {
@@ -212,10 +216,13 @@ fn parse_predicate_item(
                 ast::Expr::ShortString(terminal) => terminal.string_value(db).unwrap_or_default(),
                 ast::Expr::String(terminal) => terminal.string_value(db).unwrap_or_default(),
                 _ => {
-                    diagnostics.push(PluginDiagnostic::error(
-                        &value,
-                        "Expected a string/short-string literal.".into(),
-                    ));
+                    diagnostics.push(
+                        PluginDiagnostic::error(
+                            &value,
+                            "Expected a string/short-string literal.".into(),
+                        )
+                        .with_error_code(error_code!(E3008)),
+                    );
                     return None;
                 }
             };
@@ -224,11 +231,17 @@ fn parse_predicate_item(
         }
         AttributeArgVariant::Unnamed(value) => {
             let ast::Expr::Path(path) = value else {
-                diagnostics.push(PluginDiagnostic::error(&value, "Expected identifier.".into()));
+                diagnostics.push(
+                    PluginDiagnostic::error(&value, "Expected identifier.".into())
+                        .with_error_code(error_code!(E3008)),
+                );
                 return None;
             };
             let [ast::PathSegment::Simple(segment)] = &path.elements(db)[..] else {
-                diagnostics.push(PluginDiagnostic::error(&path, "Expected simple path.".into()));
+                diagnostics.push(
+                    PluginDiagnostic::error(&path, "Expected simple path.".into())
+                        .with_error_code(error_code!(E3008)),
+                );
                 return None;
             };
             let key = segment.ident(db).text(db);
@@ -1,4 +1,5 @@
 use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_syntax::node::ast;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::helpers::QueryAttrs;
@@ -30,17 +31,23 @@ pub fn handle_default(
                 Some((variant, variant.attributes.find_attr(db, DEFAULT_ATTR)?))
             });
             let Some((default_variant, _)) = default_variants.next() else {
-                diagnostics.push(PluginDiagnostic::error(
-                    derived,
-                    "derive `Default` for enum only supported with a default variant.".into(),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        derived,
+                        "derive `Default` for enum only supported with a default variant.".into(),
+                    )
+                    .with_error_code(error_code!(E3006)),
+                );
                 return None;
             };
             for (_, extra_default_attr) in default_variants {
-                diagnostics.push(PluginDiagnostic::error(
-                    &extra_default_attr,
-                    "Multiple variants annotated with `#[default]`".into(),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        &extra_default_attr,
+                        "Multiple variants annotated with `#[default]`".into(),
+                    )
+                    .with_error_code(error_code!(E3006)),
+                );
             }
             let default_variant = &default_variant.name;
             formatdoc!("{ty}::{default_variant}(core::traits::Default::default())")
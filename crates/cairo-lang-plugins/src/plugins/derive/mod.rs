@@ -2,6 +2,7 @@ use cairo_lang_defs::patcher::{PatchBuilder, RewriteNode};
 use cairo_lang_defs::plugin::{
     MacroPlugin, MacroPluginMetadata, PluginDiagnostic, PluginGeneratedFile, PluginResult,
 };
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_syntax::attribute::structured::{
     AttributeArg, AttributeArgVariant, AttributeStructurize,
 };
@@ -239,10 +240,10 @@ fn generate_derive_code_for_type(
         let attr = attr.structurize(db);
 
         if attr.args.is_empty() {
-            diagnostics.push(PluginDiagnostic::error(
-                attr.args_stable_ptr.untyped(),
-                "Expected args.".into(),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(attr.args_stable_ptr.untyped(), "Expected args.".into())
+                    .with_error_code(error_code!(E3006)),
+            );
             continue;
         }
 
@@ -252,7 +253,10 @@ fn generate_derive_code_for_type(
                 ..
             } = arg
             else {
-                diagnostics.push(PluginDiagnostic::error(&arg.arg, "Expected path.".into()));
+                diagnostics.push(
+                    PluginDiagnostic::error(&arg.arg, "Expected path.".into())
+                        .with_error_code(error_code!(E3006)),
+                );
                 continue;
             };
 
@@ -273,10 +277,13 @@ fn generate_derive_code_for_type(
                 "Serde" => serde::handle_serde(&info, &derived_path, &mut diagnostics),
                 _ => {
                     if !metadata.declared_derives.contains(&derived) {
-                        diagnostics.push(PluginDiagnostic::error(
-                            &derived_path,
-                            format!("Unknown derive `{derived}` - a plugin might be missing."),
-                        ));
+                        diagnostics.push(
+                            PluginDiagnostic::error(
+                                &derived_path,
+                                format!("Unknown derive `{derived}` - a plugin might be missing."),
+                            )
+                            .with_error_code(error_code!(E3006)),
+                        );
                     }
                     None
                 }
@@ -310,4 +317,5 @@ fn get_empty_impl(derived_trait: &str, info: &DeriveInfo) -> String {
 /// Returns a diagnostic for when a derive is not supported for extern types.
 fn unsupported_for_extern_diagnostic(path: &ast::ExprPath) -> PluginDiagnostic {
     PluginDiagnostic::error(path, "Unsupported trait for derive for extern types.".into())
+        .with_error_code(error_code!(E3006))
 }
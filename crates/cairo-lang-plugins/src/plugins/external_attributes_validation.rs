@@ -1,4 +1,5 @@
 use cairo_lang_defs::plugin::{MacroPlugin, MacroPluginMetadata, PluginDiagnostic, PluginResult};
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_syntax::attribute::structured::{AttributeArgVariant, AttributeStructurize};
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::helpers::QueryAttrs;
@@ -39,41 +40,56 @@ fn get_diagnostics<Item: QueryAttrs>(
     item.query_attr(db, DOC_ATTR).into_iter().for_each(|attr| {
         let args = attr.clone().structurize(db).args;
         if args.is_empty() {
-            diagnostics.push(PluginDiagnostic::error(
-                attr.stable_ptr(),
-                format!("Expected arguments. Supported args: {}", HIDDEN_ATTR),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    attr.stable_ptr(),
+                    format!("Expected arguments. Supported args: {}", HIDDEN_ATTR),
+                )
+                .with_error_code(error_code!(E3010)),
+            );
             return;
         }
         args.iter().for_each(|arg| match &arg.variant {
             AttributeArgVariant::Unnamed(value) => {
                 let ast::Expr::Path(path) = value else {
-                    diagnostics.push(PluginDiagnostic::error(
-                        value,
-                        format!("Expected identifier. Supported identifiers: {}", HIDDEN_ATTR),
-                    ));
+                    diagnostics.push(
+                        PluginDiagnostic::error(
+                            value,
+                            format!("Expected identifier. Supported identifiers: {}", HIDDEN_ATTR),
+                        )
+                        .with_error_code(error_code!(E3010)),
+                    );
                     return;
                 };
                 let [ast::PathSegment::Simple(segment)] = &path.elements(db)[..] else {
-                    diagnostics.push(PluginDiagnostic::error(
-                        path,
-                        "Wrong type of argument. Currently only #[doc(hidden)] is supported."
-                            .to_owned(),
-                    ));
+                    diagnostics.push(
+                        PluginDiagnostic::error(
+                            path,
+                            "Wrong type of argument. Currently only #[doc(hidden)] is supported."
+                                .to_owned(),
+                        )
+                        .with_error_code(error_code!(E3010)),
+                    );
                     return;
                 };
                 if segment.ident(db).text(db) != HIDDEN_ATTR {
-                    diagnostics.push(PluginDiagnostic::error(
-                        path,
-                        "Wrong type of argument. Currently only #[doc(hidden)] is supported."
-                            .to_owned(),
-                    ));
+                    diagnostics.push(
+                        PluginDiagnostic::error(
+                            path,
+                            "Wrong type of argument. Currently only #[doc(hidden)] is supported."
+                                .to_owned(),
+                        )
+                        .with_error_code(error_code!(E3010)),
+                    );
                 }
             }
-            _ => diagnostics.push(PluginDiagnostic::error(
-                &arg.arg,
-                format!("This argument is not supported. Supported args: {}", HIDDEN_ATTR),
-            )),
+            _ => diagnostics.push(
+                PluginDiagnostic::error(
+                    &arg.arg,
+                    format!("This argument is not supported. Supported args: {}", HIDDEN_ATTR),
+                )
+                .with_error_code(error_code!(E3010)),
+            ),
         });
     });
     if diagnostics.is_empty() { None } else { Some(diagnostics) }
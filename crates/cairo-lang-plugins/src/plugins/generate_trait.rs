@@ -4,6 +4,7 @@ use cairo_lang_defs::patcher::{PatchBuilder, RewriteNode};
 use cairo_lang_defs::plugin::{
     MacroPlugin, MacroPluginMetadata, PluginDiagnostic, PluginGeneratedFile, PluginResult,
 };
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_syntax::attribute::structured::{AttributeArgVariant, AttributeStructurize};
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::helpers::{BodyItems, GenericParamEx, QueryAttrs};
@@ -42,10 +43,13 @@ fn generate_trait_for_impl(db: &dyn SyntaxGroup, impl_ast: ast::ItemImpl) -> Plu
     let [trait_ast_segment] = &trait_ast.elements(db)[..] else {
         return PluginResult {
             code: None,
-            diagnostics: vec![PluginDiagnostic::error(
-                &trait_ast,
-                "Generated trait must have a single element path.".to_string(),
-            )],
+            diagnostics: vec![
+                PluginDiagnostic::error(
+                    &trait_ast,
+                    "Generated trait must have a single element path.".to_string(),
+                )
+                .with_error_code(error_code!(E3007)),
+            ],
             remove_original_item: false,
         };
     };
@@ -77,10 +81,13 @@ fn generate_trait_for_impl(db: &dyn SyntaxGroup, impl_ast: ast::ItemImpl) -> Plu
                 }
             }
             _ => {
-                diagnostics.push(PluginDiagnostic::error(
-                    &attr_arg.arg,
-                    "Expected an argument with the name `trait_attrs`.".to_string(),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        &attr_arg.arg,
+                        "Expected an argument with the name `trait_attrs`.".to_string(),
+                    )
+                    .with_error_code(error_code!(E3007)),
+                );
             }
         }
     }
@@ -133,11 +140,14 @@ fn generate_trait_for_impl(db: &dyn SyntaxGroup, impl_ast: ast::ItemImpl) -> Plu
         }
     };
     if !generic_params_match {
-        diagnostics.push(PluginDiagnostic::error(
-            &trait_ast,
-            "Generated trait must have generic args matching the impl's generic params."
-                .to_string(),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                &trait_ast,
+                "Generated trait must have generic args matching the impl's generic params."
+                    .to_string(),
+            )
+            .with_error_code(error_code!(E3007)),
+        );
     }
     match impl_ast.body(db) {
         ast::MaybeImplBody::None(semicolon) => {
@@ -228,11 +238,15 @@ fn generate_trait_for_impl(db: &dyn SyntaxGroup, impl_ast: ast::ItemImpl) -> Plu
                         });
                         builder.add_str(";\n");
                     }
-                    _ => diagnostics.push(PluginDiagnostic::error(
-                        &item,
-                        "Only functions, types, and constants are supported in #[generate_trait]."
-                            .to_string(),
-                    )),
+                    _ => diagnostics.push(
+                        PluginDiagnostic::error(
+                            &item,
+                            "Only functions, types, and constants are supported in \
+                             #[generate_trait]."
+                                .to_string(),
+                        )
+                        .with_error_code(error_code!(E3007)),
+                    ),
                 }
             }
             builder.add_node(body.rbrace(db).as_syntax_node());
@@ -2,6 +2,7 @@ use cairo_lang_defs::patcher::{PatchBuilder, RewriteNode};
 use cairo_lang_defs::plugin::{
     MacroPlugin, MacroPluginMetadata, PluginDiagnostic, PluginGeneratedFile, PluginResult,
 };
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_syntax::attribute::structured::{
     Attribute, AttributeArg, AttributeArgVariant, AttributeStructurize,
 };
@@ -61,10 +62,13 @@ fn generate_panicable_code(
     let mut diagnostics = vec![];
     if attrs.len() > 1 {
         let extra_attr = attrs.swap_remove(1);
-        diagnostics.push(PluginDiagnostic::error(
-            &extra_attr,
-            "`#[panic_with]` cannot be applied multiple times to the same item.".into(),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                &extra_attr,
+                "`#[panic_with]` cannot be applied multiple times to the same item.".into(),
+            )
+            .with_error_code(error_code!(E3009)),
+        );
         return PluginResult { code: None, diagnostics, remove_original_item: false };
     }
 
@@ -72,10 +76,13 @@ fn generate_panicable_code(
     let Some((inner_ty, success_variant, failure_variant)) =
         extract_success_ty_and_variants(db, &signature)
     else {
-        diagnostics.push(PluginDiagnostic::error(
-            &signature.ret_ty(db),
-            "Currently only wrapping functions returning an Option<T> or Result<T, E>".into(),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                &signature.ret_ty(db),
+                "Currently only wrapping functions returning an Option<T> or Result<T, E>".into(),
+            )
+            .with_error_code(error_code!(E3009)),
+        );
         return PluginResult { code: None, diagnostics, remove_original_item: false };
     };
 
@@ -84,10 +91,13 @@ fn generate_panicable_code(
     let attr = attr.structurize(db);
 
     let Some((err_value, panicable_name)) = parse_arguments(db, &attr) else {
-        diagnostics.push(PluginDiagnostic::error(
-            attr.stable_ptr.untyped(),
-            "Failed to extract panic data attribute".into(),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                attr.stable_ptr.untyped(),
+                "Failed to extract panic data attribute".into(),
+            )
+            .with_error_code(error_code!(E3009)),
+        );
         return PluginResult { code: None, diagnostics, remove_original_item: false };
     };
     builder.add_node(visibility.as_syntax_node());
@@ -1,4 +1,4 @@
-use cairo_lang_filesystem::db::{CrateSettings, Edition, ExperimentalFeaturesConfig};
+use cairo_lang_filesystem::db::{CrateSettings, Edition, ExperimentalFeaturesConfig, LintLevel};
 use indoc::indoc;
 use pretty_assertions::assert_eq;
 
@@ -22,6 +22,7 @@ fn test_serde() {
                 dependencies: Default::default(),
                 experimental_features: ExperimentalFeaturesConfig::default(),
                 cfg_set: Default::default(),
+                lints: Default::default(),
             },
             override_map: [
                 ("crate1".into(), CrateSettings {
@@ -31,6 +32,7 @@ fn test_serde() {
                     dependencies: Default::default(),
                     experimental_features: ExperimentalFeaturesConfig::default(),
                     cfg_set: Default::default(),
+                    lints: Default::default(),
                 }),
                 ("crate3".into(), CrateSettings {
                     name: None,
@@ -42,6 +44,7 @@ fn test_serde() {
                         coupons: false,
                     },
                     cfg_set: Default::default(),
+                    lints: [("unused_variables".into(), LintLevel::Deny)].into_iter().collect(),
                 }),
             ]
             .into_iter()
@@ -81,6 +84,9 @@ fn test_serde() {
             [config.override.crate3.experimental_features]
             negative_impls = true
             coupons = false
+
+            [config.override.crate3.lints]
+            unused_variables = "deny"
         "# });
     assert_eq!(config, toml::from_str(&serialized).unwrap());
 }
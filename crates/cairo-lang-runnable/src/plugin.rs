@@ -3,6 +3,7 @@ use cairo_lang_defs::patcher::{PatchBuilder, RewriteNode};
 use cairo_lang_defs::plugin::{
     MacroPlugin, MacroPluginMetadata, PluginDiagnostic, PluginGeneratedFile, PluginResult,
 };
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::plugin::{AnalyzerPlugin, PluginSuite};
 use cairo_lang_semantic::{GenericArgumentId, Mutability, corelib};
@@ -59,10 +60,13 @@ impl MacroPlugin for RunnablePlugin {
         let declaration = item.declaration(db);
         let generics = declaration.generic_params(db);
         if !generics.is_empty(db) {
-            diagnostics.push(PluginDiagnostic::error(
-                &generics,
-                "Runnable functions cannot have generic params.".to_string(),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    &generics,
+                    "Runnable functions cannot have generic params.".to_string(),
+                )
+                .with_error_code(error_code!(E3012)),
+            );
             return PluginResult { code: None, diagnostics, remove_original_item: false };
         }
         let name = declaration.name(db);
@@ -154,18 +158,24 @@ impl AnalyzerPlugin for RawRunnableAnalyzer {
                 continue;
             };
             if signature.return_type != corelib::unit_ty(db) {
-                diagnostics.push(PluginDiagnostic::error(
-                    &signature.stable_ptr.lookup(syntax_db).ret_ty(syntax_db),
-                    "Invalid return type for `#[runnable_raw]` function, expected `()`."
-                        .to_string(),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        &signature.stable_ptr.lookup(syntax_db).ret_ty(syntax_db),
+                        "Invalid return type for `#[runnable_raw]` function, expected `()`."
+                            .to_string(),
+                    )
+                    .with_error_code(error_code!(E3012)),
+                );
             }
             let [input, output] = &signature.params[..] else {
-                diagnostics.push(PluginDiagnostic::error(
-                    &signature.stable_ptr.lookup(syntax_db).parameters(syntax_db),
-                    "Invalid number of params for `#[runnable_raw]` function, expected 2."
-                        .to_string(),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        &signature.stable_ptr.lookup(syntax_db).parameters(syntax_db),
+                        "Invalid number of params for `#[runnable_raw]` function, expected 2."
+                            .to_string(),
+                    )
+                    .with_error_code(error_code!(E3012)),
+                );
                 continue;
             };
             if input.ty
@@ -173,36 +183,48 @@ impl AnalyzerPlugin for RawRunnableAnalyzer {
                     corelib::core_felt252_ty(db),
                 )])
             {
-                diagnostics.push(PluginDiagnostic::error(
-                    input.stable_ptr.untyped(),
-                    "Invalid first param type for `#[runnable_raw]` function, expected \
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        input.stable_ptr.untyped(),
+                        "Invalid first param type for `#[runnable_raw]` function, expected \
                      `Span<felt252>`."
-                        .to_string(),
-                ));
+                            .to_string(),
+                    )
+                    .with_error_code(error_code!(E3012)),
+                );
             }
             if input.mutability == Mutability::Reference {
-                diagnostics.push(PluginDiagnostic::error(
-                    input.stable_ptr.untyped(),
-                    "Invalid first param mutability for `#[runnable_raw]` function, got \
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        input.stable_ptr.untyped(),
+                        "Invalid first param mutability for `#[runnable_raw]` function, got \
                      unexpected `ref`."
-                        .to_string(),
-                ));
+                            .to_string(),
+                    )
+                    .with_error_code(error_code!(E3012)),
+                );
             }
             if output.ty != corelib::core_array_felt252_ty(db) {
-                diagnostics.push(PluginDiagnostic::error(
-                    output.stable_ptr.untyped(),
-                    "Invalid second param type for `#[runnable_raw]` function, expected \
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        output.stable_ptr.untyped(),
+                        "Invalid second param type for `#[runnable_raw]` function, expected \
                      `Array<felt252>`."
-                        .to_string(),
-                ));
+                            .to_string(),
+                    )
+                    .with_error_code(error_code!(E3012)),
+                );
             }
             if output.mutability != Mutability::Reference {
-                diagnostics.push(PluginDiagnostic::error(
-                    output.stable_ptr.untyped(),
-                    "Invalid second param mutability for `#[runnable_raw]` function, expected \
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        output.stable_ptr.untyped(),
+                        "Invalid second param mutability for `#[runnable_raw]` function, expected \
                      `ref`."
-                        .to_string(),
-                ));
+                            .to_string(),
+                    )
+                    .with_error_code(error_code!(E3012)),
+                );
             }
         }
         diagnostics
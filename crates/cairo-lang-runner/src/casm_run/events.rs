@@ -0,0 +1,293 @@
+//! Formatting of emitted events, decoded according to the ABI of the emitting contract.
+
+use std::iter::Copied;
+use std::slice::Iter;
+
+use cairo_lang_starknet_classes::abi::{
+    Contract, Enum, Event, EventFieldKind, EventKind, Item, Struct,
+};
+use cairo_lang_starknet_classes::keccak::starknet_keccak;
+use cairo_lang_utils::byte_array::{BYTE_ARRAY_MAGIC, BYTES_IN_WORD};
+use itertools::{Itertools, chain};
+use num_bigint::BigUint;
+use starknet_types_core::felt::Felt as Felt252;
+
+use super::try_format_string;
+
+#[cfg(test)]
+#[path = "events_test.rs"]
+mod test;
+
+/// An iterator over serialized values.
+type Felts<'a> = Copied<Iter<'a, Felt252>>;
+
+/// An event emitted during the run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EmittedEvent {
+    /// The address of the contract that emitted the event.
+    pub from_address: Felt252,
+    /// The keys of the event.
+    pub keys: Vec<Felt252>,
+    /// The data of the event.
+    pub data: Vec<Felt252>,
+}
+
+/// Formats an event according to the given ABI, e.g. `Transfer { from: 0x1, amount: 5 }`.
+/// If the ABI is missing or the event does not match it, formats the raw keys and data.
+pub fn format_event(abi: Option<&Contract>, keys: &[Felt252], data: &[Felt252]) -> String {
+    abi.and_then(|abi| EventDecoder { abi }.decode_root(keys, data)).unwrap_or_else(|| {
+        format!(
+            "Event {{ keys: [{}], data: [{}] }}",
+            keys.iter().map(format_hex).join(", "),
+            data.iter().map(format_hex).join(", ")
+        )
+    })
+}
+
+/// Serializes a string as panic data, i.e. a `ByteArray` prefixed by `BYTE_ARRAY_MAGIC`.
+pub fn byte_array_panic_data(value: &str) -> Vec<Felt252> {
+    let chunks = value.as_bytes().chunks_exact(BYTES_IN_WORD);
+    let num_full_words = chunks.len().into();
+    let remainder = chunks.remainder();
+    let pending_word_len = remainder.len().into();
+    let full_words = chunks.map(Felt252::from_bytes_be_slice).collect_vec();
+    let pending_word = Felt252::from_bytes_be_slice(remainder);
+
+    chain!([Felt252::from_hex(BYTE_ARRAY_MAGIC).unwrap(), num_full_words], full_words, [
+        pending_word,
+        pending_word_len
+    ])
+    .collect()
+}
+
+/// Decodes events and values according to an ABI.
+struct EventDecoder<'a> {
+    abi: &'a Contract,
+}
+impl EventDecoder<'_> {
+    /// Decodes an event of the root event type of the contract, consuming all keys and data.
+    fn decode_root(&self, keys: &[Felt252], data: &[Felt252]) -> Option<String> {
+        let root = self.root_event()?;
+        let mut keys = keys.iter().copied();
+        let mut data = data.iter().copied();
+        let formatted = self.decode_event(&root.name, &mut keys, &mut data)?;
+        (keys.next().is_none() && data.next().is_none()).then_some(formatted)
+    }
+
+    /// Returns the root event of the contract - the single event not nested in any other event.
+    fn root_event(&self) -> Option<&Event> {
+        let events = self.events().collect_vec();
+        let nested = events
+            .iter()
+            .flat_map(|event| match &event.kind {
+                EventKind::Struct { members: fields } | EventKind::Enum { variants: fields } => {
+                    fields
+                }
+            })
+            .filter(|field| matches!(field.kind, EventFieldKind::Nested | EventFieldKind::Flat))
+            .map(|field| field.ty.as_str())
+            .collect_vec();
+        events.into_iter().filter(|event| !nested.contains(&event.name.as_str())).exactly_one().ok()
+    }
+
+    /// Returns all the events of the ABI.
+    fn events(&self) -> impl Iterator<Item = &Event> {
+        self.abi.items().filter_map(|item| match item {
+            Item::Event(event) => Some(event),
+            _ => None,
+        })
+    }
+
+    /// Decodes an event of the given type.
+    fn decode_event(&self, ty: &str, keys: &mut Felts<'_>, data: &mut Felts<'_>) -> Option<String> {
+        let event = self.events().find(|event| event.name == ty)?;
+        match &event.kind {
+            EventKind::Struct { members } => {
+                let members = members
+                    .iter()
+                    .map(|member| {
+                        let value = match member.kind {
+                            EventFieldKind::KeySerde => self.decode_value(&member.ty, keys),
+                            EventFieldKind::DataSerde => self.decode_value(&member.ty, data),
+                            EventFieldKind::Nested | EventFieldKind::Flat => {
+                                self.decode_event(&member.ty, keys, data)
+                            }
+                        }?;
+                        Some(format!("{}: {value}", member.name))
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                Some(format_struct(short_name(&event.name), &members))
+            }
+            EventKind::Enum { variants } => {
+                // Flat variants are serialized without a selector, so first try the variant
+                // matching the selector, and only then the flat ones.
+                let selector = keys.clone().next();
+                if let Some(variant) = variants.iter().find(|variant| {
+                    variant.kind != EventFieldKind::Flat
+                        && selector == Some(selector_of(&variant.name))
+                }) {
+                    keys.next();
+                    return self.decode_event(&variant.ty, keys, data);
+                }
+                variants.iter().filter(|variant| variant.kind == EventFieldKind::Flat).find_map(
+                    |variant| {
+                        let (mut flat_keys, mut flat_data) = (keys.clone(), data.clone());
+                        let formatted =
+                            self.decode_event(&variant.ty, &mut flat_keys, &mut flat_data)?;
+                        (*keys, *data) = (flat_keys, flat_data);
+                        Some(formatted)
+                    },
+                )
+            }
+        }
+    }
+
+    /// Decodes a `Serde` serialized value of the given type.
+    fn decode_value(&self, ty: &str, values: &mut Felts<'_>) -> Option<String> {
+        match ty {
+            "()" => return Some("()".into()),
+            "core::felt252"
+            | "core::starknet::contract_address::ContractAddress"
+            | "core::starknet::class_hash::ClassHash"
+            | "core::starknet::eth_address::EthAddress"
+            | "core::bytes_31::bytes31" => return Some(format_hex(&values.next()?)),
+            "core::integer::u8"
+            | "core::integer::u16"
+            | "core::integer::u32"
+            | "core::integer::u64"
+            | "core::integer::u128" => {
+                return Some(values.next()?.to_biguint().to_string());
+            }
+            "core::integer::i8"
+            | "core::integer::i16"
+            | "core::integer::i32"
+            | "core::integer::i64"
+            | "core::integer::i128" => {
+                return Some(format_signed(&values.next()?));
+            }
+            "core::integer::u256" => {
+                let low = values.next()?.to_biguint();
+                let high = values.next()?.to_biguint();
+                return Some(((high << 128u32) + low).to_string());
+            }
+            "core::bool" => {
+                let value = values.next()?;
+                return if value == Felt252::ZERO {
+                    Some("false".into())
+                } else if value == Felt252::ONE {
+                    Some("true".into())
+                } else {
+                    None
+                };
+            }
+            "core::byte_array::ByteArray" => {
+                return try_format_string(values).map(|value| format!("\"{value}\""));
+            }
+            _ => {}
+        }
+        if let Some(inner) = generic_arg(ty, "core::array::Array::<")
+            .or_else(|| generic_arg(ty, "core::array::Span::<"))
+        {
+            let len = usize::try_from(values.next()?.to_biguint()).ok()?;
+            let items =
+                (0..len).map(|_| self.decode_value(inner, values)).collect::<Option<Vec<_>>>()?;
+            return Some(format!("[{}]", items.join(", ")));
+        }
+        if let Some(inner) = ty.strip_prefix('(').and_then(|ty| ty.strip_suffix(')')) {
+            let items = split_top_level(inner)
+                .into_iter()
+                .map(|ty| self.decode_value(ty, values))
+                .collect::<Option<Vec<_>>>()?;
+            return Some(format!("({})", items.join(", ")));
+        }
+        match self.abi.items().find(|item| match item {
+            Item::Struct(Struct { name, .. }) | Item::Enum(Enum { name, .. }) => name == ty,
+            _ => false,
+        })? {
+            Item::Struct(Struct { name, members }) => {
+                let members = members
+                    .iter()
+                    .map(|member| {
+                        Some(format!("{}: {}", member.name, self.decode_value(&member.ty, values)?))
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                Some(format_struct(short_name(name), &members))
+            }
+            Item::Enum(Enum { name, variants }) => {
+                let index = usize::try_from(values.next()?.to_biguint()).ok()?;
+                let variant = variants.get(index)?;
+                let value = self.decode_value(&variant.ty, values)?;
+                let name = short_name(name);
+                Some(if variant.ty == "()" {
+                    format!("{name}::{}", variant.name)
+                } else {
+                    format!("{name}::{}({value})", variant.name)
+                })
+            }
+            _ => unreachable!("Only structs and enums are searched for."),
+        }
+    }
+}
+
+/// Returns the selector of an event variant with the given name.
+fn selector_of(name: &str) -> Felt252 {
+    Felt252::from(starknet_keccak(name.as_bytes()))
+}
+
+/// Returns the last segment of a path, e.g. `Transfer` for `erc20::ERC20::Transfer`.
+fn short_name(path: &str) -> &str {
+    path.rsplit("::").next().unwrap_or(path)
+}
+
+/// Returns the generic argument of a type with the given prefix, e.g. `T` for `Array::<T>`.
+fn generic_arg<'a>(ty: &'a str, prefix: &str) -> Option<&'a str> {
+    ty.strip_prefix(prefix)?.strip_suffix('>')
+}
+
+/// Splits a comma separated list of types, ignoring commas nested in brackets.
+fn split_top_level(types: &str) -> Vec<&str> {
+    if types.trim().is_empty() {
+        return vec![];
+    }
+    let mut result = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in types.char_indices() {
+        match c {
+            '(' | '<' | '[' => depth += 1,
+            ')' | '>' | ']' => depth -= 1,
+            ',' if depth == 0 => {
+                result.push(types[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    result.push(types[start..].trim());
+    result
+}
+
+/// Formats a struct with the given formatted members.
+fn format_struct(name: &str, members: &[String]) -> String {
+    if members.is_empty() {
+        name.to_string()
+    } else {
+        format!("{name} {{ {} }}", members.join(", "))
+    }
+}
+
+/// Formats a `Felt252` as hex.
+fn format_hex(value: &Felt252) -> String {
+    format!("{:#x}", value.to_biguint())
+}
+
+/// Formats a `Felt252` as a signed integer.
+fn format_signed(value: &Felt252) -> String {
+    let half_prime: BigUint = Felt252::MAX.to_biguint() / 2u32;
+    let value_as_uint = value.to_biguint();
+    if value_as_uint > half_prime {
+        format!("-{}", (-*value).to_biguint())
+    } else {
+        value_as_uint.to_string()
+    }
+}
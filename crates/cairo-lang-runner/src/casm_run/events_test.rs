@@ -0,0 +1,102 @@
+use cairo_lang_starknet_classes::abi::{
+    Contract, Event, EventField, EventFieldKind, EventKind, Item, Struct, StructMember,
+};
+use cairo_lang_starknet_classes::keccak::starknet_keccak;
+use cairo_lang_utils::byte_array::BYTE_ARRAY_MAGIC;
+use starknet_types_core::felt::Felt as Felt252;
+use test_case::test_case;
+
+use super::{byte_array_panic_data, format_event};
+
+/// Returns an event field with the given name, type and kind.
+fn field(name: &str, ty: &str, kind: EventFieldKind) -> EventField {
+    EventField { name: name.into(), ty: ty.into(), kind }
+}
+
+/// Returns the ABI of a contract emitting `Transfer` events, and `Paused` events of a flat
+/// component event.
+fn abi() -> Contract {
+    Contract::from_items(
+        [
+            Item::Struct(Struct {
+                name: "core::integer::u256".into(),
+                members: vec![
+                    StructMember { name: "low".into(), ty: "core::integer::u128".into() },
+                    StructMember { name: "high".into(), ty: "core::integer::u128".into() },
+                ],
+            }),
+            Item::Event(Event {
+                name: "token::Transfer".into(),
+                kind: EventKind::Struct {
+                    members: vec![
+                        field(
+                            "from",
+                            "core::starknet::contract_address::ContractAddress",
+                            EventFieldKind::KeySerde,
+                        ),
+                        field(
+                            "to",
+                            "core::starknet::contract_address::ContractAddress",
+                            EventFieldKind::KeySerde,
+                        ),
+                        field("amount", "core::integer::u256", EventFieldKind::DataSerde),
+                    ],
+                },
+            }),
+            Item::Event(Event {
+                name: "pausable::Paused".into(),
+                kind: EventKind::Struct {
+                    members: vec![field("paused", "core::bool", EventFieldKind::DataSerde)],
+                },
+            }),
+            Item::Event(Event {
+                name: "pausable::Event".into(),
+                kind: EventKind::Enum {
+                    variants: vec![field("Paused", "pausable::Paused", EventFieldKind::Nested)],
+                },
+            }),
+            Item::Event(Event {
+                name: "token::Event".into(),
+                kind: EventKind::Enum {
+                    variants: vec![
+                        field("Transfer", "token::Transfer", EventFieldKind::Nested),
+                        field("PausableEvent", "pausable::Event", EventFieldKind::Flat),
+                    ],
+                },
+            }),
+        ]
+        .into_iter()
+        .collect(),
+    )
+}
+
+#[test_case(
+    Some("Transfer"),
+    &[1, 2],
+    &[100, 0],
+    "Transfer { from: 0x1, to: 0x2, amount: 100 }";
+    "struct variant"
+)]
+#[test_case(Some("Paused"), &[], &[1], "Paused { paused: true }"; "flat variant")]
+#[test_case(Some("Transfer"), &[1], &[100, 0], "Event { keys: [0x"; "missing key")]
+#[test_case(None, &[5], &[], "Event { keys: [0x5], data: [] }"; "unknown selector")]
+fn test_format_event(variant: Option<&str>, keys: &[u128], data: &[u128], expected_prefix: &str) {
+    let keys: Vec<Felt252> = variant
+        .map(|variant| starknet_keccak(variant.as_bytes()).into())
+        .into_iter()
+        .chain(keys.iter().map(|key| Felt252::from(*key)))
+        .collect();
+    let data: Vec<Felt252> = data.iter().map(|value| Felt252::from(*value)).collect();
+    let formatted = format_event(Some(&abi()), &keys, &data);
+    assert!(formatted.starts_with(expected_prefix), "Unexpected formatting: {formatted}");
+}
+
+#[test]
+fn test_byte_array_panic_data() {
+    assert_eq!(byte_array_panic_data("hello"), vec![
+        Felt252::from_hex_unchecked(BYTE_ARRAY_MAGIC),
+        Felt252::from(0),
+        Felt252::from_bytes_be_slice(b"hello"),
+        Felt252::from(5),
+    ]);
+}
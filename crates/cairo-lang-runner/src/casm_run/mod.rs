@@ -1,6 +1,6 @@
 use std::any::Any;
 use std::borrow::Cow;
-use std::collections::{HashMap, VecDeque};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::ops::{Shl, Sub};
 use std::vec::IntoIter;
 
@@ -44,6 +44,8 @@ use {ark_secp256k1 as secp256k1, ark_secp256r1 as secp256r1};
 
 use self::contract_address::calculate_contract_address;
 use self::dict_manager::DictSquashExecScope;
+pub use self::events::EmittedEvent;
+use self::events::{byte_array_panic_data, format_event};
 use crate::short_string::{as_cairo_short_string, as_cairo_short_string_ex};
 use crate::{Arg, RunResultValue, SierraCasmRunner, StarknetExecutionResources, args_size};
 
@@ -53,6 +55,7 @@ mod test;
 mod circuit;
 mod contract_address;
 mod dict_manager;
+mod events;
 
 /// Convert a Hint to the cairo-vm class HintParams by canonically serializing it to a string.
 pub fn hint_to_hint_params(hint: &Hint) -> HintParams {
@@ -66,6 +69,9 @@ pub fn hint_to_hint_params(hint: &Hint) -> HintParams {
     }
 }
 
+/// The maximal number of storage values loaded by a single `load` cheatcode.
+const MAX_LOAD_LEN: usize = 1 << 16;
+
 /// Helper object to allocate and track Secp256k1 elliptic curve points.
 #[derive(Default)]
 struct Secp256k1ExecutionScope {
@@ -132,14 +138,22 @@ type L2ToL1Message = (Felt252, Vec<Felt252>);
 pub struct StarknetState {
     /// The values of addresses in the simulated storage per contract.
     storage: HashMap<Felt252, HashMap<Felt252, Felt252>>,
+    /// The class hashes declared using the `declare` cheatcode.
+    declared_classes: HashSet<Felt252>,
     /// A mapping from contract address to class hash.
     deployed_contracts: HashMap<Felt252, Felt252>,
     /// A mapping from contract address to logs.
     logs: HashMap<Felt252, ContractLogs>,
+    /// All the events emitted during the run, in order of emission.
+    emitted_events: Vec<EmittedEvent>,
     /// The simulated execution info.
     exec_info: ExecutionInfo,
     /// A mock history, mapping block number to the class hash.
     block_hash: HashMap<u64, Felt252>,
+    /// The mocked results of `call_contract_syscall`, by contract address and selector.
+    mocked_calls: HashMap<(Felt252, Felt252), MockedCall>,
+    /// The calls expected to be made using `call_contract_syscall`, and not yet made.
+    expected_calls: Vec<ExpectedCall>,
 }
 impl StarknetState {
     /// Replaces the addresses in the context.
@@ -162,6 +176,170 @@ impl StarknetState {
         self.exec_info.contract_address = old_contract_address;
         self.exec_info.caller_address = old_caller_address;
     }
+
+    /// Takes a checkpoint of the parts of the state that are reverted if a call frame fails.
+    fn checkpoint(&self) -> StarknetStateCheckpoint {
+        StarknetStateCheckpoint {
+            storage: self.storage.clone(),
+            deployed_contracts: self.deployed_contracts.clone(),
+            logs: self.logs.clone(),
+            emitted_events_len: self.emitted_events.len(),
+            mocked_calls: self.mocked_calls.clone(),
+            expected_calls: self.expected_calls.clone(),
+        }
+    }
+
+    /// Reverts the state to a previously taken checkpoint, discarding all storage writes,
+    /// deployments, class replacements, events, L2 to L1 messages, mocks and expected calls made
+    /// since.
+    fn revert_to(&mut self, checkpoint: StarknetStateCheckpoint) {
+        let StarknetStateCheckpoint {
+            storage,
+            deployed_contracts,
+            logs,
+            emitted_events_len,
+            mocked_calls,
+            expected_calls,
+        } = checkpoint;
+        self.storage = storage;
+        self.deployed_contracts = deployed_contracts;
+        self.logs = logs;
+        self.emitted_events.truncate(emitted_events_len);
+        self.mocked_calls = mocked_calls;
+        self.expected_calls = expected_calls;
+    }
+
+    /// Returns all the events emitted during the run and not reverted, in order of emission.
+    pub fn emitted_events(&self) -> &[EmittedEvent] {
+        &self.emitted_events
+    }
+
+    /// Returns the storage of all contracts, by contract address and storage key.
+    pub fn storage(&self) -> &HashMap<Felt252, HashMap<Felt252, Felt252>> {
+        &self.storage
+    }
+
+    /// Returns the changes made to the storage since it was in the `initial` state.
+    pub fn storage_diff(
+        &self,
+        initial: &HashMap<Felt252, HashMap<Felt252, Felt252>>,
+    ) -> StorageDiff {
+        let mut diff = StorageDiff::new();
+        for (contract_address, contract_storage) in &self.storage {
+            let initial_contract_storage = initial.get(contract_address);
+            for (key, after) in contract_storage {
+                let before = initial_contract_storage
+                    .and_then(|initial_contract_storage| initial_contract_storage.get(key))
+                    .cloned()
+                    .unwrap_or_default();
+                if before != *after {
+                    diff.entry(*contract_address)
+                        .or_default()
+                        .insert(*key, StorageChange { before, after: *after });
+                }
+            }
+        }
+        diff
+    }
+
+    /// Returns the values of `len` consecutive storage addresses of a contract, starting at `key`.
+    fn load(&self, contract_address: Felt252, key: Felt252, len: usize) -> Vec<Felt252> {
+        let contract_storage = self.storage.get(&contract_address);
+        (0..len)
+            .map(|offset| {
+                let addr = key + Felt252::from(offset);
+                contract_storage
+                    .and_then(|contract_storage| contract_storage.get(&addr))
+                    .cloned()
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+
+    /// Stores values to consecutive storage addresses of a contract, starting at `key`.
+    fn store(&mut self, contract_address: Felt252, key: Felt252, values: &[Felt252]) {
+        let contract_storage = self.storage.entry(contract_address).or_default();
+        for (offset, value) in values.iter().enumerate() {
+            contract_storage.insert(key + Felt252::from(offset), *value);
+        }
+    }
+
+    /// Returns the calls expected using the `expect_call` cheatcode that were not made.
+    pub fn unfulfilled_expected_calls(&self) -> &[ExpectedCall] {
+        &self.expected_calls
+    }
+
+    /// Records a call to `call_contract_syscall`, fulfilling all the matching expected calls.
+    fn record_call(&mut self, contract_address: Felt252, selector: Felt252, calldata: &[Felt252]) {
+        self.expected_calls.retain(|expected| {
+            expected.contract_address != contract_address
+                || expected.selector != selector
+                || expected.calldata != calldata
+        });
+    }
+
+    /// Returns the mocked result of a call, if there is one, and consumes one of its uses.
+    fn use_mocked_call(
+        &mut self,
+        contract_address: Felt252,
+        selector: Felt252,
+    ) -> Option<Vec<Felt252>> {
+        let key = (contract_address, selector);
+        let mocked_call = self.mocked_calls.get_mut(&key)?;
+        mocked_call.times -= 1;
+        if mocked_call.times == 0 {
+            return self.mocked_calls.remove(&key).map(|mocked_call| mocked_call.return_data);
+        }
+        Some(mocked_call.return_data.clone())
+    }
+}
+
+/// A change of a single storage value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StorageChange {
+    /// The value before the change.
+    pub before: Felt252,
+    /// The value after the change.
+    pub after: Felt252,
+}
+
+/// The changes made to the storage, by contract address and storage key.
+pub type StorageDiff = BTreeMap<Felt252, BTreeMap<Felt252, StorageChange>>;
+
+/// A mocked result of `call_contract_syscall`.
+#[derive(Clone)]
+struct MockedCall {
+    /// The data returned by the mocked call.
+    return_data: Vec<Felt252>,
+    /// The number of calls left to be mocked.
+    times: usize,
+}
+
+/// A call expected to be made using `call_contract_syscall`.
+#[derive(Clone, Debug)]
+pub struct ExpectedCall {
+    /// The address of the called contract.
+    pub contract_address: Felt252,
+    /// The selector of the called entry point.
+    pub selector: Felt252,
+    /// The calldata the entry point is expected to be called with.
+    pub calldata: Vec<Felt252>,
+}
+
+/// The revertible parts of a [StarknetState], taken when entering a call frame.
+struct StarknetStateCheckpoint {
+    /// The storage of all contracts.
+    storage: HashMap<Felt252, HashMap<Felt252, Felt252>>,
+    /// The class hashes of all deployed contracts, including replaced classes.
+    deployed_contracts: HashMap<Felt252, Felt252>,
+    /// The events and L2 to L1 messages of all contracts.
+    logs: HashMap<Felt252, ContractLogs>,
+    /// The number of events emitted so far.
+    emitted_events_len: usize,
+    /// The mocked results of `call_contract_syscall`, including their remaining uses.
+    mocked_calls: HashMap<(Felt252, Felt252), MockedCall>,
+    /// The calls expected to be made and not yet made.
+    expected_calls: Vec<ExpectedCall>,
 }
 
 /// Object storing logs for a contract.
@@ -949,6 +1127,11 @@ impl CairoHintProcessor<'_> {
     ) -> Result<SyscallResult, HintError> {
         deduct_gas!(gas_counter, EMIT_EVENT);
         let contract = self.starknet_state.exec_info.contract_address;
+        self.starknet_state.emitted_events.push(EmittedEvent {
+            from_address: contract,
+            keys: keys.clone(),
+            data: data.clone(),
+        });
         self.starknet_state.logs.entry(contract).or_default().events.push_back((keys, data));
         Ok(SyscallResult::Success(vec![]))
     }
@@ -976,12 +1159,15 @@ impl CairoHintProcessor<'_> {
         &mut self,
         gas_counter: &mut usize,
         class_hash: Felt252,
-        _contract_address_salt: Felt252,
+        contract_address_salt: Felt252,
         calldata: Vec<Felt252>,
         deploy_from_zero: bool,
         vm: &mut dyn VMWrapper,
     ) -> Result<SyscallResult, HintError> {
         deduct_gas!(gas_counter, DEPLOY);
+        if !self.starknet_state.declared_classes.contains(&class_hash) {
+            fail_syscall!(b"CLASS_HASH_NOT_DECLARED");
+        }
 
         // Assign the starknet address of the contract.
         let deployer_address = if deploy_from_zero {
@@ -990,7 +1176,7 @@ impl CairoHintProcessor<'_> {
             self.starknet_state.exec_info.contract_address
         };
         let deployed_contract_address = calculate_contract_address(
-            &_contract_address_salt,
+            &contract_address_salt,
             &class_hash,
             &calldata,
             &deployer_address,
@@ -998,20 +1184,17 @@ impl CairoHintProcessor<'_> {
 
         // Prepare runner for running the constructor.
         let runner = self.runner.expect("Runner is needed for starknet.");
-        let Some(contract_info) = runner.starknet_contracts_info.get(&class_hash) else {
-            fail_syscall!(b"CLASS_HASH_NOT_FOUND");
-        };
+        let contract_info = runner
+            .starknet_contracts_info
+            .get(&class_hash)
+            .expect("Declared contract not found in registry.");
 
-        // Set the class hash of the deployed contract before executing the constructor,
-        // as the constructor could make an external call to this address.
-        if self
-            .starknet_state
-            .deployed_contracts
-            .insert(deployed_contract_address, class_hash)
-            .is_some()
-        {
+        if self.starknet_state.deployed_contracts.contains_key(&deployed_contract_address) {
             fail_syscall!(b"CONTRACT_ALREADY_DEPLOYED");
         }
+        // Set the class hash of the deployed contract before executing the constructor,
+        // as the constructor could make an external call to this address.
+        self.starknet_state.deployed_contracts.insert(deployed_contract_address, class_hash);
 
         // Call constructor if it exists.
         let (res_data_start, res_data_end) = if let Some(constructor) = &contract_info.constructor {
@@ -1053,6 +1236,12 @@ impl CairoHintProcessor<'_> {
         vm: &mut dyn VMWrapper,
     ) -> Result<SyscallResult, HintError> {
         deduct_gas!(gas_counter, CALL_CONTRACT);
+        self.starknet_state.record_call(contract_address, selector, &calldata);
+        if let Some(return_data) = self.starknet_state.use_mocked_call(contract_address, selector) {
+            let (res_data_start, res_data_end) =
+                segment_with_data(vm, return_data.into_iter()).expect("failed to allocate segment");
+            return Ok(SyscallResult::Success(vec![res_data_start.into(), res_data_end.into()]));
+        }
 
         // Get the class hash of the contract.
         let Some(class_hash) = self.starknet_state.deployed_contracts.get(&contract_address) else {
@@ -1098,11 +1287,15 @@ impl CairoHintProcessor<'_> {
         vm: &mut dyn VMWrapper,
     ) -> Result<SyscallResult, HintError> {
         deduct_gas!(gas_counter, LIBRARY_CALL);
+        if !self.starknet_state.declared_classes.contains(&class_hash) {
+            fail_syscall!(b"CLASS_HASH_NOT_DECLARED");
+        }
         // Prepare runner for running the call.
         let runner = self.runner.expect("Runner is needed for starknet.");
-        let Some(contract_info) = runner.starknet_contracts_info.get(&class_hash) else {
-            fail_syscall!(b"CLASS_HASH_NOT_DECLARED")
-        };
+        let contract_info = runner
+            .starknet_contracts_info
+            .get(&class_hash)
+            .expect("Declared contract not found in registry.");
 
         // Call the function.
         let Some(entry_point) = contract_info.externals.get(&selector) else {
@@ -1125,15 +1318,9 @@ impl CairoHintProcessor<'_> {
         new_class: Felt252,
     ) -> Result<SyscallResult, HintError> {
         deduct_gas!(gas_counter, REPLACE_CLASS);
-        // Validating the class hash was declared as one of the starknet contracts.
-        if !self
-            .runner
-            .expect("Runner is needed for starknet.")
-            .starknet_contracts_info
-            .contains_key(&new_class)
-        {
-            fail_syscall!(b"CLASS_HASH_NOT_FOUND");
-        };
+        if !self.starknet_state.declared_classes.contains(&new_class) {
+            fail_syscall!(b"CLASS_HASH_NOT_DECLARED");
+        }
         let address = self.starknet_state.exec_info.contract_address;
         self.starknet_state.deployed_contracts.insert(address, new_class);
         Ok(SyscallResult::Success(vec![]))
@@ -1156,6 +1343,50 @@ impl CairoHintProcessor<'_> {
         Ok(SyscallResult::Success(vec![MaybeRelocatable::Int(class_hash)]))
     }
 
+    /// Executes the `declare` cheatcode - registers a class of one of the starknet contracts known
+    /// to the runner, allowing it to be deployed, library called and replaced to.
+    fn declare(&mut self, class_hash: Felt252) -> Result<(), &'static [u8]> {
+        if !self
+            .runner
+            .expect("Runner is needed for starknet.")
+            .starknet_contracts_info
+            .contains_key(&class_hash)
+        {
+            return Err(b"CLASS_HASH_NOT_FOUND");
+        }
+        if !self.starknet_state.declared_classes.insert(class_hash) {
+            return Err(b"CLASS_ALREADY_DECLARED");
+        }
+        Ok(())
+    }
+
+    /// Executes the `deploy_at` cheatcode - places a contract of a declared class at the given
+    /// address with the given initial storage, without running its constructor.
+    fn deploy_at(
+        &mut self,
+        class_hash: Felt252,
+        contract_address: Felt252,
+        storage: impl IntoIterator<Item = (Felt252, Felt252)>,
+    ) -> Result<(), &'static [u8]> {
+        if !self.starknet_state.declared_classes.contains(&class_hash) {
+            return Err(b"CLASS_HASH_NOT_DECLARED");
+        }
+        if self.starknet_state.deployed_contracts.contains_key(&contract_address) {
+            return Err(b"CONTRACT_ALREADY_DEPLOYED");
+        }
+        self.starknet_state.deployed_contracts.insert(contract_address, class_hash);
+        self.starknet_state.storage.entry(contract_address).or_default().extend(storage);
+        Ok(())
+    }
+
+    /// Formats an event according to the ABI of the contract that emitted it.
+    fn format_emitted_event(&self, event: &EmittedEvent) -> String {
+        let abi = self.starknet_state.deployed_contracts.get(&event.from_address).and_then(
+            |class_hash| self.runner?.starknet_contracts_info.get(class_hash)?.abi.as_ref(),
+        );
+        format_event(abi, &event.keys, &event.data)
+    }
+
     /// Executes the entry point with the given calldata.
     fn call_entry_point(
         &mut self,
@@ -1170,25 +1401,30 @@ impl CairoHintProcessor<'_> {
             .registry()
             .get_function(entry_point)
             .expect("Entrypoint exists, but not found.");
+        // Any changes made by the call frame (including nested frames) are reverted on failure.
+        let checkpoint = self.starknet_state.checkpoint();
         let mut res = runner
-            .run_function_with_starknet_context(
+            .run_call_frame(
                 function,
                 vec![Arg::Array(calldata.into_iter().map(Arg::Value).collect())],
                 // The costs of the relevant syscall include `ENTRY_POINT_INITIAL_BUDGET` so we
                 // need to refund it here before running the entry point to avoid double charging.
                 Some(*gas_counter + gas_costs::ENTRY_POINT_INITIAL_BUDGET),
-                self.starknet_state.clone(),
+                std::mem::take(&mut self.starknet_state),
             )
             .expect("Internal runner error.");
+        self.starknet_state = std::mem::take(&mut res.starknet_state);
         self.syscalls_used_resources += res.used_resources;
         *gas_counter = res.gas_counter.unwrap().to_usize().unwrap();
         match res.value {
             RunResultValue::Success(value) => {
-                self.starknet_state = std::mem::take(&mut res.starknet_state);
                 Ok(segment_with_data(vm, read_array_result_as_vec(&res.memory, &value).into_iter())
                     .expect("failed to allocate segment"))
             }
-            RunResultValue::Panic(panic_data) => Err(panic_data),
+            RunResultValue::Panic(panic_data) => {
+                self.starknet_state.revert_to(checkpoint);
+                Err(panic_data)
+            }
         }
     }
 
@@ -1284,6 +1520,138 @@ impl CairoHintProcessor<'_> {
                     res_segment.write_data(data.iter())?;
                 }
             }
+            "declare" => {
+                let result = self.declare(as_single_input(inputs)?);
+                write_cheatcode_result(&mut res_segment, result)?;
+            }
+            "deploy_at" => {
+                let Some(([class_hash, contract_address], storage)) =
+                    inputs.split_first_chunk().filter(|(_, storage)| storage.len() % 2 == 0)
+                else {
+                    return Err(HintError::CustomHint(Box::from(format!(
+                        "`{selector}` cheatcode invalid args: pass span of an array with the \
+                         class hash, the contract address and pairs of storage keys and values",
+                    ))));
+                };
+                let storage = storage.iter().tuples().map(|(key, value)| (*key, *value));
+                let result = self.deploy_at(*class_hash, *contract_address, storage);
+                write_cheatcode_result(&mut res_segment, result)?;
+            }
+            "mock_call" => {
+                let Some(([contract_address, entry_point_selector, times], return_data)) =
+                    inputs.split_first_chunk()
+                else {
+                    return Err(HintError::CustomHint(Box::from(format!(
+                        "`{selector}` cheatcode invalid args: pass span of an array with the \
+                         contract address, the selector, the number of times and the return data",
+                    ))));
+                };
+                let times = times.to_usize().filter(|times| *times > 0).ok_or_else(|| {
+                    HintError::CustomHint(Box::from(format!(
+                        "`{selector}` cheatcode invalid args: times must be positive",
+                    )))
+                })?;
+                self.starknet_state.mocked_calls.insert(
+                    (*contract_address, *entry_point_selector),
+                    MockedCall { return_data: return_data.to_vec(), times },
+                );
+            }
+            "clear_mock_call" => {
+                let [contract_address, entry_point_selector] = vec_as_array(inputs, || {
+                    format!(
+                        "`{selector}` cheatcode invalid args: pass span of an array with exactly \
+                         two elements",
+                    )
+                })?;
+                self.starknet_state.mocked_calls.remove(&(contract_address, entry_point_selector));
+            }
+            "expect_call" => {
+                let Some(([contract_address, entry_point_selector], calldata)) =
+                    inputs.split_first_chunk()
+                else {
+                    return Err(HintError::CustomHint(Box::from(format!(
+                        "`{selector}` cheatcode invalid args: pass span of an array with the \
+                         contract address, the selector and the calldata",
+                    ))));
+                };
+                self.starknet_state.expected_calls.push(ExpectedCall {
+                    contract_address: *contract_address,
+                    selector: *entry_point_selector,
+                    calldata: calldata.to_vec(),
+                });
+            }
+            "load" => {
+                let [contract_address, key, len] = vec_as_array(inputs, || {
+                    format!(
+                        "`{selector}` cheatcode invalid args: pass span of an array with exactly \
+                         three elements",
+                    )
+                })?;
+                let Some(len) = len.to_usize().filter(|len| *len <= MAX_LOAD_LEN) else {
+                    return Err(HintError::CustomHint(Box::from(format!(
+                        "`{selector}` cheatcode invalid args: len must be at most {MAX_LOAD_LEN}",
+                    ))));
+                };
+                let values = self.starknet_state.load(contract_address, key, len);
+                res_segment.write_data(values.into_iter())?;
+            }
+            "store" => {
+                let Some(([contract_address, key], values)) = inputs.split_first_chunk() else {
+                    return Err(HintError::CustomHint(Box::from(format!(
+                        "`{selector}` cheatcode invalid args: pass span of an array with the \
+                         contract address, the storage key and the values",
+                    ))));
+                };
+                self.starknet_state.store(*contract_address, *key, values);
+            }
+            "spy_events" => {
+                res_segment.write(self.starknet_state.emitted_events.len())?;
+            }
+            "get_spied_events" => {
+                let start = as_single_input(inputs)?.to_usize().unwrap_or(usize::MAX);
+                let events = self.starknet_state.emitted_events.get(start..).unwrap_or_default();
+                res_segment.write(events.len())?;
+                for EmittedEvent { from_address, keys, data } in events {
+                    res_segment.write(*from_address)?;
+                    res_segment.write(keys.len())?;
+                    res_segment.write_data(keys.iter())?;
+                    res_segment.write(data.len())?;
+                    res_segment.write_data(data.iter())?;
+                }
+            }
+            "assert_event_emitted" | "assert_event_not_emitted" => {
+                let Some((start, expected)) = parse_event_assertion(&inputs) else {
+                    return Err(HintError::CustomHint(Box::from(format!(
+                        "`{selector}` cheatcode invalid args: pass span of an array with the spy \
+                         start, the contract address, the keys and the data",
+                    ))));
+                };
+                let events = self.starknet_state.emitted_events.get(start..).unwrap_or_default();
+                let emitted = events.contains(&expected);
+                let formatted_expected = self.format_emitted_event(&expected);
+                let failure = if selector == "assert_event_emitted" && !emitted {
+                    let emitted_by_contract = events
+                        .iter()
+                        .filter(|event| event.from_address == expected.from_address)
+                        .map(|event| self.format_emitted_event(event))
+                        .join(", ");
+                    Some(format!(
+                        "Event `{formatted_expected}` was not emitted by contract {:#x}. Emitted \
+                         events: [{emitted_by_contract}].",
+                        expected.from_address.to_biguint()
+                    ))
+                } else if selector == "assert_event_not_emitted" && emitted {
+                    Some(format!(
+                        "Event `{formatted_expected}` was emitted by contract {:#x}.",
+                        expected.from_address.to_biguint()
+                    ))
+                } else {
+                    None
+                };
+                if let Some(failure) = failure {
+                    res_segment.write_data(byte_array_panic_data(&failure).iter())?;
+                }
+            }
             "pop_l2_to_l1_message" => {
                 let contract_logs = self.starknet_state.logs.get_mut(&as_single_input(inputs)?);
                 if let Some((to_address, payload)) = contract_logs
@@ -1342,6 +1710,39 @@ impl CairoHintProcessor<'_> {
     }
 }
 
+/// Writes the result of a cheatcode to its output, serialized as a `SyscallResult<()>`.
+fn write_cheatcode_result(
+    res_segment: &mut MemBuffer<'_>,
+    result: Result<(), &[u8]>,
+) -> Result<(), MemoryError> {
+    match result {
+        Ok(()) => res_segment.write(Felt252::from(0)),
+        Err(reason) => {
+            res_segment.write(Felt252::from(1))?;
+            // The revert reason array, with a single element.
+            res_segment.write(Felt252::from(1))?;
+            res_segment.write(Felt252::from_bytes_be_slice(reason))
+        }
+    }
+}
+
+/// Parses the inputs of the event assertion cheatcodes - the index of the first spied event, and
+/// the expected event, serialized as the contract address, the keys span and the data span.
+fn parse_event_assertion(inputs: &[Felt252]) -> Option<(usize, EmittedEvent)> {
+    let ([start, from_address, keys_len], rest) = inputs.split_first_chunk()?;
+    let (keys, rest) = rest.split_at_checked(keys_len.to_usize()?)?;
+    let (data_len, data) = rest.split_first()?;
+    if data.len() != data_len.to_usize()? {
+        return None;
+    }
+    let start = start.to_usize().unwrap_or(usize::MAX);
+    Some((start, EmittedEvent {
+        from_address: *from_address,
+        keys: keys.to_vec(),
+        data: data.to_vec(),
+    }))
+}
+
 /// Extracts an array of felt252s from a vector of such.
 fn vec_as_array<const COUNT: usize>(
     inputs: Vec<Felt252>,
@@ -12,7 +12,7 @@ use test_case::test_case;
 
 use super::format_for_debug;
 use crate::casm_run::contract_address::calculate_contract_address;
-use crate::casm_run::{RunFunctionResult, run_function};
+use crate::casm_run::{RunFunctionResult, StorageChange, run_function};
 use crate::short_string::{as_cairo_short_string, as_cairo_short_string_ex};
 use crate::{CairoHintProcessor, StarknetState, build_hints_dict};
 
@@ -481,3 +481,33 @@ fn test_calculate_contract_address() {
         deployed_contract_address
     );
 }
+
+#[test]
+fn test_storage_diff() {
+    let mut state = StarknetState::default();
+    let [contract0, contract1, key0, key1] = [1, 2, 10, 11].map(Felt252::from);
+    state.store(contract0, key0, &[Felt252::from(5), Felt252::from(6)]);
+    let initial_storage = state.storage().clone();
+
+    // Changing a value, setting a value to its previous value, and setting a new value.
+    state.store(contract0, key0, &[Felt252::from(7), Felt252::from(6)]);
+    state.store(contract1, key1, &[Felt252::from(8)]);
+
+    let diff = state.storage_diff(&initial_storage);
+    assert_eq!(
+        diff.into_iter()
+            .map(|(contract, changes)| (contract, changes.into_iter().collect_vec()))
+            .collect_vec(),
+        vec![
+            (contract0, vec![(key0, StorageChange {
+                before: Felt252::from(5),
+                after: Felt252::from(7)
+            })]),
+            (contract1, vec![(key1, StorageChange {
+                before: Felt252::from(0),
+                after: Felt252::from(8)
+            })]),
+        ]
+    );
+    assert_eq!(state.load(contract0, key0, 3), [7, 6, 0].map(Felt252::from));
+}
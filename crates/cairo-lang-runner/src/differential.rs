@@ -0,0 +1,793 @@
+//! Differential testing of the Sierra simulator against the CASM runner.
+//!
+//! A function is run both by [simulation::run] and by [SierraCasmRunner], and the values it
+//! returns, the data it panics with and the gas it leaves are compared. [LibfuncFuzzer] wraps a
+//! single libfunc in a function, and compares the two on random inputs generated from the
+//! libfunc's signature.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+use cairo_lang_sierra::extensions::array::ArrayType;
+use cairo_lang_sierra::extensions::bitwise::BitwiseType;
+use cairo_lang_sierra::extensions::bounded_int::BoundedIntType;
+use cairo_lang_sierra::extensions::bytes31::Bytes31Type;
+use cairo_lang_sierra::extensions::circuit::{AddModType, MulModType};
+use cairo_lang_sierra::extensions::core::{CoreLibfunc, CoreType};
+use cairo_lang_sierra::extensions::ec::EcOpType;
+use cairo_lang_sierra::extensions::enm::EnumType;
+use cairo_lang_sierra::extensions::felt252::Felt252Type;
+use cairo_lang_sierra::extensions::gas::{CostTokenType, GasBuiltinType};
+use cairo_lang_sierra::extensions::int::signed::{Sint8Type, Sint16Type, Sint32Type, Sint64Type};
+use cairo_lang_sierra::extensions::int::signed128::Sint128Type;
+use cairo_lang_sierra::extensions::int::unsigned::{Uint8Type, Uint16Type, Uint32Type, Uint64Type};
+use cairo_lang_sierra::extensions::int::unsigned128::Uint128Type;
+use cairo_lang_sierra::extensions::non_zero::NonZeroType;
+use cairo_lang_sierra::extensions::pedersen::PedersenType;
+use cairo_lang_sierra::extensions::poseidon::PoseidonType;
+use cairo_lang_sierra::extensions::range_check::{RangeCheck96Type, RangeCheckType};
+use cairo_lang_sierra::extensions::segment_arena::SegmentArenaType;
+use cairo_lang_sierra::extensions::snapshot::SnapshotType;
+use cairo_lang_sierra::extensions::starknet::syscalls::SystemType;
+use cairo_lang_sierra::extensions::structure::StructType;
+use cairo_lang_sierra::extensions::{ConcreteLibfunc, ConcreteType, NamedType};
+use cairo_lang_sierra::ids::{
+    ConcreteLibfuncId, ConcreteTypeId, FunctionId, GenericLibfuncId, GenericTypeId, UserTypeId,
+    VarId,
+};
+use cairo_lang_sierra::program::{
+    BranchInfo, BranchTarget, ConcreteLibfuncLongId, ConcreteTypeLongId, Function, GenericArg,
+    Invocation, LibfuncDeclaration, Param, Program, Statement, StatementIdx, TypeDeclaration,
+};
+use cairo_lang_sierra::program_registry::{ProgramRegistry, ProgramRegistryError};
+use cairo_lang_sierra::simulation;
+use cairo_lang_sierra::simulation::value::CoreValue;
+use cairo_lang_sierra_to_casm::invocations::enm::get_variant_selector;
+use cairo_lang_utils::extract_matches;
+use itertools::{Itertools, chain, repeat_n};
+use num_bigint::{BigInt, Sign};
+use rand::Rng;
+use rand::distributions::{Distribution, Standard};
+use starknet_types_core::felt::Felt as Felt252;
+use thiserror::Error;
+
+use crate::{Arg, RunResultValue, RunnerError, SierraCasmRunner, StarknetState};
+
+#[cfg(test)]
+#[path = "differential_test.rs"]
+mod test;
+
+/// The name of the function wrapping a fuzzed libfunc.
+const WRAPPER_FUNCTION_NAME: &str = "differential_wrapper";
+/// The maximal number of attempts to generate a non-zero value of a `NonZero` type.
+const MAX_NON_ZERO_ATTEMPTS: usize = 100;
+/// The maximal length of generated arrays.
+const MAX_GENERATED_LEN: usize = 4;
+/// The maximal gas given to a fuzzed function beyond the gas required for calling it.
+const MAX_EXTRA_GAS: usize = 10000;
+
+#[derive(Debug, Error)]
+pub enum DifferentialError {
+    #[error("Failed parsing the Sierra declarations: {0}")]
+    ParseError(String),
+    #[error("Expected a single libfunc declaration, found {0}.")]
+    LibfuncCountMismatch(usize),
+    #[error(transparent)]
+    ProgramRegistryError(#[from] Box<ProgramRegistryError>),
+    #[error(transparent)]
+    RunnerError(#[from] RunnerError),
+    #[error("Function expects {expected} non-implicit inputs and received {actual} instead.")]
+    InputsCountMismatch { expected: usize, actual: usize },
+    #[error("Values of type `{0}` are not supported.")]
+    UnsupportedType(ConcreteTypeId),
+    #[error("Value `{value:?}` does not match type `{ty}`.")]
+    ValueTypeMismatch { value: CoreValue, ty: ConcreteTypeId },
+}
+
+/// The outcome of running a function.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Outcome {
+    /// The run ended successfully, with the memory of the non-implicit returns and the remaining
+    /// gas.
+    Success { values: Vec<Felt252>, gas_counter: Option<Felt252> },
+    /// The run panicked, with the carried error data.
+    Panic(Vec<Felt252>),
+    /// The run failed - either by returning an error or by a Rust panic.
+    Failure(String),
+}
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Success { values, gas_counter } => {
+                write!(f, "Success([{}]", values.iter().join(", "))?;
+                if let Some(gas_counter) = gas_counter {
+                    write!(f, ", gas: {gas_counter}")?;
+                }
+                write!(f, ")")
+            }
+            Outcome::Panic(data) => write!(f, "Panic([{}])", data.iter().join(", ")),
+            Outcome::Failure(reason) => write!(f, "Failure({reason})"),
+        }
+    }
+}
+
+/// The outcomes of running a function by the simulator and by the CASM runner.
+#[derive(Clone, Debug)]
+pub struct Comparison {
+    /// The non-implicit inputs of the run.
+    pub inputs: Vec<CoreValue>,
+    /// The gas available for the run.
+    pub available_gas: Option<usize>,
+    /// The outcome of the simulation.
+    pub simulation: Outcome,
+    /// The outcome of the CASM run.
+    pub casm: Outcome,
+}
+impl Comparison {
+    /// Returns whether the outcomes diverge. Failures of both runs are not considered a divergence.
+    pub fn is_divergent(&self) -> bool {
+        match (&self.simulation, &self.casm) {
+            (Outcome::Failure(_), Outcome::Failure(_)) => false,
+            (simulation, casm) => simulation != casm,
+        }
+    }
+}
+impl Display for Comparison {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Inputs: {:?}", self.inputs)?;
+        if let Some(available_gas) = self.available_gas {
+            writeln!(f, "Available gas: {available_gas}")?;
+        }
+        writeln!(f, "Simulation: {}", self.simulation)?;
+        write!(f, "CASM: {}", self.casm)
+    }
+}
+
+/// Runner of the functions of a Sierra program by both the simulator and the CASM runner.
+pub struct DifferentialRunner {
+    /// The runner of the CASM code of the program.
+    runner: SierraCasmRunner,
+    /// The gas withdrawn or redeposited by the gas libfuncs, by their statement.
+    statement_gas_info: HashMap<StatementIdx, i64>,
+}
+impl DifferentialRunner {
+    pub fn new(program: Program) -> Result<Self, DifferentialError> {
+        let runner =
+            SierraCasmRunner::new(program, Some(Default::default()), Default::default(), None)?;
+        let statement_gas_info = runner
+            .builder
+            .metadata()
+            .gas_info
+            .variable_values
+            .iter()
+            .filter(|((_, token_type), _)| *token_type == CostTokenType::Const)
+            .map(|((idx, _), value)| (*idx, *value))
+            .collect();
+        Ok(Self { runner, statement_gas_info })
+    }
+
+    /// Finds first function ending with `name_suffix`.
+    pub fn find_function(&self, name_suffix: &str) -> Result<&Function, DifferentialError> {
+        Ok(self.runner.find_function(name_suffix)?)
+    }
+
+    /// Returns the gas required for calling `func`.
+    pub fn required_gas(&self, func: &Function) -> usize {
+        self.runner.initial_required_gas(func).unwrap_or_default()
+    }
+
+    /// Runs `func` with the non-implicit `inputs` by both the simulator and the CASM runner.
+    pub fn run(
+        &self,
+        func: &Function,
+        inputs: Vec<CoreValue>,
+        available_gas: Option<usize>,
+    ) -> Result<Comparison, DifferentialError> {
+        let initial_gas = self.runner.get_initial_available_gas(func, available_gas)? as i64;
+        let mut user_inputs = inputs.iter();
+        let mut simulation_inputs = vec![];
+        let mut args = vec![];
+        for ty in &func.signature.param_types {
+            if let Some(value) = self.builtin_value(ty, initial_gas) {
+                simulation_inputs.push(value);
+                continue;
+            }
+            let Some(value) = user_inputs.next() else {
+                return Err(DifferentialError::InputsCountMismatch {
+                    expected: self.user_types(&func.signature.param_types).count(),
+                    actual: inputs.len(),
+                });
+            };
+            args.extend(self.value_to_args(value, ty)?);
+            simulation_inputs.push(value.clone());
+        }
+        if user_inputs.next().is_some() {
+            return Err(DifferentialError::InputsCountMismatch {
+                expected: self.user_types(&func.signature.param_types).count(),
+                actual: inputs.len(),
+            });
+        }
+
+        let simulation = match catch_panic(|| {
+            simulation::run(
+                self.runner.builder.sierra_program(),
+                &self.statement_gas_info,
+                &func.id,
+                simulation_inputs,
+            )
+        }) {
+            Ok(Ok(outputs)) => self.simulation_outcome(func, outputs)?,
+            Ok(Err(err)) => Outcome::Failure(format!("{err:?}")),
+            Err(reason) => Outcome::Failure(reason),
+        };
+        let casm = match catch_panic(|| {
+            self.runner.run_function_with_starknet_context(
+                func,
+                args,
+                available_gas,
+                StarknetState::default(),
+            )
+        }) {
+            Ok(Ok(result)) => match result.value {
+                RunResultValue::Success(values) => {
+                    Outcome::Success { values, gas_counter: result.gas_counter }
+                }
+                RunResultValue::Panic(data) => Outcome::Panic(data),
+            },
+            Ok(Err(err)) => Outcome::Failure(err.to_string()),
+            Err(reason) => Outcome::Failure(reason),
+        };
+        Ok(Comparison { inputs, available_gas, simulation, casm })
+    }
+
+    /// Generates random non-implicit inputs for `func`, preferring edge cases.
+    pub fn random_inputs(
+        &self,
+        func: &Function,
+        rng: &mut impl Rng,
+    ) -> Result<Vec<CoreValue>, DifferentialError> {
+        self.user_types(&func.signature.param_types).map(|ty| self.random_value(ty, rng)).collect()
+    }
+
+    /// Converts the outputs of a simulation of `func` to its outcome.
+    fn simulation_outcome(
+        &self,
+        func: &Function,
+        outputs: Vec<CoreValue>,
+    ) -> Result<Outcome, DifferentialError> {
+        let gas_counter = outputs.iter().find_map(|output| match output {
+            CoreValue::GasBuiltin(gas) => Some(Felt252::from(*gas)),
+            _ => None,
+        });
+        let mut values = vec![];
+        for (ty, output) in func.signature.ret_types.iter().zip(outputs) {
+            if self.builtin_value(ty, 0).is_some() {
+                continue;
+            }
+            let generic_id = &self.runner.builder.type_long_id(ty).generic_id;
+            let Some(inner_ty) = self.runner.inner_type_from_panic_wrapper(generic_id, func) else {
+                values.extend(self.value_to_felts(&output, ty)?);
+                continue;
+            };
+            let mismatch =
+                || DifferentialError::ValueTypeMismatch { value: output.clone(), ty: ty.clone() };
+            let CoreValue::Enum { value, index } = &output else {
+                return Err(mismatch());
+            };
+            if *index == 0 {
+                values.extend(self.value_to_felts(value, &inner_ty)?);
+                continue;
+            }
+            let CoreValue::Struct(members) = value.as_ref() else {
+                return Err(mismatch());
+            };
+            let [_, CoreValue::Array(data)] = &members[..] else {
+                return Err(mismatch());
+            };
+            let data = data
+                .iter()
+                .map(|felt| match felt {
+                    CoreValue::Felt252(felt) => Ok(*felt),
+                    _ => Err(mismatch()),
+                })
+                .collect::<Result<_, _>>()?;
+            return Ok(Outcome::Panic(data));
+        }
+        Ok(Outcome::Success { values, gas_counter })
+    }
+
+    /// Returns the types out of `types` that are not implicits.
+    fn user_types<'a>(
+        &'a self,
+        types: &'a [ConcreteTypeId],
+    ) -> impl Iterator<Item = &'a ConcreteTypeId> + 'a {
+        types.iter().filter(|ty| self.builtin_value(ty, 0).is_none())
+    }
+
+    /// Returns the simulated value of the builtin type `ty`, or `None` if `ty` is not a builtin.
+    fn builtin_value(&self, ty: &ConcreteTypeId, gas: i64) -> Option<CoreValue> {
+        builtin_value(&self.runner.builder.type_long_id(ty).generic_id, gas)
+    }
+
+    /// Returns the long id of the type wrapped by `ty`, skipping `NonZero` and snapshot wrappers,
+    /// as their values are represented as the values they wrap.
+    fn unwrapped_long_id(&self, ty: &ConcreteTypeId) -> &ConcreteTypeLongId {
+        let mut long_id = self.runner.builder.type_long_id(ty);
+        while [NonZeroType::ID, SnapshotType::ID].contains(&long_id.generic_id) {
+            long_id = self
+                .runner
+                .builder
+                .type_long_id(extract_matches!(&long_id.generic_args[0], GenericArg::Type));
+        }
+        long_id
+    }
+
+    /// Returns the types of the members of a struct or of the variants of an enum.
+    fn member_types(long_id: &ConcreteTypeLongId) -> Vec<&ConcreteTypeId> {
+        long_id.generic_args[1..]
+            .iter()
+            .map(|arg| extract_matches!(arg, GenericArg::Type))
+            .collect()
+    }
+
+    /// Converts a value of type `ty` to the memory cells representing it.
+    fn value_to_felts(
+        &self,
+        value: &CoreValue,
+        ty: &ConcreteTypeId,
+    ) -> Result<Vec<Felt252>, DifferentialError> {
+        self.value_to_args(value, ty)?
+            .into_iter()
+            .map(|arg| match arg {
+                Arg::Value(felt) => Ok(felt),
+                Arg::Array(_) => Err(DifferentialError::UnsupportedType(ty.clone())),
+            })
+            .collect()
+    }
+
+    /// Converts a value of type `ty` to the runner arguments representing it.
+    fn value_to_args(
+        &self,
+        value: &CoreValue,
+        ty: &ConcreteTypeId,
+    ) -> Result<Vec<Arg>, DifferentialError> {
+        let mismatch =
+            || DifferentialError::ValueTypeMismatch { value: value.clone(), ty: ty.clone() };
+        let felt = |felt: Felt252| Ok(vec![Arg::Value(felt)]);
+        let long_id = self.unwrapped_long_id(ty);
+        match value {
+            CoreValue::Felt252(value) => felt(*value),
+            CoreValue::Uint8(value) => felt((*value).into()),
+            CoreValue::Uint16(value) => felt((*value).into()),
+            CoreValue::Uint32(value) => felt((*value).into()),
+            CoreValue::Uint64(value) => felt((*value).into()),
+            CoreValue::Uint128(value) => felt((*value).into()),
+            CoreValue::Sint8(value) => felt((*value).into()),
+            CoreValue::Sint16(value) => felt((*value).into()),
+            CoreValue::Sint32(value) => felt((*value).into()),
+            CoreValue::Sint64(value) => felt((*value).into()),
+            CoreValue::Sint128(value) => felt((*value).into()),
+            CoreValue::BoundedInt(value) => felt(value.clone().into()),
+            CoreValue::EcPoint(x, y) => Ok(vec![Arg::Value(*x), Arg::Value(*y)]),
+            CoreValue::Struct(members) if long_id.generic_id == StructType::ID => {
+                let member_types = Self::member_types(long_id);
+                if members.len() != member_types.len() {
+                    return Err(mismatch());
+                }
+                let mut args = vec![];
+                for (member, member_ty) in members.iter().zip(member_types) {
+                    args.extend(self.value_to_args(member, member_ty)?);
+                }
+                Ok(args)
+            }
+            CoreValue::Enum { value, index } if long_id.generic_id == EnumType::ID => {
+                let variant_types = Self::member_types(long_id);
+                let variant_ty = variant_types.get(*index).ok_or_else(mismatch)?;
+                let selector =
+                    get_variant_selector(variant_types.len(), *index).map_err(|_| mismatch())?;
+                let padding = self.runner.builder.type_size(ty)
+                    - 1
+                    - self.runner.builder.type_size(variant_ty);
+                Ok(chain!(
+                    [Arg::Value(selector.into())],
+                    repeat_n(Felt252::ZERO, padding as usize).map(Arg::Value),
+                    self.value_to_args(value, variant_ty)?
+                )
+                .collect())
+            }
+            CoreValue::Array(elements) => {
+                let element_ty = extract_matches!(&long_id.generic_args[0], GenericArg::Type);
+                let mut args = vec![];
+                for element in elements {
+                    args.extend(self.value_to_args(element, element_ty)?);
+                }
+                Ok(vec![Arg::Array(args)])
+            }
+            _ => Err(DifferentialError::UnsupportedType(ty.clone())),
+        }
+    }
+
+    /// Generates a random value of type `ty`, preferring edge cases.
+    fn random_value(
+        &self,
+        ty: &ConcreteTypeId,
+        rng: &mut impl Rng,
+    ) -> Result<CoreValue, DifferentialError> {
+        let long_id = self.runner.builder.type_long_id(ty);
+        let generic_id = &long_id.generic_id;
+        let type_arg =
+            |index: usize| extract_matches!(&long_id.generic_args[index], GenericArg::Type);
+        Ok(if *generic_id == Felt252Type::ID {
+            CoreValue::Felt252(if rng.gen_ratio(1, 4) {
+                [Felt252::ZERO, Felt252::ONE, Felt252::MAX][rng.gen_range(0..3)]
+            } else {
+                Felt252::from_bytes_be(&rng.gen())
+            })
+        } else if *generic_id == Uint8Type::ID {
+            CoreValue::Uint8(random_primitive(rng, [0, 1, u8::MAX]))
+        } else if *generic_id == Uint16Type::ID {
+            CoreValue::Uint16(random_primitive(rng, [0, 1, u16::MAX]))
+        } else if *generic_id == Uint32Type::ID {
+            CoreValue::Uint32(random_primitive(rng, [0, 1, u32::MAX]))
+        } else if *generic_id == Uint64Type::ID {
+            CoreValue::Uint64(random_primitive(rng, [0, 1, u64::MAX]))
+        } else if *generic_id == Uint128Type::ID {
+            CoreValue::Uint128(random_primitive(rng, [0, 1, u128::MAX]))
+        } else if *generic_id == Sint8Type::ID {
+            CoreValue::Sint8(random_primitive(rng, [i8::MIN, -1, 0, 1, i8::MAX]))
+        } else if *generic_id == Sint16Type::ID {
+            CoreValue::Sint16(random_primitive(rng, [i16::MIN, -1, 0, 1, i16::MAX]))
+        } else if *generic_id == Sint32Type::ID {
+            CoreValue::Sint32(random_primitive(rng, [i32::MIN, -1, 0, 1, i32::MAX]))
+        } else if *generic_id == Sint64Type::ID {
+            CoreValue::Sint64(random_primitive(rng, [i64::MIN, -1, 0, 1, i64::MAX]))
+        } else if *generic_id == Sint128Type::ID {
+            CoreValue::Sint128(random_primitive(rng, [i128::MIN, -1, 0, 1, i128::MAX]))
+        } else if *generic_id == BoundedIntType::ID {
+            let [GenericArg::Value(min), GenericArg::Value(max)] = &long_id.generic_args[..] else {
+                return Err(DifferentialError::UnsupportedType(ty.clone()));
+            };
+            CoreValue::BoundedInt(if rng.gen_ratio(1, 4) {
+                [min, max][rng.gen_range(0..2)].clone()
+            } else {
+                let offset = BigInt::from_bytes_be(Sign::Plus, &rng.gen::<[u8; 32]>());
+                min + offset % (max - min + 1)
+            })
+        } else if *generic_id == Bytes31Type::ID {
+            CoreValue::Felt252(if rng.gen_ratio(1, 4) {
+                [Felt252::ZERO, Felt252::from_bytes_be_slice(&[u8::MAX; 31])][rng.gen_range(0..2)]
+            } else {
+                Felt252::from_bytes_be_slice(&rng.gen::<[u8; 31]>())
+            })
+        } else if *generic_id == NonZeroType::ID {
+            for _ in 0..MAX_NON_ZERO_ATTEMPTS {
+                let value = self.random_value(type_arg(0), rng)?;
+                if self.value_to_felts(&value, ty)?.iter().any(|felt| *felt != Felt252::ZERO) {
+                    return Ok(value);
+                }
+            }
+            return Err(DifferentialError::UnsupportedType(ty.clone()));
+        } else if *generic_id == SnapshotType::ID {
+            self.random_value(type_arg(0), rng)?
+        } else if *generic_id == StructType::ID {
+            CoreValue::Struct(
+                Self::member_types(long_id)
+                    .into_iter()
+                    .map(|member_ty| self.random_value(member_ty, rng))
+                    .collect::<Result<_, _>>()?,
+            )
+        } else if *generic_id == EnumType::ID {
+            let variant_types = Self::member_types(long_id);
+            if variant_types.is_empty() {
+                return Err(DifferentialError::UnsupportedType(ty.clone()));
+            }
+            let index = rng.gen_range(0..variant_types.len());
+            CoreValue::Enum {
+                value: Box::new(self.random_value(variant_types[index], rng)?),
+                index,
+            }
+        } else if *generic_id == ArrayType::ID {
+            CoreValue::Array(
+                (0..rng.gen_range(0..=MAX_GENERATED_LEN))
+                    .map(|_| self.random_value(type_arg(0), rng))
+                    .collect::<Result<_, _>>()?,
+            )
+        } else {
+            return Err(DifferentialError::UnsupportedType(ty.clone()));
+        })
+    }
+}
+
+/// Fuzzer of a single libfunc, comparing its simulation with the run of its CASM code.
+pub struct LibfuncFuzzer {
+    runner: DifferentialRunner,
+}
+impl LibfuncFuzzer {
+    /// Creates a fuzzer for the libfunc declared in `declarations`, which are the Sierra
+    /// declarations of a single libfunc and of all the types used by its signature.
+    pub fn new(declarations: &str) -> Result<Self, DifferentialError> {
+        let mut program = cairo_lang_sierra::ProgramParser::new()
+            .parse(declarations)
+            .map_err(|err| DifferentialError::ParseError(err.to_string()))?;
+        let [libfunc] = &program.libfunc_declarations[..] else {
+            return Err(DifferentialError::LibfuncCountMismatch(
+                program.libfunc_declarations.len(),
+            ));
+        };
+        let libfunc_id = libfunc.id.clone();
+        let registry = ProgramRegistry::<CoreType, CoreLibfunc>::new(&program)?;
+        let libfunc = registry.get_libfunc(&libfunc_id)?;
+        let is_builtin = |ty: &ConcreteTypeId| {
+            Ok::<_, DifferentialError>(
+                builtin_value(&registry.get_type(ty)?.info().long_id.generic_id, 0).is_some(),
+            )
+        };
+        let param_types = libfunc.param_signatures().iter().map(|param| &param.ty).collect_vec();
+        let mut implicit_types = vec![];
+        for ty in &param_types {
+            if is_builtin(ty)? {
+                implicit_types.push((*ty).clone());
+            }
+        }
+        let mut branches = vec![];
+        for branch in libfunc.branch_signatures() {
+            let mut outputs = vec![];
+            for var in &branch.vars {
+                outputs.push((var.ty.clone(), is_builtin(&var.ty)?));
+            }
+            branches.push(outputs);
+        }
+        let fallthrough = libfunc.fallthrough();
+
+        let mut wrapper = WrapperBuilder { program: &mut program, next_var_id: 0 };
+        let params = param_types
+            .iter()
+            .map(|ty| Param { id: wrapper.new_var(), ty: (*ty).clone() })
+            .collect_vec();
+        let branch_types = branches
+            .iter()
+            .enumerate()
+            .map(|(index, outputs)| {
+                let members =
+                    outputs.iter().filter(|(_, is_builtin)| !is_builtin).map(|(ty, _)| ty);
+                wrapper.declare_type(
+                    &format!("DifferentialBranch{index}"),
+                    StructType::ID,
+                    chain!(
+                        [GenericArg::UserType(UserTypeId::from_string("Tuple"))],
+                        members.map(|ty| GenericArg::Type(ty.clone()))
+                    )
+                    .collect(),
+                )
+            })
+            .collect_vec();
+        let result_ty = if let [branch_ty] = &branch_types[..] {
+            branch_ty.clone()
+        } else {
+            wrapper.declare_type(
+                "DifferentialResult",
+                EnumType::ID,
+                chain!(
+                    [GenericArg::UserType(UserTypeId::from_string("DifferentialResult"))],
+                    branch_types.iter().map(|ty| GenericArg::Type(ty.clone()))
+                )
+                .collect(),
+            )
+        };
+
+        // The fallthrough branch must directly follow the invocation, so its block is first.
+        let block_order =
+            chain!(fallthrough, (0..branches.len()).filter(|i| Some(*i) != fallthrough))
+                .collect_vec();
+        let mut blocks = vec![vec![]; branches.len()];
+        let mut branch_infos = vec![];
+        for (index, outputs) in branches.iter().enumerate() {
+            let results = outputs.iter().map(|_| wrapper.new_var()).collect_vec();
+            blocks[index] =
+                wrapper.branch_block(index, &branch_types, &result_ty, outputs, &results);
+            branch_infos.push(results);
+        }
+        let entry_point = StatementIdx(wrapper.program.statements.len());
+        let mut next_statement = entry_point.0 + 1;
+        let mut targets = vec![BranchTarget::Fallthrough; branches.len()];
+        for index in &block_order {
+            if Some(*index) != fallthrough {
+                targets[*index] = BranchTarget::Statement(StatementIdx(next_statement));
+            }
+            next_statement += blocks[*index].len();
+        }
+        wrapper.program.statements.push(Statement::Invocation(Invocation {
+            libfunc_id,
+            args: params.iter().map(|param| param.id.clone()).collect(),
+            branches: targets
+                .into_iter()
+                .zip(branch_infos)
+                .map(|(target, results)| BranchInfo { target, results })
+                .collect(),
+        }));
+        for index in block_order {
+            wrapper.program.statements.append(&mut blocks[index]);
+        }
+        wrapper.program.funcs.push(Function::new(
+            FunctionId::from_string(WRAPPER_FUNCTION_NAME),
+            params,
+            chain!(implicit_types, [result_ty]).collect(),
+            entry_point,
+        ));
+        Ok(Self { runner: DifferentialRunner::new(program)? })
+    }
+
+    /// Runs the libfunc on `runs` random inputs, returning the comparisons of the runs in which
+    /// the simulator and the CASM runner diverged.
+    pub fn fuzz(
+        &self,
+        runs: usize,
+        rng: &mut impl Rng,
+    ) -> Result<Vec<Comparison>, DifferentialError> {
+        let func = self.runner.find_function(WRAPPER_FUNCTION_NAME)?;
+        let required_gas = self.runner.required_gas(func);
+        let mut divergences = vec![];
+        for _ in 0..runs {
+            let inputs = self.runner.random_inputs(func, rng)?;
+            let available_gas = required_gas + rng.gen_range(0..=MAX_EXTRA_GAS);
+            let comparison = self.runner.run(func, inputs, Some(available_gas))?;
+            if comparison.is_divergent() {
+                divergences.push(comparison);
+            }
+        }
+        Ok(divergences)
+    }
+}
+
+/// Helper for adding the wrapper function of a fuzzed libfunc to a program.
+struct WrapperBuilder<'a> {
+    program: &'a mut Program,
+    next_var_id: u64,
+}
+impl WrapperBuilder<'_> {
+    /// Returns a new variable id.
+    fn new_var(&mut self) -> VarId {
+        self.next_var_id += 1;
+        VarId::new(self.next_var_id - 1)
+    }
+
+    /// Declares a type if not already declared, returning its id.
+    fn declare_type(
+        &mut self,
+        name: &str,
+        generic_id: GenericTypeId,
+        generic_args: Vec<GenericArg>,
+    ) -> ConcreteTypeId {
+        let long_id = ConcreteTypeLongId { generic_id, generic_args };
+        if let Some(declaration) =
+            self.program.type_declarations.iter().find(|declaration| declaration.long_id == long_id)
+        {
+            return declaration.id.clone();
+        }
+        let id = ConcreteTypeId::from_string(name);
+        self.program.type_declarations.push(TypeDeclaration {
+            id: id.clone(),
+            long_id,
+            declared_type_info: None,
+        });
+        id
+    }
+
+    /// Declares a libfunc if not already declared, returning its id.
+    fn declare_libfunc(
+        &mut self,
+        name: String,
+        generic_id: &str,
+        generic_args: Vec<GenericArg>,
+    ) -> ConcreteLibfuncId {
+        let id = ConcreteLibfuncId::from_string(name);
+        if !self.program.libfunc_declarations.iter().any(|libfunc| libfunc.id == id) {
+            self.program.libfunc_declarations.push(LibfuncDeclaration {
+                id: id.clone(),
+                long_id: ConcreteLibfuncLongId {
+                    generic_id: GenericLibfuncId::from_string(generic_id),
+                    generic_args,
+                },
+            });
+        }
+        id
+    }
+
+    /// Returns the statements returning the `results` of the branch `index` of the fuzzed libfunc,
+    /// along with the implicits, from the wrapper function.
+    fn branch_block(
+        &mut self,
+        index: usize,
+        branch_types: &[ConcreteTypeId],
+        result_ty: &ConcreteTypeId,
+        outputs: &[(ConcreteTypeId, bool)],
+        results: &[VarId],
+    ) -> Vec<Statement> {
+        let mut statements = vec![];
+        let mut invoke = |libfunc_id: ConcreteLibfuncId, args: Vec<VarId>, results: Vec<VarId>| {
+            statements.push(Statement::Invocation(Invocation {
+                libfunc_id,
+                args,
+                branches: vec![BranchInfo { target: BranchTarget::Fallthrough, results }],
+            }));
+        };
+        if branch_types.len() > 1 {
+            let branch_align = self.declare_libfunc("branch_align".into(), "branch_align", vec![]);
+            invoke(branch_align, vec![], vec![]);
+        }
+        let mut implicits = vec![];
+        let mut members = vec![];
+        for ((ty, is_builtin), var) in outputs.iter().zip(results) {
+            if *is_builtin { implicits.push((ty, var)) } else { members.push(var.clone()) }
+        }
+        let branch_ty = &branch_types[index];
+        let construct = self.declare_libfunc(
+            format!("struct_construct_{branch_ty}"),
+            "struct_construct",
+            vec![GenericArg::Type(branch_ty.clone())],
+        );
+        let mut result = self.new_var();
+        invoke(construct, members, vec![result.clone()]);
+        if branch_types.len() > 1 {
+            let enum_init =
+                self.declare_libfunc(format!("enum_init_{result_ty}_{index}"), "enum_init", vec![
+                    GenericArg::Type(result_ty.clone()),
+                    GenericArg::Value(index.into()),
+                ]);
+            let variant = result;
+            result = self.new_var();
+            invoke(enum_init, vec![variant], vec![result.clone()]);
+        }
+        let mut returned = vec![];
+        for (ty, var) in chain!(implicits, [(result_ty, &result)]) {
+            let store_temp = self.declare_libfunc(format!("store_temp_{ty}"), "store_temp", vec![
+                GenericArg::Type(ty.clone()),
+            ]);
+            invoke(store_temp, vec![var.clone()], vec![var.clone()]);
+            returned.push(var.clone());
+        }
+        statements.push(Statement::Return(returned));
+        statements
+    }
+}
+
+/// Returns the simulated value of the builtin type `generic_id`, or `None` if it is not a builtin
+/// type.
+fn builtin_value(generic_id: &GenericTypeId, gas: i64) -> Option<CoreValue> {
+    [
+        (AddModType::ID, CoreValue::AddMod),
+        (BitwiseType::ID, CoreValue::Bitwise),
+        (GasBuiltinType::ID, CoreValue::GasBuiltin(gas)),
+        (EcOpType::ID, CoreValue::EcOp),
+        (MulModType::ID, CoreValue::MulMod),
+        (PedersenType::ID, CoreValue::Pedersen),
+        (PoseidonType::ID, CoreValue::Poseidon),
+        (RangeCheck96Type::ID, CoreValue::RangeCheck96),
+        (RangeCheckType::ID, CoreValue::RangeCheck),
+        (SegmentArenaType::ID, CoreValue::SegmentArena),
+        (SystemType::ID, CoreValue::System),
+    ]
+    .into_iter()
+    .find_map(|(id, value)| (id == *generic_id).then_some(value))
+}
+
+/// Returns a random value, preferring the given edge cases.
+fn random_primitive<T: Copy, const N: usize>(rng: &mut impl Rng, edge_cases: [T; N]) -> T
+where
+    Standard: Distribution<T>,
+{
+    if rng.gen_ratio(1, 4) { edge_cases[rng.gen_range(0..N)] } else { rng.gen() }
+}
+
+/// Runs `f`, returning the message of the Rust panic it raised, if it raised any.
+fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        format!("Panicked: {message}")
+    })
+}
@@ -0,0 +1,182 @@
+use indoc::indoc;
+use itertools::Itertools;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use test_case::test_case;
+
+use super::LibfuncFuzzer;
+
+/// The number of runs per fuzzed libfunc, unless overridden by `CAIRO_DIFFERENTIAL_RUNS`.
+const DEFAULT_RUNS: usize = 50;
+/// The seed of the fuzzing, unless overridden by `CAIRO_DIFFERENTIAL_SEED`.
+const DEFAULT_SEED: u64 = 0;
+
+/// Returns the number of runs per fuzzed libfunc and the seed of the fuzzing, which may be
+/// overridden for longer local runs.
+fn fuzzing_config() -> (usize, u64) {
+    let runs = std::env::var("CAIRO_DIFFERENTIAL_RUNS")
+        .map(|runs| runs.parse().expect("CAIRO_DIFFERENTIAL_RUNS must be a number."))
+        .unwrap_or(DEFAULT_RUNS);
+    let seed = std::env::var("CAIRO_DIFFERENTIAL_SEED")
+        .map(|seed| seed.parse().expect("CAIRO_DIFFERENTIAL_SEED must be a number."))
+        .unwrap_or(DEFAULT_SEED);
+    (runs, seed)
+}
+
+#[test_case(indoc! {"
+    type felt252 = felt252;
+    libfunc tested = felt252_add;
+"}; "felt252_add")]
+#[test_case(indoc! {"
+    type felt252 = felt252;
+    libfunc tested = felt252_mul;
+"}; "felt252_mul")]
+#[test_case(indoc! {"
+    type felt252 = felt252;
+    type NonZeroFelt252 = NonZero<felt252>;
+    libfunc tested = felt252_div;
+"}; "felt252_div")]
+#[test_case(indoc! {"
+    type felt252 = felt252;
+    type NonZeroFelt252 = NonZero<felt252>;
+    libfunc tested = felt252_is_zero;
+"}; "felt252_is_zero")]
+#[test_case(indoc! {"
+    type RangeCheck = RangeCheck;
+    type u8 = u8;
+    libfunc tested = u8_overflowing_add;
+"}; "u8_overflowing_add")]
+#[test_case(indoc! {"
+    type RangeCheck = RangeCheck;
+    type u8 = u8;
+    libfunc tested = u8_overflowing_sub;
+"}; "u8_overflowing_sub")]
+#[test_case(indoc! {"
+    type u8 = u8;
+    libfunc tested = u8_eq;
+"}; "u8_eq")]
+#[test_case(indoc! {"
+    type u8 = u8;
+    type NonZeroU8 = NonZero<u8>;
+    libfunc tested = u8_is_zero;
+"}; "u8_is_zero")]
+#[test_case(indoc! {"
+    type RangeCheck = RangeCheck;
+    type u8 = u8;
+    type NonZeroU8 = NonZero<u8>;
+    libfunc tested = u8_safe_divmod;
+"}; "u8_safe_divmod")]
+#[test_case(indoc! {"
+    type u8 = u8;
+    type u16 = u16;
+    libfunc tested = u8_wide_mul;
+"}; "u8_wide_mul")]
+#[test_case(indoc! {"
+    type RangeCheck = RangeCheck;
+    type u8 = u8;
+    libfunc tested = u8_sqrt;
+"}; "u8_sqrt")]
+#[test_case(indoc! {"
+    type Bitwise = Bitwise;
+    type u8 = u8;
+    libfunc tested = u8_bitwise;
+"}; "u8_bitwise")]
+#[test_case(indoc! {"
+    type RangeCheck = RangeCheck;
+    type u128 = u128;
+    libfunc tested = u128_overflowing_add;
+"}; "u128_overflowing_add")]
+#[test_case(indoc! {"
+    type RangeCheck = RangeCheck;
+    type u128 = u128;
+    type NonZeroU128 = NonZero<u128>;
+    libfunc tested = u128_safe_divmod;
+"}; "u128_safe_divmod")]
+#[test_case(indoc! {"
+    type RangeCheck = RangeCheck;
+    type felt252 = felt252;
+    type u128 = u128;
+    libfunc tested = u128s_from_felt252;
+"}; "u128s_from_felt252")]
+#[test_case(indoc! {"
+    type Bitwise = Bitwise;
+    type u128 = u128;
+    libfunc tested = u128_byte_reverse;
+"}; "u128_byte_reverse")]
+#[test_case(indoc! {"
+    type RangeCheck = RangeCheck;
+    type u64 = u64;
+    type u128 = u128;
+    libfunc tested = u128_sqrt;
+"}; "u128_sqrt")]
+#[test_case(indoc! {"
+    type RangeCheck = RangeCheck;
+    type i8 = i8;
+    libfunc tested = i8_overflowing_add_impl;
+"}; "i8_overflowing_add_impl")]
+#[test_case(indoc! {"
+    type RangeCheck = RangeCheck;
+    type i8 = i8;
+    type u8 = u8;
+    libfunc tested = i8_diff;
+"}; "i8_diff")]
+#[test_case(indoc! {"
+    type i8 = i8;
+    type i16 = i16;
+    libfunc tested = i8_wide_mul;
+"}; "i8_wide_mul")]
+#[test_case(indoc! {"
+    type RangeCheck = RangeCheck;
+    type i128 = i128;
+    libfunc tested = i128_overflowing_sub_impl;
+"}; "i128_overflowing_sub_impl")]
+#[test_case(indoc! {"
+    type RangeCheck = RangeCheck;
+    type i128 = i128;
+    type u128 = u128;
+    libfunc tested = i128_diff;
+"}; "i128_diff")]
+#[test_case(indoc! {"
+    type u128 = u128;
+    type u256 = Struct<ut@core::integer::u256, u128, u128>;
+    type NonZeroU256 = NonZero<u256>;
+    libfunc tested = u256_is_zero;
+"}; "u256_is_zero")]
+#[test_case(indoc! {"
+    type RangeCheck = RangeCheck;
+    type felt252 = felt252;
+    type bytes31 = bytes31;
+    libfunc tested = bytes31_try_from_felt252;
+"}; "bytes31_try_from_felt252")]
+#[test_case(indoc! {"
+    type RangeCheck = RangeCheck;
+    type u8 = u8;
+    type u128 = u128;
+    libfunc tested = downcast<u128, u8>;
+"}; "downcast")]
+#[test_case(indoc! {"
+    type Unit = Struct<ut@Tuple>;
+    type bool = Enum<ut@core::bool, Unit, Unit>;
+    libfunc tested = bool_xor_impl;
+"}; "bool_xor_impl")]
+#[test_case(indoc! {"
+    type BoundedInt_0_10 = BoundedInt<0, 10>;
+    type BoundedInt_m5_5 = BoundedInt<-5, 5>;
+    type BoundedInt_m5_15 = BoundedInt<-5, 15>;
+    libfunc tested = bounded_int_add<BoundedInt_0_10, BoundedInt_m5_5>;
+"}; "bounded_int_add")]
+#[test_case(indoc! {"
+    type RangeCheck = RangeCheck;
+    type GasBuiltin = GasBuiltin;
+    libfunc tested = withdraw_gas;
+"}; "withdraw_gas")]
+fn fuzz_libfunc(declarations: &str) {
+    let (runs, seed) = fuzzing_config();
+    let fuzzer = LibfuncFuzzer::new(declarations).unwrap();
+    let divergences = fuzzer.fuzz(runs, &mut StdRng::seed_from_u64(seed)).unwrap();
+    assert!(
+        divergences.is_empty(),
+        "The simulation and the CASM run diverged (seed: {seed}):\n{}",
+        divergences.iter().join("\n\n")
+    );
+}
@@ -24,7 +24,7 @@ use cairo_vm::vm::runners::cairo_runner::{ExecutionResources, RunResources};
 use cairo_vm::vm::trace::trace_entry::RelocatedTraceEntry;
 use cairo_vm::vm::vm_core::VirtualMachine;
 use casm_run::hint_to_hint_params;
-pub use casm_run::{CairoHintProcessor, StarknetState};
+pub use casm_run::{CairoHintProcessor, EmittedEvent, StarknetState, StorageChange, StorageDiff};
 use itertools::chain;
 use num_bigint::BigInt;
 use num_traits::ToPrimitive;
@@ -35,7 +35,10 @@ use thiserror::Error;
 use crate::casm_run::RunFunctionResult;
 
 pub mod casm_run;
+#[cfg(any(feature = "testing", test))]
+pub mod differential;
 pub mod profiling;
+pub mod profiling_export;
 pub mod short_string;
 
 const MAX_STACK_TRACE_DEPTH_DEFAULT: usize = 100;
@@ -60,6 +63,8 @@ pub struct RunResultStarknet {
     pub memory: Vec<Option<Felt252>>,
     pub value: RunResultValue,
     pub starknet_state: StarknetState,
+    /// The changes made to the storage of every contract during the run.
+    pub storage_diff: StorageDiff,
     pub used_resources: StarknetExecutionResources,
     /// The profiling info of the run, if requested.
     pub profiling_info: Option<ProfilingInfo>,
@@ -194,6 +199,23 @@ impl SierraCasmRunner {
         args: Vec<Arg>,
         available_gas: Option<usize>,
         starknet_state: StarknetState,
+    ) -> Result<RunResultStarknet, RunnerError> {
+        let initial_storage = starknet_state.storage().clone();
+        let result = self.run_call_frame(func, args, available_gas, starknet_state)?;
+        let storage_diff = result.starknet_state.storage_diff(&initial_storage);
+        Ok(RunResultStarknet { storage_diff, ..result })
+    }
+
+    /// Runs the vm starting from a function in the context of a given starknet state, as a call
+    /// frame of a run.
+    ///
+    /// The storage diff of the result is left empty, as only the diff of the whole run is needed.
+    pub(crate) fn run_call_frame(
+        &self,
+        func: &Function,
+        args: Vec<Arg>,
+        available_gas: Option<usize>,
+        starknet_state: StarknetState,
     ) -> Result<RunResultStarknet, RunnerError> {
         let (assembled_program, builtins) =
             self.builder.assemble_function_program(func, EntryCodeConfig::testing())?;
@@ -222,6 +244,7 @@ impl SierraCasmRunner {
             gas_counter,
             memory,
             value,
+            storage_diff: Default::default(),
             starknet_state: hint_processor.starknet_state,
             used_resources: all_used_resources,
             profiling_info,
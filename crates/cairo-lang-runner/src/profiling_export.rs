@@ -0,0 +1,189 @@
+//! Exporters of profiling stack traces into formats of standard profile viewers.
+
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use itertools::Itertools;
+
+use crate::profiling::StackTraceWeights;
+
+#[cfg(test)]
+#[path = "profiling_export_test.rs"]
+mod test;
+
+/// A format to export profiling stack traces into.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProfileExportFormat {
+    /// The collapsed-stack format used by flamegraph tools - a line per stack trace, of the
+    /// `;`-separated function names from the outermost function, followed by the weight.
+    CollapsedStacks,
+    /// An (uncompressed) pprof protobuf profile.
+    Pprof,
+}
+impl ProfileExportFormat {
+    /// Exports the given stack trace weights in this format.
+    /// The weights are expected to be inclusive, as collected by the runner - the weight of a
+    /// stack trace includes the weights of the stack traces of the functions it called.
+    pub fn export(&self, stack_trace_weights: &OrderedHashMap<Vec<String>, usize>) -> Vec<u8> {
+        match self {
+            ProfileExportFormat::CollapsedStacks => {
+                to_collapsed_stacks(stack_trace_weights).into_bytes()
+            }
+            ProfileExportFormat::Pprof => to_pprof(stack_trace_weights),
+        }
+    }
+}
+
+impl StackTraceWeights {
+    /// Returns the stack trace weights best suited for exporting - the Cairo stack traces if
+    /// processed, and otherwise the Sierra stack traces.
+    pub fn exportable(&self) -> Option<&OrderedHashMap<Vec<String>, usize>> {
+        self.cairo_stack_trace_weights.as_ref().or(self.sierra_stack_trace_weights.as_ref())
+    }
+}
+
+/// Converts inclusive stack trace weights to self weights - the weight of a stack trace excluding
+/// the weights of the stack traces of the functions it called.
+fn self_weights(
+    stack_trace_weights: &OrderedHashMap<Vec<String>, usize>,
+) -> OrderedHashMap<Vec<String>, usize> {
+    let mut self_weights = stack_trace_weights.clone();
+    for (stack_trace, weight) in stack_trace_weights.iter() {
+        let Some((_, caller_stack_trace)) = stack_trace.split_last() else { continue };
+        if let Some(caller_weight) = self_weights.get_mut(caller_stack_trace) {
+            *caller_weight = caller_weight.saturating_sub(*weight);
+        }
+    }
+    self_weights
+}
+
+/// Converts inclusive stack trace weights to the collapsed-stack format used by flamegraph tools.
+pub fn to_collapsed_stacks(stack_trace_weights: &OrderedHashMap<Vec<String>, usize>) -> String {
+    self_weights(stack_trace_weights)
+        .iter()
+        .filter(|(stack_trace, weight)| !stack_trace.is_empty() && **weight > 0)
+        .map(|(stack_trace, weight)| {
+            // `;` separates the frames in the format, so it can't appear in a function name.
+            format!(
+                "{} {weight}\n",
+                stack_trace.iter().map(|name| name.replace(';', ":")).join(";")
+            )
+        })
+        .collect()
+}
+
+/// Converts inclusive stack trace weights to a pprof protobuf profile, with the self weights as
+/// `steps` samples.
+///
+/// See https://github.com/google/pprof/blob/main/proto/profile.proto for the format.
+pub fn to_pprof(stack_trace_weights: &OrderedHashMap<Vec<String>, usize>) -> Vec<u8> {
+    // Index 0 of the string table must be the empty string.
+    let mut strings = OrderedHashMap::<&str, u64>::from_iter([("", 0)]);
+    let mut intern = |s| {
+        let next = strings.len() as u64;
+        *strings.entry(s).or_insert(next)
+    };
+    let steps = intern("steps");
+    let count = intern("count");
+    // Every function has a single location - so both are identified by the function name.
+    // Ids are 1 based, as 0 is reserved.
+    let mut function_ids = OrderedHashMap::<&str, u64>::default();
+    let mut samples = vec![];
+    let self_weights = self_weights(stack_trace_weights);
+    for (stack_trace, weight) in self_weights.iter() {
+        if *weight == 0 {
+            continue;
+        }
+        // Samples hold the location ids from the innermost function.
+        let location_ids = stack_trace
+            .iter()
+            .rev()
+            .map(|name| {
+                let next = function_ids.len() as u64 + 1;
+                *function_ids.entry(name.as_str()).or_insert(next)
+            })
+            .collect_vec();
+        samples.push((location_ids, *weight as u64));
+    }
+    let function_names = function_ids.keys().map(|name| intern(*name)).collect_vec();
+
+    let mut profile = ProtoWriter::default();
+    let value_type = |w: &mut ProtoWriter| {
+        w.uint(1, steps);
+        w.uint(2, count);
+    };
+    // `sample_type`.
+    profile.message(1, value_type);
+    // `sample`.
+    for (location_ids, weight) in &samples {
+        profile.message(2, |sample| {
+            sample.packed(1, location_ids);
+            sample.packed(2, &[*weight]);
+        });
+    }
+    for (idx, name) in function_names.iter().enumerate() {
+        let id = idx as u64 + 1;
+        // `location`, with a single `line` of the function.
+        profile.message(4, |location| {
+            location.uint(1, id);
+            location.message(4, |line| line.uint(1, id));
+        });
+        // `function`.
+        profile.message(5, |function| {
+            function.uint(1, id);
+            function.uint(2, *name);
+            function.uint(3, *name);
+        });
+    }
+    // `string_table`.
+    for s in strings.keys() {
+        profile.bytes(6, s.as_bytes());
+    }
+    // `period_type` and `period`.
+    profile.message(11, value_type);
+    profile.uint(12, 1);
+    profile.buf
+}
+
+/// A minimal writer of protobuf messages.
+#[derive(Default)]
+struct ProtoWriter {
+    buf: Vec<u8>,
+}
+impl ProtoWriter {
+    /// Writes a varint.
+    fn varint(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.buf.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        self.buf.push(value as u8);
+    }
+
+    /// Writes a varint field.
+    fn uint(&mut self, field: u64, value: u64) {
+        self.varint(field << 3);
+        self.varint(value);
+    }
+
+    /// Writes a length-delimited field.
+    fn bytes(&mut self, field: u64, bytes: &[u8]) {
+        self.varint((field << 3) | 2);
+        self.varint(bytes.len() as u64);
+        self.buf.extend_from_slice(bytes);
+    }
+
+    /// Writes a packed repeated varint field.
+    fn packed(&mut self, field: u64, values: &[u64]) {
+        let mut inner = ProtoWriter::default();
+        for value in values {
+            inner.varint(*value);
+        }
+        self.bytes(field, &inner.buf);
+    }
+
+    /// Writes an embedded message field.
+    fn message(&mut self, field: u64, write_fields: impl FnOnce(&mut ProtoWriter)) {
+        let mut inner = ProtoWriter::default();
+        write_fields(&mut inner);
+        self.bytes(field, &inner.buf);
+    }
+}
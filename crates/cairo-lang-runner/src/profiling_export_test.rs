@@ -0,0 +1,104 @@
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use indoc::indoc;
+
+use super::{to_collapsed_stacks, to_pprof};
+
+/// Returns inclusive stack trace weights of a small program, with self weights of 10, 25 and 130.
+fn stack_trace_weights() -> OrderedHashMap<Vec<String>, usize> {
+    [
+        (vec!["test::main".to_string()], 165),
+        (vec!["test::main".to_string(), "test::foo".to_string()], 155),
+        (vec!["test::main".to_string(), "test::foo".to_string(), "core::bar".to_string()], 130),
+    ]
+    .into_iter()
+    .collect()
+}
+
+/// A field of a decoded protobuf message.
+#[derive(Debug, PartialEq)]
+enum Field {
+    Varint(u64),
+    Bytes(Vec<u8>),
+}
+
+/// Reads a varint from the start of the given bytes, advancing them.
+fn read_varint(bytes: &mut &[u8]) -> u64 {
+    let mut value = 0;
+    for shift in (0..).step_by(7) {
+        let (byte, rest) = bytes.split_first().unwrap();
+        *bytes = rest;
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            break;
+        }
+    }
+    value
+}
+
+/// Decodes the top level fields of a protobuf message.
+fn decode(mut bytes: &[u8]) -> Vec<(u64, Field)> {
+    let mut fields = vec![];
+    while !bytes.is_empty() {
+        let key = read_varint(&mut bytes);
+        let field = match key & 7 {
+            0 => Field::Varint(read_varint(&mut bytes)),
+            2 => {
+                let len = read_varint(&mut bytes) as usize;
+                let (value, rest) = bytes.split_at(len);
+                bytes = rest;
+                Field::Bytes(value.to_vec())
+            }
+            wire_type => panic!("Unexpected wire type {wire_type}."),
+        };
+        fields.push((key >> 3, field));
+    }
+    fields
+}
+
+/// Returns the bytes values of the fields with the given number.
+fn bytes_fields(fields: &[(u64, Field)], number: u64) -> Vec<&[u8]> {
+    fields
+        .iter()
+        .filter(|(n, _)| *n == number)
+        .map(|(_, field)| match field {
+            Field::Bytes(bytes) => bytes.as_slice(),
+            Field::Varint(_) => panic!("Expected a bytes field."),
+        })
+        .collect()
+}
+
+#[test]
+fn test_collapsed_stacks() {
+    assert_eq!(to_collapsed_stacks(&stack_trace_weights()), indoc! {"
+            test::main 10
+            test::main;test::foo 25
+            test::main;test::foo;core::bar 130
+        "});
+}
+
+#[test]
+fn test_pprof() {
+    let profile = decode(&to_pprof(&stack_trace_weights()));
+    let string_table = bytes_fields(&profile, 6)
+        .into_iter()
+        .map(|s| String::from_utf8(s.to_vec()).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(string_table, ["", "steps", "count", "test::main", "test::foo", "core::bar"]);
+    // The sample type is `steps` in `count` units.
+    assert_eq!(decode(bytes_fields(&profile, 1)[0]), [
+        (1, Field::Varint(1)),
+        (2, Field::Varint(2))
+    ]);
+    // Samples hold the location ids from the innermost function, and the weight.
+    let samples = bytes_fields(&profile, 2).into_iter().map(decode).collect::<Vec<_>>();
+    assert_eq!(samples, [
+        vec![(1, Field::Bytes(vec![1])), (2, Field::Bytes(vec![10]))],
+        vec![(1, Field::Bytes(vec![2, 1])), (2, Field::Bytes(vec![25]))],
+        vec![(1, Field::Bytes(vec![3, 2, 1])), (2, Field::Bytes(vec![130, 1]))],
+    ]);
+    // Each function has a location with the same id, and is named by its index in the string
+    // table.
+    let functions = bytes_fields(&profile, 5).into_iter().map(decode).collect::<Vec<_>>();
+    assert_eq!(functions[2], [(1, Field::Varint(3)), (2, Field::Varint(5)), (3, Field::Varint(5))]);
+    assert_eq!(bytes_fields(&profile, 4).len(), 3);
+}
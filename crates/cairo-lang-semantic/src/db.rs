@@ -36,6 +36,8 @@ use crate::items::trt::{
 };
 use crate::items::us::SemanticUseEx;
 use crate::items::visibility::Visibility;
+use crate::lint_passes::{Lint, LintPass, check_function_body};
+use crate::lints::apply_lint_levels;
 use crate::plugin::AnalyzerPlugin;
 use crate::resolve::{ResolvedConcreteItem, ResolvedGenericItem, ResolverData};
 use crate::substitution::GenericSubstitution;
@@ -1536,6 +1538,19 @@ pub trait SemanticGroup:
     /// An allow that is not in this set will be handled as an unknown allow.
     fn declared_allows(&self) -> Arc<OrderedHashSet<String>>;
 
+    // Lint passes.
+    // ============
+    #[salsa::input]
+    fn lint_passes(&self) -> Vec<Arc<dyn LintPass>>;
+
+    /// The lints reported by the lint passes of later compilation phases, which are not known to
+    /// this phase.
+    #[salsa::input]
+    fn external_lints(&self) -> Arc<Vec<&'static Lint>>;
+
+    /// Returns the lints declared by the lint passes, including the external ones.
+    fn declared_lints(&self) -> Arc<Vec<&'static Lint>>;
+
     // Helpers for language server.
     // ============================
     /// Returns all methods in a module that match the given type filter.
@@ -1578,6 +1593,14 @@ pub trait SemanticGroup:
     ) -> Arc<[(TraitId, String)]>;
 }
 
+/// Initializes the inputs of [SemanticGroup] to their defaults.
+///
+/// Lint passes are not run unless set with [SemanticGroup::set_lint_passes].
+pub fn init_semantic_group(db: &mut (dyn SemanticGroup + 'static)) {
+    db.set_lint_passes(vec![]);
+    db.set_external_lints(Arc::new(vec![]));
+}
+
 impl<T: Upcast<dyn SemanticGroup + 'static>> Elongate for T {
     fn elongate(&self) -> &(dyn SemanticGroup + 'static) {
         self.upcast()
@@ -1611,6 +1634,11 @@ fn module_semantic_diagnostics(
             ModuleItemId::FreeFunction(free_function) => {
                 diagnostics.extend(db.free_function_declaration_diagnostics(*free_function));
                 diagnostics.extend(db.free_function_body_diagnostics(*free_function));
+                add_lint_pass_diagnostics(
+                    db,
+                    FunctionWithBodyId::Free(*free_function),
+                    &mut diagnostics,
+                );
             }
             ModuleItemId::Struct(struct_id) => {
                 diagnostics.extend(db.struct_declaration_diagnostics(*struct_id));
@@ -1623,10 +1651,30 @@ fn module_semantic_diagnostics(
             ModuleItemId::Trait(trait_id) => {
                 diagnostics.extend(db.trait_semantic_declaration_diagnostics(*trait_id));
                 diagnostics.extend(db.trait_semantic_definition_diagnostics(*trait_id));
+                if let Ok(trait_functions) = db.trait_functions(*trait_id) {
+                    for trait_function in trait_functions.values() {
+                        if matches!(db.trait_function_body(*trait_function), Ok(Some(_))) {
+                            add_lint_pass_diagnostics(
+                                db,
+                                FunctionWithBodyId::Trait(*trait_function),
+                                &mut diagnostics,
+                            );
+                        }
+                    }
+                }
             }
             ModuleItemId::Impl(impl_def_id) => {
                 diagnostics.extend(db.impl_semantic_declaration_diagnostics(*impl_def_id));
                 diagnostics.extend(db.impl_semantic_definition_diagnostics(*impl_def_id));
+                if let Ok(impl_functions) = db.impl_functions(*impl_def_id) {
+                    for impl_function in impl_functions.values() {
+                        add_lint_pass_diagnostics(
+                            db,
+                            FunctionWithBodyId::Impl(*impl_function),
+                            &mut diagnostics,
+                        );
+                    }
+                }
             }
             ModuleItemId::Submodule(submodule_id) => {
                 // Note that the parent module does not report the diagnostics of its submodules.
@@ -1675,7 +1723,7 @@ fn module_semantic_diagnostics(
         }
     }
 
-    Ok(diagnostics.build())
+    Ok(apply_lint_levels(db, module_id, diagnostics.build()))
 }
 
 fn declared_allows(db: &dyn SemanticGroup) -> Arc<OrderedHashSet<String>> {
@@ -1684,6 +1732,36 @@ fn declared_allows(db: &dyn SemanticGroup) -> Arc<OrderedHashSet<String>> {
     ))
 }
 
+fn declared_lints(db: &dyn SemanticGroup) -> Arc<Vec<&'static Lint>> {
+    Arc::new(
+        db.lint_passes()
+            .iter()
+            .flat_map(|lint_pass| lint_pass.lints())
+            .chain(db.external_lints().iter().copied())
+            .collect(),
+    )
+}
+
+/// Adds the diagnostics reported by the lint passes on the body of a function.
+fn add_lint_pass_diagnostics(
+    db: &dyn SemanticGroup,
+    function_id: FunctionWithBodyId,
+    diagnostics: &mut DiagnosticsBuilder<SemanticDiagnostic>,
+) {
+    let Ok(reports) = check_function_body(db, function_id) else {
+        return;
+    };
+    for report in reports {
+        diagnostics.add(SemanticDiagnostic::new(
+            StableLocation::new(report.stable_ptr),
+            SemanticDiagnosticKind::LintPassDiagnostic {
+                lint: report.lint,
+                message: report.message,
+            },
+        ));
+    }
+}
+
 /// Adds diagnostics for unused items in a module.
 ///
 /// Returns `None` if skipped attempt to add diagnostics.
@@ -8,18 +8,22 @@ use cairo_lang_defs::ids::{
 };
 use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::{
-    DiagnosticAdded, DiagnosticEntry, DiagnosticLocation, DiagnosticsBuilder, ErrorCode, Severity,
-    error_code,
+    Applicability, DiagnosticAdded, DiagnosticEdit, DiagnosticEntry, DiagnosticFix,
+    DiagnosticLocation, DiagnosticsBuilder, ErrorCode, Severity, error_code,
 };
 use cairo_lang_syntax as syntax;
 use itertools::Itertools;
 use smol_str::SmolStr;
 use syntax::node::ids::SyntaxStablePtrId;
+use syntax::node::kind::SyntaxKind;
+use syntax::node::{TypedSyntaxNode, ast};
 
 use crate::corelib::LiteralError;
 use crate::db::SemanticGroup;
 use crate::expr::inference::InferenceError;
 use crate::items::feature_kind::FeatureMarkerDiagnostic;
+use crate::lint_passes::Lint;
+use crate::lints::{self, LintDiagnostic};
 use crate::resolve::ResolvedConcreteItem;
 use crate::types::peel_snapshots;
 use crate::{ConcreteTraitId, semantic};
@@ -67,15 +71,26 @@ pub struct SemanticDiagnostic {
     /// true if the diagnostic should be reported *after* the given location. Normally false, in
     /// which case the diagnostic points to the given location (as-is).
     pub after: bool,
+    /// true if the lint covering the diagnostic is denied, in which case it is reported as an
+    /// error.
+    pub denied: bool,
+    /// Fixes suggested when the diagnostic was reported, in addition to those derived from its
+    /// kind.
+    pub fixes: Vec<DiagnosticFix>,
 }
 impl SemanticDiagnostic {
     /// Create a diagnostic in the given location.
     pub fn new(stable_location: StableLocation, kind: SemanticDiagnosticKind) -> Self {
-        SemanticDiagnostic { stable_location, kind, after: false }
+        SemanticDiagnostic { stable_location, kind, after: false, denied: false, fixes: vec![] }
     }
     /// Create a diagnostic in the location after the given location (with width 0).
     pub fn new_after(stable_location: StableLocation, kind: SemanticDiagnosticKind) -> Self {
-        SemanticDiagnostic { stable_location, kind, after: true }
+        SemanticDiagnostic { stable_location, kind, after: true, denied: false, fixes: vec![] }
+    }
+    /// Attaches suggested fixes to the diagnostic.
+    pub fn with_fixes(mut self, fixes: Vec<DiagnosticFix>) -> Self {
+        self.fixes = fixes;
+        self
     }
 }
 impl DiagnosticEntry for SemanticDiagnostic {
@@ -630,6 +645,7 @@ impl DiagnosticEntry for SemanticDiagnostic {
             SemanticDiagnosticKind::PluginDiagnostic(diagnostic) => {
                 format!("Plugin diagnostic: {}", diagnostic.message)
             }
+            SemanticDiagnosticKind::LintPassDiagnostic { message, .. } => message.clone(),
             SemanticDiagnosticKind::NameDefinedMultipleTimes(name) => {
                 format!("The name `{name}` is defined multiple times.")
             }
@@ -762,6 +778,12 @@ impl DiagnosticEntry for SemanticDiagnostic {
                 // TODO(orizi): Add information about the allowed arguments.
                 "`allow` attribute argument not supported.".into()
             }
+            SemanticDiagnosticKind::UnsupportedWarnAttrArguments => {
+                "`warn` attribute argument not supported.".into()
+            }
+            SemanticDiagnosticKind::UnsupportedDenyAttrArguments => {
+                "`deny` attribute argument not supported.".into()
+            }
             SemanticDiagnosticKind::UnsupportedPubArgument => "Unsupported `pub` argument.".into(),
             SemanticDiagnosticKind::UnknownStatementAttribute => {
                 "Unknown statement attribute.".into()
@@ -888,6 +910,9 @@ impl DiagnosticEntry for SemanticDiagnostic {
     }
 
     fn severity(&self) -> Severity {
+        if self.denied {
+            return Severity::Error;
+        }
         match &self.kind {
             SemanticDiagnosticKind::UnusedVariable
             | SemanticDiagnosticKind::UnhandledMustUseType { .. }
@@ -902,20 +927,82 @@ impl DiagnosticEntry for SemanticDiagnostic {
             | SemanticDiagnosticKind::UnusedImport { .. }
             | SemanticDiagnosticKind::CallingShadowedFunction { .. }
             | SemanticDiagnosticKind::UnusedConstant
-            | SemanticDiagnosticKind::UnusedUse => Severity::Warning,
+            | SemanticDiagnosticKind::UnusedUse
+            | SemanticDiagnosticKind::LintPassDiagnostic { .. } => Severity::Warning,
             SemanticDiagnosticKind::PluginDiagnostic(diag) => diag.severity,
             _ => Severity::Error,
         }
     }
 
     fn error_code(&self) -> Option<ErrorCode> {
-        self.kind.error_code()
+        Some(self.kind.error_code())
+    }
+
+    fn fixes(&self, db: &Self::DbType) -> Vec<DiagnosticFix> {
+        let mut fixes = self.fixes.clone();
+        if self.kind == SemanticDiagnosticKind::UnusedVariable {
+            fixes.push(rename_unused_variable_fix(db, self.stable_location));
+        }
+        fixes
     }
 
     fn is_same_kind(&self, other: &Self) -> bool {
         other.kind == self.kind
     }
 }
+impl LintDiagnostic for SemanticDiagnostic {
+    fn lint(&self) -> Option<&'static str> {
+        self.kind.lint()
+    }
+
+    fn stable_location(&self) -> StableLocation {
+        self.stable_location
+    }
+
+    fn deny(&mut self) {
+        self.denied = true;
+    }
+}
+
+/// Returns the fix prefixing the name of an unused variable, declared at `stable_location`, with
+/// `_`.
+fn rename_unused_variable_fix(
+    db: &dyn SemanticGroup,
+    stable_location: StableLocation,
+) -> DiagnosticFix {
+    let syntax_db = db.upcast();
+    let mut node = stable_location.syntax_node(db.upcast());
+    // Unused parameters are reported on the whole parameter, including its modifiers and type.
+    if node.kind(syntax_db) == SyntaxKind::Param {
+        node = ast::Param::from_syntax_node(syntax_db, node).name(syntax_db).as_syntax_node();
+    }
+    let file_id = stable_location.file_id(db.upcast());
+    let location = DiagnosticLocation { file_id, span: node.span_without_trivia(syntax_db) };
+    let name = node.get_text_without_trivia(syntax_db);
+    let mut edits = vec![DiagnosticEdit::insert_before(&location, "_")];
+    // A struct pattern shorthand also names the bound member, so it is expanded to keep binding the
+    // same member, e.g. `A { mut a }` becomes `A { a: mut _a }`.
+    let shorthand = node.parent().filter(|pattern| {
+        pattern.kind(syntax_db) == SyntaxKind::PatternIdentifier
+            && pattern
+                .parent()
+                .is_some_and(|params| params.kind(syntax_db) == SyntaxKind::PatternStructParamList)
+    });
+    if let Some(pattern) = shorthand {
+        let pattern_location =
+            DiagnosticLocation { file_id, span: pattern.span_without_trivia(syntax_db) };
+        if pattern_location.span.start == location.span.start {
+            edits = vec![DiagnosticEdit::insert_before(&location, format!("{name}: _"))];
+        } else {
+            edits.insert(0, DiagnosticEdit::insert_before(&pattern_location, format!("{name}: ")));
+        }
+    }
+    DiagnosticFix {
+        message: format!("Rename to `_{name}`"),
+        applicability: Applicability::MachineApplicable,
+        edits,
+    }
+}
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum SemanticDiagnosticKind {
@@ -1161,6 +1248,10 @@ pub enum SemanticDiagnosticKind {
     PanicableFromNonPanicable,
     PanicableExternFunction,
     PluginDiagnostic(PluginDiagnostic),
+    LintPassDiagnostic {
+        lint: &'static Lint,
+        message: String,
+    },
     NameDefinedMultipleTimes(SmolStr),
     NamedArgumentsAreNotSupported,
     ArgPassedToNegativeImpl,
@@ -1205,6 +1296,8 @@ pub enum SemanticDiagnosticKind {
     UnsupportedImplicitPrecedenceArguments,
     UnsupportedFeatureAttrArguments,
     UnsupportedAllowAttrArguments,
+    UnsupportedWarnAttrArguments,
+    UnsupportedDenyAttrArguments,
     UnsupportedPubArgument,
     UnknownStatementAttribute,
     InlineMacroNotFound(SmolStr),
@@ -1250,16 +1343,216 @@ pub enum MultiArmExprKind {
 }
 
 impl SemanticDiagnosticKind {
-    pub fn error_code(&self) -> Option<ErrorCode> {
-        Some(match &self {
-            Self::UnusedVariable => error_code!(E0001),
-            Self::CannotCallMethod { .. } => {
-                error_code!(E0002)
-            }
-            Self::MissingMember(_) => error_code!(E0003),
+    /// Returns the name of the lint covering this diagnostic kind, if it is a warning.
+    pub fn lint(&self) -> Option<&'static str> {
+        Some(match self {
+            Self::UnusedVariable => lints::UNUSED_VARIABLES,
+            Self::UnhandledMustUseType { .. } | Self::UnhandledMustUseFunction => {
+                lints::UNUSED_MUST_USE
+            }
+            Self::TraitInTraitMustBeExplicit
+            | Self::ImplInImplMustBeExplicit
+            | Self::TraitItemForbiddenInTheTrait
+            | Self::TraitItemForbiddenInItsImpl
+            | Self::ImplItemForbiddenInTheImpl => lints::USE_SELF,
+            Self::UnstableFeature { .. } => lints::UNSTABLE_FEATURES,
+            Self::DeprecatedFeature { .. } => lints::DEPRECATED,
+            Self::UnusedImport(_) | Self::UnusedUse => lints::UNUSED_IMPORTS,
+            Self::CallingShadowedFunction { .. } => lints::SHADOWED_FUNCTION_CALLS,
+            Self::UnusedConstant => lints::UNUSED_CONSTANTS,
+            Self::PluginDiagnostic(diag) if diag.severity == Severity::Warning => {
+                lints::PLUGIN_WARNINGS
+            }
+            Self::LintPassDiagnostic { lint, .. } => lint.name,
             _ => return None,
         })
     }
+
+    /// Returns the stable error code of the diagnostic kind.
+    ///
+    /// Semantic diagnostics use the `E0xxx` codes. Codes are never reused, so new kinds must get
+    /// new codes.
+    pub fn error_code(&self) -> ErrorCode {
+        match self {
+            Self::ModuleFileNotFound(_) => error_code!(E0004),
+            Self::Unsupported => error_code!(E0005),
+            Self::UnknownLiteral => error_code!(E0006),
+            Self::UnknownBinaryOperator => error_code!(E0007),
+            Self::UnknownTrait => error_code!(E0008),
+            Self::UnknownImpl => error_code!(E0009),
+            Self::UnexpectedElement { .. } => error_code!(E0010),
+            Self::UnknownType => error_code!(E0011),
+            Self::UnknownEnum => error_code!(E0012),
+            Self::LiteralError(_) => error_code!(E0013),
+            Self::NotAVariant => error_code!(E0014),
+            Self::NotAStruct => error_code!(E0015),
+            Self::NotAType => error_code!(E0016),
+            Self::NotATrait => error_code!(E0017),
+            Self::NotAnImpl => error_code!(E0018),
+            Self::ImplItemNotInTrait { .. } => error_code!(E0019),
+            Self::ImplicitImplNotInferred { .. } => error_code!(E0020),
+            Self::GenericsNotSupportedInItem { .. } => error_code!(E0021),
+            Self::UnexpectedGenericArgs => error_code!(E0022),
+            Self::UnknownMember => error_code!(E0023),
+            Self::CannotCreateInstancesOfPhantomTypes => error_code!(E0024),
+            Self::NonPhantomTypeContainingPhantomType => error_code!(E0025),
+            Self::MemberSpecifiedMoreThanOnce => error_code!(E0026),
+            Self::StructBaseStructExpressionNotLast => error_code!(E0027),
+            Self::StructBaseStructExpressionNoEffect => error_code!(E0028),
+            Self::ConstCycle => error_code!(E0029),
+            Self::UseCycle => error_code!(E0030),
+            Self::TypeAliasCycle => error_code!(E0031),
+            Self::ImplAliasCycle => error_code!(E0032),
+            Self::ImplRequirementCycle => error_code!(E0033),
+            Self::MissingMember(_) => error_code!(E0003),
+            Self::WrongNumberOfParameters { .. } => error_code!(E0034),
+            Self::WrongNumberOfArguments { .. } => error_code!(E0035),
+            Self::WrongParameterType { .. } => error_code!(E0036),
+            Self::VariantCtorNotImmutable => error_code!(E0037),
+            Self::TraitParamMutable { .. } => error_code!(E0038),
+            Self::ParameterShouldBeReference { .. } => error_code!(E0039),
+            Self::ParameterShouldNotBeReference { .. } => error_code!(E0040),
+            Self::WrongParameterName { .. } => error_code!(E0041),
+            Self::WrongType { .. } => error_code!(E0042),
+            Self::InconsistentBinding => error_code!(E0043),
+            Self::WrongArgumentType { .. } => error_code!(E0044),
+            Self::WrongReturnType { .. } => error_code!(E0045),
+            Self::WrongExprType { .. } => error_code!(E0046),
+            Self::WrongNumberOfGenericParamsForImplFunction { .. } => error_code!(E0047),
+            Self::WrongReturnTypeForImpl { .. } => error_code!(E0048),
+            Self::AmbiguousTrait { .. } => error_code!(E0049),
+            Self::VariableNotFound(_) => error_code!(E0050),
+            Self::MissingVariableInPattern => error_code!(E0051),
+            Self::StructMemberRedefinition { .. } => error_code!(E0052),
+            Self::EnumVariantRedefinition { .. } => error_code!(E0053),
+            Self::InfiniteSizeType(_) => error_code!(E0054),
+            Self::ArrayOfZeroSizedElements(_) => error_code!(E0055),
+            Self::ParamNameRedefinition { .. } => error_code!(E0056),
+            Self::ConditionNotBool(_) => error_code!(E0057),
+            Self::IncompatibleArms { .. } => error_code!(E0058),
+            Self::LogicalOperatorNotAllowedInIfLet => error_code!(E0059),
+            Self::LogicalOperatorNotAllowedInWhileLet => error_code!(E0060),
+            Self::TypeHasNoMembers { .. } => error_code!(E0061),
+            Self::CannotCallMethod { .. } => error_code!(E0002),
+            Self::NoSuchStructMember { .. } => error_code!(E0062),
+            Self::NoSuchTypeMember { .. } => error_code!(E0063),
+            Self::MemberNotVisible(_) => error_code!(E0064),
+            Self::NoSuchVariant { .. } => error_code!(E0065),
+            Self::ReturnTypeNotErrorPropagateType => error_code!(E0066),
+            Self::IncompatibleErrorPropagateType { .. } => error_code!(E0067),
+            Self::ErrorPropagateOnNonErrorType(_) => error_code!(E0068),
+            Self::UnhandledMustUseType(_) => error_code!(E0069),
+            Self::UnstableFeature { .. } => error_code!(E0070),
+            Self::DeprecatedFeature { .. } => error_code!(E0071),
+            Self::InternalFeature { .. } => error_code!(E0072),
+            Self::FeatureMarkerDiagnostic(_) => error_code!(E0073),
+            Self::UnhandledMustUseFunction => error_code!(E0074),
+            Self::UnusedVariable => error_code!(E0001),
+            Self::UnusedConstant => error_code!(E0075),
+            Self::UnusedUse => error_code!(E0076),
+            Self::MultipleConstantDefinition(_) => error_code!(E0077),
+            Self::MultipleDefinitionforBinding(_) => error_code!(E0078),
+            Self::MultipleGenericItemDefinition(_) => error_code!(E0079),
+            Self::UnsupportedUseItemInStatement => error_code!(E0080),
+            Self::ConstGenericParamNotSupported => error_code!(E0081),
+            Self::NegativeImplsNotEnabled => error_code!(E0082),
+            Self::NegativeImplsOnlyOnImpls => error_code!(E0083),
+            Self::RefArgNotAVariable => error_code!(E0084),
+            Self::RefArgNotMutable => error_code!(E0085),
+            Self::RefArgNotExplicit => error_code!(E0086),
+            Self::ImmutableArgWithModifiers => error_code!(E0087),
+            Self::AssignmentToImmutableVar => error_code!(E0088),
+            Self::InvalidLhsForAssignment => error_code!(E0089),
+            Self::InvalidMemberExpression => error_code!(E0090),
+            Self::InvalidPath => error_code!(E0091),
+            Self::PathNotFound(_) => error_code!(E0092),
+            Self::TraitInTraitMustBeExplicit => error_code!(E0093),
+            Self::ImplInImplMustBeExplicit => error_code!(E0094),
+            Self::TraitItemForbiddenInTheTrait => error_code!(E0095),
+            Self::TraitItemForbiddenInItsImpl => error_code!(E0096),
+            Self::ImplItemForbiddenInTheImpl => error_code!(E0097),
+            Self::SuperUsedInRootModule => error_code!(E0098),
+            Self::ItemNotVisible(_) => error_code!(E0099),
+            Self::UnusedImport(_) => error_code!(E0100),
+            Self::RedundantModifier { .. } => error_code!(E0101),
+            Self::ReferenceLocalVariable => error_code!(E0102),
+            Self::UnexpectedEnumPattern(_) => error_code!(E0103),
+            Self::UnexpectedStructPattern(_) => error_code!(E0104),
+            Self::UnexpectedTuplePattern(_) => error_code!(E0105),
+            Self::UnexpectedFixedSizeArrayPattern(_) => error_code!(E0106),
+            Self::WrongNumberOfTupleElements { .. } => error_code!(E0107),
+            Self::WrongNumberOfFixedSizeArrayElements { .. } => error_code!(E0108),
+            Self::WrongEnum { .. } => error_code!(E0109),
+            Self::InvalidCopyTraitImpl(_) => error_code!(E0110),
+            Self::InvalidDropTraitImpl(_) => error_code!(E0111),
+            Self::InvalidImplItem(_) => error_code!(E0112),
+            Self::MissingItemsInImpl(_) => error_code!(E0113),
+            Self::PassPanicAsNopanic { .. } => error_code!(E0114),
+            Self::PanicableFromNonPanicable => error_code!(E0115),
+            Self::PanicableExternFunction => error_code!(E0116),
+            Self::PluginDiagnostic(diagnostic) => diagnostic.error_code,
+            Self::NameDefinedMultipleTimes(_) => error_code!(E0117),
+            Self::NamedArgumentsAreNotSupported => error_code!(E0118),
+            Self::ArgPassedToNegativeImpl => error_code!(E0119),
+            Self::UnnamedArgumentFollowsNamed => error_code!(E0120),
+            Self::NamedArgumentMismatch { .. } => error_code!(E0121),
+            Self::UnsupportedOutsideOfFunction(_) => error_code!(E0122),
+            Self::UnsupportedConstant => error_code!(E0123),
+            Self::DivisionByZero => error_code!(E0124),
+            Self::ExternTypeWithImplGenericsNotSupported => error_code!(E0125),
+            Self::MissingSemicolon => error_code!(E0126),
+            Self::TraitMismatch { .. } => error_code!(E0127),
+            Self::DesnapNonSnapshot => error_code!(E0128),
+            Self::InternalInferenceError(_) => error_code!(E0129),
+            Self::NoImplementationOfIndexOperator { .. } => error_code!(E0130),
+            Self::NoImplementationOfTrait { .. } => error_code!(E0131),
+            Self::MultipleImplementationOfIndexOperator(_) => error_code!(E0132),
+            Self::UnsupportedInlineArguments => error_code!(E0133),
+            Self::RedundantInlineAttribute => error_code!(E0134),
+            Self::InlineAttrForExternFunctionNotAllowed => error_code!(E0135),
+            Self::InlineAlwaysWithImplGenericArgNotAllowed => error_code!(E0136),
+            Self::TailExpressionNotAllowedInLoop => error_code!(E0137),
+            Self::ContinueOnlyAllowedInsideALoop => error_code!(E0138),
+            Self::BreakOnlyAllowedInsideALoop => error_code!(E0139),
+            Self::BreakWithValueOnlyAllowedInsideALoop => error_code!(E0140),
+            Self::ReturnNotAllowedInsideALoop => error_code!(E0141),
+            Self::ErrorPropagateNotAllowedInsideALoop => error_code!(E0142),
+            Self::ImplicitPrecedenceAttrForExternFunctionNotAllowed => error_code!(E0143),
+            Self::RedundantImplicitPrecedenceAttribute => error_code!(E0144),
+            Self::UnsupportedImplicitPrecedenceArguments => error_code!(E0145),
+            Self::UnsupportedFeatureAttrArguments => error_code!(E0146),
+            Self::UnsupportedAllowAttrArguments => error_code!(E0147),
+            Self::UnsupportedPubArgument => error_code!(E0148),
+            Self::UnknownStatementAttribute => error_code!(E0149),
+            Self::InlineMacroNotFound(_) => error_code!(E0150),
+            Self::InlineMacroFailed(_) => error_code!(E0151),
+            Self::UnknownGenericParam(_) => error_code!(E0152),
+            Self::PositionalGenericAfterNamed => error_code!(E0153),
+            Self::GenericArgDuplicate(_) => error_code!(E0154),
+            Self::TooManyGenericArguments { .. } => error_code!(E0155),
+            Self::GenericArgOutOfOrder(_) => error_code!(E0156),
+            Self::CouponForExternFunctionNotAllowed => error_code!(E0157),
+            Self::CouponArgumentNoModifiers => error_code!(E0158),
+            Self::CouponsDisabled => error_code!(E0159),
+            Self::FixedSizeArrayTypeNonSingleType => error_code!(E0160),
+            Self::FixedSizeArrayTypeEmptySize => error_code!(E0161),
+            Self::FixedSizeArrayNonNumericSize => error_code!(E0162),
+            Self::FixedSizeArrayNonSingleValue => error_code!(E0163),
+            Self::FixedSizeArraySizeTooBig => error_code!(E0164),
+            Self::SelfNotSupportedInContext => error_code!(E0165),
+            Self::SelfMustBeFirst => error_code!(E0166),
+            Self::DerefCycle { .. } => error_code!(E0167),
+            Self::TypeEqualTraitReImplementation => error_code!(E0168),
+            Self::ClosureInGlobalScope => error_code!(E0169),
+            Self::MaybeMissingColonColon => error_code!(E0170),
+            Self::CallingShadowedFunction { .. } => error_code!(E0171),
+            Self::RefClosureArgument => error_code!(E0172),
+            Self::MutableCapturedVariable => error_code!(E0173),
+            Self::UnsupportedWarnAttrArguments => error_code!(E0174),
+            Self::UnsupportedDenyAttrArguments => error_code!(E0175),
+            Self::LintPassDiagnostic { .. } => error_code!(E0176),
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -10,6 +10,7 @@ use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::helpers::QueryAttrs;
 use cairo_lang_syntax::node::{TypedStablePtr, ast};
 use indoc::indoc;
+use itertools::Itertools;
 use pretty_assertions::assert_eq;
 use test_log::test;
 
@@ -54,7 +55,7 @@ fn test_missing_module_file() {
     assert_eq!(
         db.module_semantic_diagnostics(ModuleId::Submodule(submodule_id)).unwrap().format(db),
         indoc! {"
-            error: Module file not found. Expected path: abc.cairo
+            error[E0004]: Module file not found. Expected path: abc.cairo
              --> lib.cairo:3:9
                     mod abc;
                     ^******^
@@ -150,12 +151,12 @@ fn test_inline_module_diagnostics() {
 
     // Verify we get diagnostics both for the original and the generated code.
     assert_eq!(get_crate_semantic_diagnostics(db, crate_id).format(db), indoc! {r#"
-            error: Unexpected return type. Expected: "core::integer::u128", found: "core::felt252".
+            error[E0045]: Unexpected return type. Expected: "core::integer::u128", found: "core::felt252".
              --> lib.cairo:4:16
                     return 5_felt252;
                            ^*******^
 
-            error: Unexpected return type. Expected: "test::a::inner_mod::NewType", found: "core::felt252".
+            error[E0045]: Unexpected return type. Expected: "test::a::inner_mod::NewType", found: "core::felt252".
              --> lib.cairo:4:16
                     return 5_felt252;
                            ^*******^
@@ -191,12 +192,12 @@ fn test_inline_inline_module_diagnostics() {
 
     assert_eq!(
         get_crate_semantic_diagnostics(db, crate_id).format(db),
-        indoc! {r#"error: Unexpected return type. Expected: "core::integer::u128", found: "core::felt252".
+        indoc! {r#"error[E0045]: Unexpected return type. Expected: "core::integer::u128", found: "core::felt252".
              --> lib.cairo:3:16
                     return 1_felt252;
                            ^*******^
 
-            error: Unexpected return type. Expected: "core::integer::u128", found: "core::felt252".
+            error[E0045]: Unexpected return type. Expected: "core::integer::u128", found: "core::felt252".
              --> lib.cairo:9:20
                         return 2_felt252;
                                ^*******^
@@ -256,30 +257,88 @@ fn test_analyzer_diagnostics() {
        "});
 
     assert_eq!(get_crate_semantic_diagnostics(db, crate_id).format(db), indoc! {r#"
-        error: Plugin diagnostic: Use items for u128 disallowed.
+        error[E3001]: Plugin diagnostic: Use items for u128 disallowed.
          --> lib.cairo:7:20
         use core::integer::u128 as long_u128_rename;
                            ^**********************^
 
-        error: Plugin diagnostic: Use items for u128 disallowed.
+        error[E3001]: Plugin diagnostic: Use items for u128 disallowed.
          --> lib.cairo:8:5
         use u128 as short_u128_rename;
             ^***********************^
 
-        error: Plugin diagnostic: Use items for u128 disallowed.
+        error[E3001]: Plugin diagnostic: Use items for u128 disallowed.
          --> lib.cairo:9:12
         use inner::long_u128_rename as additional_u128_rename;
                    ^****************************************^
 
-        error: Plugin diagnostic: Use items for u128 disallowed.
+        error[E3001]: Plugin diagnostic: Use items for u128 disallowed.
          --> lib.cairo:2:24
             use core::integer::u128 as long_u128_rename;
                                ^**********************^
 
-        error: Plugin diagnostic: Use items for u128 disallowed.
+        error[E3001]: Plugin diagnostic: Use items for u128 disallowed.
          --> lib.cairo:3:9
             use u128 as short_u128_rename;
                 ^***********************^
 
     "#},);
 }
+
+#[test]
+fn test_diagnostic_fixes() {
+    let db_val = SemanticDatabaseForTesting::default();
+    let db = &db_val;
+    let crate_id = setup_test_crate(db, indoc! {"
+        struct A { a: felt252, b: felt252 }
+        fn foo(mut x: felt252) -> A {
+            let y = 1;
+            let A { a, mut b } = A { a: 1, b: 2 };
+            A { a: 0 }
+        }
+        trait ATrait<T> {
+            fn some_method(self: @T);
+        }
+        impl ATraitImpl of ATrait<felt252> {
+            fn some_method(self: @felt252) {}
+        }
+        mod inner {
+            fn bar() {
+                5_felt252.some_method();
+            }
+        }
+    "});
+
+    let edits = get_crate_semantic_diagnostics(db, crate_id)
+        .format_structured(db)
+        .into_iter()
+        .flat_map(|diagnostic| diagnostic.fixes)
+        .flat_map(|fix| {
+            fix.edits.into_iter().map(move |edit| {
+                let span = edit.span;
+                (
+                    (span.line_start, span.column_start),
+                    format!(
+                        "{}:{}: {} ({:?}): {:?}",
+                        span.line_start,
+                        span.column_start,
+                        fix.message,
+                        fix.applicability,
+                        edit.replacement
+                    ),
+                )
+            })
+        })
+        .sorted()
+        .map(|(_, edit)| edit)
+        .collect_vec();
+    assert_eq!(edits, [
+        r#"2:12: Rename to `_x` (MachineApplicable): "_""#,
+        r#"3:9: Rename to `_y` (MachineApplicable): "_""#,
+        r#"4:13: Rename to `_a` (MachineApplicable): "a: _""#,
+        r#"4:16: Rename to `_b` (MachineApplicable): "b: ""#,
+        r#"4:20: Rename to `_b` (MachineApplicable): "_""#,
+        r#"5:13: Fill struct fields (MaybeIncorrect): ", b: ()""#,
+        r#"14:5: Import super::ATrait (MaybeIncorrect): "use super::ATrait;\n""#,
+    ]);
+}
@@ -12,13 +12,17 @@ use cairo_lang_defs::db::{get_all_path_leaves, validate_attributes_flat};
 use cairo_lang_defs::diagnostic_utils::StableLocation;
 use cairo_lang_defs::ids::{
     EnumId, FunctionTitleId, GenericKind, LanguageElementId, LocalVarLongId, LookupItemId,
-    MemberId, ModuleItemId, NamedLanguageElementId, StatementConstLongId, StatementItemId,
-    StatementUseLongId, TraitFunctionId, TraitId, VarId,
+    MemberId, ModuleId, ModuleItemId, NamedLanguageElementId, StatementConstLongId,
+    StatementItemId, StatementUseLongId, TraitFunctionId, TraitId, VarId,
 };
 use cairo_lang_defs::plugin::MacroPluginMetadata;
-use cairo_lang_diagnostics::{Maybe, ToOption, skip_diagnostic};
+use cairo_lang_diagnostics::{
+    Applicability, DiagnosticEdit, DiagnosticFix, DiagnosticLocation, Maybe, ToOption,
+    skip_diagnostic,
+};
 use cairo_lang_filesystem::cfg::CfgSet;
 use cairo_lang_filesystem::ids::{FileKind, FileLongId, VirtualFile};
+use cairo_lang_filesystem::span::TextSpan;
 use cairo_lang_proc_macros::DebugWithDb;
 use cairo_lang_syntax::node::ast::{
     BinaryOperator, BlockOrIf, ClosureParamWrapper, ExprPtr, OptionReturnTypeClause, PatternListOr,
@@ -59,7 +63,7 @@ use crate::corelib::{
 use crate::db::SemanticGroup;
 use crate::diagnostic::SemanticDiagnosticKind::{self, *};
 use crate::diagnostic::{
-    ElementKind, MultiArmExprKind, NotFoundItemType, SemanticDiagnostics,
+    ElementKind, MultiArmExprKind, NotFoundItemType, SemanticDiagnostic, SemanticDiagnostics,
     SemanticDiagnosticsBuilder, TraitInferenceErrors, UnsupportedOutsideOfFunctionFeatureName,
 };
 use crate::items::constant::{ConstValue, resolve_const_expr_and_evaluate};
@@ -71,6 +75,7 @@ use crate::items::modifiers::compute_mutability;
 use crate::items::us::get_use_path_segments;
 use crate::items::visibility;
 use crate::literals::try_extract_minus_literal;
+use crate::lsp_helpers::find_methods_for_type;
 use crate::resolve::{
     EnrichedMembers, EnrichedTypeMemberAccess, ResolvedConcreteItem, ResolvedGenericItem, Resolver,
 };
@@ -1936,10 +1941,22 @@ fn compute_method_function_call_data(
     );
     let trait_function_id = match candidates[..] {
         [] => {
-            return Err(no_implementation_diagnostic(self_ty, func_name, TraitInferenceErrors {
-                traits_and_errors: inference_errors,
+            return Err(no_implementation_diagnostic(
+                self_ty,
+                func_name.clone(),
+                TraitInferenceErrors { traits_and_errors: inference_errors },
+            )
+            .map(|diag| {
+                let fixes = if matches!(diag, CannotCallMethod { .. }) {
+                    import_trait_fixes(ctx, candidate_traits, self_ty, &func_name, &self_expr)
+                } else {
+                    vec![]
+                };
+                ctx.diagnostics.add(
+                    SemanticDiagnostic::new(StableLocation::new(method_syntax), diag)
+                        .with_fixes(fixes),
+                )
             })
-            .map(|diag| ctx.diagnostics.report(method_syntax, diag))
             .unwrap_or_else(skip_diagnostic));
         }
         [trait_function_id] => trait_function_id,
@@ -2528,18 +2545,29 @@ fn struct_ctor_expr(
     }
 
     // Report errors for missing members.
-    for (member_name, member) in members.iter() {
-        if !member_exprs.contains_key(&member.id) {
-            if base_struct.is_some() {
-                check_struct_member_is_visible(
-                    ctx,
-                    member,
-                    base_struct.clone().unwrap().1.stable_ptr().untyped(),
-                    member_name,
-                );
-            } else {
-                ctx.diagnostics.report(ctor_syntax, MissingMember(member_name.clone()));
-            }
+    let missing_members =
+        members.iter().filter(|(_, member)| !member_exprs.contains_key(&member.id)).collect_vec();
+    let fill_members_fix = if base_struct.is_none() {
+        fill_struct_members_fix(ctx, ctor_syntax, &missing_members)
+    } else {
+        None
+    };
+    for (member_name, member) in missing_members {
+        if base_struct.is_some() {
+            check_struct_member_is_visible(
+                ctx,
+                member,
+                base_struct.clone().unwrap().1.stable_ptr().untyped(),
+                member_name,
+            );
+        } else {
+            ctx.diagnostics.add(
+                SemanticDiagnostic::new(
+                    StableLocation::from_ast(ctor_syntax),
+                    MissingMember(member_name.clone()),
+                )
+                .with_fixes(fill_members_fix.iter().cloned().collect()),
+            );
         }
     }
     if members.len() == member_exprs.len() {
@@ -3735,15 +3763,108 @@ fn check_struct_member_is_visible(
     stable_ptr: SyntaxStablePtrId,
     member_name: &SmolStr,
 ) {
+    if !is_struct_member_visible(ctx, member) {
+        ctx.diagnostics.report(stable_ptr, MemberNotVisible(member_name.clone()));
+    }
+}
+
+/// Returns whether the struct member is visible in the current context.
+fn is_struct_member_visible(ctx: &ComputationContext<'_>, member: &Member) -> bool {
     let db = ctx.db.upcast();
     let containing_module_id = member.id.parent_module(db);
     if ctx.resolver.ignore_visibility_checks(containing_module_id) {
-        return;
+        return true;
     }
     let user_module_id = ctx.resolver.module_file_id.0;
-    if !visibility::peek_visible_in(db, member.visibility, containing_module_id, user_module_id) {
-        ctx.diagnostics.report(stable_ptr, MemberNotVisible(member_name.clone()));
+    visibility::peek_visible_in(db, member.visibility, containing_module_id, user_module_id)
+}
+
+/// Returns a fix adding the visible missing members to a struct constructor, with a unit
+/// placeholder as their value. Returns `None` if none of them is visible.
+fn fill_struct_members_fix(
+    ctx: &ComputationContext<'_>,
+    ctor_syntax: &ast::ExprStructCtorCall,
+    missing_members: &[(&SmolStr, &Member)],
+) -> Option<DiagnosticFix> {
+    let syntax_db = ctx.db.upcast();
+    let members_code = missing_members
+        .iter()
+        .filter(|(_, member)| is_struct_member_visible(ctx, member))
+        .map(|(member_name, _)| format!("{member_name}: ()"))
+        .join(", ");
+    if members_code.is_empty() {
+        return None;
+    }
+    // Insert after the last argument or separator, or after the opening brace if there are none.
+    let arguments = ctor_syntax.arguments(syntax_db);
+    let argument_list = arguments.arguments(syntax_db);
+    let prefix = if argument_list.has_tail(syntax_db) { ", " } else { " " };
+    let insert_after = syntax_db
+        .get_children(argument_list.as_syntax_node())
+        .last()
+        .cloned()
+        .unwrap_or_else(|| arguments.lbrace(syntax_db).as_syntax_node());
+    let location =
+        StableLocation::new(insert_after.stable_ptr()).diagnostic_location(ctx.db.upcast());
+    Some(DiagnosticFix {
+        message: "Fill struct fields".into(),
+        applicability: Applicability::MaybeIncorrect,
+        edits: vec![DiagnosticEdit {
+            location: location.after(),
+            replacement: format!("{prefix}{members_code}"),
+        }],
+    })
+}
+
+/// Returns fixes importing the traits not in context that have a method `method_name` callable on
+/// `self_expr`, of type `self_ty`.
+fn import_trait_fixes(
+    ctx: &mut ComputationContext<'_>,
+    candidate_traits: &[TraitId],
+    self_ty: TypeId,
+    method_name: &str,
+    self_expr: &ExprAndId,
+) -> Vec<DiagnosticFix> {
+    let module_id = ctx.resolver.module_file_id.0;
+    let Some(visible_traits) = ctx.db.visible_traits_from_module(module_id) else {
+        return vec![];
+    };
+    let Some(location) = module_start_location(ctx.db, module_id) else {
+        return vec![];
+    };
+    let db = ctx.db;
+    find_methods_for_type(db, &mut ctx.resolver, self_ty, self_expr.stable_ptr().untyped())
+        .into_iter()
+        .filter(|trait_function| trait_function.name(db.upcast()) == method_name)
+        .map(|trait_function| trait_function.trait_id(db.upcast()))
+        .filter(|trait_id| !candidate_traits.contains(trait_id))
+        .filter_map(|trait_id| visible_traits.get(&trait_id))
+        .unique()
+        .map(|trait_path| DiagnosticFix {
+            message: format!("Import {trait_path}"),
+            applicability: Applicability::MaybeIncorrect,
+            edits: vec![DiagnosticEdit::insert_before(&location, format!("use {trait_path};\n"))],
+        })
+        .collect()
+}
+
+/// Returns the location of the start of the items of a module, where `use` items are added.
+fn module_start_location(
+    db: &dyn SemanticGroup,
+    module_id: ModuleId,
+) -> Option<DiagnosticLocation> {
+    if let ModuleId::Submodule(submodule_id) = module_id {
+        let syntax_db = db.upcast();
+        let module_syntax = submodule_id.stable_ptr(db.upcast()).lookup(syntax_db);
+        if let ast::MaybeModuleBody::Some(body) = module_syntax.body(syntax_db) {
+            return Some(
+                StableLocation::new(body.items(syntax_db).stable_ptr().untyped())
+                    .diagnostic_location(db.upcast()),
+            );
+        }
     }
+    let file_id = db.module_main_file(module_id).ok()?;
+    Some(DiagnosticLocation { file_id, span: TextSpan::default() })
 }
 
 /// Verifies that the statement attributes are valid statements attributes, if not a diagnostic is
@@ -6,6 +6,7 @@ use cairo_lang_defs::plugin::{
 use cairo_lang_defs::plugin_utils::{
     escape_node, try_extract_unnamed_arg, unsupported_bracket_diagnostic,
 };
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_syntax::node::ast::WrappedArgList;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode, ast};
@@ -31,19 +32,28 @@ impl InlineMacroExprPlugin for AssertMacro {
         let Some((value, format_args)) = arguments.split_first() else {
             return InlinePluginResult {
                 code: None,
-                diagnostics: vec![PluginDiagnostic::error(
-                    arguments_syntax.lparen(db).stable_ptr().untyped(),
-                    format!("Macro `{}` requires at least 1 argument.", Self::NAME),
-                )],
+                diagnostics: vec![
+                    PluginDiagnostic::error(
+                        arguments_syntax.lparen(db).stable_ptr().untyped(),
+                        format!("Macro `{}` requires at least 1 argument.", Self::NAME),
+                    )
+                    .with_error_code(error_code!(E3005)),
+                ],
             };
         };
         let Some(value) = try_extract_unnamed_arg(db, value) else {
             return InlinePluginResult {
                 code: None,
-                diagnostics: vec![PluginDiagnostic::error(
-                    value.stable_ptr().untyped(),
-                    format!("Macro `{}` requires the first argument to be unnamed.", Self::NAME),
-                )],
+                diagnostics: vec![
+                    PluginDiagnostic::error(
+                        value.stable_ptr().untyped(),
+                        format!(
+                            "Macro `{}` requires the first argument to be unnamed.",
+                            Self::NAME
+                        ),
+                    )
+                    .with_error_code(error_code!(E3005)),
+                ],
             };
         };
         let f = "__formatter_for_assert_macro__";
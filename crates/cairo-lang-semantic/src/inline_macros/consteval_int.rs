@@ -3,6 +3,7 @@ use cairo_lang_defs::plugin::{
     InlineMacroExprPlugin, InlinePluginResult, MacroPluginMetadata, NamedPlugin, PluginDiagnostic,
     PluginGeneratedFile,
 };
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_filesystem::ids::{CodeMapping, CodeOrigin};
 use cairo_lang_filesystem::span::{TextOffset, TextSpan, TextWidth};
 use cairo_lang_syntax::node::db::SyntaxGroup;
@@ -31,15 +32,18 @@ impl InlineMacroExprPlugin for ConstevalIntMacro {
         let mut diagnostics = vec![];
         const DEPRECATION_FEATURE: &str = r#""deprecated-consteval-int-macro""#;
         if !metadata.allowed_features.contains(DEPRECATION_FEATURE) {
-            diagnostics.push(PluginDiagnostic::warning(
-                syntax.stable_ptr().untyped(),
-                format!(
-                    "Usage of deprecated macro `{}` with no `#[feature({DEPRECATION_FEATURE})]` \
-                     attribute. Note: Use simple calculations instead, as these are supported in \
-                     const context.",
-                    Self::NAME
-                ),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::warning(
+                    syntax.stable_ptr().untyped(),
+                    format!(
+                        "Usage of deprecated macro `{}` with no \
+                         `#[feature({DEPRECATION_FEATURE})]` attribute. Note: Use simple \
+                         calculations instead, as these are supported in const context.",
+                        Self::NAME
+                    ),
+                )
+                .with_error_code(error_code!(E3005)),
+            );
         }
         let code = compute_constant_expr(db, &constant_expression, &mut diagnostics);
         InlinePluginResult {
@@ -132,10 +136,13 @@ pub fn compute_constant_expr(
                     ^ compute_constant_expr(db, &bin_expr.rhs(db), diagnostics)?,
             ),
             _ => {
-                diagnostics.push(PluginDiagnostic::error(
-                    bin_expr.stable_ptr().untyped(),
-                    "Unsupported binary operator in consteval_int macro".to_string(),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        bin_expr.stable_ptr().untyped(),
+                        "Unsupported binary operator in consteval_int macro".to_string(),
+                    )
+                    .with_error_code(error_code!(E3005)),
+                );
                 None
             }
         },
@@ -144,10 +151,13 @@ pub fn compute_constant_expr(
                 Some(-compute_constant_expr(db, &un_expr.expr(db), diagnostics)?)
             }
             _ => {
-                diagnostics.push(PluginDiagnostic::error(
-                    un_expr.stable_ptr().untyped(),
-                    "Unsupported unary operator in consteval_int macro".to_string(),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        un_expr.stable_ptr().untyped(),
+                        "Unsupported unary operator in consteval_int macro".to_string(),
+                    )
+                    .with_error_code(error_code!(E3005)),
+                );
                 None
             }
         },
@@ -155,10 +165,13 @@ pub fn compute_constant_expr(
             compute_constant_expr(db, &paren_expr.expr(db), diagnostics)
         }
         _ => {
-            diagnostics.push(PluginDiagnostic::error(
-                value.stable_ptr().untyped(),
-                "Unsupported expression in consteval_int macro".to_string(),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    value.stable_ptr().untyped(),
+                    "Unsupported expression in consteval_int macro".to_string(),
+                )
+                .with_error_code(error_code!(E3005)),
+            );
             None
         }
     }
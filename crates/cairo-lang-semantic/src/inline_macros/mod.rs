@@ -15,6 +15,7 @@ use self::print::{PrintMacro, PrintlnMacro};
 use self::write::{WriteMacro, WritelnMacro};
 use super::inline_macros::array::ArrayMacro;
 use super::inline_macros::consteval_int::ConstevalIntMacro;
+use crate::lint_passes::felt252_arithmetic::Felt252ArithmeticLintPass;
 use crate::plugin::PluginSuite;
 
 /// Gets the default plugin suite to load into the Cairo compiler.
@@ -29,6 +30,7 @@ pub fn get_default_plugin_suite() -> PluginSuite {
         .add_inline_macro_plugin::<PrintMacro>()
         .add_inline_macro_plugin::<PrintlnMacro>()
         .add_inline_macro_plugin::<WriteMacro>()
-        .add_inline_macro_plugin::<WritelnMacro>();
+        .add_inline_macro_plugin::<WritelnMacro>()
+        .add_lint_pass::<Felt252ArithmeticLintPass>();
     suite
 }
@@ -27,12 +27,12 @@ fn test_enum() {
         "})
     .split();
     assert_eq!(diagnostics, indoc! {r#"
-        error: Redefinition of variant "a" on enum "test::A".
+        error[E0053]: Redefinition of variant "a" on enum "test::A".
          --> lib.cairo:5:5
             a: (),
             ^***^
 
-        error: Redefinition of variant "a" on enum "test::A".
+        error[E0053]: Redefinition of variant "a" on enum "test::A".
          --> lib.cairo:6:5
             a: ()
             ^***^
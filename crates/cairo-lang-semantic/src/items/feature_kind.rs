@@ -2,7 +2,7 @@ use cairo_lang_defs::diagnostic_utils::StableLocation;
 use cairo_lang_defs::ids::{LanguageElementId, ModuleId};
 use cairo_lang_diagnostics::DiagnosticsBuilder;
 use cairo_lang_syntax::attribute::consts::{
-    ALLOW_ATTR, DEPRECATED_ATTR, FEATURE_ATTR, INTERNAL_ATTR, UNSTABLE_ATTR,
+    ALLOW_ATTR, DENY_ATTR, DEPRECATED_ATTR, FEATURE_ATTR, INTERNAL_ATTR, UNSTABLE_ATTR, WARN_ATTR,
 };
 use cairo_lang_syntax::attribute::structured::{
     self, AttributeArg, AttributeArgVariant, AttributeStructurize,
@@ -17,6 +17,7 @@ use smol_str::SmolStr;
 use crate::SemanticDiagnostic;
 use crate::db::SemanticGroup;
 use crate::diagnostic::{SemanticDiagnosticKind, SemanticDiagnostics, SemanticDiagnosticsBuilder};
+use crate::lints::is_known_lint;
 
 /// The kind of a feature for an item.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -212,9 +213,25 @@ pub fn extract_item_feature_config(
                 config.allow_unused_imports = true;
                 true
             }
-            other => db.declared_allows().contains(other),
+            other => is_known_lint(db, other) || db.declared_allows().contains(other),
         },
     );
+    for (attr, diagnostic_kind) in [
+        (WARN_ATTR, SemanticDiagnosticKind::UnsupportedWarnAttrArguments),
+        (DENY_ATTR, SemanticDiagnosticKind::UnsupportedDenyAttrArguments),
+    ] {
+        process_feature_attr_kind(
+            syntax_db,
+            syntax,
+            attr,
+            || diagnostic_kind.clone(),
+            diagnostics,
+            |value| {
+                let lint = value.as_syntax_node().get_text_without_trivia(syntax_db);
+                is_known_lint(db, &lint)
+            },
+        );
+    }
     config
 }
 
@@ -28,12 +28,12 @@ fn test_struct() {
         "})
     .split();
     assert_eq!(diagnostics, indoc! {r#"
-        error: Redefinition of member "a" on struct "test::A".
+        error[E0052]: Redefinition of member "a" on struct "test::A".
          --> lib.cairo:6:5
             a: (),
             ^***^
 
-        error: Redefinition of member "a" on struct "test::A".
+        error[E0052]: Redefinition of member "a" on struct "test::A".
          --> lib.cairo:7:5
             a: ()
             ^***^
@@ -8,6 +8,8 @@ cairo_lang_test_utils::test_file_test!(
         extern_func: "extern_func",
         free_function: "free_function",
         impl_alias: "impl_alias",
+        lint_passes: "lint_passes",
+        lints: "lints",
         panicable: "panicable",
         struct_: "struct",
         trait_: "trait",
@@ -8,6 +8,8 @@ pub mod diagnostic;
 pub mod expr;
 pub mod inline_macros;
 pub mod items;
+pub mod lint_passes;
+pub mod lints;
 pub mod literals;
 pub mod lookup_item;
 pub mod lsp_helpers;
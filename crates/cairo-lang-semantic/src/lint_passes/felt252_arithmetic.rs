@@ -0,0 +1,55 @@
+use cairo_lang_filesystem::db::LintLevel;
+use cairo_lang_syntax::node::{TypedStablePtr, ast};
+
+use super::{Lint, LintContext, LintPass};
+use crate::{Expr, ExprFunctionCallArg, ExprId};
+
+/// Arithmetic operators applied to `felt252` values.
+pub static FELT252_ARITHMETIC: Lint = Lint {
+    name: "felt252_arithmetic",
+    default_level: LintLevel::Allow,
+    description: "Arithmetic on `felt252` values, which silently wraps around the field prime.",
+};
+
+/// Lint pass reporting [FELT252_ARITHMETIC].
+///
+/// Allowed by default, as `felt252` arithmetic is common in low level code, and usually intended.
+#[derive(Debug, Default)]
+pub struct Felt252ArithmeticLintPass;
+
+impl LintPass for Felt252ArithmeticLintPass {
+    fn lints(&self) -> Vec<&'static Lint> {
+        vec![&FELT252_ARITHMETIC]
+    }
+
+    fn check_expr(&self, cx: &mut LintContext<'_>, _expr_id: ExprId, expr: &Expr) {
+        let Expr::FunctionCall(call) = expr else {
+            return;
+        };
+        let syntax_db = cx.db.upcast();
+        let ast::Expr::Binary(binary) = call.stable_ptr.lookup(syntax_db) else {
+            return;
+        };
+        let op = match binary.op(syntax_db) {
+            ast::BinaryOperator::Plus(_) | ast::BinaryOperator::PlusEq(_) => "+",
+            ast::BinaryOperator::Minus(_) | ast::BinaryOperator::MinusEq(_) => "-",
+            ast::BinaryOperator::Mul(_) | ast::BinaryOperator::MulEq(_) => "*",
+            _ => return,
+        };
+        let lhs_ty = match call.args.first() {
+            Some(ExprFunctionCallArg::Value(expr_id)) => cx.body.arenas.exprs[*expr_id].ty(),
+            Some(ExprFunctionCallArg::Reference(member_path)) => member_path.ty(),
+            None => return,
+        };
+        if lhs_ty == cx.db.core_felt252_ty() {
+            cx.report(
+                call.stable_ptr,
+                &FELT252_ARITHMETIC,
+                format!(
+                    "`{op}` on `felt252` values wraps around the field prime instead of \
+                     overflowing. Consider using an integer type."
+                ),
+            );
+        }
+    }
+}
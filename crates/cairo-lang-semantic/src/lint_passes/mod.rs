@@ -0,0 +1,247 @@
+//! Lint passes: plugins checking function bodies for patterns worth a warning.
+//!
+//! A lint pass is registered in a [crate::plugin::PluginSuite], declares the [Lint]s it reports,
+//! and is given every expression and statement of the bodies of the functions of the user code.
+//! The lints it declares are named lint levels, which may be set with the `#[allow(...)]`,
+//! `#[warn(...)]` and `#[deny(...)]` attributes like the lints of the compiler.
+//!
+//! Lint passes of later compilation phases report [Lint]s as well, and declare them through
+//! [SemanticGroup::external_lints].
+
+use std::sync::Arc;
+
+use cairo_lang_defs::ids::FunctionWithBodyId;
+use cairo_lang_diagnostics::Maybe;
+use cairo_lang_filesystem::db::LintLevel;
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+
+use crate::db::SemanticGroup;
+use crate::{
+    Condition, Expr, ExprFunctionCallArg, ExprId, FixedSizeArrayItems, FunctionBody, Statement,
+};
+
+pub mod felt252_arithmetic;
+
+/// A named class of warnings reported by a lint pass.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Lint {
+    /// The name of the lint, used in the lint attributes and in the `lints` crate settings.
+    pub name: &'static str,
+    /// The level of the lint, unless set otherwise.
+    pub default_level: LintLevel,
+    /// A short description of what the lint reports.
+    pub description: &'static str,
+}
+
+/// A trait for a lint pass: a plugin that reports lints on the bodies of functions.
+pub trait LintPass: std::fmt::Debug + Sync + Send {
+    /// The lints this pass may report.
+    ///
+    /// Lints reported by the pass must be declared here, so their names are accepted by the lint
+    /// attributes.
+    fn lints(&self) -> Vec<&'static Lint>;
+    /// Checks an expression of a function body.
+    ///
+    /// Expressions are visited before the expressions they contain, which are then available in
+    /// [LintContext::ancestors].
+    fn check_expr(&self, _cx: &mut LintContext<'_>, _expr_id: ExprId, _expr: &Expr) {}
+    /// Checks a statement of a function body.
+    fn check_statement(&self, _cx: &mut LintContext<'_>, _statement: &Statement) {}
+}
+
+/// A lint reported by a lint pass.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LintReport {
+    /// The reported lint.
+    pub lint: &'static Lint,
+    /// The location of the report.
+    pub stable_ptr: SyntaxStablePtrId,
+    /// The message of the report.
+    pub message: String,
+}
+
+/// The context of a lint pass checking a function body.
+pub struct LintContext<'a> {
+    /// The semantic database.
+    pub db: &'a dyn SemanticGroup,
+    /// The checked function.
+    pub function_id: FunctionWithBodyId,
+    /// The semantic body of the checked function.
+    pub body: &'a FunctionBody,
+    /// The expressions enclosing the currently checked one.
+    ancestors: Vec<ExprId>,
+    /// The lints reported so far.
+    reports: Vec<LintReport>,
+}
+impl<'a> LintContext<'a> {
+    /// Creates a context for checking the body of `function_id`.
+    pub fn new(
+        db: &'a dyn SemanticGroup,
+        function_id: FunctionWithBodyId,
+        body: &'a FunctionBody,
+    ) -> Self {
+        Self { db, function_id, body, ancestors: vec![], reports: vec![] }
+    }
+
+    /// Returns the expressions enclosing the currently checked expression or statement, outermost
+    /// first.
+    pub fn ancestors(&self) -> &[ExprId] {
+        &self.ancestors
+    }
+
+    /// Reports `lint` at `stable_ptr`.
+    pub fn report(
+        &mut self,
+        stable_ptr: impl Into<SyntaxStablePtrId>,
+        lint: &'static Lint,
+        message: impl Into<String>,
+    ) {
+        self.reports.push(LintReport {
+            lint,
+            stable_ptr: stable_ptr.into(),
+            message: message.into(),
+        });
+    }
+
+    /// Returns the lints reported in this context.
+    pub fn into_reports(self) -> Vec<LintReport> {
+        self.reports
+    }
+}
+
+/// Runs the expression and statement checks of the lint passes on the body of `function_id`.
+pub fn check_function_body(
+    db: &dyn SemanticGroup,
+    function_id: FunctionWithBodyId,
+) -> Maybe<Vec<LintReport>> {
+    let lint_passes = db.lint_passes();
+    if lint_passes.is_empty() {
+        return Ok(vec![]);
+    }
+    let body = db.function_body(function_id)?;
+    let mut cx = LintContext::new(db, function_id, &body);
+    let mut walker = BodyWalker { lint_passes: &lint_passes, cx: &mut cx };
+    walker.walk_expr(body.body_expr);
+    Ok(cx.into_reports())
+}
+
+/// Walks the expressions and statements of a function body, running the lint passes on each.
+struct BodyWalker<'a, 'b> {
+    lint_passes: &'a [Arc<dyn LintPass>],
+    cx: &'a mut LintContext<'b>,
+}
+impl BodyWalker<'_, '_> {
+    fn walk_expr(&mut self, expr_id: ExprId) {
+        let body = self.cx.body;
+        let expr = &body.arenas.exprs[expr_id];
+        for lint_pass in self.lint_passes {
+            lint_pass.check_expr(self.cx, expr_id, expr);
+        }
+        self.cx.ancestors.push(expr_id);
+        match expr {
+            Expr::Tuple(expr) => self.walk_exprs(&expr.items),
+            Expr::FixedSizeArray(expr) => match &expr.items {
+                FixedSizeArrayItems::Items(items) => self.walk_exprs(items),
+                FixedSizeArrayItems::ValueAndSize(value, _) => self.walk_expr(*value),
+            },
+            Expr::Snapshot(expr) => self.walk_expr(expr.inner),
+            Expr::Desnap(expr) => self.walk_expr(expr.inner),
+            Expr::Assignment(expr) => self.walk_expr(expr.rhs),
+            Expr::LogicalOperator(expr) => {
+                self.walk_expr(expr.lhs);
+                self.walk_expr(expr.rhs);
+            }
+            Expr::Block(expr) => {
+                for statement_id in &expr.statements {
+                    self.walk_statement(&body.arenas.statements[*statement_id]);
+                }
+                if let Some(tail) = expr.tail {
+                    self.walk_expr(tail);
+                }
+            }
+            Expr::Loop(expr) => self.walk_expr(expr.body),
+            Expr::While(expr) => {
+                self.walk_condition(&expr.condition);
+                self.walk_expr(expr.body);
+            }
+            Expr::For(expr) => {
+                self.walk_expr(expr.expr_id);
+                self.walk_expr(expr.body);
+            }
+            Expr::FunctionCall(expr) => {
+                for arg in &expr.args {
+                    if let ExprFunctionCallArg::Value(arg) = arg {
+                        self.walk_expr(*arg);
+                    }
+                }
+                if let Some(coupon_arg) = expr.coupon_arg {
+                    self.walk_expr(coupon_arg);
+                }
+            }
+            Expr::Match(expr) => {
+                self.walk_expr(expr.matched_expr);
+                for arm in &expr.arms {
+                    self.walk_expr(arm.expression);
+                }
+            }
+            Expr::If(expr) => {
+                self.walk_condition(&expr.condition);
+                self.walk_expr(expr.if_block);
+                if let Some(else_block) = expr.else_block {
+                    self.walk_expr(else_block);
+                }
+            }
+            Expr::MemberAccess(expr) => self.walk_expr(expr.expr),
+            Expr::StructCtor(expr) => {
+                for (_, member_expr) in &expr.members {
+                    self.walk_expr(*member_expr);
+                }
+                if let Some(base_struct) = expr.base_struct {
+                    self.walk_expr(base_struct);
+                }
+            }
+            Expr::EnumVariantCtor(expr) => self.walk_expr(expr.value_expr),
+            Expr::PropagateError(expr) => self.walk_expr(expr.inner),
+            Expr::ExprClosure(expr) => self.walk_expr(expr.body),
+            Expr::Var(_)
+            | Expr::Literal(_)
+            | Expr::StringLiteral(_)
+            | Expr::Constant(_)
+            | Expr::Missing(_) => {}
+        }
+        self.cx.ancestors.pop();
+    }
+
+    fn walk_exprs(&mut self, expr_ids: &[ExprId]) {
+        for expr_id in expr_ids {
+            self.walk_expr(*expr_id);
+        }
+    }
+
+    fn walk_condition(&mut self, condition: &Condition) {
+        match condition {
+            Condition::BoolExpr(expr_id) | Condition::Let(expr_id, _) => self.walk_expr(*expr_id),
+        }
+    }
+
+    fn walk_statement(&mut self, statement: &Statement) {
+        for lint_pass in self.lint_passes {
+            lint_pass.check_statement(self.cx, statement);
+        }
+        match statement {
+            Statement::Expr(statement) => self.walk_expr(statement.expr),
+            Statement::Let(statement) => self.walk_expr(statement.expr),
+            Statement::Return(statement) => {
+                if let Some(expr_id) = statement.expr_option {
+                    self.walk_expr(expr_id);
+                }
+            }
+            Statement::Break(statement) => {
+                if let Some(expr_id) = statement.expr_option {
+                    self.walk_expr(expr_id);
+                }
+            }
+            Statement::Continue(_) | Statement::Item(_) => {}
+        }
+    }
+}
@@ -0,0 +1,152 @@
+//! Lint levels of warning diagnostics.
+//!
+//! Every warning is covered by a named lint, whose level may be set with the `#[allow(...)]`,
+//! `#[warn(...)]` and `#[deny(...)]` attributes on items, statements and modules, or with the
+//! `lints` table of the crate settings. The innermost level applies. Allowed warnings are not
+//! reported, and denied warnings are reported as errors.
+//!
+//! Besides the lints of the compiler, listed in [LINTS], the lint passes of the plugin suite
+//! declare their own lints, see [crate::lint_passes].
+
+use cairo_lang_defs::diagnostic_utils::StableLocation;
+use cairo_lang_defs::ids::ModuleId;
+use cairo_lang_diagnostics::{DiagnosticEntry, Diagnostics, DiagnosticsBuilder};
+use cairo_lang_filesystem::db::LintLevel;
+use cairo_lang_syntax::attribute::consts::{ALLOW_ATTR, DENY_ATTR, WARN_ATTR};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::helpers::QueryAttrs;
+
+use crate::db::SemanticGroup;
+
+/// Variables that are never used.
+pub const UNUSED_VARIABLES: &str = "unused_variables";
+/// Values of `#[must_use]` types or functions that are never used.
+pub const UNUSED_MUST_USE: &str = "unused_must_use";
+/// Trait and impl items referring to their own trait or impl without `Self`.
+pub const USE_SELF: &str = "use_self";
+/// Usages of unstable features that are not enabled.
+pub const UNSTABLE_FEATURES: &str = "unstable_features";
+/// Usages of deprecated features.
+pub const DEPRECATED: &str = "deprecated";
+/// Imports that are never used.
+pub const UNUSED_IMPORTS: &str = "unused_imports";
+/// Calls to functions shadowed by a local variable of the same name.
+pub const SHADOWED_FUNCTION_CALLS: &str = "shadowed_function_calls";
+/// Constants that are never used.
+pub const UNUSED_CONSTANTS: &str = "unused_constants";
+/// Code that is never reached.
+pub const UNREACHABLE_CODE: &str = "unreachable_code";
+/// Warnings reported by compiler plugins.
+pub const PLUGIN_WARNINGS: &str = "plugin_warnings";
+
+/// All the lints known to the compiler.
+pub const LINTS: [&str; 10] = [
+    UNUSED_VARIABLES,
+    UNUSED_MUST_USE,
+    USE_SELF,
+    UNSTABLE_FEATURES,
+    DEPRECATED,
+    UNUSED_IMPORTS,
+    SHADOWED_FUNCTION_CALLS,
+    UNUSED_CONSTANTS,
+    UNREACHABLE_CODE,
+    PLUGIN_WARNINGS,
+];
+
+/// Returns whether `lint` is the name of a lint of the compiler or of a lint pass.
+pub fn is_known_lint(db: &dyn SemanticGroup, lint: &str) -> bool {
+    LINTS.contains(&lint) || db.declared_lints().iter().any(|declared| declared.name == lint)
+}
+
+/// A diagnostic that may be covered by a lint.
+pub trait LintDiagnostic: DiagnosticEntry {
+    /// Returns the name of the lint covering this diagnostic, if any.
+    fn lint(&self) -> Option<&'static str>;
+    /// Returns the location of the diagnostic, whose enclosing items determine the lint level.
+    fn stable_location(&self) -> StableLocation;
+    /// Marks the diagnostic as denied, making it be reported as an error.
+    fn deny(&mut self);
+}
+
+/// Returns the level of `lint` at `stable_location`, which is inside the module `module_id`.
+///
+/// Lint attributes of the enclosing syntax nodes are considered first, then those of the enclosing
+/// modules, then the lint levels in the crate settings, and last the default level of the lint.
+pub fn lint_level(
+    db: &dyn SemanticGroup,
+    module_id: ModuleId,
+    stable_location: StableLocation,
+    lint: &str,
+) -> LintLevel {
+    let syntax_db = db.upcast();
+    let mut node = Some(stable_location.syntax_node(db.upcast()));
+    while let Some(current) = node {
+        if let Some(level) = attrs_lint_level(syntax_db, &current, lint) {
+            return level;
+        }
+        node = current.parent();
+    }
+    let mut current_module_id = module_id;
+    loop {
+        match current_module_id {
+            ModuleId::CrateRoot(crate_id) => {
+                return db
+                    .crate_config(crate_id)
+                    .and_then(|config| config.settings.lints.get(lint).copied())
+                    .unwrap_or_else(|| default_lint_level(db, lint));
+            }
+            ModuleId::Submodule(id) => {
+                current_module_id = id.parent_module(db.upcast());
+                let Ok(submodules) = db.module_submodules(current_module_id) else {
+                    continue;
+                };
+                if let Some(level) = attrs_lint_level(syntax_db, &submodules[&id], lint) {
+                    return level;
+                }
+            }
+        }
+    }
+}
+
+/// Returns the default level of `lint`, which is [LintLevel::Warn] unless a lint pass declares
+/// otherwise.
+fn default_lint_level(db: &dyn SemanticGroup, lint: &str) -> LintLevel {
+    db.declared_lints()
+        .iter()
+        .find(|declared| declared.name == lint)
+        .map_or_else(LintLevel::default, |declared| declared.default_level)
+}
+
+/// Returns the level of `lint` set by the attributes of `syntax`, if any.
+fn attrs_lint_level(
+    db: &dyn SyntaxGroup,
+    syntax: &impl QueryAttrs,
+    lint: &str,
+) -> Option<LintLevel> {
+    [(ALLOW_ATTR, LintLevel::Allow), (WARN_ATTR, LintLevel::Warn), (DENY_ATTR, LintLevel::Deny)]
+        .into_iter()
+        .find(|(attr, _)| syntax.has_attr_with_arg(db, attr, lint))
+        .map(|(_, level)| level)
+}
+
+/// Applies the lint levels to the diagnostics of the module `module_id`.
+///
+/// Drops the allowed diagnostics and marks the denied ones.
+pub fn apply_lint_levels<T: LintDiagnostic>(
+    db: &dyn SemanticGroup,
+    module_id: ModuleId,
+    diagnostics: Diagnostics<T>,
+) -> Diagnostics<T> {
+    let mut builder = DiagnosticsBuilder::default();
+    for mut diagnostic in diagnostics.get_all() {
+        if let Some(lint) = diagnostic.lint() {
+            match lint_level(db, module_id, diagnostic.stable_location(), lint) {
+                LintLevel::Allow => continue,
+                LintLevel::Warn => {}
+                LintLevel::Deny => diagnostic.deny(),
+            }
+        }
+        builder.add(diagnostic);
+    }
+    builder.build()
+}
@@ -4,15 +4,20 @@ use cairo_lang_defs::ids::{
     LanguageElementId, ModuleId, NamedLanguageElementId, TraitFunctionId, TraitId,
 };
 use cairo_lang_filesystem::ids::CrateId;
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
 use cairo_lang_utils::ordered_hash_map::{Entry, OrderedHashMap};
 use cairo_lang_utils::unordered_hash_set::UnorderedHashSet;
 use smol_str::SmolStr;
 
+use crate::TypeId;
 use crate::corelib::{core_submodule, get_submodule};
 use crate::db::SemanticGroup;
+use crate::expr::inference::infers::InferenceEmbeddings;
+use crate::expr::inference::solver::SolutionSet;
+use crate::expr::inference::{ImplVarTraitItemMappings, InferenceId};
 use crate::items::us::SemanticUseEx;
 use crate::items::visibility::peek_visible_in;
-use crate::resolve::ResolvedGenericItem;
+use crate::resolve::{ResolvedGenericItem, Resolver};
 use crate::types::TypeHead;
 
 /// A filter for types.
@@ -83,6 +88,59 @@ pub fn methods_in_crate(
     result.into()
 }
 
+/// Finds all methods that can be called on a type.
+pub fn find_methods_for_type(
+    db: &dyn SemanticGroup,
+    resolver: &mut Resolver<'_>,
+    ty: TypeId,
+    stable_ptr: SyntaxStablePtrId,
+) -> Vec<TraitFunctionId> {
+    let type_filter = match ty.head(db) {
+        Some(head) => TypeFilter::TypeHead(head),
+        None => TypeFilter::NoFilter,
+    };
+
+    let mut relevant_methods = Vec::new();
+    // Find methods on type.
+    // TODO(spapini): Look only in current crate dependencies.
+    for crate_id in db.crates() {
+        let methods = db.methods_in_crate(crate_id, type_filter.clone());
+        for trait_function in methods.iter().copied() {
+            let clone_data =
+                &mut resolver.inference().clone_with_inference_id(db, InferenceId::NoContext);
+            let mut inference = clone_data.inference(db);
+            let lookup_context = resolver.impl_lookup_context();
+            // Check if trait function signature's first param can fit our expr type.
+            let Some((concrete_trait_id, _)) = inference.infer_concrete_trait_by_self(
+                trait_function,
+                ty,
+                &lookup_context,
+                Some(stable_ptr),
+                |_| {},
+            ) else {
+                continue;
+            };
+
+            // Find impls for it.
+
+            // ignore the result as nothing can be done with the error, if any.
+            inference.solve().ok();
+            if !matches!(
+                inference.trait_solution_set(
+                    concrete_trait_id,
+                    ImplVarTraitItemMappings::default(),
+                    lookup_context
+                ),
+                Ok(SolutionSet::Unique(_) | SolutionSet::Ambiguous(_))
+            ) {
+                continue;
+            }
+            relevant_methods.push(trait_function);
+        }
+    }
+    relevant_methods
+}
+
 /// Query implementation of [crate::db::SemanticGroup::visible_traits_in_module].
 pub fn visible_traits_in_module(
     db: &dyn SemanticGroup,
@@ -5,6 +5,7 @@ use cairo_lang_defs::plugin::{InlineMacroExprPlugin, MacroPlugin, NamedPlugin, P
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 
 use crate::db::SemanticGroup;
+use crate::lint_passes::LintPass;
 
 /// A trait for an analyzer plugin: external plugin that generates additional diagnostics for
 /// modules.
@@ -30,6 +31,8 @@ pub struct PluginSuite {
     pub inline_macro_plugins: OrderedHashMap<String, Arc<dyn InlineMacroExprPlugin>>,
     /// The analyzer plugins, running on all modules.
     pub analyzer_plugins: Vec<Arc<dyn AnalyzerPlugin>>,
+    /// The lint passes, running on all function bodies.
+    pub lint_passes: Vec<Arc<dyn LintPass>>,
 }
 impl PluginSuite {
     /// Adds a macro plugin.
@@ -64,11 +67,21 @@ impl PluginSuite {
     pub fn add_analyzer_plugin<T: AnalyzerPlugin + Default + 'static>(&mut self) -> &mut Self {
         self.add_analyzer_plugin_ex(Arc::new(T::default()))
     }
+    /// Adds a lint pass.
+    pub fn add_lint_pass_ex(&mut self, lint_pass: Arc<dyn LintPass>) -> &mut Self {
+        self.lint_passes.push(lint_pass);
+        self
+    }
+    /// Adds a lint pass.
+    pub fn add_lint_pass<T: LintPass + Default + 'static>(&mut self) -> &mut Self {
+        self.add_lint_pass_ex(Arc::new(T::default()))
+    }
     /// Adds another plugin suite into this suite.
     pub fn add(&mut self, suite: PluginSuite) -> &mut Self {
         self.plugins.extend(suite.plugins);
         self.inline_macro_plugins.extend(suite.inline_macro_plugins);
         self.analyzer_plugins.extend(suite.analyzer_plugins);
+        self.lint_passes.extend(suite.lint_passes);
         self
     }
 }
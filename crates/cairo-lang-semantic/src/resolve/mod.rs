@@ -657,6 +657,10 @@ impl<'db> Resolver<'db> {
                 self.data.used_items.insert(LookupItemId::ModuleItem(inner_item_info.item_id));
                 let inner_generic_item =
                     ResolvedGenericItem::from_module_item(self.db, inner_item_info.item_id)?;
+                // Mark the generic item for the language server, as some specialized items do not
+                // refer back to it, e.g. the value of a constant.
+                let db = self.db;
+                self.resolved_items.mark_generic(db, segment, inner_generic_item.clone());
                 let specialized_item = self.specialize_generic_module_item(
                     diagnostics,
                     identifier,
@@ -0,0 +1,510 @@
+//! A compact, versioned binary encoding of [VersionedProgram].
+//!
+//! The encoding starts with [MAGIC], followed by the version of the encoding and the version of the
+//! encoded program. Integers are encoded as LEB128 varints, and strings and sequences are prefixed
+//! by their length. Annotation values are encoded as their JSON text, since they are arbitrary.
+//! Maps are written in a fixed order, so encoding the same program always yields the same bytes.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::hash::Hash;
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use itertools::Itertools;
+use num_bigint::{BigInt, BigUint};
+use smol_str::SmolStr;
+use thiserror::Error;
+
+use crate::ProgramParser;
+use crate::debug_info::DebugInfo;
+use crate::ids::{
+    ConcreteLibfuncId, ConcreteTypeId, FunctionId, GenericLibfuncId, GenericTypeId, UserTypeId,
+    VarId,
+};
+use crate::program::{
+    BranchInfo, BranchTarget, ConcreteLibfuncLongId, ConcreteTypeLongId, DeclaredTypeInfo,
+    Function, FunctionSignature, GenericArg, Invocation, LibfuncDeclaration, Param, Program,
+    ProgramArtifact, Statement, StatementIdx, TypeDeclaration, VersionedProgram,
+};
+
+#[cfg(test)]
+#[path = "binary_format_test.rs"]
+mod test;
+
+/// The bytes every binary encoded program starts with.
+pub const MAGIC: &[u8; 7] = b"\x7fSIERRA";
+
+/// The current version of the binary encoding.
+pub const FORMAT_VERSION: u8 = 1;
+
+/// Error in reading or writing a binary encoded program.
+#[derive(Debug, Error)]
+pub enum BinaryFormatError {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("Not a binary encoded Sierra program.")]
+    InvalidMagic,
+    #[error("Unsupported binary encoding version {0}.")]
+    UnsupportedFormatVersion(u8),
+    #[error("Unsupported Sierra program version {0}.")]
+    UnsupportedProgramVersion(u8),
+    #[error("Invalid {kind} tag {tag}.")]
+    InvalidTag { kind: &'static str, tag: u8 },
+    #[error("Encoded integer is out of range.")]
+    IntegerOverflow,
+    #[error("Encoded string is not valid UTF-8.")]
+    InvalidUtf8,
+    #[error("Invalid annotation value: {0}")]
+    InvalidAnnotation(serde_json::Error),
+    #[error("Failed to parse Sierra program: {0}")]
+    InvalidText(String),
+}
+
+type Result<T, E = BinaryFormatError> = std::result::Result<T, E>;
+
+/// Returns whether the content of `reader` is a binary encoded program, without consuming it.
+pub fn is_binary_program(reader: &mut impl BufRead) -> io::Result<bool> {
+    Ok(reader.fill_buf()?.starts_with(MAGIC))
+}
+
+/// Writes `program` to `writer` in the binary encoding.
+///
+/// The writer is buffered internally, so there is no need to pass a buffered one.
+pub fn write_program(program: &VersionedProgram, writer: impl Write) -> Result<()> {
+    let mut encoder = Encoder { writer: BufWriter::new(writer) };
+    encoder.writer.write_all(MAGIC)?;
+    encoder.write_u8(FORMAT_VERSION)?;
+    match program {
+        VersionedProgram::V1 { program, .. } => {
+            encoder.write_u8(1)?;
+            encoder.write_artifact(program)?;
+        }
+    }
+    encoder.writer.flush()?;
+    Ok(())
+}
+
+/// Reads the program in the file at `path`, which is either binary encoded or in the text format.
+pub fn read_program_auto(path: &Path) -> Result<Program> {
+    let mut reader = BufReader::new(File::open(path)?);
+    if is_binary_program(&mut reader)? {
+        let VersionedProgram::V1 { program, .. } = read_program(reader)?;
+        return Ok(program.program);
+    }
+    let mut sierra_code = String::new();
+    reader.read_to_string(&mut sierra_code)?;
+    ProgramParser::new()
+        .parse(&sierra_code)
+        .map_err(|err| BinaryFormatError::InvalidText(err.to_string()))
+}
+
+/// Reads a binary encoded program from `reader`, consuming exactly the bytes of the program.
+pub fn read_program(reader: impl BufRead) -> Result<VersionedProgram> {
+    let mut decoder = Decoder { reader };
+    let mut magic = [0; MAGIC.len()];
+    match decoder.reader.read_exact(&mut magic) {
+        Ok(()) if &magic == MAGIC => {}
+        Err(err) if err.kind() != io::ErrorKind::UnexpectedEof => return Err(err.into()),
+        _ => return Err(BinaryFormatError::InvalidMagic),
+    }
+    let format_version = decoder.read_u8()?;
+    if format_version != FORMAT_VERSION {
+        return Err(BinaryFormatError::UnsupportedFormatVersion(format_version));
+    }
+    match decoder.read_u8()? {
+        1 => Ok(VersionedProgram::v1(decoder.read_artifact()?)),
+        version => Err(BinaryFormatError::UnsupportedProgramVersion(version)),
+    }
+}
+
+/// Access to the parts of the ids made of a number and an optional debug name.
+trait NumericId: Sized {
+    fn parts(&self) -> (u64, &Option<SmolStr>);
+    fn from_parts(id: u64, debug_name: Option<SmolStr>) -> Self;
+}
+macro_rules! impl_numeric_id {
+    ($($type_name:ident),*) => {
+        $(
+            impl NumericId for $type_name {
+                fn parts(&self) -> (u64, &Option<SmolStr>) {
+                    (self.id, &self.debug_name)
+                }
+
+                fn from_parts(id: u64, debug_name: Option<SmolStr>) -> Self {
+                    Self { id, debug_name }
+                }
+            }
+        )*
+    };
+}
+impl_numeric_id!(ConcreteLibfuncId, FunctionId, VarId, ConcreteTypeId);
+
+/// Helper for writing the binary encoding of a program.
+struct Encoder<W: Write> {
+    writer: W,
+}
+impl<W: Write> Encoder<W> {
+    fn write_artifact(&mut self, artifact: &ProgramArtifact) -> Result<()> {
+        self.write_program(&artifact.program)?;
+        self.write_option(artifact.debug_info.as_ref(), Self::write_debug_info)
+    }
+
+    fn write_program(&mut self, program: &Program) -> Result<()> {
+        self.write_seq(&program.type_declarations, Self::write_type_declaration)?;
+        self.write_seq(&program.libfunc_declarations, Self::write_libfunc_declaration)?;
+        self.write_seq(&program.statements, Self::write_statement)?;
+        self.write_seq(&program.funcs, Self::write_function)
+    }
+
+    fn write_type_declaration(&mut self, declaration: &TypeDeclaration) -> Result<()> {
+        self.write_id(&declaration.id)?;
+        self.write_str(&declaration.long_id.generic_id.0)?;
+        self.write_seq(&declaration.long_id.generic_args, Self::write_generic_arg)?;
+        self.write_option(declaration.declared_type_info.as_ref(), |encoder, info| {
+            encoder.write_bool(info.storable)?;
+            encoder.write_bool(info.droppable)?;
+            encoder.write_bool(info.duplicatable)?;
+            encoder.write_bool(info.zero_sized)
+        })
+    }
+
+    fn write_libfunc_declaration(&mut self, declaration: &LibfuncDeclaration) -> Result<()> {
+        self.write_id(&declaration.id)?;
+        self.write_str(&declaration.long_id.generic_id.0)?;
+        self.write_seq(&declaration.long_id.generic_args, Self::write_generic_arg)
+    }
+
+    fn write_generic_arg(&mut self, arg: &GenericArg) -> Result<()> {
+        match arg {
+            GenericArg::UserType(id) => {
+                self.write_u8(0)?;
+                self.write_bytes(&id.id.to_bytes_le())?;
+                self.write_option(id.debug_name.as_ref(), |encoder, name| encoder.write_str(name))
+            }
+            GenericArg::Type(id) => {
+                self.write_u8(1)?;
+                self.write_id(id)
+            }
+            GenericArg::Value(value) => {
+                self.write_u8(2)?;
+                self.write_bytes(&value.to_signed_bytes_le())
+            }
+            GenericArg::UserFunc(id) => {
+                self.write_u8(3)?;
+                self.write_id(id)
+            }
+            GenericArg::Libfunc(id) => {
+                self.write_u8(4)?;
+                self.write_id(id)
+            }
+        }
+    }
+
+    fn write_statement(&mut self, statement: &Statement) -> Result<()> {
+        match statement {
+            Statement::Invocation(invocation) => {
+                self.write_u8(0)?;
+                self.write_id(&invocation.libfunc_id)?;
+                self.write_seq(&invocation.args, Self::write_id)?;
+                self.write_seq(&invocation.branches, |encoder, branch| {
+                    // Fallthrough is encoded as 0, and a jump to statement `i` as `i + 1`.
+                    encoder.write_uint(match branch.target {
+                        BranchTarget::Fallthrough => 0,
+                        BranchTarget::Statement(StatementIdx(idx)) => idx as u64 + 1,
+                    })?;
+                    encoder.write_seq(&branch.results, Self::write_id)
+                })
+            }
+            Statement::Return(var_ids) => {
+                self.write_u8(1)?;
+                self.write_seq(var_ids, Self::write_id)
+            }
+        }
+    }
+
+    fn write_function(&mut self, function: &Function) -> Result<()> {
+        self.write_id(&function.id)?;
+        self.write_seq(&function.signature.param_types, Self::write_id)?;
+        self.write_seq(&function.signature.ret_types, Self::write_id)?;
+        self.write_seq(&function.params, |encoder, param| {
+            encoder.write_id(&param.id)?;
+            encoder.write_id(&param.ty)
+        })?;
+        self.write_uint(function.entry_point.0 as u64)
+    }
+
+    fn write_debug_info(&mut self, debug_info: &DebugInfo) -> Result<()> {
+        self.write_names(&debug_info.type_names)?;
+        self.write_names(&debug_info.libfunc_names)?;
+        self.write_names(&debug_info.user_func_names)?;
+        let annotations = debug_info.annotations.iter().collect_vec();
+        self.write_seq(&annotations, |encoder, (namespace, value)| {
+            encoder.write_str(namespace)?;
+            encoder.write_bytes(
+                &serde_json::to_vec(value).map_err(BinaryFormatError::InvalidAnnotation)?,
+            )
+        })?;
+        let executables =
+            debug_info.executables.iter().sorted_by(|(a, _), (b, _)| a.cmp(b)).collect_vec();
+        self.write_seq(&executables, |encoder, (name, ids)| {
+            encoder.write_str(name)?;
+            encoder.write_seq(ids, Self::write_id)
+        })
+    }
+
+    /// Writes a map from ids to names, ordered by the ids.
+    fn write_names<T: NumericId>(&mut self, names: &HashMap<T, SmolStr>) -> Result<()> {
+        let names = names.iter().sorted_by_key(|(id, _)| id.parts().0).collect_vec();
+        self.write_seq(&names, |encoder, (id, name)| {
+            encoder.write_id(*id)?;
+            encoder.write_str(name)
+        })
+    }
+
+    fn write_id<T: NumericId>(&mut self, id: &T) -> Result<()> {
+        let (id, debug_name) = id.parts();
+        self.write_uint(id)?;
+        self.write_option(debug_name.as_ref(), |encoder, name| encoder.write_str(name))
+    }
+
+    fn write_seq<T>(
+        &mut self,
+        items: &[T],
+        mut write_item: impl FnMut(&mut Self, &T) -> Result<()>,
+    ) -> Result<()> {
+        self.write_uint(items.len() as u64)?;
+        for item in items {
+            write_item(self, item)?;
+        }
+        Ok(())
+    }
+
+    fn write_option<T>(
+        &mut self,
+        value: Option<&T>,
+        write_value: impl FnOnce(&mut Self, &T) -> Result<()>,
+    ) -> Result<()> {
+        self.write_bool(value.is_some())?;
+        match value {
+            Some(value) => write_value(self, value),
+            None => Ok(()),
+        }
+    }
+
+    fn write_str(&mut self, value: &str) -> Result<()> {
+        self.write_bytes(value.as_bytes())
+    }
+
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<()> {
+        self.write_uint(bytes.len() as u64)?;
+        Ok(self.writer.write_all(bytes)?)
+    }
+
+    fn write_bool(&mut self, value: bool) -> Result<()> {
+        self.write_u8(value.into())
+    }
+
+    fn write_uint(&mut self, mut value: u64) -> Result<()> {
+        while value >= 0x80 {
+            self.write_u8((value & 0x7f) as u8 | 0x80)?;
+            value >>= 7;
+        }
+        self.write_u8(value as u8)
+    }
+
+    fn write_u8(&mut self, value: u8) -> Result<()> {
+        Ok(self.writer.write_all(&[value])?)
+    }
+}
+
+/// Helper for reading the binary encoding of a program.
+struct Decoder<R: BufRead> {
+    reader: R,
+}
+impl<R: BufRead> Decoder<R> {
+    fn read_artifact(&mut self) -> Result<ProgramArtifact> {
+        Ok(ProgramArtifact {
+            program: self.read_program()?,
+            debug_info: self.read_option(Self::read_debug_info)?,
+        })
+    }
+
+    fn read_program(&mut self) -> Result<Program> {
+        Ok(Program {
+            type_declarations: self.read_seq(Self::read_type_declaration)?,
+            libfunc_declarations: self.read_seq(Self::read_libfunc_declaration)?,
+            statements: self.read_seq(Self::read_statement)?,
+            funcs: self.read_seq(Self::read_function)?,
+        })
+    }
+
+    fn read_type_declaration(&mut self) -> Result<TypeDeclaration> {
+        Ok(TypeDeclaration {
+            id: self.read_id()?,
+            long_id: ConcreteTypeLongId {
+                generic_id: GenericTypeId(self.read_str()?),
+                generic_args: self.read_seq(Self::read_generic_arg)?,
+            },
+            declared_type_info: self.read_option(|decoder| {
+                Ok(DeclaredTypeInfo {
+                    storable: decoder.read_bool()?,
+                    droppable: decoder.read_bool()?,
+                    duplicatable: decoder.read_bool()?,
+                    zero_sized: decoder.read_bool()?,
+                })
+            })?,
+        })
+    }
+
+    fn read_libfunc_declaration(&mut self) -> Result<LibfuncDeclaration> {
+        Ok(LibfuncDeclaration {
+            id: self.read_id()?,
+            long_id: ConcreteLibfuncLongId {
+                generic_id: GenericLibfuncId(self.read_str()?),
+                generic_args: self.read_seq(Self::read_generic_arg)?,
+            },
+        })
+    }
+
+    fn read_generic_arg(&mut self) -> Result<GenericArg> {
+        Ok(match self.read_u8()? {
+            0 => GenericArg::UserType(UserTypeId {
+                id: BigUint::from_bytes_le(&self.read_bytes()?),
+                debug_name: self.read_option(Self::read_str)?,
+            }),
+            1 => GenericArg::Type(self.read_id()?),
+            2 => GenericArg::Value(BigInt::from_signed_bytes_le(&self.read_bytes()?)),
+            3 => GenericArg::UserFunc(self.read_id()?),
+            4 => GenericArg::Libfunc(self.read_id()?),
+            tag => return Err(BinaryFormatError::InvalidTag { kind: "generic argument", tag }),
+        })
+    }
+
+    fn read_statement(&mut self) -> Result<Statement> {
+        Ok(match self.read_u8()? {
+            0 => Statement::Invocation(Invocation {
+                libfunc_id: self.read_id()?,
+                args: self.read_seq(Self::read_id)?,
+                branches: self.read_seq(|decoder| {
+                    Ok(BranchInfo {
+                        target: match decoder.read_usize()? {
+                            0 => BranchTarget::Fallthrough,
+                            idx => BranchTarget::Statement(StatementIdx(idx - 1)),
+                        },
+                        results: decoder.read_seq(Self::read_id)?,
+                    })
+                })?,
+            }),
+            1 => Statement::Return(self.read_seq(Self::read_id)?),
+            tag => return Err(BinaryFormatError::InvalidTag { kind: "statement", tag }),
+        })
+    }
+
+    fn read_function(&mut self) -> Result<Function> {
+        Ok(Function {
+            id: self.read_id()?,
+            signature: FunctionSignature {
+                param_types: self.read_seq(Self::read_id)?,
+                ret_types: self.read_seq(Self::read_id)?,
+            },
+            params: self
+                .read_seq(|decoder| Ok(Param { id: decoder.read_id()?, ty: decoder.read_id()? }))?,
+            entry_point: StatementIdx(self.read_usize()?),
+        })
+    }
+
+    fn read_debug_info(&mut self) -> Result<DebugInfo> {
+        Ok(DebugInfo {
+            type_names: self.read_names()?,
+            libfunc_names: self.read_names()?,
+            user_func_names: self.read_names()?,
+            annotations: self
+                .read_seq(|decoder| {
+                    let namespace = decoder.read_str()?.to_string();
+                    let value = serde_json::from_slice(&decoder.read_bytes()?)
+                        .map_err(BinaryFormatError::InvalidAnnotation)?;
+                    Ok((namespace, value))
+                })?
+                .into_iter()
+                .collect(),
+            executables: self
+                .read_seq(|decoder| Ok((decoder.read_str()?, decoder.read_seq(Self::read_id)?)))?
+                .into_iter()
+                .collect(),
+        })
+    }
+
+    fn read_names<T: NumericId + Eq + Hash>(&mut self) -> Result<HashMap<T, SmolStr>> {
+        Ok(self
+            .read_seq(|decoder| Ok((decoder.read_id()?, decoder.read_str()?)))?
+            .into_iter()
+            .collect())
+    }
+
+    fn read_id<T: NumericId>(&mut self) -> Result<T> {
+        let id = self.read_uint()?;
+        Ok(T::from_parts(id, self.read_option(Self::read_str)?))
+    }
+
+    fn read_seq<T>(&mut self, mut read_item: impl FnMut(&mut Self) -> Result<T>) -> Result<Vec<T>> {
+        // Not preallocating, as the length may come from an untrusted source.
+        (0..self.read_usize()?).map(|_| read_item(self)).collect()
+    }
+
+    fn read_option<T>(
+        &mut self,
+        read_value: impl FnOnce(&mut Self) -> Result<T>,
+    ) -> Result<Option<T>> {
+        if self.read_bool()? { Ok(Some(read_value(self)?)) } else { Ok(None) }
+    }
+
+    fn read_str(&mut self) -> Result<SmolStr> {
+        let bytes = self.read_bytes()?;
+        Ok(String::from_utf8(bytes).map_err(|_| BinaryFormatError::InvalidUtf8)?.into())
+    }
+
+    fn read_bytes(&mut self) -> Result<Vec<u8>> {
+        let len = self.read_uint()?;
+        let mut bytes = vec![];
+        (&mut self.reader).take(len).read_to_end(&mut bytes)?;
+        if bytes.len() as u64 != len {
+            return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+        }
+        Ok(bytes)
+    }
+
+    fn read_bool(&mut self) -> Result<bool> {
+        match self.read_u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            tag => Err(BinaryFormatError::InvalidTag { kind: "bool", tag }),
+        }
+    }
+
+    fn read_usize(&mut self) -> Result<usize> {
+        self.read_uint()?.try_into().map_err(|_| BinaryFormatError::IntegerOverflow)
+    }
+
+    fn read_uint(&mut self) -> Result<u64> {
+        let mut value = 0;
+        for shift in (0..64).step_by(7) {
+            let byte = self.read_u8()?;
+            let bits = u64::from(byte & 0x7f);
+            if bits << shift >> shift != bits {
+                return Err(BinaryFormatError::IntegerOverflow);
+            }
+            value |= bits << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(BinaryFormatError::IntegerOverflow)
+    }
+
+    fn read_u8(&mut self) -> Result<u8> {
+        let mut byte = [0];
+        self.reader.read_exact(&mut byte)?;
+        Ok(byte[0])
+    }
+}
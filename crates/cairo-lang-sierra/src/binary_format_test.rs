@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use test_case::test_case;
+
+use super::{
+    BinaryFormatError, FORMAT_VERSION, MAGIC, is_binary_program, read_program, read_program_auto,
+    write_program,
+};
+use crate::ProgramParser;
+use crate::debug_info::DebugInfo;
+use crate::program::VersionedProgram;
+
+/// Returns the parsed example program, with its debug info.
+fn example_program(code: &str) -> VersionedProgram {
+    let program = ProgramParser::new().parse(code).unwrap();
+    let mut debug_info = DebugInfo::extract(&program);
+    debug_info.annotations.insert(
+        "github.com/software-mansion/cairo-profiler".into(),
+        serde_json::json!({"statements_code_locations": {"0": [["lib.cairo", 1]]}}),
+    );
+    debug_info.executables = HashMap::from([("main".into(), vec![program.funcs[0].id.clone()])]);
+    program.into_artifact().into_v1().unwrap().with_debug_info(debug_info).into()
+}
+
+/// Encodes `program` in the binary encoding.
+fn encode(program: &VersionedProgram) -> Vec<u8> {
+    let mut bytes = vec![];
+    write_program(program, &mut bytes).unwrap();
+    bytes
+}
+
+#[test_case(include_str!("../examples/fib_jumps.sierra"); "fib_jumps")]
+#[test_case(include_str!("../examples/fib_no_gas.sierra"); "fib_no_gas")]
+fn round_trip(code: &str) {
+    let program = example_program(code);
+    let bytes = encode(&program);
+    assert!(is_binary_program(&mut bytes.as_slice()).unwrap());
+    let decoded = read_program(bytes.as_slice()).unwrap();
+    assert_eq!(decoded, program);
+    // Equality of ids ignores their debug names, so the names are compared through the text form.
+    assert_eq!(decoded.to_string(), program.to_string());
+}
+
+#[test]
+fn round_trip_stripped() {
+    let program = ProgramParser::new()
+        .parse(include_str!("../examples/fib_jumps.sierra"))
+        .unwrap()
+        .into_artifact();
+    assert_eq!(read_program(encode(&program).as_slice()).unwrap(), program);
+}
+
+#[test]
+fn deterministic_encoding() {
+    let code = include_str!("../examples/fib_no_gas.sierra");
+    assert_eq!(encode(&example_program(code)), encode(&example_program(code)));
+}
+
+#[test]
+fn streaming() {
+    let code = include_str!("../examples/fib_jumps.sierra");
+    let first = example_program(code);
+    let second = ProgramParser::new().parse(code).unwrap().into_artifact();
+    let mut bytes = encode(&first);
+    bytes.extend(encode(&second));
+    let mut reader = bytes.as_slice();
+    assert_eq!(read_program(&mut reader).unwrap(), first);
+    assert_eq!(read_program(&mut reader).unwrap(), second);
+    assert!(reader.is_empty());
+}
+
+#[test]
+fn text_is_not_binary() {
+    let mut reader = include_str!("../examples/fib_jumps.sierra").as_bytes();
+    assert!(!is_binary_program(&mut reader).unwrap());
+    assert!(matches!(read_program(reader), Err(BinaryFormatError::InvalidMagic)));
+}
+
+#[test]
+fn invalid_header() {
+    assert!(matches!(read_program(&MAGIC[..3]), Err(BinaryFormatError::InvalidMagic)));
+    let mut bytes = MAGIC.to_vec();
+    bytes.push(FORMAT_VERSION + 1);
+    assert!(matches!(
+        read_program(bytes.as_slice()),
+        Err(BinaryFormatError::UnsupportedFormatVersion(version)) if version == FORMAT_VERSION + 1
+    ));
+    let mut bytes = MAGIC.to_vec();
+    bytes.extend([FORMAT_VERSION, 2]);
+    assert!(matches!(
+        read_program(bytes.as_slice()),
+        Err(BinaryFormatError::UnsupportedProgramVersion(2))
+    ));
+}
+
+#[test]
+fn truncated() {
+    let bytes = encode(&example_program(include_str!("../examples/fib_jumps.sierra")));
+    assert!(matches!(
+        read_program(&bytes[..bytes.len() - 1]),
+        Err(BinaryFormatError::Io(err)) if err.kind() == std::io::ErrorKind::UnexpectedEof
+    ));
+}
+
+#[test]
+fn read_program_auto_formats() {
+    let text_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples/fib_jumps.sierra");
+    let program =
+        ProgramParser::new().parse(&std::fs::read_to_string(&text_path).unwrap()).unwrap();
+    assert_eq!(read_program_auto(&text_path).unwrap(), program);
+
+    let binary_path = std::env::temp_dir().join("cairo_lang_sierra_read_program_auto.sierra");
+    std::fs::write(&binary_path, encode(&program.clone().into_artifact())).unwrap();
+    let decoded = read_program_auto(&binary_path);
+    std::fs::remove_file(&binary_path).unwrap();
+    assert_eq!(decoded.unwrap(), program);
+
+    assert!(matches!(
+        read_program_auto(&Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml")),
+        Err(BinaryFormatError::InvalidText(_))
+    ));
+}
@@ -12,6 +12,7 @@
 use lalrpop_util::lalrpop_mod;
 
 pub mod algorithm;
+pub mod binary_format;
 pub mod debug_info;
 pub mod edit_state;
 pub mod extensions;
@@ -23,6 +24,7 @@ pub mod program_registry;
 pub mod simulation;
 #[cfg(test)]
 mod test_utils;
+pub mod verifier;
 
 lalrpop_mod!(
     #[allow(clippy::all, unused_extern_crates)]
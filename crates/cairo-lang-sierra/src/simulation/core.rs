@@ -1,33 +1,75 @@
 use std::collections::HashMap;
 
 use cairo_lang_utils::extract_matches;
+use itertools::repeat_n;
 use num_bigint::BigInt;
 use num_integer::Integer;
-use num_traits::{One, ToPrimitive, Zero};
+use num_traits::{One, Signed, ToPrimitive, Zero};
 use starknet_types_core::felt::{Felt as Felt252, NonZeroFelt as NonZeroFelt252};
+use starknet_types_core::hash::{Pedersen, Poseidon, StarkHash};
 
 use super::LibfuncSimulationError;
+use super::syscalls::{Secp256Curve, Secp256Point, SyscallError, SyscallHandler, SyscallResult};
 use super::value::CoreValue;
-use crate::extensions::array::ArrayConcreteLibfunc;
+use crate::extensions::array::{ArrayConcreteLibfunc, ConcreteMultiPopLibfunc};
 use crate::extensions::boolean::BoolConcreteLibfunc;
-use crate::extensions::core::CoreConcreteLibfunc;
+use crate::extensions::bounded_int::{
+    BoundedIntConcreteLibfunc, BoundedIntConstrainConcreteLibfunc, BoundedIntType,
+};
+use crate::extensions::bytes31::{Bytes31ConcreteLibfunc, Bytes31Type};
+use crate::extensions::casts::{CastConcreteLibfunc, DowncastConcreteLibfunc};
+use crate::extensions::circuit::{
+    CircuitConcreteLibfunc, CircuitInfo, CircuitTypeConcrete, ConcreteCircuit,
+    ConcreteGetOutputLibFunc, GateOffsets,
+};
+use crate::extensions::const_type::{
+    ConstAsBoxConcreteLibfunc, ConstAsImmediateConcreteLibfunc, ConstConcreteLibfunc,
+    ConstConcreteType,
+};
+use crate::extensions::consts::SignatureAndConstConcreteLibfunc;
+use crate::extensions::core::{CoreConcreteLibfunc, CoreTypeConcrete};
+use crate::extensions::coupon::CouponConcreteLibfunc;
 use crate::extensions::ec::EcConcreteLibfunc;
 use crate::extensions::enm::{EnumConcreteLibfunc, EnumInitConcreteLibfunc};
 use crate::extensions::felt252::{
     Felt252BinaryOpConcreteLibfunc, Felt252BinaryOperationConcrete, Felt252BinaryOperator,
     Felt252Concrete, Felt252ConstConcreteLibfunc, Felt252OperationWithConstConcreteLibfunc,
+    Felt252Type,
+};
+use crate::extensions::felt252_dict::{
+    Felt252DictConcreteLibfunc, Felt252DictEntryConcreteLibfunc,
 };
-use crate::extensions::felt252_dict::Felt252DictConcreteLibfunc;
 use crate::extensions::function_call::SignatureAndFunctionConcreteLibfunc;
 use crate::extensions::gas::GasConcreteLibfunc;
+use crate::extensions::int::signed::{
+    Sint8Type, Sint16Type, Sint32Type, Sint64Type, SintConcrete, SintTraits,
+};
+use crate::extensions::int::signed128::{Sint128Concrete, Sint128Type};
 use crate::extensions::int::unsigned::{
-    Uint8Concrete, Uint16Concrete, Uint32Concrete, Uint64Concrete,
+    Uint8Concrete, Uint8Type, Uint16Concrete, Uint16Type, Uint32Concrete, Uint32Type,
+    Uint64Concrete, Uint64Type,
 };
-use crate::extensions::int::unsigned128::Uint128Concrete;
-use crate::extensions::int::{IntConstConcreteLibfunc, IntOperator};
+use crate::extensions::int::unsigned128::{Uint128Concrete, Uint128Type};
+use crate::extensions::int::unsigned256::Uint256Concrete;
+use crate::extensions::int::unsigned512::Uint512Concrete;
+use crate::extensions::int::{IntConstConcreteLibfunc, IntMulTraits, IntOperator, IntTraits};
+use crate::extensions::is_zero::IsZeroTraits;
+use crate::extensions::lib_func::SignatureAndTypeConcreteLibfunc;
 use crate::extensions::mem::MemConcreteLibfunc;
-use crate::extensions::structure::StructConcreteLibfunc;
-use crate::ids::FunctionId;
+use crate::extensions::nullable::NullableConcreteLibfunc;
+use crate::extensions::range::IntRangeConcreteLibfunc;
+use crate::extensions::starknet::StarkNetConcreteLibfunc;
+use crate::extensions::starknet::interoperability::{ClassHashType, ContractAddressType};
+use crate::extensions::starknet::secp256::{
+    Secp256ConcreteLibfunc, Secp256OpConcreteLibfunc, Secp256Trait,
+};
+use crate::extensions::starknet::storage::{StorageAddressType, StorageBaseAddressType};
+use crate::extensions::starknet::testing::{CheatcodeConcreteLibfunc, TestingConcreteLibfunc};
+use crate::extensions::structure::{StructConcreteLibfunc, StructConcreteType};
+use crate::extensions::types::InfoAndTypeConcreteType;
+use crate::extensions::{ConcreteType, NamedType, SignatureBasedConcreteLibfunc};
+use crate::ids::{ConcreteTypeId, FunctionId, GenericTypeId};
+use crate::program::GenericArg;
 
 /// Helper macro to take the inputs and return an error if the number of inputs is wrong, or the
 /// type of the expected inputs is wrong. Usage:
@@ -46,16 +88,23 @@ macro_rules! take_inputs {
 /// Simulates the run of a single libfunc. Returns the value representations of the outputs, and
 /// the chosen branch given the inputs.
 ///
+/// `get_type` returns the concrete type of a type id. It is used by the libfuncs whose outputs
+/// depend on their generic type arguments.
 /// `simulate_function` is a function that simulates running of a user function. It is provided here
 /// for the case where the extensions need to use it.
+/// `syscall_handler` handles the system calls of the StarkNet libfuncs.
 pub fn simulate<
+    'a,
     GetStatementGasInfo: Fn() -> Option<i64>,
+    GetType: Fn(&ConcreteTypeId) -> Option<&'a CoreTypeConcrete>,
     SimulateFunction: Fn(&FunctionId, Vec<CoreValue>) -> Result<Vec<CoreValue>, LibfuncSimulationError>,
 >(
     libfunc: &CoreConcreteLibfunc,
     inputs: Vec<CoreValue>,
     get_statement_gas_info: GetStatementGasInfo,
+    get_type: GetType,
     simulate_function: SimulateFunction,
+    syscall_handler: &dyn SyscallHandler,
 ) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
     Ok(match libfunc {
         CoreConcreteLibfunc::Drop(_) => {
@@ -66,45 +115,37 @@ pub fn simulate<
             let [value] = take_inputs(inputs)?;
             (vec![value.clone(), value], 0)
         }
-        CoreConcreteLibfunc::Ec(libfunc) => {
-            match libfunc {
-                EcConcreteLibfunc::TryNew(_) => {
-                    take_inputs!(let [CoreValue::Felt252(x), CoreValue::Felt252(y)] = inputs);
-                    const BETA: Felt252 = Felt252::from_hex_unchecked(
-                        "0x6f21413efbe40de150e596d72f7a8c5609ad26c15c915c1f4cdfcb99cee9e89",
-                    );
-                    // If the point is on the curve use the fallthrough branch and return the
-                    // point.
-                    if y * y == x * x * x + x + BETA {
-                        (vec![CoreValue::EcPoint(x, y)], 0)
-                    } else {
-                        (vec![], 1)
-                    }
-                }
-                EcConcreteLibfunc::UnwrapPoint(_) => {
-                    take_inputs!(let [CoreValue::EcPoint(x, y)] = inputs);
-                    (vec![CoreValue::Felt252(x), CoreValue::Felt252(y)], 0)
-                }
-                _ => unimplemented!(),
-            }
-        }
+        CoreConcreteLibfunc::Ec(libfunc) => simulate_ec_libfunc(libfunc, inputs)?,
         CoreConcreteLibfunc::FunctionCall(SignatureAndFunctionConcreteLibfunc {
             function, ..
-        })
-        | CoreConcreteLibfunc::CouponCall(SignatureAndFunctionConcreteLibfunc {
-            function, ..
         }) => (simulate_function(&function.id, inputs)?, 0),
+        CoreConcreteLibfunc::CouponCall(SignatureAndFunctionConcreteLibfunc {
+            function, ..
+        }) => {
+            // The last input is the coupon paying for the call, which is not passed to the
+            // function.
+            let mut inputs = inputs;
+            match inputs.pop() {
+                Some(CoreValue::Coupon) => (simulate_function(&function.id, inputs)?, 0),
+                Some(_) => return Err(LibfuncSimulationError::WrongArgType),
+                None => return Err(LibfuncSimulationError::WrongNumberOfArgs),
+            }
+        }
         CoreConcreteLibfunc::Gas(GasConcreteLibfunc::WithdrawGas(_)) => {
             let count = get_statement_gas_info()
                 .ok_or(LibfuncSimulationError::UnresolvedStatementGasInfo)?;
             take_inputs!(let [CoreValue::RangeCheck, CoreValue::GasBuiltin(gas_counter)] = inputs);
-            if gas_counter >= count {
-                // Have enough gas - return reduced counter and jump to success branch.
-                (vec![CoreValue::RangeCheck, CoreValue::GasBuiltin(gas_counter - count)], 0)
-            } else {
-                // Don't have enough gas - return the same counter and jump to failure branch.
-                (vec![CoreValue::RangeCheck, CoreValue::GasBuiltin(gas_counter)], 1)
-            }
+            simulate_withdraw_gas(gas_counter, count)
+        }
+        CoreConcreteLibfunc::Gas(GasConcreteLibfunc::BuiltinWithdrawGas(_)) => {
+            let count = get_statement_gas_info()
+                .ok_or(LibfuncSimulationError::UnresolvedStatementGasInfo)?;
+            take_inputs!(let [
+                CoreValue::RangeCheck,
+                CoreValue::GasBuiltin(gas_counter),
+                CoreValue::BuiltinCosts,
+            ] = inputs);
+            simulate_withdraw_gas(gas_counter, count)
         }
         CoreConcreteLibfunc::Gas(GasConcreteLibfunc::RedepositGas(_)) => {
             let count = get_statement_gas_info()
@@ -116,86 +157,28 @@ pub fn simulate<
             take_inputs!(let [CoreValue::GasBuiltin(gas_counter)] = inputs);
             (vec![CoreValue::GasBuiltin(gas_counter), CoreValue::Uint128(gas_counter as u128)], 0)
         }
-        CoreConcreteLibfunc::Gas(
-            GasConcreteLibfunc::BuiltinWithdrawGas(_) | GasConcreteLibfunc::GetBuiltinCosts(_),
-        ) => {
-            unimplemented!("Simulation of the builtin cost functionality is not implemented yet.")
+        CoreConcreteLibfunc::Gas(GasConcreteLibfunc::GetBuiltinCosts(_)) => {
+            let [] = take_inputs(inputs)?;
+            (vec![CoreValue::BuiltinCosts], 0)
         }
         CoreConcreteLibfunc::BranchAlign(_) => {
             let [] = take_inputs(inputs)?;
             get_statement_gas_info().ok_or(LibfuncSimulationError::UnresolvedStatementGasInfo)?;
             (vec![], 0)
         }
-        CoreConcreteLibfunc::Array(ArrayConcreteLibfunc::New(_)) => {
-            let [] = take_inputs(inputs)?;
-            (vec![CoreValue::Array(vec![])], 0)
-        }
-        CoreConcreteLibfunc::Array(ArrayConcreteLibfunc::SpanFromTuple(_)) => {
-            take_inputs!(let [CoreValue::Struct(members)] = inputs);
-            (vec![CoreValue::Array(members)], 0)
-        }
-        CoreConcreteLibfunc::Array(ArrayConcreteLibfunc::TupleFromSpan(_)) => todo!(),
-        CoreConcreteLibfunc::Array(ArrayConcreteLibfunc::Append(_)) => {
-            take_inputs!(let [CoreValue::Array(mut arr), element] = inputs);
-            arr.push(element);
-            (vec![CoreValue::Array(arr)], 0)
-        }
-        CoreConcreteLibfunc::Array(ArrayConcreteLibfunc::PopFront(_)) => {
-            take_inputs!(let [CoreValue::Array(mut arr)] = inputs);
-            if arr.is_empty() {
-                (vec![CoreValue::Array(arr)], 1)
-            } else {
-                let front = arr.remove(0);
-                (vec![CoreValue::Array(arr), front], 0)
-            }
-        }
-        CoreConcreteLibfunc::Array(ArrayConcreteLibfunc::PopFrontConsume(_)) => {
-            take_inputs!(let [CoreValue::Array(mut arr)] = inputs);
-            if arr.is_empty() { (vec![CoreValue::Array(arr)], 1) } else { (vec![arr.remove(0)], 0) }
-        }
-        CoreConcreteLibfunc::Array(ArrayConcreteLibfunc::Get(_)) => {
-            take_inputs!(
-                let [CoreValue::RangeCheck, CoreValue::Array(arr), CoreValue::Uint32(idx)] = inputs
-            );
-            match arr.get(idx as usize).cloned() {
-                Some(element) => (vec![CoreValue::RangeCheck, element], 0),
-                None => (vec![CoreValue::RangeCheck], 1),
-            }
-        }
-        CoreConcreteLibfunc::Array(ArrayConcreteLibfunc::Slice(_)) => {
-            take_inputs!(let [
-                CoreValue::RangeCheck,
-                CoreValue::Array(arr),
-                CoreValue::Uint32(start),
-                CoreValue::Uint32(length),
-            ] = inputs);
-            match arr.get(start as usize..(start + length) as usize) {
-                Some(elements) => {
-                    (vec![CoreValue::RangeCheck, CoreValue::Array(elements.to_vec())], 0)
-                }
-                None => (vec![CoreValue::RangeCheck], 1),
-            }
-        }
-        CoreConcreteLibfunc::Array(ArrayConcreteLibfunc::Len(_)) => {
-            take_inputs!(let [CoreValue::Array(arr)] = inputs);
-            (vec![CoreValue::Uint32(arr.len() as u32)], 0)
-        }
-        CoreConcreteLibfunc::Array(ArrayConcreteLibfunc::SnapshotPopFront(_)) => todo!(),
-        CoreConcreteLibfunc::Array(ArrayConcreteLibfunc::SnapshotPopBack(_)) => todo!(),
-        CoreConcreteLibfunc::Array(ArrayConcreteLibfunc::SnapshotMultiPopFront(_)) => todo!(),
-        CoreConcreteLibfunc::Array(ArrayConcreteLibfunc::SnapshotMultiPopBack(_)) => todo!(),
+        CoreConcreteLibfunc::Array(libfunc) => simulate_array_libfunc(libfunc, inputs, &get_type)?,
         CoreConcreteLibfunc::Uint8(libfunc) => simulate_u8_libfunc(libfunc, inputs)?,
         CoreConcreteLibfunc::Uint16(libfunc) => simulate_u16_libfunc(libfunc, inputs)?,
         CoreConcreteLibfunc::Uint32(libfunc) => simulate_u32_libfunc(libfunc, inputs)?,
         CoreConcreteLibfunc::Uint64(libfunc) => simulate_u64_libfunc(libfunc, inputs)?,
         CoreConcreteLibfunc::Uint128(libfunc) => simulate_u128_libfunc(libfunc, inputs)?,
-        CoreConcreteLibfunc::Sint8(_)
-        | CoreConcreteLibfunc::Sint16(_)
-        | CoreConcreteLibfunc::Sint32(_)
-        | CoreConcreteLibfunc::Sint64(_)
-        | CoreConcreteLibfunc::Sint128(_) => {
-            unimplemented!("Simulation of signed integer libfuncs is not implemented yet.")
-        }
+        CoreConcreteLibfunc::Uint256(libfunc) => simulate_u256_libfunc(libfunc, inputs)?,
+        CoreConcreteLibfunc::Uint512(libfunc) => simulate_u512_libfunc(libfunc, inputs)?,
+        CoreConcreteLibfunc::Sint8(libfunc) => simulate_sint_libfunc(libfunc, inputs)?,
+        CoreConcreteLibfunc::Sint16(libfunc) => simulate_sint_libfunc(libfunc, inputs)?,
+        CoreConcreteLibfunc::Sint32(libfunc) => simulate_sint_libfunc(libfunc, inputs)?,
+        CoreConcreteLibfunc::Sint64(libfunc) => simulate_sint_libfunc(libfunc, inputs)?,
+        CoreConcreteLibfunc::Sint128(libfunc) => simulate_i128_libfunc(libfunc, inputs)?,
         CoreConcreteLibfunc::Bool(libfunc) => simulate_bool_libfunc(libfunc, inputs)?,
         CoreConcreteLibfunc::Felt252(libfunc) => simulate_felt252_libfunc(libfunc, inputs)?,
         CoreConcreteLibfunc::UnwrapNonZero(_) => (inputs, 0),
@@ -234,7 +217,14 @@ pub fn simulate<
             take_inputs!(let [CoreValue::Enum { value, index }] = inputs);
             (vec![*value], index)
         }
-        CoreConcreteLibfunc::Enum(EnumConcreteLibfunc::FromBoundedInt(_)) => todo!(),
+        CoreConcreteLibfunc::Enum(EnumConcreteLibfunc::FromBoundedInt(_)) => {
+            let [value] = take_inputs(inputs)?;
+            // The input range starts at 0, so the value is the index of the variant.
+            let index = int_value(&value)
+                .and_then(|value| value.to_usize())
+                .ok_or(LibfuncSimulationError::WrongArgType)?;
+            (vec![CoreValue::Enum { value: Box::new(CoreValue::Struct(vec![])), index }], 0)
+        }
         CoreConcreteLibfunc::Struct(StructConcreteLibfunc::Construct(_)) => {
             (vec![CoreValue::Struct(inputs)], 0)
         }
@@ -245,26 +235,72 @@ pub fn simulate<
             (members, 0)
         }
         CoreConcreteLibfunc::Felt252Dict(Felt252DictConcreteLibfunc::New(_)) => {
-            let [] = take_inputs(inputs)?;
-            (vec![CoreValue::Dict(HashMap::new())], 0)
+            take_inputs!(let [CoreValue::SegmentArena] = inputs);
+            (vec![CoreValue::SegmentArena, CoreValue::Dict(HashMap::new())], 0)
         }
         CoreConcreteLibfunc::Felt252Dict(Felt252DictConcreteLibfunc::Squash(_)) => {
-            take_inputs!(let [CoreValue::RangeCheck, CoreValue::Dict(dict)] = inputs);
+            take_inputs!(let [
+                CoreValue::RangeCheck,
+                CoreValue::GasBuiltin(gas_counter),
+                CoreValue::SegmentArena,
+                CoreValue::Dict(dict),
+            ] = inputs);
             // Returning the same dict since it is exactly the same as the squashed one.
-            (vec![CoreValue::RangeCheck, CoreValue::Dict(dict)], 0)
+            (
+                vec![
+                    CoreValue::RangeCheck,
+                    CoreValue::GasBuiltin(gas_counter),
+                    CoreValue::SegmentArena,
+                    CoreValue::Dict(dict),
+                ],
+                0,
+            )
+        }
+        CoreConcreteLibfunc::Felt252DictEntry(Felt252DictEntryConcreteLibfunc::Get(
+            SignatureAndTypeConcreteLibfunc { ty, .. },
+        )) => {
+            take_inputs!(let [CoreValue::Dict(dict), CoreValue::Felt252(key)] = inputs);
+            let value = match dict.get(&key) {
+                Some(value) => value.clone(),
+                None => dict_default_value(&get_type, ty)?,
+            };
+            (vec![CoreValue::Felt252DictEntry { dict, key }, value], 0)
+        }
+        CoreConcreteLibfunc::Felt252DictEntry(Felt252DictEntryConcreteLibfunc::Finalize(_)) => {
+            take_inputs!(let [CoreValue::Felt252DictEntry { mut dict, key }, value] = inputs);
+            dict.insert(key, value);
+            (vec![CoreValue::Dict(dict)], 0)
         }
         CoreConcreteLibfunc::Pedersen(_) => {
-            unimplemented!("Simulation of the Pedersen hash function is not implemented yet.");
+            take_inputs!(let [
+                CoreValue::Pedersen, CoreValue::Felt252(lhs), CoreValue::Felt252(rhs)
+            ] = inputs);
+            (vec![CoreValue::Pedersen, CoreValue::Felt252(Pedersen::hash(&lhs, &rhs))], 0)
         }
         CoreConcreteLibfunc::Poseidon(_) => {
-            unimplemented!("Simulation of the Poseidon hash function is not implemented yet.");
-        }
-        CoreConcreteLibfunc::StarkNet(_) => {
-            unimplemented!("Simulation of the StarkNet functionalities is not implemented yet.")
+            take_inputs!(let [
+                CoreValue::Poseidon,
+                CoreValue::Felt252(s0),
+                CoreValue::Felt252(s1),
+                CoreValue::Felt252(s2),
+            ] = inputs);
+            let mut state = [s0, s1, s2];
+            Poseidon::hades_permutation(&mut state);
+            let [s0, s1, s2] = state;
+            (
+                vec![
+                    CoreValue::Poseidon,
+                    CoreValue::Felt252(s0),
+                    CoreValue::Felt252(s1),
+                    CoreValue::Felt252(s2),
+                ],
+                0,
+            )
         }
-        CoreConcreteLibfunc::Nullable(_) => {
-            unimplemented!("Simulation of nullable is not implemented yet.")
+        CoreConcreteLibfunc::StarkNet(libfunc) => {
+            simulate_starknet_libfunc(libfunc, inputs, syscall_handler)?
         }
+        CoreConcreteLibfunc::Nullable(libfunc) => simulate_nullable_libfunc(libfunc, inputs)?,
         CoreConcreteLibfunc::Debug(_) => {
             take_inputs!(let [CoreValue::Array(arr)] = inputs);
             let mut bytes = Vec::new();
@@ -283,16 +319,143 @@ pub fn simulate<
             let [value] = take_inputs(inputs)?;
             (vec![value.clone(), value], 0)
         }
-        CoreConcreteLibfunc::Cast(_) => unimplemented!(),
-        CoreConcreteLibfunc::Felt252DictEntry(_) => unimplemented!(),
-        CoreConcreteLibfunc::Uint256(_) => unimplemented!(),
-        CoreConcreteLibfunc::Uint512(_) => unimplemented!(),
-        CoreConcreteLibfunc::Bytes31(_) => unimplemented!(),
-        CoreConcreteLibfunc::Const(_) => unimplemented!(),
-        CoreConcreteLibfunc::Coupon(_) => unimplemented!(),
-        CoreConcreteLibfunc::BoundedInt(_) => unimplemented!(),
-        CoreConcreteLibfunc::Circuit(_) => unimplemented!(),
-        CoreConcreteLibfunc::IntRange(_) => unimplemented!(),
+        CoreConcreteLibfunc::Cast(libfunc) => simulate_cast_libfunc(libfunc, inputs, &get_type)?,
+        CoreConcreteLibfunc::Bytes31(libfunc) => simulate_bytes31_libfunc(libfunc, inputs)?,
+        CoreConcreteLibfunc::Const(
+            ConstConcreteLibfunc::AsBox(ConstAsBoxConcreteLibfunc { const_type, .. })
+            | ConstConcreteLibfunc::AsImmediate(ConstAsImmediateConcreteLibfunc {
+                const_type, ..
+            }),
+        ) => {
+            let [] = take_inputs(inputs)?;
+            (vec![const_value(&get_type, const_type)?], 0)
+        }
+        CoreConcreteLibfunc::Coupon(CouponConcreteLibfunc::Buy(_)) => {
+            let [] = take_inputs(inputs)?;
+            (vec![CoreValue::Coupon], 0)
+        }
+        CoreConcreteLibfunc::Coupon(CouponConcreteLibfunc::Refund(_)) => {
+            take_inputs!(let [CoreValue::Coupon] = inputs);
+            (vec![], 0)
+        }
+        CoreConcreteLibfunc::BoundedInt(libfunc) => simulate_bounded_int_libfunc(libfunc, inputs)?,
+        CoreConcreteLibfunc::Circuit(libfunc) => {
+            simulate_circuit_libfunc(libfunc, inputs, &get_type)?
+        }
+        CoreConcreteLibfunc::IntRange(libfunc) => simulate_int_range_libfunc(libfunc, inputs)?,
+    })
+}
+
+/// Simulates withdrawing `count` gas from `gas_counter`.
+fn simulate_withdraw_gas(gas_counter: i64, count: i64) -> (Vec<CoreValue>, usize) {
+    if gas_counter >= count {
+        // Have enough gas - return reduced counter and jump to success branch.
+        (vec![CoreValue::RangeCheck, CoreValue::GasBuiltin(gas_counter - count)], 0)
+    } else {
+        // Don't have enough gas - return the same counter and jump to failure branch.
+        (vec![CoreValue::RangeCheck, CoreValue::GasBuiltin(gas_counter)], 1)
+    }
+}
+
+/// Simulate array library functions.
+fn simulate_array_libfunc<'a>(
+    libfunc: &ArrayConcreteLibfunc,
+    inputs: Vec<CoreValue>,
+    get_type: &impl Fn(&ConcreteTypeId) -> Option<&'a CoreTypeConcrete>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    Ok(match libfunc {
+        ArrayConcreteLibfunc::New(_) => {
+            let [] = take_inputs(inputs)?;
+            (vec![CoreValue::Array(vec![])], 0)
+        }
+        ArrayConcreteLibfunc::SpanFromTuple(_) => {
+            take_inputs!(let [CoreValue::Struct(members)] = inputs);
+            (vec![CoreValue::Array(members)], 0)
+        }
+        ArrayConcreteLibfunc::TupleFromSpan(SignatureAndTypeConcreteLibfunc { ty, .. }) => {
+            take_inputs!(let [CoreValue::Array(arr)] = inputs);
+            if arr.len() == struct_members(get_type, ty)?.len() {
+                (vec![CoreValue::Struct(arr)], 0)
+            } else {
+                (vec![], 1)
+            }
+        }
+        ArrayConcreteLibfunc::Append(_) => {
+            take_inputs!(let [CoreValue::Array(mut arr), element] = inputs);
+            arr.push(element);
+            (vec![CoreValue::Array(arr)], 0)
+        }
+        ArrayConcreteLibfunc::PopFront(_) | ArrayConcreteLibfunc::SnapshotPopFront(_) => {
+            take_inputs!(let [CoreValue::Array(mut arr)] = inputs);
+            if arr.is_empty() {
+                (vec![CoreValue::Array(arr)], 1)
+            } else {
+                let front = arr.remove(0);
+                (vec![CoreValue::Array(arr), front], 0)
+            }
+        }
+        ArrayConcreteLibfunc::PopFrontConsume(_) => {
+            take_inputs!(let [CoreValue::Array(mut arr)] = inputs);
+            if arr.is_empty() { (vec![CoreValue::Array(arr)], 1) } else { (vec![arr.remove(0)], 0) }
+        }
+        ArrayConcreteLibfunc::SnapshotPopBack(_) => {
+            take_inputs!(let [CoreValue::Array(mut arr)] = inputs);
+            match arr.pop() {
+                Some(back) => (vec![CoreValue::Array(arr), back], 0),
+                None => (vec![CoreValue::Array(arr)], 1),
+            }
+        }
+        ArrayConcreteLibfunc::SnapshotMultiPopFront(ConcreteMultiPopLibfunc {
+            popped_ty, ..
+        }) => {
+            take_inputs!(let [CoreValue::RangeCheck, CoreValue::Array(mut arr)] = inputs);
+            let count = struct_members(get_type, popped_ty)?.len();
+            if arr.len() < count {
+                (vec![CoreValue::RangeCheck, CoreValue::Array(arr)], 1)
+            } else {
+                let popped = arr.drain(..count).collect();
+                (vec![CoreValue::RangeCheck, CoreValue::Array(arr), CoreValue::Struct(popped)], 0)
+            }
+        }
+        ArrayConcreteLibfunc::SnapshotMultiPopBack(ConcreteMultiPopLibfunc {
+            popped_ty, ..
+        }) => {
+            take_inputs!(let [CoreValue::RangeCheck, CoreValue::Array(mut arr)] = inputs);
+            let count = struct_members(get_type, popped_ty)?.len();
+            if arr.len() < count {
+                (vec![CoreValue::RangeCheck, CoreValue::Array(arr)], 1)
+            } else {
+                let popped = arr.split_off(arr.len() - count);
+                (vec![CoreValue::RangeCheck, CoreValue::Array(arr), CoreValue::Struct(popped)], 0)
+            }
+        }
+        ArrayConcreteLibfunc::Get(_) => {
+            take_inputs!(
+                let [CoreValue::RangeCheck, CoreValue::Array(arr), CoreValue::Uint32(idx)] = inputs
+            );
+            match arr.get(idx as usize).cloned() {
+                Some(element) => (vec![CoreValue::RangeCheck, element], 0),
+                None => (vec![CoreValue::RangeCheck], 1),
+            }
+        }
+        ArrayConcreteLibfunc::Slice(_) => {
+            take_inputs!(let [
+                CoreValue::RangeCheck,
+                CoreValue::Array(arr),
+                CoreValue::Uint32(start),
+                CoreValue::Uint32(length),
+            ] = inputs);
+            match arr.get(start as usize..(start as usize + length as usize)) {
+                Some(elements) => {
+                    (vec![CoreValue::RangeCheck, CoreValue::Array(elements.to_vec())], 0)
+                }
+                None => (vec![CoreValue::RangeCheck], 1),
+            }
+        }
+        ArrayConcreteLibfunc::Len(_) => {
+            take_inputs!(let [CoreValue::Array(arr)] = inputs);
+            (vec![CoreValue::Uint32(arr.len() as u32)], 0)
+        }
     })
 }
 
@@ -372,7 +535,18 @@ fn simulate_u128_libfunc(
             take_inputs!(let [CoreValue::RangeCheck, CoreValue::Felt252(value)] = inputs);
             match value.to_u128() {
                 Some(value) => (vec![CoreValue::RangeCheck, CoreValue::Uint128(value)], 0),
-                None => (vec![CoreValue::RangeCheck], 1),
+                None => {
+                    // Overflow - returning the value as its high and low 128 bit limbs.
+                    let (high, low) = value.to_bigint().div_rem(&(BigInt::one() << 128));
+                    (
+                        vec![
+                            CoreValue::RangeCheck,
+                            CoreValue::Uint128(high.to_u128().unwrap()),
+                            CoreValue::Uint128(low.to_u128().unwrap()),
+                        ],
+                        1,
+                    )
+                }
             }
         }
         Uint128Concrete::ToFelt252(_) => {
@@ -404,11 +578,11 @@ fn simulate_u128_libfunc(
         }
         Uint128Concrete::GuaranteeMul(_) => {
             take_inputs!(let [CoreValue::Uint128(lhs), CoreValue::Uint128(rhs)] = inputs);
-            let (limb1, limb0) = (BigInt::from(lhs) * rhs).div_rem(&BigInt::one().pow(128));
+            let (high, low) = (BigInt::from(lhs) * rhs).div_rem(&(BigInt::one() << 128));
             (
                 vec![
-                    CoreValue::Uint128(limb0.to_u128().unwrap()),
-                    CoreValue::Uint128(limb1.to_u128().unwrap()),
+                    CoreValue::Uint128(high.to_u128().unwrap()),
+                    CoreValue::Uint128(low.to_u128().unwrap()),
                     CoreValue::U128MulGuarantee,
                 ],
                 0,
@@ -433,7 +607,10 @@ fn simulate_u128_libfunc(
             // "True" branch (branch 1) is the case a == b.
             (vec![], usize::from(lhs == rhs))
         }
-        Uint128Concrete::ByteReverse(_) => todo!("ByteReverse"),
+        Uint128Concrete::ByteReverse(_) => {
+            take_inputs!(let [CoreValue::Bitwise, CoreValue::Uint128(value)] = inputs);
+            (vec![CoreValue::Bitwise, CoreValue::Uint128(value.swap_bytes())], 0)
+        }
         Uint128Concrete::Bitwise(_) => {
             take_inputs!(let [
                 CoreValue::Bitwise, CoreValue::Uint128(lhs), CoreValue::Uint128(rhs)
@@ -442,8 +619,8 @@ fn simulate_u128_libfunc(
                 vec![
                     CoreValue::Bitwise,
                     CoreValue::Uint128(lhs & rhs),
-                    CoreValue::Uint128(lhs | rhs),
                     CoreValue::Uint128(lhs ^ rhs),
+                    CoreValue::Uint128(lhs | rhs),
                 ],
                 0,
             )
@@ -487,15 +664,43 @@ fn simulate_u8_libfunc(
             (vec![CoreValue::Felt252(Felt252::from(value))], 0)
         }
         Uint8Concrete::FromFelt252(_) => {
-            take_inputs!(let [CoreValue::RangeCheck, CoreValue::Uint8(value)] = inputs);
+            take_inputs!(let [CoreValue::RangeCheck, CoreValue::Felt252(value)] = inputs);
             match value.to_u8() {
                 Some(value) => (vec![CoreValue::RangeCheck, CoreValue::Uint8(value)], 0),
                 None => (vec![CoreValue::RangeCheck], 1),
             }
         }
-        Uint8Concrete::IsZero(_) => unimplemented!(),
-        Uint8Concrete::Divmod(_) => unimplemented!(),
-        Uint8Concrete::Bitwise(_) => unimplemented!(),
+        Uint8Concrete::IsZero(_) => {
+            take_inputs!(let [CoreValue::Uint8(value)] = inputs);
+            if value.is_zero() { (vec![], 0) } else { (vec![CoreValue::Uint8(value)], 1) }
+        }
+        Uint8Concrete::Divmod(_) => {
+            take_inputs!(let [
+                CoreValue::RangeCheck, CoreValue::Uint8(lhs), CoreValue::Uint8(rhs)
+            ] = inputs);
+            (
+                vec![
+                    CoreValue::RangeCheck,
+                    CoreValue::Uint8(lhs / rhs),
+                    CoreValue::Uint8(lhs % rhs),
+                ],
+                0,
+            )
+        }
+        Uint8Concrete::Bitwise(_) => {
+            take_inputs!(let [
+                CoreValue::Bitwise, CoreValue::Uint8(lhs), CoreValue::Uint8(rhs)
+            ] = inputs);
+            (
+                vec![
+                    CoreValue::Bitwise,
+                    CoreValue::Uint8(lhs & rhs),
+                    CoreValue::Uint8(lhs ^ rhs),
+                    CoreValue::Uint8(lhs | rhs),
+                ],
+                0,
+            )
+        }
         Uint8Concrete::WideMul(_) => {
             take_inputs!(let [CoreValue::Uint8(lhs), CoreValue::Uint8(rhs)] = inputs);
             (vec![CoreValue::Uint16(u16::from(lhs) * u16::from(rhs))], 0)
@@ -539,15 +744,43 @@ fn simulate_u16_libfunc(
             (vec![CoreValue::Felt252(Felt252::from(value))], 0)
         }
         Uint16Concrete::FromFelt252(_) => {
-            take_inputs!(let [CoreValue::RangeCheck, CoreValue::Uint16(value)] = inputs);
+            take_inputs!(let [CoreValue::RangeCheck, CoreValue::Felt252(value)] = inputs);
             match value.to_u16() {
                 Some(value) => (vec![CoreValue::RangeCheck, CoreValue::Uint16(value)], 0),
                 None => (vec![CoreValue::RangeCheck], 1),
             }
         }
-        Uint16Concrete::IsZero(_) => unimplemented!(),
-        Uint16Concrete::Divmod(_) => unimplemented!(),
-        Uint16Concrete::Bitwise(_) => unimplemented!(),
+        Uint16Concrete::IsZero(_) => {
+            take_inputs!(let [CoreValue::Uint16(value)] = inputs);
+            if value.is_zero() { (vec![], 0) } else { (vec![CoreValue::Uint16(value)], 1) }
+        }
+        Uint16Concrete::Divmod(_) => {
+            take_inputs!(let [
+                CoreValue::RangeCheck, CoreValue::Uint16(lhs), CoreValue::Uint16(rhs)
+            ] = inputs);
+            (
+                vec![
+                    CoreValue::RangeCheck,
+                    CoreValue::Uint16(lhs / rhs),
+                    CoreValue::Uint16(lhs % rhs),
+                ],
+                0,
+            )
+        }
+        Uint16Concrete::Bitwise(_) => {
+            take_inputs!(let [
+                CoreValue::Bitwise, CoreValue::Uint16(lhs), CoreValue::Uint16(rhs)
+            ] = inputs);
+            (
+                vec![
+                    CoreValue::Bitwise,
+                    CoreValue::Uint16(lhs & rhs),
+                    CoreValue::Uint16(lhs ^ rhs),
+                    CoreValue::Uint16(lhs | rhs),
+                ],
+                0,
+            )
+        }
         Uint16Concrete::WideMul(_) => {
             take_inputs!(let [CoreValue::Uint16(lhs), CoreValue::Uint16(rhs)] = inputs);
             (vec![CoreValue::Uint32(u32::from(lhs) * u32::from(rhs))], 0)
@@ -597,9 +830,37 @@ fn simulate_u32_libfunc(
                 None => (vec![CoreValue::RangeCheck], 1),
             }
         }
-        Uint32Concrete::IsZero(_) => unimplemented!(),
-        Uint32Concrete::Divmod(_) => unimplemented!(),
-        Uint32Concrete::Bitwise(_) => unimplemented!(),
+        Uint32Concrete::IsZero(_) => {
+            take_inputs!(let [CoreValue::Uint32(value)] = inputs);
+            if value.is_zero() { (vec![], 0) } else { (vec![CoreValue::Uint32(value)], 1) }
+        }
+        Uint32Concrete::Divmod(_) => {
+            take_inputs!(let [
+                CoreValue::RangeCheck, CoreValue::Uint32(lhs), CoreValue::Uint32(rhs)
+            ] = inputs);
+            (
+                vec![
+                    CoreValue::RangeCheck,
+                    CoreValue::Uint32(lhs / rhs),
+                    CoreValue::Uint32(lhs % rhs),
+                ],
+                0,
+            )
+        }
+        Uint32Concrete::Bitwise(_) => {
+            take_inputs!(let [
+                CoreValue::Bitwise, CoreValue::Uint32(lhs), CoreValue::Uint32(rhs)
+            ] = inputs);
+            (
+                vec![
+                    CoreValue::Bitwise,
+                    CoreValue::Uint32(lhs & rhs),
+                    CoreValue::Uint32(lhs ^ rhs),
+                    CoreValue::Uint32(lhs | rhs),
+                ],
+                0,
+            )
+        }
         Uint32Concrete::WideMul(_) => {
             take_inputs!(let [CoreValue::Uint32(lhs), CoreValue::Uint32(rhs)] = inputs);
             (vec![CoreValue::Uint64(u64::from(lhs) * u64::from(rhs))], 0)
@@ -637,7 +898,7 @@ fn simulate_u64_libfunc(
             (vec![], usize::from(lhs == rhs))
         }
         Uint64Concrete::ToFelt252(_) => {
-            take_inputs!(let [CoreValue::RangeCheck, CoreValue::Uint64(value)] = inputs);
+            take_inputs!(let [CoreValue::Uint64(value)] = inputs);
             (vec![CoreValue::Felt252(Felt252::from(value))], 0)
         }
         Uint64Concrete::FromFelt252(_) => {
@@ -647,14 +908,1344 @@ fn simulate_u64_libfunc(
                 None => (vec![CoreValue::RangeCheck], 1),
             }
         }
-        Uint64Concrete::IsZero(_) => unimplemented!(),
-        Uint64Concrete::Divmod(_) => unimplemented!(),
-        Uint64Concrete::Bitwise(_) => unimplemented!(),
-        Uint64Concrete::WideMul(_) => {
-            take_inputs!(let [CoreValue::Uint64(lhs), CoreValue::Uint64(rhs)] = inputs);
-            (vec![CoreValue::Uint128(u128::from(lhs) * u128::from(rhs))], 0)
+        Uint64Concrete::IsZero(_) => {
+            take_inputs!(let [CoreValue::Uint64(value)] = inputs);
+            if value.is_zero() { (vec![], 0) } else { (vec![CoreValue::Uint64(value)], 1) }
         }
-    })
+        Uint64Concrete::Divmod(_) => {
+            take_inputs!(let [CoreValue::RangeCheck, CoreValue::Uint64(lhs), CoreValue::Uint64(rhs)] = inputs);
+            (
+                vec![
+                    CoreValue::RangeCheck,
+                    CoreValue::Uint64(lhs / rhs),
+                    CoreValue::Uint64(lhs % rhs),
+                ],
+                0,
+            )
+        }
+        Uint64Concrete::Bitwise(_) => {
+            take_inputs!(let [
+                CoreValue::Bitwise, CoreValue::Uint64(lhs), CoreValue::Uint64(rhs)
+            ] = inputs);
+            (
+                vec![
+                    CoreValue::Bitwise,
+                    CoreValue::Uint64(lhs & rhs),
+                    CoreValue::Uint64(lhs ^ rhs),
+                    CoreValue::Uint64(lhs | rhs),
+                ],
+                0,
+            )
+        }
+        Uint64Concrete::WideMul(_) => {
+            take_inputs!(let [CoreValue::Uint64(lhs), CoreValue::Uint64(rhs)] = inputs);
+            (vec![CoreValue::Uint128(u128::from(lhs) * u128::from(rhs))], 0)
+        }
+    })
+}
+
+/// Simulate u256 library functions.
+fn simulate_u256_libfunc(
+    libfunc: &Uint256Concrete,
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    Ok(match libfunc {
+        Uint256Concrete::IsZero(_) => {
+            let [value] = take_inputs(inputs)?;
+            if u128_limbs_value(&value)?.is_zero() { (vec![], 0) } else { (vec![value], 1) }
+        }
+        Uint256Concrete::Divmod(_) => {
+            take_inputs!(let [CoreValue::RangeCheck, lhs, rhs] = inputs);
+            let (quotient, remainder) = u128_limbs_value(&lhs)?.div_rem(&u128_limbs_value(&rhs)?);
+            (
+                vec![
+                    CoreValue::RangeCheck,
+                    u128_limbs_struct(&quotient, 2),
+                    u128_limbs_struct(&remainder, 2),
+                    CoreValue::U128MulGuarantee,
+                ],
+                0,
+            )
+        }
+        Uint256Concrete::SquareRoot(_) => {
+            take_inputs!(let [CoreValue::RangeCheck, value] = inputs);
+            let root = u128_limbs_value(&value)?.sqrt();
+            (vec![CoreValue::RangeCheck, CoreValue::Uint128(root.to_u128().unwrap())], 0)
+        }
+        Uint256Concrete::InvModN(_) => {
+            take_inputs!(let [CoreValue::RangeCheck, value, modulus] = inputs);
+            let (value, modulus) = (u128_limbs_value(&value)?, u128_limbs_value(&modulus)?);
+            let egcd = value.extended_gcd(&modulus);
+            if !modulus.is_one() && egcd.gcd.is_one() {
+                let mut outputs =
+                    vec![CoreValue::RangeCheck, u128_limbs_struct(&egcd.x.mod_floor(&modulus), 2)];
+                outputs.extend(repeat_n(CoreValue::U128MulGuarantee, 8));
+                (outputs, 0)
+            } else {
+                (
+                    vec![
+                        CoreValue::RangeCheck,
+                        CoreValue::U128MulGuarantee,
+                        CoreValue::U128MulGuarantee,
+                    ],
+                    1,
+                )
+            }
+        }
+    })
+}
+
+/// Simulate u512 library functions.
+fn simulate_u512_libfunc(
+    libfunc: &Uint512Concrete,
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    Ok(match libfunc {
+        Uint512Concrete::DivModU256(_) => {
+            take_inputs!(let [CoreValue::RangeCheck, lhs, rhs] = inputs);
+            let (quotient, remainder) = u128_limbs_value(&lhs)?.div_rem(&u128_limbs_value(&rhs)?);
+            let mut outputs = vec![
+                CoreValue::RangeCheck,
+                u128_limbs_struct(&quotient, 4),
+                u128_limbs_struct(&remainder, 2),
+            ];
+            outputs.extend(repeat_n(CoreValue::U128MulGuarantee, 5));
+            (outputs, 0)
+        }
+    })
+}
+
+/// Simulate signed integer library functions, for the types smaller than i128.
+fn simulate_sint_libfunc<TSintTraits: SintTraits + IntMulTraits + IsZeroTraits>(
+    libfunc: &SintConcrete<TSintTraits>,
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    let ty = &<TSintTraits as IntTraits>::GENERIC_TYPE_ID;
+    let bits = std::mem::size_of::<<TSintTraits as IntTraits>::IntType>() * 8;
+    match libfunc {
+        SintConcrete::Const(IntConstConcreteLibfunc { c, .. }) => {
+            let [] = take_inputs(inputs)?;
+            Ok((vec![typed_int(ty, (*c).into())?], 0))
+        }
+        SintConcrete::Equal(_) => simulate_int_equal(ty, inputs),
+        SintConcrete::ToFelt252(_) => simulate_int_to_felt252(ty, inputs),
+        SintConcrete::FromFelt252(_) => simulate_int_from_felt252(ty, inputs),
+        SintConcrete::Operation(libfunc) => {
+            simulate_sint_operation(ty, bits, libfunc.operator, inputs)
+        }
+        SintConcrete::Diff(_) => {
+            simulate_sint_diff(ty, &TSintTraits::UNSIGNED_INT_TYPE, bits, inputs)
+        }
+        SintConcrete::IsZero(_) => simulate_int_is_zero(ty, inputs),
+        SintConcrete::WideMul(_) => {
+            let [lhs, rhs] = take_inputs(inputs)?;
+            let product = typed_int_value(ty, &lhs)? * typed_int_value(ty, &rhs)?;
+            Ok((vec![typed_int(&TSintTraits::WIDE_MUL_RES_TYPE_ID, product)?], 0))
+        }
+    }
+}
+
+/// Simulate i128 library functions.
+fn simulate_i128_libfunc(
+    libfunc: &Sint128Concrete,
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    let ty = &Sint128Type::ID;
+    match libfunc {
+        Sint128Concrete::Const(IntConstConcreteLibfunc { c, .. }) => {
+            let [] = take_inputs(inputs)?;
+            Ok((vec![CoreValue::Sint128(*c)], 0))
+        }
+        Sint128Concrete::Equal(_) => simulate_int_equal(ty, inputs),
+        Sint128Concrete::ToFelt252(_) => simulate_int_to_felt252(ty, inputs),
+        Sint128Concrete::FromFelt252(_) => simulate_int_from_felt252(ty, inputs),
+        Sint128Concrete::Operation(libfunc) => {
+            simulate_sint_operation(ty, 128, libfunc.operator, inputs)
+        }
+        Sint128Concrete::Diff(_) => simulate_sint_diff(ty, &Uint128Type::ID, 128, inputs),
+        Sint128Concrete::IsZero(_) => simulate_int_is_zero(ty, inputs),
+    }
+}
+
+/// Simulates the equality check of integers of the generic type `ty`.
+fn simulate_int_equal(
+    ty: &GenericTypeId,
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    let [lhs, rhs] = take_inputs(inputs)?;
+    // "False" branch (branch 0) is the case a != b.
+    // "True" branch (branch 1) is the case a == b.
+    Ok((vec![], usize::from(typed_int_value(ty, &lhs)? == typed_int_value(ty, &rhs)?)))
+}
+
+/// Simulates the conversion of an integer of the generic type `ty` to a felt252.
+fn simulate_int_to_felt252(
+    ty: &GenericTypeId,
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    let [value] = take_inputs(inputs)?;
+    Ok((vec![CoreValue::Felt252(Felt252::from(&typed_int_value(ty, &value)?))], 0))
+}
+
+/// Simulates the conversion of a felt252 to an integer of the generic type `ty`.
+fn simulate_int_from_felt252(
+    ty: &GenericTypeId,
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    take_inputs!(let [CoreValue::RangeCheck, CoreValue::Felt252(value)] = inputs);
+    Ok(match int_of_generic_type(ty, felt252_to_signed(&value)) {
+        Some(value) => (vec![CoreValue::RangeCheck, value], 0),
+        None => (vec![CoreValue::RangeCheck], 1),
+    })
+}
+
+/// Simulates the zero check of an integer of the generic type `ty`.
+fn simulate_int_is_zero(
+    ty: &GenericTypeId,
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    let [value] = take_inputs(inputs)?;
+    Ok(if typed_int_value(ty, &value)?.is_zero() { (vec![], 0) } else { (vec![value], 1) })
+}
+
+/// Simulates an overflowing operation of signed integers of the generic type `ty`, with `bits`
+/// bits. The branches are in range, below range and above range, where out of range results are
+/// wrapped.
+fn simulate_sint_operation(
+    ty: &GenericTypeId,
+    bits: usize,
+    operator: IntOperator,
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    take_inputs!(let [CoreValue::RangeCheck, lhs, rhs] = inputs);
+    let (lhs, rhs) = (typed_int_value(ty, &lhs)?, typed_int_value(ty, &rhs)?);
+    let value = match operator {
+        IntOperator::OverflowingAdd => lhs + rhs,
+        IntOperator::OverflowingSub => lhs - rhs,
+    };
+    let modulus = BigInt::one() << bits;
+    let upper = BigInt::one() << (bits - 1);
+    let (value, branch) = if value < -&upper {
+        (value + modulus, 1)
+    } else if value >= upper {
+        (value - modulus, 2)
+    } else {
+        (value, 0)
+    };
+    Ok((vec![CoreValue::RangeCheck, typed_int(ty, value)?], branch))
+}
+
+/// Simulates the difference of signed integers of the generic type `ty`, with `bits` bits. The
+/// result is of the matching unsigned type `unsigned_ty`, wrapped if negative.
+fn simulate_sint_diff(
+    ty: &GenericTypeId,
+    unsigned_ty: &GenericTypeId,
+    bits: usize,
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    take_inputs!(let [CoreValue::RangeCheck, lhs, rhs] = inputs);
+    let diff = typed_int_value(ty, &lhs)? - typed_int_value(ty, &rhs)?;
+    Ok(if diff.is_negative() {
+        (vec![CoreValue::RangeCheck, typed_int(unsigned_ty, diff + (BigInt::one() << bits))?], 1)
+    } else {
+        (vec![CoreValue::RangeCheck, typed_int(unsigned_ty, diff)?], 0)
+    })
+}
+
+/// Simulate bounded int library functions.
+fn simulate_bounded_int_libfunc(
+    libfunc: &BoundedIntConcreteLibfunc,
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    Ok(match libfunc {
+        BoundedIntConcreteLibfunc::Add(_) => {
+            let [lhs, rhs] = take_inputs(inputs)?;
+            (vec![CoreValue::BoundedInt(checked_int_value(&lhs)? + checked_int_value(&rhs)?)], 0)
+        }
+        BoundedIntConcreteLibfunc::Sub(_) => {
+            let [lhs, rhs] = take_inputs(inputs)?;
+            (vec![CoreValue::BoundedInt(checked_int_value(&lhs)? - checked_int_value(&rhs)?)], 0)
+        }
+        BoundedIntConcreteLibfunc::Mul(_) => {
+            let [lhs, rhs] = take_inputs(inputs)?;
+            (vec![CoreValue::BoundedInt(checked_int_value(&lhs)? * checked_int_value(&rhs)?)], 0)
+        }
+        BoundedIntConcreteLibfunc::DivRem(_) => {
+            take_inputs!(let [CoreValue::RangeCheck, lhs, rhs] = inputs);
+            let (quotient, remainder) = checked_int_value(&lhs)?.div_rem(&checked_int_value(&rhs)?);
+            (
+                vec![
+                    CoreValue::RangeCheck,
+                    CoreValue::BoundedInt(quotient),
+                    CoreValue::BoundedInt(remainder),
+                ],
+                0,
+            )
+        }
+        BoundedIntConcreteLibfunc::Constrain(BoundedIntConstrainConcreteLibfunc {
+            boundary,
+            ..
+        }) => {
+            take_inputs!(let [CoreValue::RangeCheck, value] = inputs);
+            let value = checked_int_value(&value)?;
+            let branch = usize::from(&value >= boundary);
+            (vec![CoreValue::RangeCheck, CoreValue::BoundedInt(value)], branch)
+        }
+        BoundedIntConcreteLibfunc::IsZero(_) => {
+            let [value] = take_inputs(inputs)?;
+            if checked_int_value(&value)?.is_zero() { (vec![], 0) } else { (vec![value], 1) }
+        }
+        BoundedIntConcreteLibfunc::WrapNonZero(_) => {
+            let [value] = take_inputs(inputs)?;
+            (vec![value], 0)
+        }
+    })
+}
+
+/// Simulate int range library functions.
+fn simulate_int_range_libfunc(
+    libfunc: &IntRangeConcreteLibfunc,
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    Ok(match libfunc {
+        IntRangeConcreteLibfunc::TryNew(_) => {
+            take_inputs!(let [CoreValue::RangeCheck, start, end] = inputs);
+            if checked_int_value(&start)? <= checked_int_value(&end)? {
+                let range = CoreValue::IntRange { start: Box::new(start), end: Box::new(end) };
+                (vec![CoreValue::RangeCheck, range], 0)
+            } else {
+                // An invalid range is returned as the empty range `[end, end)`.
+                let range =
+                    CoreValue::IntRange { start: Box::new(end.clone()), end: Box::new(end) };
+                (vec![CoreValue::RangeCheck, range], 1)
+            }
+        }
+        IntRangeConcreteLibfunc::PopFront(_) => {
+            take_inputs!(let [CoreValue::IntRange { start, end }] = inputs);
+            let start_value = checked_int_value(&start)?;
+            if start_value < checked_int_value(&end)? {
+                let next = int_like(&start, start_value + 1)
+                    .ok_or(LibfuncSimulationError::WrongArgType)?;
+                (vec![CoreValue::IntRange { start: Box::new(next), end }, *start], 1)
+            } else {
+                (vec![], 0)
+            }
+        }
+    })
+}
+
+/// Simulate cast library functions.
+fn simulate_cast_libfunc<'a>(
+    libfunc: &CastConcreteLibfunc,
+    inputs: Vec<CoreValue>,
+    get_type: &impl Fn(&ConcreteTypeId) -> Option<&'a CoreTypeConcrete>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    Ok(match libfunc {
+        CastConcreteLibfunc::Downcast(DowncastConcreteLibfunc { to_ty, to_range, .. }) => {
+            take_inputs!(let [CoreValue::RangeCheck, value] = inputs);
+            let value = checked_int_value(&value)?;
+            if to_range.lower <= value && value < to_range.upper {
+                (vec![CoreValue::RangeCheck, int_of_type(get_type, to_ty, value)?], 0)
+            } else {
+                (vec![CoreValue::RangeCheck], 1)
+            }
+        }
+        CastConcreteLibfunc::Upcast(libfunc) => {
+            let [value] = take_inputs(inputs)?;
+            let to_ty = &libfunc.signature().branch_signatures[0].vars[0].ty;
+            (vec![int_of_type(get_type, to_ty, checked_int_value(&value)?)?], 0)
+        }
+    })
+}
+
+/// Simulate bytes31 library functions.
+fn simulate_bytes31_libfunc(
+    libfunc: &Bytes31ConcreteLibfunc,
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    match libfunc {
+        Bytes31ConcreteLibfunc::Const(SignatureAndConstConcreteLibfunc { c, .. }) => {
+            let [] = take_inputs(inputs)?;
+            Ok((vec![CoreValue::Felt252(Felt252::from(c))], 0))
+        }
+        Bytes31ConcreteLibfunc::ToFelt252(_) => {
+            take_inputs!(let [CoreValue::Felt252(value)] = inputs);
+            Ok((vec![CoreValue::Felt252(value)], 0))
+        }
+        Bytes31ConcreteLibfunc::TryFromFelt252(_) => simulate_bounded_felt252_try_from(inputs, 248),
+    }
+}
+
+/// Simulates the conversion of a felt252 to a felt252 based type holding the values in
+/// `[0, 2**bits)`.
+fn simulate_bounded_felt252_try_from(
+    inputs: Vec<CoreValue>,
+    bits: usize,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    take_inputs!(let [CoreValue::RangeCheck, CoreValue::Felt252(value)] = inputs);
+    Ok(if value.to_bigint() < BigInt::one() << bits {
+        (vec![CoreValue::RangeCheck, CoreValue::Felt252(value)], 0)
+    } else {
+        (vec![CoreValue::RangeCheck], 1)
+    })
+}
+
+/// Simulate nullable library functions.
+fn simulate_nullable_libfunc(
+    libfunc: &NullableConcreteLibfunc,
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    Ok(match libfunc {
+        NullableConcreteLibfunc::Null(_) => {
+            let [] = take_inputs(inputs)?;
+            (vec![CoreValue::Nullable(None)], 0)
+        }
+        NullableConcreteLibfunc::NullableFromBox(_) => {
+            let [value] = take_inputs(inputs)?;
+            (vec![CoreValue::Nullable(Some(Box::new(value)))], 0)
+        }
+        NullableConcreteLibfunc::MatchNullable(_) => {
+            take_inputs!(let [CoreValue::Nullable(value)] = inputs);
+            match value {
+                None => (vec![], 0),
+                Some(value) => (vec![*value], 1),
+            }
+        }
+        NullableConcreteLibfunc::ForwardSnapshot(_) => {
+            take_inputs!(let [CoreValue::Nullable(value)] = inputs);
+            (vec![CoreValue::Nullable(value)], 0)
+        }
+    })
+}
+
+/// Simulate EC library functions.
+fn simulate_ec_libfunc(
+    libfunc: &EcConcreteLibfunc,
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    const BETA: Felt252 = Felt252::from_hex_unchecked(
+        "0x6f21413efbe40de150e596d72f7a8c5609ad26c15c915c1f4cdfcb99cee9e89",
+    );
+    Ok(match libfunc {
+        EcConcreteLibfunc::Zero(_) => {
+            let [] = take_inputs(inputs)?;
+            (vec![CoreValue::EcPoint(Felt252::ZERO, Felt252::ZERO)], 0)
+        }
+        EcConcreteLibfunc::TryNew(_) => {
+            take_inputs!(let [CoreValue::Felt252(x), CoreValue::Felt252(y)] = inputs);
+            // If the point is on the curve use the fallthrough branch and return the point.
+            if y * y == x * x * x + x + BETA {
+                (vec![CoreValue::EcPoint(x, y)], 0)
+            } else {
+                (vec![], 1)
+            }
+        }
+        EcConcreteLibfunc::PointFromX(_) => {
+            take_inputs!(let [CoreValue::RangeCheck, CoreValue::Felt252(x)] = inputs);
+            match (x * x * x + x + BETA).sqrt() {
+                Some(y) => (vec![CoreValue::RangeCheck, CoreValue::EcPoint(x, y)], 0),
+                None => (vec![CoreValue::RangeCheck], 1),
+            }
+        }
+        EcConcreteLibfunc::UnwrapPoint(_) => {
+            take_inputs!(let [CoreValue::EcPoint(x, y)] = inputs);
+            (vec![CoreValue::Felt252(x), CoreValue::Felt252(y)], 0)
+        }
+        EcConcreteLibfunc::Neg(_) => {
+            take_inputs!(let [CoreValue::EcPoint(x, y)] = inputs);
+            (vec![CoreValue::EcPoint(x, Felt252::ZERO - y)], 0)
+        }
+        EcConcreteLibfunc::IsZero(_) => {
+            take_inputs!(let [CoreValue::EcPoint(x, y)] = inputs);
+            // The zero point is the only point with a zero `y` coordinate.
+            if y == Felt252::ZERO { (vec![], 0) } else { (vec![CoreValue::EcPoint(x, y)], 1) }
+        }
+        EcConcreteLibfunc::StateInit(_) => {
+            let [] = take_inputs(inputs)?;
+            (vec![CoreValue::EcState(Felt252::ZERO, Felt252::ZERO)], 0)
+        }
+        EcConcreteLibfunc::StateAdd(_) => {
+            take_inputs!(let [CoreValue::EcState(sx, sy), CoreValue::EcPoint(x, y)] = inputs);
+            let (sx, sy) = ec_add((sx, sy), (x, y));
+            (vec![CoreValue::EcState(sx, sy)], 0)
+        }
+        EcConcreteLibfunc::StateAddMul(_) => {
+            take_inputs!(let [
+                CoreValue::EcOp,
+                CoreValue::EcState(sx, sy),
+                CoreValue::Felt252(m),
+                CoreValue::EcPoint(x, y),
+            ] = inputs);
+            let (sx, sy) = ec_add((sx, sy), ec_mul((x, y), &m));
+            (vec![CoreValue::EcOp, CoreValue::EcState(sx, sy)], 0)
+        }
+        EcConcreteLibfunc::StateFinalize(_) => {
+            take_inputs!(let [CoreValue::EcState(x, y)] = inputs);
+            if y == Felt252::ZERO { (vec![], 1) } else { (vec![CoreValue::EcPoint(x, y)], 0) }
+        }
+    })
+}
+
+/// Returns the sum of two EC points. The zero point is represented as `(0, 0)`.
+fn ec_add(p: (Felt252, Felt252), q: (Felt252, Felt252)) -> (Felt252, Felt252) {
+    if p.1 == Felt252::ZERO {
+        return q;
+    }
+    if q.1 == Felt252::ZERO {
+        return p;
+    }
+    if p.0 == q.0 {
+        return if p.1 == q.1 { ec_double(p) } else { (Felt252::ZERO, Felt252::ZERO) };
+    }
+    let slope = (q.1 - p.1).field_div(&NonZeroFelt252::from_felt_unchecked(q.0 - p.0));
+    let x = slope * slope - p.0 - q.0;
+    (x, slope * (p.0 - x) - p.1)
+}
+
+/// Returns the double of an EC point.
+fn ec_double(p: (Felt252, Felt252)) -> (Felt252, Felt252) {
+    if p.1 == Felt252::ZERO {
+        return p;
+    }
+    // The curve is `y^2 = x^3 + alpha * x + beta`, with `alpha = 1`.
+    let slope = (Felt252::THREE * p.0 * p.0 + Felt252::ONE)
+        .field_div(&NonZeroFelt252::from_felt_unchecked(Felt252::TWO * p.1));
+    let x = slope * slope - Felt252::TWO * p.0;
+    (x, slope * (p.0 - x) - p.1)
+}
+
+/// Returns the multiplication of an EC point by a scalar.
+fn ec_mul(p: (Felt252, Felt252), scalar: &Felt252) -> (Felt252, Felt252) {
+    let scalar = scalar.to_bigint();
+    let mut result = (Felt252::ZERO, Felt252::ZERO);
+    let mut addend = p;
+    for i in 0..scalar.bits() {
+        if scalar.bit(i) {
+            result = ec_add(result, addend);
+        }
+        addend = ec_double(addend);
+    }
+    result
+}
+
+/// Simulate StarkNet library functions.
+fn simulate_starknet_libfunc(
+    libfunc: &StarkNetConcreteLibfunc,
+    inputs: Vec<CoreValue>,
+    syscall_handler: &dyn SyscallHandler,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    match libfunc {
+        StarkNetConcreteLibfunc::ClassHashConst(SignatureAndConstConcreteLibfunc { c, .. })
+        | StarkNetConcreteLibfunc::ContractAddressConst(SignatureAndConstConcreteLibfunc {
+            c,
+            ..
+        })
+        | StarkNetConcreteLibfunc::StorageBaseAddressConst(SignatureAndConstConcreteLibfunc {
+            c,
+            ..
+        }) => {
+            let [] = take_inputs(inputs)?;
+            Ok((vec![CoreValue::Felt252(Felt252::from(c))], 0))
+        }
+        StarkNetConcreteLibfunc::ClassHashTryFromFelt252(_)
+        | StarkNetConcreteLibfunc::ContractAddressTryFromFelt252(_)
+        | StarkNetConcreteLibfunc::StorageAddressTryFromFelt252(_) => {
+            simulate_bounded_felt252_try_from(inputs, 251)
+        }
+        StarkNetConcreteLibfunc::ClassHashToFelt252(_)
+        | StarkNetConcreteLibfunc::ContractAddressToFelt252(_)
+        | StarkNetConcreteLibfunc::StorageAddressToFelt252(_)
+        | StarkNetConcreteLibfunc::StorageAddressFromBase(_)
+        | StarkNetConcreteLibfunc::Sha256StateHandleInit(_)
+        | StarkNetConcreteLibfunc::Sha256StateHandleDigest(_) => {
+            let [value] = take_inputs(inputs)?;
+            Ok((vec![value], 0))
+        }
+        StarkNetConcreteLibfunc::StorageBaseAddressFromFelt252(_) => {
+            take_inputs!(let [CoreValue::RangeCheck, CoreValue::Felt252(value)] = inputs);
+            // Values out of the `[0, 2**251 - 256)` range are reduced into it.
+            let bound = Felt252::from((BigInt::one() << 251) - 256);
+            let value = if value >= bound { value - bound } else { value };
+            Ok((vec![CoreValue::RangeCheck, CoreValue::Felt252(value)], 0))
+        }
+        StarkNetConcreteLibfunc::StorageAddressFromBaseAndOffset(_) => {
+            take_inputs!(let [CoreValue::Felt252(base), CoreValue::Uint8(offset)] = inputs);
+            Ok((vec![CoreValue::Felt252(base + Felt252::from(offset))], 0))
+        }
+        StarkNetConcreteLibfunc::StorageRead(_) => {
+            take_inputs!(let [
+                CoreValue::GasBuiltin(mut gas),
+                CoreValue::System,
+                CoreValue::Uint32(address_domain),
+                CoreValue::Felt252(address),
+            ] = inputs);
+            let result = syscall_handler.storage_read(&mut gas, address_domain, address);
+            syscall_outputs(gas, result.map(|value| vec![CoreValue::Felt252(value)]))
+        }
+        StarkNetConcreteLibfunc::StorageWrite(_) => {
+            take_inputs!(let [
+                CoreValue::GasBuiltin(mut gas),
+                CoreValue::System,
+                CoreValue::Uint32(address_domain),
+                CoreValue::Felt252(address),
+                CoreValue::Felt252(value),
+            ] = inputs);
+            let result = syscall_handler.storage_write(&mut gas, address_domain, address, value);
+            syscall_outputs(gas, result.map(|()| vec![]))
+        }
+        StarkNetConcreteLibfunc::EmitEvent(_) => {
+            take_inputs!(let [
+                CoreValue::GasBuiltin(mut gas), CoreValue::System, keys, data
+            ] = inputs);
+            let result =
+                syscall_handler.emit_event(&mut gas, &span_felts(keys)?, &span_felts(data)?);
+            syscall_outputs(gas, result.map(|()| vec![]))
+        }
+        StarkNetConcreteLibfunc::GetBlockHash(_) => {
+            take_inputs!(let [
+                CoreValue::GasBuiltin(mut gas),
+                CoreValue::System,
+                CoreValue::Uint64(block_number),
+            ] = inputs);
+            let result = syscall_handler.get_block_hash(&mut gas, block_number);
+            syscall_outputs(gas, result.map(|hash| vec![CoreValue::Felt252(hash)]))
+        }
+        StarkNetConcreteLibfunc::GetExecutionInfo(_) => {
+            take_inputs!(let [CoreValue::GasBuiltin(mut gas), CoreValue::System] = inputs);
+            let result = syscall_handler.get_execution_info(&mut gas);
+            syscall_outputs(gas, result.map(|info| vec![info]))
+        }
+        StarkNetConcreteLibfunc::GetExecutionInfoV2(_) => {
+            take_inputs!(let [CoreValue::GasBuiltin(mut gas), CoreValue::System] = inputs);
+            let result = syscall_handler.get_execution_info_v2(&mut gas);
+            syscall_outputs(gas, result.map(|info| vec![info]))
+        }
+        StarkNetConcreteLibfunc::CallContract(_) => {
+            take_inputs!(let [
+                CoreValue::GasBuiltin(mut gas),
+                CoreValue::System,
+                CoreValue::Felt252(address),
+                CoreValue::Felt252(entry_point_selector),
+                calldata,
+            ] = inputs);
+            let result = syscall_handler.call_contract(
+                &mut gas,
+                address,
+                entry_point_selector,
+                &span_felts(calldata)?,
+            );
+            syscall_outputs(gas, result.map(|output| vec![felts_span(output)]))
+        }
+        StarkNetConcreteLibfunc::LibraryCall(_) => {
+            take_inputs!(let [
+                CoreValue::GasBuiltin(mut gas),
+                CoreValue::System,
+                CoreValue::Felt252(class_hash),
+                CoreValue::Felt252(function_selector),
+                calldata,
+            ] = inputs);
+            let result = syscall_handler.library_call(
+                &mut gas,
+                class_hash,
+                function_selector,
+                &span_felts(calldata)?,
+            );
+            syscall_outputs(gas, result.map(|output| vec![felts_span(output)]))
+        }
+        StarkNetConcreteLibfunc::Deploy(_) => {
+            take_inputs!(let [
+                CoreValue::GasBuiltin(mut gas),
+                CoreValue::System,
+                CoreValue::Felt252(class_hash),
+                CoreValue::Felt252(contract_address_salt),
+                calldata,
+                CoreValue::Enum { index: deploy_from_zero, .. },
+            ] = inputs);
+            let result = syscall_handler.deploy(
+                &mut gas,
+                class_hash,
+                contract_address_salt,
+                &span_felts(calldata)?,
+                deploy_from_zero == 1,
+            );
+            syscall_outputs(
+                gas,
+                result
+                    .map(|(address, output)| vec![CoreValue::Felt252(address), felts_span(output)]),
+            )
+        }
+        StarkNetConcreteLibfunc::ReplaceClass(_) => {
+            take_inputs!(let [
+                CoreValue::GasBuiltin(mut gas),
+                CoreValue::System,
+                CoreValue::Felt252(class_hash),
+            ] = inputs);
+            let result = syscall_handler.replace_class(&mut gas, class_hash);
+            syscall_outputs(gas, result.map(|()| vec![]))
+        }
+        StarkNetConcreteLibfunc::GetClassHashAt(_) => {
+            take_inputs!(let [
+                CoreValue::GasBuiltin(mut gas),
+                CoreValue::System,
+                CoreValue::Felt252(contract_address),
+            ] = inputs);
+            let result = syscall_handler.get_class_hash_at(&mut gas, contract_address);
+            syscall_outputs(gas, result.map(|class_hash| vec![CoreValue::Felt252(class_hash)]))
+        }
+        StarkNetConcreteLibfunc::SendMessageToL1(_) => {
+            take_inputs!(let [
+                CoreValue::GasBuiltin(mut gas),
+                CoreValue::System,
+                CoreValue::Felt252(to_address),
+                payload,
+            ] = inputs);
+            let result =
+                syscall_handler.send_message_to_l1(&mut gas, to_address, &span_felts(payload)?);
+            syscall_outputs(gas, result.map(|()| vec![]))
+        }
+        StarkNetConcreteLibfunc::Keccak(_) => {
+            take_inputs!(let [CoreValue::GasBuiltin(mut gas), CoreValue::System, input] = inputs);
+            let input = span_elements(input)?
+                .into_iter()
+                .map(|word| match word {
+                    CoreValue::Uint64(word) => Ok(word),
+                    _ => Err(LibfuncSimulationError::WrongArgType),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let result = syscall_handler.keccak(&mut gas, &input);
+            syscall_outputs(gas, result.map(|hash| vec![u128_limbs_struct(&hash, 2)]))
+        }
+        StarkNetConcreteLibfunc::Sha256ProcessBlock(_) => {
+            take_inputs!(let [
+                CoreValue::GasBuiltin(mut gas), CoreValue::System, state, block
+            ] = inputs);
+            let result = syscall_handler.sha256_process_block(
+                &mut gas,
+                u32_array(state)?,
+                u32_array(block)?,
+            );
+            syscall_outputs(
+                gas,
+                result.map(|state| {
+                    vec![CoreValue::Struct(state.into_iter().map(CoreValue::Uint32).collect())]
+                }),
+            )
+        }
+        StarkNetConcreteLibfunc::Testing(TestingConcreteLibfunc::Cheatcode(
+            CheatcodeConcreteLibfunc { selector, .. },
+        )) => {
+            let [input] = take_inputs(inputs)?;
+            let output = syscall_handler
+                .cheatcode(selector, &span_felts(input)?)
+                .ok_or(LibfuncSimulationError::UnsupportedSyscall)?;
+            Ok((vec![felts_span(output)], 0))
+        }
+        StarkNetConcreteLibfunc::Secp256(Secp256ConcreteLibfunc::K1(libfunc)) => {
+            simulate_secp256_libfunc(Secp256Curve::K1, libfunc, inputs, syscall_handler)
+        }
+        StarkNetConcreteLibfunc::Secp256(Secp256ConcreteLibfunc::R1(libfunc)) => {
+            simulate_secp256_libfunc(Secp256Curve::R1, libfunc, inputs, syscall_handler)
+        }
+    }
+}
+
+/// Simulate the secp256 library functions of the given curve.
+fn simulate_secp256_libfunc<T: Secp256Trait>(
+    curve: Secp256Curve,
+    libfunc: &Secp256OpConcreteLibfunc<T>,
+    inputs: Vec<CoreValue>,
+    syscall_handler: &dyn SyscallHandler,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    match libfunc {
+        Secp256OpConcreteLibfunc::New(_) => {
+            take_inputs!(let [CoreValue::GasBuiltin(mut gas), CoreValue::System, x, y] = inputs);
+            let result = syscall_handler.secp256_new(
+                &mut gas,
+                curve,
+                u128_limbs_value(&x)?,
+                u128_limbs_value(&y)?,
+            );
+            syscall_outputs(gas, result.map(|point| vec![secp256_point_option(point)]))
+        }
+        Secp256OpConcreteLibfunc::Add(_) => {
+            take_inputs!(let [
+                CoreValue::GasBuiltin(mut gas),
+                CoreValue::System,
+                CoreValue::Secp256Point { x: x0, y: y0 },
+                CoreValue::Secp256Point { x: x1, y: y1 },
+            ] = inputs);
+            let result = syscall_handler.secp256_add(&mut gas, curve, (x0, y0), (x1, y1));
+            syscall_outputs(gas, result.map(|point| vec![secp256_point(point)]))
+        }
+        Secp256OpConcreteLibfunc::Mul(_) => {
+            take_inputs!(let [
+                CoreValue::GasBuiltin(mut gas),
+                CoreValue::System,
+                CoreValue::Secp256Point { x, y },
+                scalar,
+            ] = inputs);
+            let result =
+                syscall_handler.secp256_mul(&mut gas, curve, (x, y), u128_limbs_value(&scalar)?);
+            syscall_outputs(gas, result.map(|point| vec![secp256_point(point)]))
+        }
+        Secp256OpConcreteLibfunc::GetPointFromX(_) => {
+            take_inputs!(let [
+                CoreValue::GasBuiltin(mut gas),
+                CoreValue::System,
+                x,
+                CoreValue::Enum { index: y_parity, .. },
+            ] = inputs);
+            let result = syscall_handler.secp256_get_point_from_x(
+                &mut gas,
+                curve,
+                u128_limbs_value(&x)?,
+                y_parity == 1,
+            );
+            syscall_outputs(gas, result.map(|point| vec![secp256_point_option(point)]))
+        }
+        Secp256OpConcreteLibfunc::GetXy(_) => {
+            take_inputs!(let [
+                CoreValue::GasBuiltin(mut gas),
+                CoreValue::System,
+                CoreValue::Secp256Point { x, y },
+            ] = inputs);
+            let result = syscall_handler.secp256_get_xy(&mut gas, curve, (x, y));
+            syscall_outputs(
+                gas,
+                result.map(|(x, y)| vec![u128_limbs_struct(&x, 2), u128_limbs_struct(&y, 2)]),
+            )
+        }
+    }
+}
+
+/// Returns the outputs of a syscall libfunc, given the remaining gas and the result of the
+/// syscall.
+fn syscall_outputs(
+    gas: i64,
+    result: SyscallResult<Vec<CoreValue>>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    let mut outputs = vec![CoreValue::GasBuiltin(gas), CoreValue::System];
+    match result {
+        Ok(values) => {
+            outputs.extend(values);
+            Ok((outputs, 0))
+        }
+        Err(SyscallError::Revert(reason)) => {
+            outputs.push(CoreValue::Array(reason.into_iter().map(CoreValue::Felt252).collect()));
+            Ok((outputs, 1))
+        }
+        Err(SyscallError::Unsupported) => Err(LibfuncSimulationError::UnsupportedSyscall),
+    }
+}
+
+/// Returns the value of a secp256 point.
+fn secp256_point((x, y): Secp256Point) -> CoreValue {
+    CoreValue::Secp256Point { x, y }
+}
+
+/// Returns the value of an optional secp256 point.
+fn secp256_point_option(point: Option<Secp256Point>) -> CoreValue {
+    match point {
+        Some(point) => option_some(secp256_point(point)),
+        None => option_none(),
+    }
+}
+
+/// Simulate circuit library functions.
+fn simulate_circuit_libfunc<'a>(
+    libfunc: &CircuitConcreteLibfunc,
+    inputs: Vec<CoreValue>,
+    get_type: &impl Fn(&ConcreteTypeId) -> Option<&'a CoreTypeConcrete>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    Ok(match libfunc {
+        CircuitConcreteLibfunc::InitCircuitData(_) => {
+            take_inputs!(let [CoreValue::RangeCheck96] = inputs);
+            (vec![CoreValue::RangeCheck96, CoreValue::CircuitInputAccumulator(vec![])], 0)
+        }
+        CircuitConcreteLibfunc::AddInput(SignatureAndTypeConcreteLibfunc { ty, .. }) => {
+            take_inputs!(let [CoreValue::CircuitInputAccumulator(mut values), value] = inputs);
+            values.push(u96_limbs_value(&value)?);
+            if values.len() == circuit_info(get_type, ty)?.n_inputs {
+                (vec![CoreValue::CircuitData(values)], 0)
+            } else {
+                (vec![CoreValue::CircuitInputAccumulator(values)], 1)
+            }
+        }
+        CircuitConcreteLibfunc::GetDescriptor(_) => {
+            let [] = take_inputs(inputs)?;
+            (vec![CoreValue::CircuitDescriptor], 0)
+        }
+        CircuitConcreteLibfunc::Eval(SignatureAndTypeConcreteLibfunc { ty, .. }) => {
+            take_inputs!(let [
+                CoreValue::AddMod,
+                CoreValue::MulMod,
+                CoreValue::CircuitDescriptor,
+                CoreValue::CircuitData(circuit_inputs),
+                CoreValue::CircuitModulus(modulus),
+                _,
+                _,
+            ] = inputs);
+            let info = circuit_info(get_type, ty)?;
+            // The value at offset 0 is the constant `1`, followed by the unreduced inputs.
+            let mut values = vec![None; 1 + info.n_inputs + info.values.len()];
+            values[0] = Some(BigInt::one());
+            for (value, input) in values[1..].iter_mut().zip(circuit_inputs) {
+                *value = Some(input);
+            }
+            match fill_circuit_values(info, &mut values, &modulus) {
+                Ok(()) => {
+                    let values = values.into_iter().map(Option::unwrap_or_default).collect();
+                    (
+                        vec![CoreValue::AddMod, CoreValue::MulMod, CoreValue::CircuitOutputs {
+                            values,
+                            modulus,
+                        }],
+                        0,
+                    )
+                }
+                Err(nullifier) => (
+                    vec![
+                        CoreValue::AddMod,
+                        CoreValue::MulMod,
+                        CoreValue::CircuitPartialOutputs,
+                        CoreValue::CircuitFailureGuarantee { nullifier, modulus },
+                    ],
+                    1,
+                ),
+            }
+        }
+        CircuitConcreteLibfunc::GetOutput(ConcreteGetOutputLibFunc {
+            circuit_ty,
+            output_ty,
+            ..
+        }) => {
+            take_inputs!(let [CoreValue::CircuitOutputs { values, modulus }] = inputs);
+            let offset = *circuit_info(get_type, circuit_ty)?
+                .values
+                .get(output_ty)
+                .ok_or_else(|| LibfuncSimulationError::UnresolvedType(output_ty.clone()))?;
+            let value = &values[offset];
+            (
+                vec![
+                    CoreValue::Struct(
+                        u96_limbs(value).into_iter().map(CoreValue::BoundedInt).collect(),
+                    ),
+                    CoreValue::U96LimbsLessThanGuarantee {
+                        lhs: u96_limbs(value),
+                        rhs: u96_limbs(&modulus),
+                    },
+                ],
+                0,
+            )
+        }
+        CircuitConcreteLibfunc::TryIntoCircuitModulus(_) => {
+            let [value] = take_inputs(inputs)?;
+            let value = u96_limbs_value(&value)?;
+            if value >= BigInt::from(2) {
+                (vec![CoreValue::CircuitModulus(value)], 0)
+            } else {
+                (vec![], 1)
+            }
+        }
+        CircuitConcreteLibfunc::FailureGuaranteeVerify(_) => {
+            take_inputs!(let [
+                CoreValue::RangeCheck96,
+                CoreValue::MulMod,
+                CoreValue::CircuitFailureGuarantee { nullifier, modulus },
+                _,
+                _,
+            ] = inputs);
+            (
+                vec![
+                    CoreValue::RangeCheck96,
+                    CoreValue::MulMod,
+                    CoreValue::U96LimbsLessThanGuarantee {
+                        lhs: u96_limbs(&nullifier),
+                        rhs: u96_limbs(&modulus),
+                    },
+                ],
+                0,
+            )
+        }
+        CircuitConcreteLibfunc::IntoU96Guarantee(_) => {
+            let [value] = take_inputs(inputs)?;
+            (vec![CoreValue::U96Guarantee(checked_int_value(&value)?)], 0)
+        }
+        CircuitConcreteLibfunc::U96GuaranteeVerify(_) => {
+            take_inputs!(let [CoreValue::RangeCheck96, CoreValue::U96Guarantee(_)] = inputs);
+            (vec![CoreValue::RangeCheck96], 0)
+        }
+        CircuitConcreteLibfunc::U96LimbsLessThanGuaranteeVerify(_) => {
+            take_inputs!(let [CoreValue::U96LimbsLessThanGuarantee { mut lhs, mut rhs }] = inputs);
+            let (Some(lhs_high), Some(rhs_high)) = (lhs.pop(), rhs.pop()) else {
+                return Err(LibfuncSimulationError::WrongArgType);
+            };
+            let diff = rhs_high - lhs_high;
+            if diff.is_zero() {
+                (vec![CoreValue::U96LimbsLessThanGuarantee { lhs, rhs }], 0)
+            } else {
+                (vec![CoreValue::U96Guarantee(diff)], 1)
+            }
+        }
+        CircuitConcreteLibfunc::U96SingleLimbLessThanGuaranteeVerify(_) => {
+            take_inputs!(let [CoreValue::U96LimbsLessThanGuarantee { lhs, rhs }] = inputs);
+            let ([lhs], [rhs]) = (&lhs[..], &rhs[..]) else {
+                return Err(LibfuncSimulationError::WrongArgType);
+            };
+            (vec![CoreValue::U96Guarantee(rhs - lhs)], 0)
+        }
+    })
+}
+
+/// Returns the info of the circuit type `ty`.
+fn circuit_info<'a>(
+    get_type: &impl Fn(&ConcreteTypeId) -> Option<&'a CoreTypeConcrete>,
+    ty: &ConcreteTypeId,
+) -> Result<&'a CircuitInfo, LibfuncSimulationError> {
+    match get_concrete_type(get_type, ty)? {
+        CoreTypeConcrete::Circuit(CircuitTypeConcrete::Circuit(ConcreteCircuit {
+            circuit_info,
+            ..
+        })) => Ok(circuit_info),
+        _ => Err(LibfuncSimulationError::UnresolvedType(ty.clone())),
+    }
+}
+
+/// Fills the values of a circuit, given the constant `1` and its inputs, the way the `AddMod` and
+/// `MulMod` builtins do. On failure, returns the nullifier of the first inverse gate whose input is
+/// not invertible.
+fn fill_circuit_values(
+    info: &CircuitInfo,
+    values: &mut [Option<BigInt>],
+    modulus: &BigInt,
+) -> Result<(), BigInt> {
+    let mut next_add_gate = 0;
+    for gate in &info.mul_offsets {
+        fill_add_gates(&info.add_offsets, &mut next_add_gate, values, modulus);
+        match (values[gate.lhs].clone(), values[gate.rhs].clone()) {
+            (Some(lhs), Some(rhs)) => values[gate.output] = Some((lhs * rhs).mod_floor(modulus)),
+            // An inverse gate, computing `lhs` such that `lhs * rhs = 1`.
+            (None, Some(rhs)) => {
+                let egcd = rhs.extended_gcd(modulus);
+                if !egcd.gcd.is_one() {
+                    return Err(modulus / egcd.gcd);
+                }
+                values[gate.lhs] = Some(egcd.x.mod_floor(modulus));
+            }
+            _ => unreachable!("The gates are ordered so the inputs are known before use."),
+        }
+    }
+    fill_add_gates(&info.add_offsets, &mut next_add_gate, values, modulus);
+    Ok(())
+}
+
+/// Fills the values of the add gates starting at `next`, until reaching a gate depending on an
+/// unknown value.
+fn fill_add_gates(
+    add_offsets: &[GateOffsets],
+    next: &mut usize,
+    values: &mut [Option<BigInt>],
+    modulus: &BigInt,
+) {
+    while let Some(gate) = add_offsets.get(*next) {
+        let (offset, value) = match (&values[gate.lhs], &values[gate.rhs], &values[gate.output]) {
+            (Some(lhs), Some(rhs), _) => (gate.output, (lhs + rhs).mod_floor(modulus)),
+            // A sub gate, computing `lhs` such that `lhs + rhs = output`.
+            (None, Some(rhs), Some(output)) => (gate.lhs, (output - rhs).mod_floor(modulus)),
+            _ => return,
+        };
+        values[offset] = Some(value);
+        *next += 1;
+    }
+}
+
+/// Returns the value of an integer value of any type. Felt252 values are taken as signed, in the
+/// range `(-P/2, P/2]`.
+fn int_value(value: &CoreValue) -> Option<BigInt> {
+    Some(match value {
+        CoreValue::Felt252(value) => felt252_to_signed(value),
+        CoreValue::Uint8(value) => (*value).into(),
+        CoreValue::Uint16(value) => (*value).into(),
+        CoreValue::Uint32(value) => (*value).into(),
+        CoreValue::Uint64(value) => (*value).into(),
+        CoreValue::Uint128(value) => (*value).into(),
+        CoreValue::Sint8(value) => (*value).into(),
+        CoreValue::Sint16(value) => (*value).into(),
+        CoreValue::Sint32(value) => (*value).into(),
+        CoreValue::Sint64(value) => (*value).into(),
+        CoreValue::Sint128(value) => (*value).into(),
+        CoreValue::BoundedInt(value) | CoreValue::U96Guarantee(value) => value.clone(),
+        _ => return None,
+    })
+}
+
+/// Returns the value of an integer value of any type, or an error if it is not an integer.
+fn checked_int_value(value: &CoreValue) -> Result<BigInt, LibfuncSimulationError> {
+    int_value(value).ok_or(LibfuncSimulationError::WrongArgType)
+}
+
+/// Returns the value of an integer value of the generic type `ty`, or an error if it is of another
+/// type.
+fn typed_int_value(
+    ty: &GenericTypeId,
+    value: &CoreValue,
+) -> Result<BigInt, LibfuncSimulationError> {
+    match int_zero_of_generic_type(ty) {
+        Some(zero) if std::mem::discriminant(&zero) == std::mem::discriminant(value) => {
+            checked_int_value(value)
+        }
+        _ => Err(LibfuncSimulationError::WrongArgType),
+    }
+}
+
+/// Returns the signed value of a felt252, in the range `(-P/2, P/2]`.
+fn felt252_to_signed(value: &Felt252) -> BigInt {
+    let value = value.to_bigint();
+    let prime = BigInt::from(Felt252::prime());
+    if value > &prime / 2 { value - prime } else { value }
+}
+
+/// Returns an integer value of the same type as `like`, if `value` is in the range of the type.
+fn int_like(like: &CoreValue, value: BigInt) -> Option<CoreValue> {
+    Some(match like {
+        CoreValue::Felt252(_) => CoreValue::Felt252(Felt252::from(value)),
+        CoreValue::Uint8(_) => CoreValue::Uint8(value.to_u8()?),
+        CoreValue::Uint16(_) => CoreValue::Uint16(value.to_u16()?),
+        CoreValue::Uint32(_) => CoreValue::Uint32(value.to_u32()?),
+        CoreValue::Uint64(_) => CoreValue::Uint64(value.to_u64()?),
+        CoreValue::Uint128(_) => CoreValue::Uint128(value.to_u128()?),
+        CoreValue::Sint8(_) => CoreValue::Sint8(value.to_i8()?),
+        CoreValue::Sint16(_) => CoreValue::Sint16(value.to_i16()?),
+        CoreValue::Sint32(_) => CoreValue::Sint32(value.to_i32()?),
+        CoreValue::Sint64(_) => CoreValue::Sint64(value.to_i64()?),
+        CoreValue::Sint128(_) => CoreValue::Sint128(value.to_i128()?),
+        CoreValue::BoundedInt(_) => CoreValue::BoundedInt(value),
+        CoreValue::U96Guarantee(_) => CoreValue::U96Guarantee(value),
+        _ => return None,
+    })
+}
+
+/// Returns the zero value of the integer generic type `ty`, or `None` if `ty` is not an integer
+/// type.
+fn int_zero_of_generic_type(ty: &GenericTypeId) -> Option<CoreValue> {
+    let felt252_based_types = [
+        Felt252Type::ID,
+        Bytes31Type::ID,
+        ContractAddressType::ID,
+        ClassHashType::ID,
+        StorageBaseAddressType::ID,
+        StorageAddressType::ID,
+    ];
+    Some(if felt252_based_types.contains(ty) {
+        CoreValue::Felt252(Felt252::ZERO)
+    } else if *ty == Uint8Type::ID {
+        CoreValue::Uint8(0)
+    } else if *ty == Uint16Type::ID {
+        CoreValue::Uint16(0)
+    } else if *ty == Uint32Type::ID {
+        CoreValue::Uint32(0)
+    } else if *ty == Uint64Type::ID {
+        CoreValue::Uint64(0)
+    } else if *ty == Uint128Type::ID {
+        CoreValue::Uint128(0)
+    } else if *ty == Sint8Type::ID {
+        CoreValue::Sint8(0)
+    } else if *ty == Sint16Type::ID {
+        CoreValue::Sint16(0)
+    } else if *ty == Sint32Type::ID {
+        CoreValue::Sint32(0)
+    } else if *ty == Sint64Type::ID {
+        CoreValue::Sint64(0)
+    } else if *ty == Sint128Type::ID {
+        CoreValue::Sint128(0)
+    } else if *ty == BoundedIntType::ID {
+        CoreValue::BoundedInt(BigInt::zero())
+    } else {
+        return None;
+    })
+}
+
+/// Returns the integer value `value` of the generic type `ty`, if `value` is in its range.
+fn int_of_generic_type(ty: &GenericTypeId, value: BigInt) -> Option<CoreValue> {
+    int_like(&int_zero_of_generic_type(ty)?, value)
+}
+
+/// Returns the integer value `value` of the generic type `ty`, or an error if `value` is not in its
+/// range.
+fn typed_int(ty: &GenericTypeId, value: BigInt) -> Result<CoreValue, LibfuncSimulationError> {
+    int_of_generic_type(ty, value).ok_or(LibfuncSimulationError::WrongArgType)
+}
+
+/// Returns the concrete type of `ty`.
+fn get_concrete_type<'a>(
+    get_type: &impl Fn(&ConcreteTypeId) -> Option<&'a CoreTypeConcrete>,
+    ty: &ConcreteTypeId,
+) -> Result<&'a CoreTypeConcrete, LibfuncSimulationError> {
+    get_type(ty).ok_or_else(|| LibfuncSimulationError::UnresolvedType(ty.clone()))
+}
+
+/// Returns the integer value `value` of the concrete type `ty`. `NonZero` types are represented by
+/// the value they wrap.
+fn int_of_type<'a>(
+    get_type: &impl Fn(&ConcreteTypeId) -> Option<&'a CoreTypeConcrete>,
+    ty: &ConcreteTypeId,
+    value: BigInt,
+) -> Result<CoreValue, LibfuncSimulationError> {
+    match get_concrete_type(get_type, ty)? {
+        CoreTypeConcrete::NonZero(InfoAndTypeConcreteType { ty, .. }) => {
+            int_of_type(get_type, ty, value)
+        }
+        concrete => int_of_generic_type(&concrete.info().long_id.generic_id, value)
+            .ok_or_else(|| LibfuncSimulationError::UnresolvedType(ty.clone())),
+    }
+}
+
+/// Returns the members of the struct type `ty`.
+fn struct_members<'a>(
+    get_type: &impl Fn(&ConcreteTypeId) -> Option<&'a CoreTypeConcrete>,
+    ty: &ConcreteTypeId,
+) -> Result<&'a Vec<ConcreteTypeId>, LibfuncSimulationError> {
+    match get_concrete_type(get_type, ty)? {
+        CoreTypeConcrete::Struct(StructConcreteType { members, .. }) => Ok(members),
+        _ => Err(LibfuncSimulationError::UnresolvedType(ty.clone())),
+    }
+}
+
+/// Returns the value of the const type `const_ty`.
+fn const_value<'a>(
+    get_type: &impl Fn(&ConcreteTypeId) -> Option<&'a CoreTypeConcrete>,
+    const_ty: &ConcreteTypeId,
+) -> Result<CoreValue, LibfuncSimulationError> {
+    let unresolved = || LibfuncSimulationError::UnresolvedType(const_ty.clone());
+    let CoreTypeConcrete::Const(ConstConcreteType { inner_ty, inner_data, .. }) =
+        get_concrete_type(get_type, const_ty)?
+    else {
+        return Err(unresolved());
+    };
+    match (get_concrete_type(get_type, inner_ty)?, &inner_data[..]) {
+        (CoreTypeConcrete::Struct(_), members) => Ok(CoreValue::Struct(
+            members
+                .iter()
+                .map(|member| match member {
+                    GenericArg::Type(ty) => const_value(get_type, ty),
+                    _ => Err(unresolved()),
+                })
+                .collect::<Result<_, _>>()?,
+        )),
+        (CoreTypeConcrete::Enum(_), [GenericArg::Value(index), GenericArg::Type(ty)]) => {
+            Ok(CoreValue::Enum {
+                value: Box::new(const_value(get_type, ty)?),
+                index: index.to_usize().ok_or_else(unresolved)?,
+            })
+        }
+        (CoreTypeConcrete::NonZero(_), [GenericArg::Type(ty)]) => const_value(get_type, ty),
+        (_, [GenericArg::Value(value)]) => int_of_type(get_type, inner_ty, value.clone()),
+        _ => Err(unresolved()),
+    }
+}
+
+/// Returns the value of a missing entry of a dict with values of type `ty`.
+fn dict_default_value<'a>(
+    get_type: &impl Fn(&ConcreteTypeId) -> Option<&'a CoreTypeConcrete>,
+    ty: &ConcreteTypeId,
+) -> Result<CoreValue, LibfuncSimulationError> {
+    match get_concrete_type(get_type, ty)? {
+        CoreTypeConcrete::Nullable(_) => Ok(CoreValue::Nullable(None)),
+        _ => int_of_type(get_type, ty, BigInt::zero()),
+    }
+}
+
+/// Returns the value of a struct of u128 limbs, ordered from the least significant one, such as a
+/// `u256` or a `u512`.
+fn u128_limbs_value(value: &CoreValue) -> Result<BigInt, LibfuncSimulationError> {
+    let CoreValue::Struct(limbs) = value else {
+        return Err(LibfuncSimulationError::WrongArgType);
+    };
+    limbs.iter().rev().try_fold(BigInt::zero(), |value, limb| match limb {
+        CoreValue::Uint128(limb) => Ok((value << 128) + *limb),
+        _ => Err(LibfuncSimulationError::WrongArgType),
+    })
+}
+
+/// Returns a struct of `n_limbs` u128 limbs of `value`, ordered from the least significant one.
+fn u128_limbs_struct(value: &BigInt, n_limbs: usize) -> CoreValue {
+    let mask = (BigInt::one() << 128) - 1;
+    CoreValue::Struct(
+        (0..n_limbs)
+            .map(|i| CoreValue::Uint128(((value >> (128 * i)) & &mask).to_u128().unwrap()))
+            .collect(),
+    )
+}
+
+/// Returns the 4 u96 limbs of `value`, ordered from the least significant one.
+fn u96_limbs(value: &BigInt) -> Vec<BigInt> {
+    let mask = (BigInt::one() << 96) - 1;
+    (0..4).map(|i| (value >> (96 * i)) & &mask).collect()
+}
+
+/// Returns the value of a struct of u96 limbs, ordered from the least significant one, such as a
+/// `u384` or the input of a circuit.
+fn u96_limbs_value(value: &CoreValue) -> Result<BigInt, LibfuncSimulationError> {
+    let CoreValue::Struct(limbs) = value else {
+        return Err(LibfuncSimulationError::WrongArgType);
+    };
+    limbs
+        .iter()
+        .rev()
+        .try_fold(BigInt::zero(), |value, limb| Ok((value << 96) + checked_int_value(limb)?))
+}
+
+/// Returns the elements of a span value.
+fn span_elements(value: CoreValue) -> Result<Vec<CoreValue>, LibfuncSimulationError> {
+    match value {
+        CoreValue::Struct(members) => match <[CoreValue; 1]>::try_from(members) {
+            Ok([CoreValue::Array(elements)]) => Ok(elements),
+            _ => Err(LibfuncSimulationError::WrongArgType),
+        },
+        _ => Err(LibfuncSimulationError::WrongArgType),
+    }
+}
+
+/// Returns the elements of a span of felt252s.
+fn span_felts(value: CoreValue) -> Result<Vec<Felt252>, LibfuncSimulationError> {
+    span_elements(value)?
+        .into_iter()
+        .map(|element| match element {
+            CoreValue::Felt252(element) => Ok(element),
+            _ => Err(LibfuncSimulationError::WrongArgType),
+        })
+        .collect()
+}
+
+/// Returns a span of the given felt252s.
+fn felts_span(felts: Vec<Felt252>) -> CoreValue {
+    CoreValue::Struct(vec![CoreValue::Array(felts.into_iter().map(CoreValue::Felt252).collect())])
+}
+
+/// Returns the values of a fixed size array of u32s, such as the sha256 state.
+fn u32_array<const N: usize>(value: CoreValue) -> Result<[u32; N], LibfuncSimulationError> {
+    let CoreValue::Struct(members) = value else {
+        return Err(LibfuncSimulationError::WrongArgType);
+    };
+    members
+        .into_iter()
+        .map(|member| match member {
+            CoreValue::Uint32(member) => Ok(member),
+            _ => Err(LibfuncSimulationError::WrongArgType),
+        })
+        .collect::<Result<Vec<_>, _>>()?
+        .try_into()
+        .map_err(|_| LibfuncSimulationError::WrongArgType)
+}
+
+/// Returns the `Some` variant of an `Option` holding `value`.
+fn option_some(value: CoreValue) -> CoreValue {
+    CoreValue::Enum { value: Box::new(value), index: 0 }
+}
+
+/// Returns the `None` variant of an `Option`.
+fn option_none() -> CoreValue {
+    CoreValue::Enum { value: Box::new(CoreValue::Struct(vec![])), index: 1 }
 }
 
 /// Simulate felt252 library functions.
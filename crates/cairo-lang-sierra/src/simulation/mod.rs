@@ -4,14 +4,16 @@ use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use itertools::izip;
 use thiserror::Error;
 
+use self::syscalls::{EmptySyscallHandler, SyscallHandler};
 use self::value::CoreValue;
 use crate::edit_state::{EditStateError, put_results, take_args};
 use crate::extensions::core::{CoreConcreteLibfunc, CoreLibfunc, CoreType};
-use crate::ids::{FunctionId, VarId};
+use crate::ids::{ConcreteTypeId, FunctionId, VarId};
 use crate::program::{Program, Statement, StatementIdx};
 use crate::program_registry::{ProgramRegistry, ProgramRegistryError};
 
 pub mod core;
+pub mod syscalls;
 #[cfg(test)]
 mod test;
 pub mod value;
@@ -27,6 +29,10 @@ pub enum LibfuncSimulationError {
     UnresolvedStatementGasInfo,
     #[error("Error occurred during user function call")]
     FunctionSimulationError(FunctionId, Box<SimulationError>),
+    #[error("Could not resolve the value representation of a type")]
+    UnresolvedType(ConcreteTypeId),
+    #[error("Syscall is not supported by the syscall handler")]
+    UnsupportedSyscall,
 }
 
 /// Error occurring while simulating a program function.
@@ -47,16 +53,31 @@ pub enum SimulationError {
 }
 
 /// Runs a function from the program with the given inputs.
+///
+/// Syscalls are not supported - use [run_ex] to simulate programs using them.
 pub fn run(
     program: &Program,
     statement_gas_info: &HashMap<StatementIdx, i64>,
     function_id: &FunctionId,
     inputs: Vec<CoreValue>,
+) -> Result<Vec<CoreValue>, SimulationError> {
+    run_ex(program, statement_gas_info, function_id, inputs, &EmptySyscallHandler)
+}
+
+/// Runs a function from the program with the given inputs, handling syscalls with
+/// `syscall_handler`.
+pub fn run_ex(
+    program: &Program,
+    statement_gas_info: &HashMap<StatementIdx, i64>,
+    function_id: &FunctionId,
+    inputs: Vec<CoreValue>,
+    syscall_handler: &dyn SyscallHandler,
 ) -> Result<Vec<CoreValue>, SimulationError> {
     let context = SimulationContext {
         program,
         statement_gas_info,
         registry: &ProgramRegistry::new(program)?,
+        syscall_handler,
     };
     context.simulate_function(function_id, inputs)
 }
@@ -66,6 +87,7 @@ struct SimulationContext<'a> {
     pub program: &'a Program,
     pub statement_gas_info: &'a HashMap<StatementIdx, i64>,
     pub registry: &'a ProgramRegistry<CoreType, CoreLibfunc>,
+    pub syscall_handler: &'a dyn SyscallHandler,
 }
 impl SimulationContext<'_> {
     /// Simulates the run of a function, even recursively.
@@ -140,6 +162,7 @@ impl SimulationContext<'_> {
             libfunc,
             inputs,
             || self.statement_gas_info.get(idx).copied(),
+            |ty| self.registry.get_type(ty).ok(),
             |function_id, inputs| {
                 self.simulate_function(function_id, inputs).map_err(|error| {
                     LibfuncSimulationError::FunctionSimulationError(
@@ -148,6 +171,7 @@ impl SimulationContext<'_> {
                     )
                 })
             },
+            self.syscall_handler,
         )
         .map_err(|error| SimulationError::LibfuncSimulationError(error, current_statement_id))
     }
@@ -0,0 +1,186 @@
+use num_bigint::BigInt;
+use starknet_types_core::felt::Felt as Felt252;
+
+use super::value::CoreValue;
+
+/// The error of a simulated syscall.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SyscallError {
+    /// The syscall failed, with the given revert reason.
+    Revert(Vec<Felt252>),
+    /// The syscall is not supported by the handler.
+    Unsupported,
+}
+
+/// The result of a simulated syscall.
+pub type SyscallResult<T> = Result<T, SyscallError>;
+
+/// The secp256 curves supported by the secp256 syscalls.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Secp256Curve {
+    K1,
+    R1,
+}
+
+/// A secp256 point, as its `(x, y)` coordinates. The point at infinity is `(0, 0)`.
+pub type Secp256Point = (BigInt, BigInt);
+
+/// Handler for the syscalls of a simulated program.
+///
+/// Each syscall is given the gas counter of the call, which it may reduce to charge for the
+/// syscall. Spans are given and returned as their elements. All syscalls are unsupported unless
+/// implemented.
+pub trait SyscallHandler {
+    fn storage_read(
+        &self,
+        _gas: &mut i64,
+        _address_domain: u32,
+        _address: Felt252,
+    ) -> SyscallResult<Felt252> {
+        Err(SyscallError::Unsupported)
+    }
+    fn storage_write(
+        &self,
+        _gas: &mut i64,
+        _address_domain: u32,
+        _address: Felt252,
+        _value: Felt252,
+    ) -> SyscallResult<()> {
+        Err(SyscallError::Unsupported)
+    }
+    fn emit_event(
+        &self,
+        _gas: &mut i64,
+        _keys: &[Felt252],
+        _data: &[Felt252],
+    ) -> SyscallResult<()> {
+        Err(SyscallError::Unsupported)
+    }
+    fn get_block_hash(&self, _gas: &mut i64, _block_number: u64) -> SyscallResult<Felt252> {
+        Err(SyscallError::Unsupported)
+    }
+    /// Returns the boxed `ExecutionInfo` struct.
+    fn get_execution_info(&self, _gas: &mut i64) -> SyscallResult<CoreValue> {
+        Err(SyscallError::Unsupported)
+    }
+    /// Returns the boxed `v2::ExecutionInfo` struct.
+    fn get_execution_info_v2(&self, _gas: &mut i64) -> SyscallResult<CoreValue> {
+        Err(SyscallError::Unsupported)
+    }
+    fn call_contract(
+        &self,
+        _gas: &mut i64,
+        _address: Felt252,
+        _entry_point_selector: Felt252,
+        _calldata: &[Felt252],
+    ) -> SyscallResult<Vec<Felt252>> {
+        Err(SyscallError::Unsupported)
+    }
+    fn library_call(
+        &self,
+        _gas: &mut i64,
+        _class_hash: Felt252,
+        _function_selector: Felt252,
+        _calldata: &[Felt252],
+    ) -> SyscallResult<Vec<Felt252>> {
+        Err(SyscallError::Unsupported)
+    }
+    /// Returns the address of the deployed contract and the result of its constructor.
+    fn deploy(
+        &self,
+        _gas: &mut i64,
+        _class_hash: Felt252,
+        _contract_address_salt: Felt252,
+        _calldata: &[Felt252],
+        _deploy_from_zero: bool,
+    ) -> SyscallResult<(Felt252, Vec<Felt252>)> {
+        Err(SyscallError::Unsupported)
+    }
+    fn replace_class(&self, _gas: &mut i64, _class_hash: Felt252) -> SyscallResult<()> {
+        Err(SyscallError::Unsupported)
+    }
+    fn get_class_hash_at(
+        &self,
+        _gas: &mut i64,
+        _contract_address: Felt252,
+    ) -> SyscallResult<Felt252> {
+        Err(SyscallError::Unsupported)
+    }
+    fn send_message_to_l1(
+        &self,
+        _gas: &mut i64,
+        _to_address: Felt252,
+        _payload: &[Felt252],
+    ) -> SyscallResult<()> {
+        Err(SyscallError::Unsupported)
+    }
+    /// Returns the keccak hash of the input as a `u256`.
+    fn keccak(&self, _gas: &mut i64, _input: &[u64]) -> SyscallResult<BigInt> {
+        Err(SyscallError::Unsupported)
+    }
+    /// Returns the sha256 state after processing `block`.
+    fn sha256_process_block(
+        &self,
+        _gas: &mut i64,
+        _state: [u32; 8],
+        _block: [u32; 16],
+    ) -> SyscallResult<[u32; 8]> {
+        Err(SyscallError::Unsupported)
+    }
+    /// Returns the point with the given coordinates, if it is on the curve.
+    fn secp256_new(
+        &self,
+        _gas: &mut i64,
+        _curve: Secp256Curve,
+        _x: BigInt,
+        _y: BigInt,
+    ) -> SyscallResult<Option<Secp256Point>> {
+        Err(SyscallError::Unsupported)
+    }
+    fn secp256_add(
+        &self,
+        _gas: &mut i64,
+        _curve: Secp256Curve,
+        _p0: Secp256Point,
+        _p1: Secp256Point,
+    ) -> SyscallResult<Secp256Point> {
+        Err(SyscallError::Unsupported)
+    }
+    fn secp256_mul(
+        &self,
+        _gas: &mut i64,
+        _curve: Secp256Curve,
+        _p: Secp256Point,
+        _scalar: BigInt,
+    ) -> SyscallResult<Secp256Point> {
+        Err(SyscallError::Unsupported)
+    }
+    /// Returns the point with the given `x` coordinate and `y` parity, if there is one.
+    fn secp256_get_point_from_x(
+        &self,
+        _gas: &mut i64,
+        _curve: Secp256Curve,
+        _x: BigInt,
+        _y_parity: bool,
+    ) -> SyscallResult<Option<Secp256Point>> {
+        Err(SyscallError::Unsupported)
+    }
+    fn secp256_get_xy(
+        &self,
+        _gas: &mut i64,
+        _curve: Secp256Curve,
+        _p: Secp256Point,
+    ) -> SyscallResult<Secp256Point> {
+        Err(SyscallError::Unsupported)
+    }
+    /// Returns the output of the `cheatcode` testing libfunc with the given selector, or `None` if
+    /// the cheatcode is not supported.
+    fn cheatcode(&self, _selector: &BigInt, _input: &[Felt252]) -> Option<Vec<Felt252>> {
+        None
+    }
+}
+
+/// A syscall handler supporting no syscalls.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EmptySyscallHandler;
+impl SyscallHandler for EmptySyscallHandler {}
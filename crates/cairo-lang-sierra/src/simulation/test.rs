@@ -1,16 +1,27 @@
+use std::collections::HashMap;
+
 use bimap::BiMap;
+use indoc::indoc;
 use num_bigint::BigInt;
+use starknet_types_core::felt::Felt;
 use test_case::test_case;
 
 use super::LibfuncSimulationError::{
-    self, FunctionSimulationError, WrongArgType, WrongNumberOfArgs,
+    self, FunctionSimulationError, UnsupportedSyscall, WrongArgType, WrongNumberOfArgs,
 };
+use super::syscalls::{EmptySyscallHandler, SyscallError, SyscallHandler, SyscallResult};
 use super::value::CoreValue::{
-    self, Array, GasBuiltin, RangeCheck, Uint32, Uint128, Uninitialized,
+    self, AddMod, Array, Bitwise, BoundedInt, CircuitData, CircuitDescriptor,
+    CircuitFailureGuarantee, CircuitInputAccumulator, CircuitModulus, CircuitOutputs,
+    CircuitPartialOutputs, Dict, EcOp, EcPoint, EcState, Enum, Felt252, Felt252DictEntry,
+    GasBuiltin, MulMod, Nullable, Pedersen, Poseidon, RangeCheck, RangeCheck96, SegmentArena,
+    Sint8, Sint16, Struct, System, U96Guarantee, U96LimbsLessThanGuarantee, U128MulGuarantee,
+    Uint8, Uint16, Uint32, Uint64, Uint128, Uninitialized,
 };
 use super::{SimulationError, core};
+use crate::ProgramParser;
 use crate::extensions::GenericLibfunc;
-use crate::extensions::core::CoreLibfunc;
+use crate::extensions::core::{CoreLibfunc, CoreType};
 use crate::extensions::lib_func::{
     SierraApChange, SignatureSpecializationContext, SpecializationContext,
 };
@@ -18,6 +29,7 @@ use crate::extensions::type_specialization_context::TypeSpecializationContext;
 use crate::extensions::types::TypeInfo;
 use crate::ids::{ConcreteTypeId, FunctionId, GenericTypeId};
 use crate::program::{ConcreteTypeLongId, Function, FunctionSignature, GenericArg, StatementIdx};
+use crate::program_registry::ProgramRegistry;
 use crate::test_utils::build_bijective_mapping;
 
 fn type_arg(name: &str) -> GenericArg {
@@ -125,6 +137,7 @@ fn simulate(
             .unwrap(),
         inputs,
         || Some(4),
+        |_| None,
         |id, inputs| {
             if id == &"drop_all_inputs".into() {
                 Ok(vec![])
@@ -137,6 +150,7 @@ fn simulate(
                 ))
             }
         },
+        &EmptySyscallHandler,
     )
 }
 
@@ -157,6 +171,36 @@ fn simulate(
 #[test_case("u128_overflowing_sub", vec![], vec![RangeCheck, Uint128(3), Uint128(5)]
              => Ok((vec![RangeCheck, Uint128(u128::MAX - 1)], 1));
             "u128_overflowing_sub(3, 5)")]
+#[test_case("u128s_from_felt252", vec![], vec![RangeCheck, Felt252(Felt::from(7))]
+             => Ok((vec![RangeCheck, Uint128(7)], 0));
+            "u128s_from_felt252(7)")]
+#[test_case("u128s_from_felt252", vec![],
+             vec![RangeCheck, Felt252(Felt::from(u128::MAX) + Felt::TWO)]
+             => Ok((vec![RangeCheck, Uint128(1), Uint128(1)], 1));
+            "u128s_from_felt252(2**128 + 1)")]
+#[test_case("u8_is_zero", vec![], vec![Uint8(3)] => Ok((vec![Uint8(3)], 1)); "u8_is_zero(3)")]
+#[test_case("u8_is_zero", vec![], vec![Uint8(0)] => Ok((vec![], 0)); "u8_is_zero(0)")]
+#[test_case("i8_overflowing_add_impl", vec![], vec![RangeCheck, Sint8(1), Sint8(-3)]
+             => Ok((vec![RangeCheck, Sint8(-2)], 0));
+            "i8_overflowing_add_impl(1, -3)")]
+#[test_case("i8_overflowing_add_impl", vec![], vec![RangeCheck, Sint8(-100), Sint8(-100)]
+             => Ok((vec![RangeCheck, Sint8(56)], 1));
+            "i8_overflowing_add_impl(-100, -100)")]
+#[test_case("i8_overflowing_sub_impl", vec![], vec![RangeCheck, Sint8(100), Sint8(-100)]
+             => Ok((vec![RangeCheck, Sint8(-56)], 2));
+            "i8_overflowing_sub_impl(100, -100)")]
+#[test_case("i8_diff", vec![], vec![RangeCheck, Sint8(3), Sint8(-1)]
+             => Ok((vec![RangeCheck, Uint8(4)], 0));
+            "i8_diff(3, -1)")]
+#[test_case("i8_diff", vec![], vec![RangeCheck, Sint8(1), Sint8(3)]
+             => Ok((vec![RangeCheck, Uint8(254)], 1));
+            "i8_diff(1, 3)")]
+#[test_case("bytes31_try_from_felt252", vec![], vec![RangeCheck, Felt252(Felt::from(5))]
+             => Ok((vec![RangeCheck, Felt252(Felt::from(5))], 0));
+            "bytes31_try_from_felt252(5)")]
+#[test_case("bytes31_try_from_felt252", vec![], vec![RangeCheck, Felt252(Felt::from(-1))]
+             => Ok((vec![RangeCheck], 1));
+            "bytes31_try_from_felt252(-1)")]
 fn simulate_branch(
     id: &str,
     generic_args: Vec<GenericArg>,
@@ -194,6 +238,19 @@ fn simulate_branch(
              => Ok(vec![]); "function_call<drop_all_inputs>()")]
 #[test_case("function_call", vec![user_func_arg("identity")], vec![Uint128(3), Uint128(5)]
              => Ok(vec![Uint128(3), Uint128(5)]); "function_call<identity>()")]
+#[test_case("u128_guarantee_mul", vec![], vec![Uint128(u128::MAX), Uint128(2)]
+             => Ok(vec![Uint128(1), Uint128(u128::MAX - 1), U128MulGuarantee]);
+            "u128_guarantee_mul(u128::MAX, 2)")]
+#[test_case("u128_byte_reverse", vec![], vec![Bitwise, Uint128(1)]
+             => Ok(vec![Bitwise, Uint128(1 << 120)]); "u128_byte_reverse(1)")]
+#[test_case("u8_safe_divmod", vec![], vec![RangeCheck, Uint8(17), Uint8(5)]
+             => Ok(vec![RangeCheck, Uint8(3), Uint8(2)]); "u8_safe_divmod(17, 5)")]
+#[test_case("u8_bitwise", vec![], vec![Bitwise, Uint8(12), Uint8(10)]
+             => Ok(vec![Bitwise, Uint8(8), Uint8(6), Uint8(14)]); "u8_bitwise(12, 10)")]
+#[test_case("u64_sqrt", vec![], vec![RangeCheck, Uint64(17)]
+             => Ok(vec![RangeCheck, Uint32(4)]); "u64_sqrt(17)")]
+#[test_case("i8_wide_mul", vec![], vec![Sint8(-128), Sint8(-128)]
+             => Ok(vec![Sint16(16384)]); "i8_wide_mul(-128, -128)")]
 fn simulate_none_branch(
     id: &str,
     generic_args: Vec<GenericArg>,
@@ -241,3 +298,473 @@ fn simulate_error(
 ) -> LibfuncSimulationError {
     simulate(id, generic_args, inputs).err().unwrap()
 }
+
+/// The x coordinate of the generator of the STARK curve.
+const GEN_X: Felt =
+    Felt::from_hex_unchecked("0x1ef15c18599971b7beced415a40f0c7deacfd9b0d1819e03d723d8bc943cfca");
+/// The y coordinate of the generator of the STARK curve.
+const GEN_Y: Felt =
+    Felt::from_hex_unchecked("0x5668060aa49730b7be4801df46ec62de53ecd11abe43a32873000c36e8dc1f");
+
+const DICT_TYPES: &str = indoc! {"
+    type RangeCheck = RangeCheck;
+    type GasBuiltin = GasBuiltin;
+    type SegmentArena = SegmentArena;
+    type felt252 = felt252;
+    type Felt252Dict<felt252> = Felt252Dict<felt252>;
+    type Felt252DictEntry<felt252> = Felt252DictEntry<felt252>;
+    type SquashedFelt252Dict<felt252> = SquashedFelt252Dict<felt252>;
+    type Nullable<felt252> = Nullable<felt252>;
+    type Felt252Dict<Nullable<felt252>> = Felt252Dict<Nullable<felt252>>;
+    type Felt252DictEntry<Nullable<felt252>> = Felt252DictEntry<Nullable<felt252>>;
+"};
+
+const EC_TYPES: &str = indoc! {"
+    type felt252 = felt252;
+    type EcOp = EcOp;
+    type EcPoint = EcPoint;
+    type NonZero<EcPoint> = NonZero<EcPoint>;
+    type EcState = EcState;
+"};
+
+const HASH_TYPES: &str = indoc! {"
+    type felt252 = felt252;
+    type Pedersen = Pedersen;
+    type Poseidon = Poseidon;
+"};
+
+const SYSCALL_TYPES: &str = indoc! {"
+    type felt252 = felt252;
+    type u32 = u32;
+    type Array<felt252> = Array<felt252>;
+    type GasBuiltin = GasBuiltin;
+    type System = System;
+    type StorageAddress = StorageAddress;
+"};
+
+const U256_TYPES: &str = indoc! {"
+    type RangeCheck = RangeCheck;
+    type u128 = u128;
+    type U128MulGuarantee = U128MulGuarantee;
+    type u256 = Struct<ut@core::integer::u256, u128, u128>;
+    type NonZero<u256> = NonZero<u256>;
+    type u512 = Struct<ut@core::integer::u512, u128, u128, u128, u128>;
+"};
+
+const BOUNDED_INT_TYPES: &str = indoc! {"
+    type RangeCheck = RangeCheck;
+    type u8 = u8;
+    type i8 = i8;
+    type NonZero<i8> = NonZero<i8>;
+    type BoundedInt<0, 510> = BoundedInt<0, 510>;
+    type BoundedInt<0, 127> = BoundedInt<0, 127>;
+    type BoundedInt<128, 255> = BoundedInt<128, 255>;
+    type BoundedInt<3, 8> = BoundedInt<3, 8>;
+    type NonZero<BoundedInt<3, 8>> = NonZero<BoundedInt<3, 8>>;
+    type BoundedInt<16, 85> = BoundedInt<16, 85>;
+    type BoundedInt<0, 7> = BoundedInt<0, 7>;
+"};
+
+const CAST_TYPES: &str = indoc! {"
+    type RangeCheck = RangeCheck;
+    type u8 = u8;
+    type u16 = u16;
+    type u64 = u64;
+"};
+
+const ENUM_TYPES: &str = indoc! {"
+    type Unit = Struct<ut@Tuple>;
+    type BoundedInt<0, 2> = BoundedInt<0, 2>;
+    type Enum3 = Enum<ut@Enum3, Unit, Unit, Unit>;
+"};
+
+/// The types of the circuit computing `in0 / in1`.
+const CIRCUIT_TYPES: &str = indoc! {"
+    type RangeCheck96 = RangeCheck96;
+    type AddMod = AddMod;
+    type MulMod = MulMod;
+    type U96Guarantee = U96Guarantee;
+    type U96GuaranteeLimbs =
+        Struct<ut@Tuple, U96Guarantee, U96Guarantee, U96Guarantee, U96Guarantee>;
+    type u96 = BoundedInt<0, 79228162514264337593543950335>;
+    type U96Limbs = Struct<ut@Tuple, u96, u96, u96, u96>;
+    type BoundedInt<0, 0> = BoundedInt<0, 0>;
+    type BoundedInt<1, 1> = BoundedInt<1, 1>;
+    type U96LimbsLtGuarantee<1> = U96LimbsLtGuarantee<1>;
+    type U96LimbsLtGuarantee<2> = U96LimbsLtGuarantee<2>;
+    type CircuitModulus = CircuitModulus;
+    type CircuitFailureGuarantee = CircuitFailureGuarantee;
+    type In0 = CircuitInput<0>;
+    type In1 = CircuitInput<1>;
+    type Inverse = InverseGate<In1>;
+    type Div = MulModGate<In0, Inverse>;
+    type Outputs = Struct<ut@Tuple, Div>;
+    type DivCircuit = Circuit<Outputs>;
+    type CircuitInputAccumulator<DivCircuit> = CircuitInputAccumulator<DivCircuit>;
+    type CircuitData<DivCircuit> = CircuitData<DivCircuit>;
+    type CircuitDescriptor<DivCircuit> = CircuitDescriptor<DivCircuit>;
+    type CircuitOutputs<DivCircuit> = CircuitOutputs<DivCircuit>;
+    type CircuitPartialOutputs<DivCircuit> = CircuitPartialOutputs<DivCircuit>;
+"};
+
+/// A syscall handler whose storage holds twice the address at each address. Reading address 0
+/// reverts.
+struct DoublingStorageSyscallHandler;
+impl SyscallHandler for DoublingStorageSyscallHandler {
+    fn storage_read(
+        &self,
+        gas: &mut i64,
+        _address_domain: u32,
+        address: Felt,
+    ) -> SyscallResult<Felt> {
+        *gas -= 100;
+        if address == Felt::ZERO {
+            Err(SyscallError::Revert(vec![Felt::from(1)]))
+        } else {
+            Ok(address + address)
+        }
+    }
+}
+
+/// Expects to find the libfunc `libfunc` in a program declaring `types`, and simulates it.
+fn simulate_in_program(
+    types: &str,
+    libfunc: &str,
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    let program = ProgramParser::new().parse(&format!("{types}libfunc f = {libfunc};")).unwrap();
+    let registry = ProgramRegistry::<CoreType, CoreLibfunc>::new(&program).unwrap();
+    core::simulate(
+        registry.get_libfunc(&"f".into()).unwrap(),
+        inputs,
+        || None,
+        |ty| registry.get_type(ty).ok(),
+        |id, _| {
+            Err(FunctionSimulationError(
+                id.clone(),
+                Box::new(SimulationError::StatementOutOfBounds(StatementIdx(0))),
+            ))
+        },
+        &DoublingStorageSyscallHandler,
+    )
+}
+
+/// Returns a dict with the given felt252 entries.
+fn felt252_dict(entries: &[(i64, i64)]) -> HashMap<Felt, CoreValue> {
+    entries.iter().map(|(key, value)| (Felt::from(*key), Felt252(Felt::from(*value)))).collect()
+}
+
+/// Returns a struct of u128 limbs, ordered from the least significant one.
+fn u128_limbs(limbs: &[u128]) -> CoreValue {
+    Struct(limbs.iter().map(|limb| Uint128(*limb)).collect())
+}
+
+/// Returns a struct of the 4 u96 limbs of `value`, each wrapped by `limb`.
+fn u96_limbs(value: i64, limb: fn(BigInt) -> CoreValue) -> CoreValue {
+    Struct(vec![limb(value.into()), limb(0.into()), limb(0.into()), limb(0.into())])
+}
+
+/// Returns a vector of big integers.
+fn big_ints(values: &[i64]) -> Vec<BigInt> {
+    values.iter().map(|value| BigInt::from(*value)).collect()
+}
+
+/// Tests for simulation of libfuncs specialized in a program declaring their types.
+#[test_case(DICT_TYPES, "felt252_dict_new<felt252>", vec![SegmentArena]
+             => Ok((vec![SegmentArena, Dict(felt252_dict(&[]))], 0));
+            "felt252_dict_new()")]
+#[test_case(DICT_TYPES, "felt252_dict_entry_get<felt252>",
+             vec![Dict(felt252_dict(&[(1, 5)])), Felt252(Felt::from(1))]
+             => Ok((vec![
+                 Felt252DictEntry { dict: felt252_dict(&[(1, 5)]), key: Felt::from(1) },
+                 Felt252(Felt::from(5)),
+             ], 0));
+            "felt252_dict_entry_get({1: 5}, 1)")]
+#[test_case(DICT_TYPES, "felt252_dict_entry_get<felt252>",
+             vec![Dict(felt252_dict(&[(1, 5)])), Felt252(Felt::from(2))]
+             => Ok((vec![
+                 Felt252DictEntry { dict: felt252_dict(&[(1, 5)]), key: Felt::from(2) },
+                 Felt252(Felt::ZERO),
+             ], 0));
+            "felt252_dict_entry_get({1: 5}, 2)")]
+#[test_case(DICT_TYPES, "felt252_dict_entry_get<Nullable<felt252>>",
+             vec![Dict(HashMap::new()), Felt252(Felt::from(2))]
+             => Ok((vec![
+                 Felt252DictEntry { dict: HashMap::new(), key: Felt::from(2) },
+                 Nullable(None),
+             ], 0));
+            "felt252_dict_entry_get<Nullable<felt252>>({}, 2)")]
+#[test_case(DICT_TYPES, "felt252_dict_entry_finalize<felt252>",
+             vec![
+                 Felt252DictEntry { dict: felt252_dict(&[(1, 5)]), key: Felt::from(1) },
+                 Felt252(Felt::from(7)),
+             ]
+             => Ok((vec![Dict(felt252_dict(&[(1, 7)]))], 0));
+            "felt252_dict_entry_finalize({1: 5}[1], 7)")]
+#[test_case(DICT_TYPES, "felt252_dict_squash<felt252>",
+             vec![RangeCheck, GasBuiltin(5), SegmentArena, Dict(felt252_dict(&[(1, 5)]))]
+             => Ok((
+                 vec![RangeCheck, GasBuiltin(5), SegmentArena, Dict(felt252_dict(&[(1, 5)]))],
+                 0,
+             ));
+            "felt252_dict_squash({1: 5})")]
+#[test_case(EC_TYPES, "ec_point_try_new_nz", vec![Felt252(GEN_X), Felt252(GEN_Y)]
+             => Ok((vec![EcPoint(GEN_X, GEN_Y)], 0));
+            "ec_point_try_new_nz(G)")]
+#[test_case(EC_TYPES, "ec_point_try_new_nz", vec![Felt252(GEN_X), Felt252(Felt::from(1))]
+             => Ok((vec![], 1));
+            "ec_point_try_new_nz(G.x, 1)")]
+#[test_case(EC_TYPES, "ec_neg", vec![EcPoint(GEN_X, GEN_Y)]
+             => Ok((vec![EcPoint(GEN_X, Felt::ZERO - GEN_Y)], 0));
+            "ec_neg(G)")]
+#[test_case(EC_TYPES, "ec_point_is_zero", vec![EcPoint(Felt::ZERO, Felt::ZERO)]
+             => Ok((vec![], 0));
+            "ec_point_is_zero(0)")]
+#[test_case(EC_TYPES, "ec_point_is_zero", vec![EcPoint(GEN_X, GEN_Y)]
+             => Ok((vec![EcPoint(GEN_X, GEN_Y)], 1));
+            "ec_point_is_zero(G)")]
+#[test_case(EC_TYPES, "ec_state_add", vec![EcState(Felt::ZERO, Felt::ZERO), EcPoint(GEN_X, GEN_Y)]
+             => Ok((vec![EcState(GEN_X, GEN_Y)], 0));
+            "ec_state_add(0, G)")]
+#[test_case(EC_TYPES, "ec_state_add_mul",
+             vec![
+                 EcOp,
+                 EcState(GEN_X, GEN_Y),
+                 Felt252(Felt::from(1)),
+                 EcPoint(GEN_X, Felt::ZERO - GEN_Y),
+             ]
+             => Ok((vec![EcOp, EcState(Felt::ZERO, Felt::ZERO)], 0));
+            "ec_state_add_mul(G, 1, -G)")]
+#[test_case(EC_TYPES, "ec_state_try_finalize_nz", vec![EcState(GEN_X, GEN_Y)]
+             => Ok((vec![EcPoint(GEN_X, GEN_Y)], 0));
+            "ec_state_try_finalize_nz(G)")]
+#[test_case(EC_TYPES, "ec_state_try_finalize_nz", vec![EcState(Felt::ZERO, Felt::ZERO)]
+             => Ok((vec![], 1));
+            "ec_state_try_finalize_nz(0)")]
+#[test_case(SYSCALL_TYPES, "storage_read_syscall",
+             vec![GasBuiltin(1000), System, Uint32(0), Felt252(Felt::from(5))]
+             => Ok((vec![GasBuiltin(900), System, Felt252(Felt::from(10))], 0));
+            "storage_read_syscall(5)")]
+#[test_case(SYSCALL_TYPES, "storage_read_syscall",
+             vec![GasBuiltin(1000), System, Uint32(0), Felt252(Felt::ZERO)]
+             => Ok((vec![GasBuiltin(900), System, Array(vec![Felt252(Felt::from(1))])], 1));
+            "storage_read_syscall(0)")]
+#[test_case(U256_TYPES, "u256_is_zero", vec![u128_limbs(&[0, 0])] => Ok((vec![], 0));
+            "u256_is_zero(0)")]
+#[test_case(U256_TYPES, "u256_is_zero", vec![u128_limbs(&[0, 1])]
+             => Ok((vec![u128_limbs(&[0, 1])], 1));
+            "u256_is_zero(2**128)")]
+#[test_case(U256_TYPES, "u256_safe_divmod",
+             vec![RangeCheck, u128_limbs(&[0, 1]), u128_limbs(&[3, 0])]
+             => Ok((vec![
+                 RangeCheck,
+                 u128_limbs(&[u128::MAX / 3, 0]),
+                 u128_limbs(&[1, 0]),
+                 U128MulGuarantee,
+             ], 0));
+            "u256_safe_divmod(2**128, 3)")]
+#[test_case(U256_TYPES, "u256_sqrt", vec![RangeCheck, u128_limbs(&[0, 1])]
+             => Ok((vec![RangeCheck, Uint128(1 << 64)], 0));
+            "u256_sqrt(2**128)")]
+#[test_case(U256_TYPES, "u256_guarantee_inv_mod_n",
+             vec![RangeCheck, u128_limbs(&[3, 0]), u128_limbs(&[7, 0])]
+             => Ok((
+                 [vec![RangeCheck, u128_limbs(&[5, 0])], vec![U128MulGuarantee; 8]].concat(),
+                 0,
+             ));
+            "u256_guarantee_inv_mod_n(3, 7)")]
+#[test_case(U256_TYPES, "u256_guarantee_inv_mod_n",
+             vec![RangeCheck, u128_limbs(&[2, 0]), u128_limbs(&[4, 0])]
+             => Ok((vec![RangeCheck, U128MulGuarantee, U128MulGuarantee], 1));
+            "u256_guarantee_inv_mod_n(2, 4)")]
+#[test_case(U256_TYPES, "u512_safe_divmod_by_u256",
+             vec![RangeCheck, u128_limbs(&[0, 0, 1, 0]), u128_limbs(&[0, 1])]
+             => Ok((
+                 [
+                     vec![RangeCheck, u128_limbs(&[0, 1, 0, 0]), u128_limbs(&[0, 0])],
+                     vec![U128MulGuarantee; 5],
+                 ].concat(),
+                 0,
+             ));
+            "u512_safe_divmod_by_u256(2**256, 2**128)")]
+#[test_case(BOUNDED_INT_TYPES, "bounded_int_add<u8, u8>", vec![Uint8(200), Uint8(100)]
+             => Ok((vec![BoundedInt(300.into())], 0));
+            "bounded_int_add(200, 100)")]
+#[test_case(BOUNDED_INT_TYPES, "bounded_int_div_rem<BoundedInt<128, 255>, BoundedInt<3, 8>>",
+             vec![RangeCheck, BoundedInt(200.into()), BoundedInt(7.into())]
+             => Ok((vec![RangeCheck, BoundedInt(28.into()), BoundedInt(4.into())], 0));
+            "bounded_int_div_rem(200, 7)")]
+#[test_case(BOUNDED_INT_TYPES, "bounded_int_constrain<u8, 128>", vec![RangeCheck, Uint8(100)]
+             => Ok((vec![RangeCheck, BoundedInt(100.into())], 0));
+            "bounded_int_constrain<u8, 128>(100)")]
+#[test_case(BOUNDED_INT_TYPES, "bounded_int_constrain<u8, 128>", vec![RangeCheck, Uint8(200)]
+             => Ok((vec![RangeCheck, BoundedInt(200.into())], 1));
+            "bounded_int_constrain<u8, 128>(200)")]
+#[test_case(BOUNDED_INT_TYPES, "bounded_int_is_zero<i8>", vec![Sint8(0)] => Ok((vec![], 0));
+            "bounded_int_is_zero<i8>(0)")]
+#[test_case(BOUNDED_INT_TYPES, "bounded_int_is_zero<i8>", vec![Sint8(-3)]
+             => Ok((vec![Sint8(-3)], 1));
+            "bounded_int_is_zero<i8>(-3)")]
+#[test_case(CAST_TYPES, "downcast<u64, u16>", vec![RangeCheck, Uint64(1000)]
+             => Ok((vec![RangeCheck, Uint16(1000)], 0));
+            "downcast<u64, u16>(1000)")]
+#[test_case(CAST_TYPES, "downcast<u64, u16>", vec![RangeCheck, Uint64(70000)]
+             => Ok((vec![RangeCheck], 1));
+            "downcast<u64, u16>(70000)")]
+#[test_case(CAST_TYPES, "upcast<u8, u64>", vec![Uint8(200)] => Ok((vec![Uint64(200)], 0));
+            "upcast<u8, u64>(200)")]
+#[test_case(ENUM_TYPES, "enum_from_bounded_int<Enum3>", vec![BoundedInt(2.into())]
+             => Ok((vec![Enum { value: Box::new(Struct(vec![])), index: 2 }], 0));
+            "enum_from_bounded_int<Enum3>(2)")]
+#[test_case(CIRCUIT_TYPES, "init_circuit_data<DivCircuit>", vec![RangeCheck96]
+             => Ok((vec![RangeCheck96, CircuitInputAccumulator(vec![])], 0));
+            "init_circuit_data()")]
+#[test_case(CIRCUIT_TYPES, "add_circuit_input<DivCircuit>",
+             vec![CircuitInputAccumulator(vec![]), u96_limbs(6, U96Guarantee)]
+             => Ok((vec![CircuitInputAccumulator(big_ints(&[6]))], 1));
+            "add_circuit_input([], 6)")]
+#[test_case(CIRCUIT_TYPES, "add_circuit_input<DivCircuit>",
+             vec![CircuitInputAccumulator(big_ints(&[6])), u96_limbs(3, U96Guarantee)]
+             => Ok((vec![CircuitData(big_ints(&[6, 3]))], 0));
+            "add_circuit_input([6], 3)")]
+#[test_case(CIRCUIT_TYPES, "get_circuit_descriptor<DivCircuit>", vec![]
+             => Ok((vec![CircuitDescriptor], 0));
+            "get_circuit_descriptor()")]
+#[test_case(CIRCUIT_TYPES, "eval_circuit<DivCircuit>",
+             vec![
+                 AddMod,
+                 MulMod,
+                 CircuitDescriptor,
+                 CircuitData(big_ints(&[6, 3])),
+                 CircuitModulus(7.into()),
+                 BoundedInt(0.into()),
+                 BoundedInt(1.into()),
+             ]
+             => Ok((vec![
+                 AddMod,
+                 MulMod,
+                 CircuitOutputs { values: big_ints(&[1, 6, 3, 6, 3, 5, 2]), modulus: 7.into() },
+             ], 0));
+            "eval_circuit(6 / 3 mod 7)")]
+#[test_case(CIRCUIT_TYPES, "eval_circuit<DivCircuit>",
+             vec![
+                 AddMod,
+                 MulMod,
+                 CircuitDescriptor,
+                 CircuitData(big_ints(&[1, 4])),
+                 CircuitModulus(6.into()),
+                 BoundedInt(0.into()),
+                 BoundedInt(1.into()),
+             ]
+             => Ok((vec![
+                 AddMod,
+                 MulMod,
+                 CircuitPartialOutputs,
+                 CircuitFailureGuarantee { nullifier: 3.into(), modulus: 6.into() },
+             ], 1));
+            "eval_circuit(1 / 4 mod 6)")]
+#[test_case(CIRCUIT_TYPES, "try_into_circuit_modulus", vec![u96_limbs(7, BoundedInt)]
+             => Ok((vec![CircuitModulus(7.into())], 0));
+            "try_into_circuit_modulus(7)")]
+#[test_case(CIRCUIT_TYPES, "try_into_circuit_modulus", vec![u96_limbs(1, BoundedInt)]
+             => Ok((vec![], 1));
+            "try_into_circuit_modulus(1)")]
+#[test_case(CIRCUIT_TYPES, "u96_guarantee_verify", vec![RangeCheck96, U96Guarantee(5.into())]
+             => Ok((vec![RangeCheck96], 0));
+            "u96_guarantee_verify(5)")]
+#[test_case(CIRCUIT_TYPES, "u96_limbs_less_than_guarantee_verify<2>",
+             vec![U96LimbsLessThanGuarantee { lhs: big_ints(&[1, 5]), rhs: big_ints(&[3, 5]) }]
+             => Ok((
+                 vec![U96LimbsLessThanGuarantee { lhs: big_ints(&[1]), rhs: big_ints(&[3]) }],
+                 0,
+             ));
+            "u96_limbs_less_than_guarantee_verify([1, 5], [3, 5])")]
+#[test_case(CIRCUIT_TYPES, "u96_limbs_less_than_guarantee_verify<2>",
+             vec![U96LimbsLessThanGuarantee { lhs: big_ints(&[1, 5]), rhs: big_ints(&[0, 7]) }]
+             => Ok((vec![U96Guarantee(2.into())], 1));
+            "u96_limbs_less_than_guarantee_verify([1, 5], [0, 7])")]
+fn simulate_typed_branch(
+    types: &str,
+    libfunc: &str,
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    simulate_in_program(types, libfunc, inputs)
+}
+
+#[test_case(DICT_TYPES, "felt252_dict_entry_finalize<felt252>", vec![Dict(HashMap::new())]
+             => WrongNumberOfArgs;
+            "felt252_dict_entry_finalize({})")]
+#[test_case(EC_TYPES, "ec_neg", vec![Felt252(GEN_X)] => WrongArgType; "ec_neg(G.x)")]
+#[test_case(HASH_TYPES, "pedersen", vec![Pedersen, Felt252(Felt::from(1))] => WrongNumberOfArgs;
+            "pedersen(1)")]
+#[test_case(SYSCALL_TYPES, "storage_write_syscall",
+             vec![
+                 GasBuiltin(1000),
+                 System,
+                 Uint32(0),
+                 Felt252(Felt::from(5)),
+                 Felt252(Felt::from(6)),
+             ]
+             => UnsupportedSyscall;
+            "storage_write_syscall(5, 6)")]
+#[test_case(U256_TYPES, "u256_sqrt", vec![RangeCheck, Uint128(4)] => WrongArgType; "u256_sqrt(4)")]
+#[test_case(BOUNDED_INT_TYPES, "bounded_int_add<u8, u8>", vec![Uint8(1), Array(vec![])]
+             => WrongArgType;
+            "bounded_int_add(1, [])")]
+#[test_case(CAST_TYPES, "downcast<u64, u16>", vec![Uint64(1000)] => WrongNumberOfArgs;
+            "downcast<u64, u16>(1000)")]
+#[test_case(ENUM_TYPES, "enum_from_bounded_int<Enum3>", vec![Felt252(Felt::from(-1))]
+             => WrongArgType;
+            "enum_from_bounded_int<Enum3>(-1)")]
+#[test_case(CIRCUIT_TYPES, "u96_guarantee_verify", vec![RangeCheck, U96Guarantee(5.into())]
+             => WrongArgType;
+            "u96_guarantee_verify(RangeCheck)")]
+fn simulate_typed_error(
+    types: &str,
+    libfunc: &str,
+    inputs: Vec<CoreValue>,
+) -> LibfuncSimulationError {
+    simulate_in_program(types, libfunc, inputs).err().unwrap()
+}
+
+#[test]
+fn simulate_pedersen() {
+    let hash = Felt::from_dec_str(
+        "2592987851775965742543459319508348457290966253241455514226127639100457844774",
+    )
+    .unwrap();
+    assert_eq!(
+        simulate_in_program(HASH_TYPES, "pedersen", vec![
+            Pedersen,
+            Felt252(Felt::from(1)),
+            Felt252(Felt::from(2)),
+        ]),
+        Ok((vec![Pedersen, Felt252(hash)], 0))
+    );
+}
+
+#[test]
+fn simulate_hades_permutation() {
+    let felt = |value| Felt252(Felt::from_dec_str(value).unwrap());
+    assert_eq!(
+        simulate_in_program(HASH_TYPES, "hades_permutation", vec![
+            Poseidon,
+            Felt252(Felt::from(1)),
+            Felt252(Felt::from(2)),
+            Felt252(Felt::from(3)),
+        ]),
+        Ok((
+            vec![
+                Poseidon,
+                felt("442682200349489646213731521593476982257703159825582578145778919623645026501"),
+                felt(
+                    "2233832504250924383748553933071188903279928981104663696710686541536735838182"
+                ),
+                felt(
+                    "2512222140811166287287541003826449032093371832913959128171347018667852712082"
+                ),
+            ],
+            0
+        ))
+    );
+}
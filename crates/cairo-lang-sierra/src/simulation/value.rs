@@ -1,11 +1,19 @@
 use std::collections::HashMap;
 
+use num_bigint::BigInt;
 use starknet_types_core::felt::Felt as Felt252;
 
 /// The logical value of a variable for Sierra simulation.
+///
+/// `NonZero`, `Box` and snapshot values are represented as the values they wrap. `bytes31`,
+/// `ContractAddress`, `ClassHash`, `StorageBaseAddress` and `StorageAddress` values are represented
+/// as [CoreValue::Felt252], and `Sha256StateHandle` values as the `[u32; 8]` state they hold.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum CoreValue {
+    /// An EC point. The zero point is represented as `(0, 0)`.
     EcPoint(Felt252, Felt252),
+    /// An EC state, represented as the EC point accumulated in it.
+    EcState(Felt252, Felt252),
     Felt252(Felt252),
     GasBuiltin(i64),
     Uint8(u8),
@@ -13,17 +21,74 @@ pub enum CoreValue {
     Uint32(u32),
     Uint64(u64),
     Uint128(u128),
+    Sint8(i8),
+    Sint16(i16),
+    Sint32(i32),
+    Sint64(i64),
+    Sint128(i128),
+    BoundedInt(BigInt),
     Array(Vec<CoreValue>),
     Dict(HashMap<Felt252, CoreValue>),
+    /// An entry of a dict, holding the dict until finalized.
+    Felt252DictEntry {
+        dict: HashMap<Felt252, CoreValue>,
+        key: Felt252,
+    },
     Enum {
         value: Box<CoreValue>,
         /// The index of the relevant variant.
         index: usize,
     },
     Struct(Vec<CoreValue>),
+    Nullable(Option<Box<CoreValue>>),
+    /// The range `[start, end)` of an integer type.
+    IntRange {
+        start: Box<CoreValue>,
+        end: Box<CoreValue>,
+    },
+    /// A point on a secp256 curve. The point at infinity is represented as `(0, 0)`.
+    Secp256Point {
+        x: BigInt,
+        y: BigInt,
+    },
+    /// The circuit inputs added so far.
+    CircuitInputAccumulator(Vec<BigInt>),
+    /// All the inputs of a circuit.
+    CircuitData(Vec<BigInt>),
+    CircuitModulus(BigInt),
+    /// The values of an evaluated circuit, by their offset in the values of the circuit.
+    CircuitOutputs {
+        values: Vec<BigInt>,
+        modulus: BigInt,
+    },
+    /// A guarantee that a circuit failed, as `nullifier` has no inverse modulo `modulus`.
+    CircuitFailureGuarantee {
+        nullifier: BigInt,
+        modulus: BigInt,
+    },
+    /// A guarantee that the number with the `lhs` u96 limbs is less than the one with the `rhs`
+    /// limbs. The limbs are ordered from the least significant one.
+    U96LimbsLessThanGuarantee {
+        lhs: Vec<BigInt>,
+        rhs: Vec<BigInt>,
+    },
+    /// A guarantee that the value is a u96.
+    U96Guarantee(BigInt),
     // The untracked types - do not carry any value.
     Uninitialized,
     RangeCheck,
+    RangeCheck96,
     Bitwise,
+    Pedersen,
+    Poseidon,
+    EcOp,
+    AddMod,
+    MulMod,
+    SegmentArena,
+    System,
+    BuiltinCosts,
+    Coupon,
+    CircuitDescriptor,
+    CircuitPartialOutputs,
     U128MulGuarantee,
 }
@@ -9,9 +9,12 @@ use crate::program::{ConcreteTypeLongId, GenericArg};
 pub fn build_bijective_mapping() -> BiMap<ConcreteTypeId, ConcreteTypeLongId> {
     let mut elements = BiMap::new();
     elements.insert("T".into(), as_type_long_id("T", &[]));
+    elements.insert("u8".into(), as_type_long_id("u8", &[]));
     elements.insert("u32".into(), as_type_long_id("u32", &[]));
     elements.insert("u64".into(), as_type_long_id("u64", &[]));
     elements.insert("u128".into(), as_type_long_id("u128", &[]));
+    elements.insert("i8".into(), as_type_long_id("i8", &[]));
+    elements.insert("i16".into(), as_type_long_id("i16", &[]));
     elements.insert("bytes31".into(), as_type_long_id("bytes31", &[]));
     elements.insert("felt252".into(), as_type_long_id("felt252", &[]));
     elements.insert("Tuple<>".into(), as_named_type_long_id("Struct", "Tuple", &[]));
@@ -22,6 +25,7 @@ pub fn build_bijective_mapping() -> BiMap<ConcreteTypeId, ConcreteTypeLongId> {
     elements
         .insert("Option".into(), as_named_type_long_id("Enum", "Option", &["felt252", "Tuple<>"]));
     elements.insert("NonZeroFelt252".into(), as_type_long_id("NonZero", &["felt252"]));
+    elements.insert("NonZeroU8".into(), as_type_long_id("NonZero", &["u8"]));
     elements.insert("NonZeroU128".into(), as_type_long_id("NonZero", &["u128"]));
     elements.insert("ArrayFelt252".into(), as_type_long_id("Array", &["felt252"]));
     elements.insert("ArrayFelt252".into(), as_type_long_id("Array", &["felt252"]));
@@ -51,6 +55,8 @@ pub fn build_bijective_mapping() -> BiMap<ConcreteTypeId, ConcreteTypeLongId> {
     elements.insert("Uninitializedu128".into(), as_type_long_id("Uninitialized", &["u128"]));
     elements.insert("GasBuiltin".into(), as_type_long_id("GasBuiltin", &[]));
     elements.insert("RangeCheck".into(), as_type_long_id("RangeCheck", &[]));
+    elements.insert("Bitwise".into(), as_type_long_id("Bitwise", &[]));
+    elements.insert("U128MulGuarantee".into(), as_type_long_id("U128MulGuarantee", &[]));
     elements.insert("System".into(), as_type_long_id("System", &[]));
     elements.insert("StorageBaseAddress".into(), as_type_long_id("StorageBaseAddress", &[]));
     elements.insert("StorageAddress".into(), as_type_long_id("StorageAddress", &[]));
@@ -0,0 +1,287 @@
+//! Static verification of Sierra programs.
+//!
+//! Verifies the flow of variables through the statements of every function against the signatures
+//! of the invoked libfuncs, without compiling the program. Together with the checks of
+//! [ProgramRegistry::new], a program passing [verify] is well-typed.
+
+use std::collections::HashSet;
+
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use itertools::izip;
+use thiserror::Error;
+
+use crate::extensions::ConcreteLibfunc;
+use crate::extensions::core::{CoreLibfunc, CoreType};
+use crate::ids::{ConcreteTypeId, FunctionId, VarId};
+use crate::program::{Function, Program, Statement, StatementIdx};
+use crate::program_registry::{ProgramRegistry, ProgramRegistryError};
+
+#[cfg(test)]
+#[path = "verifier_test.rs"]
+mod test;
+
+/// Error found while verifying a Sierra program.
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum VerificationError {
+    #[error(transparent)]
+    ProgramRegistryError(#[from] Box<ProgramRegistryError>),
+    #[error("Function `{0}`'s parameters do not match its signature.")]
+    ParamsSignatureMismatch(FunctionId),
+    #[error("#{0}: Statement is unreachable.")]
+    UnreachableStatement(StatementIdx),
+    #[error("`{function_id}` #{statement_idx}: {error}")]
+    StatementError { function_id: FunctionId, statement_idx: StatementIdx, error: StatementError },
+}
+
+/// Error in a statement of a function.
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum StatementError {
+    #[error("Variable `{0}` is undefined.")]
+    UndefinedVariable(VarId),
+    #[error("Variable `{0}` was used after being moved.")]
+    UseAfterMove(VarId),
+    #[error("Variable `{0}` was overridden.")]
+    VariableOverride(VarId),
+    #[error("Argument #{index} `{var_id}` is of type `{actual}`, expected `{expected}`.")]
+    ArgumentTypeMismatch {
+        index: usize,
+        var_id: VarId,
+        expected: ConcreteTypeId,
+        actual: ConcreteTypeId,
+    },
+    #[error("Returning {actual} values, expected {expected}.")]
+    ReturnCountMismatch { expected: usize, actual: usize },
+    #[error("Returned value #{index} `{var_id}` is of type `{actual}`, expected `{expected}`.")]
+    ReturnTypeMismatch {
+        index: usize,
+        var_id: VarId,
+        expected: ConcreteTypeId,
+        actual: ConcreteTypeId,
+    },
+    #[error("Variable `{0}` is not consumed before returning.")]
+    DanglingVariable(VarId),
+    #[error("Variable `{0}` is inconsistent between the flows reaching the statement.")]
+    InconsistentVariable(VarId),
+    #[error("Statement also belongs to function `{0}`.")]
+    MultipleFunctions(FunctionId),
+}
+
+/// Verifies that `program` is well-typed, returning all the errors found.
+///
+/// Checks that every variable is defined, of the type expected by its usage and used at most once
+/// before being redefined, that all the flows reaching a statement agree on the variables, that
+/// every function returns its declared types without leaving variables behind, and that every
+/// statement is reachable from exactly one function.
+pub fn verify(program: &Program) -> Result<(), Vec<VerificationError>> {
+    let registry =
+        ProgramRegistry::<CoreType, CoreLibfunc>::new(program).map_err(|err| vec![err.into()])?;
+    let mut verifier = Verifier {
+        program,
+        registry: &registry,
+        statement_infos: vec![None; program.statements.len()],
+        errors: vec![],
+    };
+    for func in &program.funcs {
+        verifier.verify_function(func);
+    }
+    let Verifier { statement_infos, mut errors, .. } = verifier;
+    errors.extend(
+        statement_infos
+            .iter()
+            .enumerate()
+            .filter(|(_, info)| info.is_none())
+            .map(|(idx, _)| VerificationError::UnreachableStatement(StatementIdx(idx))),
+    );
+    if errors.is_empty() { Ok(()) } else { Err(errors) }
+}
+
+/// The state of the variables at a point of a function.
+#[derive(Clone, Debug, Default)]
+struct VarsState {
+    /// The types of the defined variables.
+    vars: OrderedHashMap<VarId, ConcreteTypeId>,
+    /// The variables that were used, and not redefined since.
+    moved: HashSet<VarId>,
+}
+impl VarsState {
+    /// Takes a variable out of the state, returning its type.
+    fn take(&mut self, var_id: &VarId) -> Result<ConcreteTypeId, StatementError> {
+        let Some(ty) = self.vars.swap_remove(var_id) else {
+            return Err(if self.moved.contains(var_id) {
+                StatementError::UseAfterMove(var_id.clone())
+            } else {
+                StatementError::UndefinedVariable(var_id.clone())
+            });
+        };
+        self.moved.insert(var_id.clone());
+        Ok(ty)
+    }
+
+    /// Defines a variable in the state.
+    fn put(&mut self, var_id: &VarId, ty: ConcreteTypeId) -> Result<(), StatementError> {
+        if self.vars.insert(var_id.clone(), ty).is_some() {
+            return Err(StatementError::VariableOverride(var_id.clone()));
+        }
+        self.moved.remove(var_id);
+        Ok(())
+    }
+}
+
+/// The verification information of a reached statement.
+#[derive(Clone)]
+struct StatementInfo {
+    /// The function the statement belongs to.
+    function_id: FunctionId,
+    /// The variables defined before the statement, in the first flow reaching it.
+    vars: OrderedHashMap<VarId, ConcreteTypeId>,
+}
+
+/// Helper for verifying the functions of a program.
+struct Verifier<'a> {
+    program: &'a Program,
+    registry: &'a ProgramRegistry<CoreType, CoreLibfunc>,
+    /// The information of each statement, or `None` if not reached yet.
+    statement_infos: Vec<Option<StatementInfo>>,
+    errors: Vec<VerificationError>,
+}
+impl Verifier<'_> {
+    /// Verifies the statements reachable from the entry point of `func`.
+    fn verify_function(&mut self, func: &Function) {
+        if !itertools::equal(func.params.iter().map(|param| &param.ty), &func.signature.param_types)
+        {
+            self.errors.push(VerificationError::ParamsSignatureMismatch(func.id.clone()));
+        }
+        let mut state = VarsState::default();
+        for param in &func.params {
+            if let Err(error) = state.put(&param.id, param.ty.clone()) {
+                self.add_error(func, func.entry_point, error);
+                return;
+            }
+        }
+        let mut pending = vec![(func.entry_point, state)];
+        while let Some((idx, state)) = pending.pop() {
+            if let Some(info) = &self.statement_infos[idx.0] {
+                let error = if info.function_id != func.id {
+                    Some(StatementError::MultipleFunctions(info.function_id.clone()))
+                } else {
+                    inconsistent_var(&info.vars, &state.vars)
+                        .map(StatementError::InconsistentVariable)
+                };
+                if let Some(error) = error {
+                    self.add_error(func, idx, error);
+                }
+                continue;
+            }
+            self.statement_infos[idx.0] =
+                Some(StatementInfo { function_id: func.id.clone(), vars: state.vars.clone() });
+            let (next, error) = self.verify_statement(func, idx, state);
+            if let Some(error) = error {
+                self.add_error(func, idx, error);
+            }
+            pending.extend(next);
+        }
+    }
+
+    /// Verifies the statement `idx` of `func`, given the state before it. Returns the statements
+    /// following it, with the states before them, and the first error found in it.
+    ///
+    /// The following statements are returned even if the statement is erroneous, so the rest of the
+    /// function is still verified.
+    fn verify_statement(
+        &self,
+        func: &Function,
+        idx: StatementIdx,
+        mut state: VarsState,
+    ) -> (Vec<(StatementIdx, VarsState)>, Option<StatementError>) {
+        let mut error = None;
+        match &self.program.statements[idx.0] {
+            Statement::Invocation(invocation) => {
+                // The registry validated the libfuncs of all invocations.
+                let libfunc = self.registry.get_libfunc(&invocation.libfunc_id).unwrap();
+                for (index, (var_id, param)) in
+                    izip!(&invocation.args, libfunc.param_signatures()).enumerate()
+                {
+                    match state.take(var_id) {
+                        Ok(ty) if ty != param.ty => {
+                            error.get_or_insert(StatementError::ArgumentTypeMismatch {
+                                index,
+                                var_id: var_id.clone(),
+                                expected: param.ty.clone(),
+                                actual: ty,
+                            });
+                        }
+                        Ok(_) => {}
+                        Err(err) => {
+                            error.get_or_insert(err);
+                        }
+                    }
+                }
+                let mut next = vec![];
+                for (branch, signature) in izip!(&invocation.branches, libfunc.branch_signatures())
+                {
+                    let mut branch_state = state.clone();
+                    for (var_id, var_info) in izip!(&branch.results, &signature.vars) {
+                        if let Err(err) = branch_state.put(var_id, var_info.ty.clone()) {
+                            error.get_or_insert(err);
+                        }
+                    }
+                    next.push((idx.next(&branch.target), branch_state));
+                }
+                (next, error)
+            }
+            Statement::Return(var_ids) => {
+                let ret_types = &func.signature.ret_types;
+                if var_ids.len() != ret_types.len() {
+                    return (
+                        vec![],
+                        Some(StatementError::ReturnCountMismatch {
+                            expected: ret_types.len(),
+                            actual: var_ids.len(),
+                        }),
+                    );
+                }
+                for (index, (var_id, expected)) in izip!(var_ids, ret_types).enumerate() {
+                    match state.take(var_id) {
+                        Ok(ty) if ty != *expected => {
+                            error.get_or_insert(StatementError::ReturnTypeMismatch {
+                                index,
+                                var_id: var_id.clone(),
+                                expected: expected.clone(),
+                                actual: ty,
+                            });
+                        }
+                        Ok(_) => {}
+                        Err(err) => {
+                            error.get_or_insert(err);
+                        }
+                    }
+                }
+                if let Some(var_id) = state.vars.keys().next() {
+                    error.get_or_insert(StatementError::DanglingVariable(var_id.clone()));
+                }
+                (vec![], error)
+            }
+        }
+    }
+
+    /// Adds an error in the statement `idx` of `func`.
+    fn add_error(&mut self, func: &Function, idx: StatementIdx, error: StatementError) {
+        self.errors.push(VerificationError::StatementError {
+            function_id: func.id.clone(),
+            statement_idx: idx,
+            error,
+        });
+    }
+}
+
+/// Returns a variable defined differently in `expected` and `actual`, if there is one.
+fn inconsistent_var(
+    expected: &OrderedHashMap<VarId, ConcreteTypeId>,
+    actual: &OrderedHashMap<VarId, ConcreteTypeId>,
+) -> Option<VarId> {
+    expected
+        .iter()
+        .find(|(var_id, ty)| actual.get(*var_id) != Some(*ty))
+        .or_else(|| actual.iter().find(|(var_id, _)| expected.get(*var_id).is_none()))
+        .map(|(var_id, _)| var_id.clone())
+}
@@ -0,0 +1,206 @@
+use indoc::indoc;
+use test_case::test_case;
+
+use super::{StatementError, VerificationError, verify};
+use crate::ProgramParser;
+use crate::program::StatementIdx;
+
+/// Parses and verifies a Sierra program.
+fn verify_code(code: &str) -> Result<(), Vec<VerificationError>> {
+    verify(&ProgramParser::new().parse(code).unwrap())
+}
+
+#[test]
+fn verify_examples() {
+    assert_eq!(verify_code(include_str!("../examples/fib_jumps.sierra")), Ok(()));
+    assert_eq!(verify_code(include_str!("../examples/fib_no_gas.sierra")), Ok(()));
+}
+
+#[test_case(indoc! {"
+    type felt252 = felt252;
+    type u128 = u128;
+
+    libfunc felt252_add = felt252_add;
+
+    felt252_add(a, b) -> (c);
+    return(c);
+
+    Func@0(a: felt252, b: u128) -> (felt252);
+"},
+StatementError::ArgumentTypeMismatch {
+    index: 1, var_id: "b".into(), expected: "felt252".into(), actual: "u128".into(),
+};
+"argument type mismatch")]
+#[test_case(indoc! {"
+    type felt252 = felt252;
+
+    libfunc felt252_add = felt252_add;
+
+    felt252_add(a, a) -> (b);
+    return(b);
+
+    Func@0(a: felt252) -> (felt252);
+"},
+StatementError::UseAfterMove("a".into());
+"double use")]
+#[test_case(indoc! {"
+    type felt252 = felt252;
+
+    libfunc felt252_add = felt252_add;
+
+    felt252_add(a, c) -> (b);
+    return(b);
+
+    Func@0(a: felt252) -> (felt252);
+"},
+StatementError::UndefinedVariable("c".into());
+"undefined variable")]
+#[test_case(indoc! {"
+    type felt252 = felt252;
+
+    libfunc felt252_dup = dup<felt252>;
+
+    felt252_dup(a) -> (b, b);
+    return(b);
+
+    Func@0(a: felt252) -> (felt252);
+"},
+StatementError::VariableOverride("b".into());
+"variable override")]
+#[test_case(indoc! {"
+    type felt252 = felt252;
+
+    return(a);
+
+    Func@0(a: felt252, b: felt252) -> (felt252);
+"},
+StatementError::DanglingVariable("b".into());
+"dangling variable")]
+#[test_case(indoc! {"
+    type felt252 = felt252;
+
+    return(a);
+
+    Func@0(a: felt252) -> (felt252, felt252);
+"},
+StatementError::ReturnCountMismatch { expected: 2, actual: 1 };
+"return count mismatch")]
+#[test_case(indoc! {"
+    type felt252 = felt252;
+    type u128 = u128;
+
+    return(a);
+
+    Func@0(a: u128) -> (felt252);
+"},
+StatementError::ReturnTypeMismatch {
+    index: 0, var_id: "a".into(), expected: "felt252".into(), actual: "u128".into(),
+};
+"return type mismatch")]
+fn statement_error(code: &str, error: StatementError) {
+    assert_eq!(
+        verify_code(code),
+        Err(vec![VerificationError::StatementError {
+            function_id: "Func".into(),
+            statement_idx: StatementIdx(0),
+            error,
+        }])
+    );
+}
+
+#[test]
+fn errors_after_erroneous_statement() {
+    assert_eq!(
+        verify_code(indoc! {"
+            type felt252 = felt252;
+
+            libfunc felt252_add = felt252_add;
+            libfunc felt252_dup = dup<felt252>;
+
+            felt252_add(a, b) -> (c);
+            felt252_add(a, c) -> (d);
+            felt252_dup(d) -> (e, f);
+            return(e);
+
+            Func@0(a: felt252, b: felt252) -> (felt252);
+        "}),
+        Err(vec![
+            VerificationError::StatementError {
+                function_id: "Func".into(),
+                statement_idx: StatementIdx(1),
+                error: StatementError::UseAfterMove("a".into()),
+            },
+            VerificationError::StatementError {
+                function_id: "Func".into(),
+                statement_idx: StatementIdx(3),
+                error: StatementError::DanglingVariable("f".into()),
+            },
+        ])
+    );
+}
+
+#[test]
+fn inconsistent_variables() {
+    assert_eq!(
+        verify_code(indoc! {"
+            type felt252 = felt252;
+            type NonZeroFelt252 = NonZero<felt252>;
+
+            libfunc branch_align = branch_align;
+            libfunc felt252_is_zero = felt252_is_zero;
+            libfunc unwrap_non_zero = unwrap_non_zero<felt252>;
+            libfunc jump = jump;
+
+            felt252_is_zero(a) { fallthrough() NonZero(a) };
+            branch_align() -> ();
+            jump() { End() };
+            NonZero:
+            branch_align() -> ();
+            unwrap_non_zero(a) -> (b);
+            jump() { End() };
+            End:
+            return(b);
+
+            Func@0(a: felt252) -> (felt252);
+        "}),
+        Err(vec![VerificationError::StatementError {
+            function_id: "Func".into(),
+            statement_idx: StatementIdx(6),
+            error: StatementError::InconsistentVariable("b".into()),
+        }])
+    );
+}
+
+#[test]
+fn statement_in_multiple_functions() {
+    assert_eq!(
+        verify_code(indoc! {"
+            type felt252 = felt252;
+
+            return(a);
+
+            Func1@0(a: felt252) -> (felt252);
+            Func2@0(a: felt252) -> (felt252);
+        "}),
+        Err(vec![VerificationError::StatementError {
+            function_id: "Func2".into(),
+            statement_idx: StatementIdx(0),
+            error: StatementError::MultipleFunctions("Func1".into()),
+        }])
+    );
+}
+
+#[test]
+fn unreachable_statement() {
+    assert_eq!(
+        verify_code(indoc! {"
+            type felt252 = felt252;
+
+            return(a);
+            return(a);
+
+            Func@0(a: felt252) -> (felt252);
+        "}),
+        Err(vec![VerificationError::UnreachableStatement(StatementIdx(1))])
+    );
+}
@@ -17,6 +17,11 @@ impl Contract {
         serde_json::to_string_pretty(&self).unwrap()
     }
 
+    /// Returns the items of the ABI.
+    pub fn items(&self) -> impl Iterator<Item = &Item> {
+        self.items.iter()
+    }
+
     /// Validates the ABI entry points counts match the expected counts.
     pub fn sanity_check(
         &self,
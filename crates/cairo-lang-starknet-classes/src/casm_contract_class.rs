@@ -47,9 +47,8 @@ use crate::compiler_version::{
     current_sierra_version_id,
 };
 use crate::contract_class::{ContractClass, ContractEntryPoint};
-use crate::contract_segmentation::{
-    NestedIntList, SegmentationError, compute_bytecode_segment_lengths,
-};
+pub use crate::contract_segmentation::NestedIntList;
+use crate::contract_segmentation::{SegmentationError, compute_bytecode_segment_lengths};
 use crate::felt252_serde::{Felt252SerdeError, sierra_from_felt252s};
 use crate::keccak::starknet_keccak;
 
@@ -34,6 +34,7 @@ mod self_caller {
 
 #[test]
 fn test_deploy_in_construct() {
+    starknet::testing::declare(self_caller::TEST_CLASS_HASH.try_into().unwrap()).unwrap();
     let (contract_address, _) = deploy_syscall(
         self_caller::TEST_CLASS_HASH.try_into().unwrap(), 0, [].span(), false,
     )
@@ -44,6 +45,7 @@ fn test_deploy_in_construct() {
 
 #[test]
 fn test_redeploy_in_construct() {
+    starknet::testing::declare(self_caller::TEST_CLASS_HASH.try_into().unwrap()).unwrap();
     assert!(
         deploy_syscall(self_caller::TEST_CLASS_HASH.try_into().unwrap(), 0, [].span(), false)
             .is_ok(),
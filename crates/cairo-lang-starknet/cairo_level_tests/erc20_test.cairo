@@ -6,6 +6,7 @@ use starknet::contract_address::contract_address_const;
 #[test]
 fn test_erc20_transfer() {
     let class_hash = erc_20::TEST_CLASS_HASH.try_into().unwrap();
+    starknet::testing::declare(class_hash).unwrap();
 
     set_caller_address(contract_address_const::<2_felt252>());
     let contract_address = contract_address_const::<1_felt252>();
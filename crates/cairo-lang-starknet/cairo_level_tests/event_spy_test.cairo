@@ -0,0 +1,152 @@
+use starknet::testing::{EmittedEvent, EventSpyTrait};
+use starknet::{ContractAddress, SyscallResultTrait, testing};
+
+#[starknet::interface]
+trait IToken<T> {
+    fn transfer(ref self: T, from: ContractAddress, to: ContractAddress, amount: u256);
+    fn try_transfer(ref self: T, from: ContractAddress, to: ContractAddress, amount: u256) -> bool;
+}
+
+#[starknet::contract]
+mod token {
+    use starknet::ContractAddress;
+    use starknet::syscalls::call_contract_syscall;
+
+    #[storage]
+    struct Storage {}
+
+    #[derive(Copy, Drop, Debug, PartialEq, starknet::Event)]
+    pub struct Transfer {
+        #[key]
+        pub from: ContractAddress,
+        #[key]
+        pub to: ContractAddress,
+        pub amount: u256,
+    }
+
+    #[event]
+    #[derive(Copy, Drop, Debug, PartialEq, starknet::Event)]
+    pub enum Event {
+        Transfer: Transfer,
+    }
+
+    #[abi(embed_v0)]
+    impl TokenImpl of super::IToken<ContractState> {
+        fn transfer(
+            ref self: ContractState, from: ContractAddress, to: ContractAddress, amount: u256,
+        ) {
+            self.emit(Transfer { from, to, amount });
+            if amount == 0 {
+                core::panic_with_felt252('ZERO_AMOUNT');
+            }
+        }
+
+        fn try_transfer(
+            ref self: ContractState, from: ContractAddress, to: ContractAddress, amount: u256,
+        ) -> bool {
+            let mut calldata = array![];
+            from.serialize(ref calldata);
+            to.serialize(ref calldata);
+            amount.serialize(ref calldata);
+            call_contract_syscall(
+                starknet::get_contract_address(), selector!("transfer"), calldata.span(),
+            )
+                .is_ok()
+        }
+    }
+}
+
+use token::{Event, Transfer};
+
+/// Returns a `Transfer` event from `0x1` to `0x2` of the given amount.
+fn transfer_event(amount: u256) -> Event {
+    Event::Transfer(Transfer { from: sender(), to: recipient(), amount })
+}
+
+fn sender() -> ContractAddress {
+    starknet::contract_address_const::<0x1>()
+}
+
+fn recipient() -> ContractAddress {
+    starknet::contract_address_const::<0x2>()
+}
+
+/// Declares the `token` class and places a contract of it at `0x1234`.
+fn deploy_token() -> ITokenDispatcher {
+    let class_hash = token::TEST_CLASS_HASH.try_into().unwrap();
+    let contract_address = starknet::contract_address_const::<0x1234>();
+    testing::declare(class_hash).unwrap_syscall();
+    testing::deploy_at(class_hash, contract_address, [].span()).unwrap_syscall();
+    ITokenDispatcher { contract_address }
+}
+
+#[test]
+fn test_get_events() {
+    let token = deploy_token();
+    token.transfer(sender(), recipient(), 1);
+    let spy = testing::spy_events();
+    token.transfer(sender(), recipient(), 2);
+    token.transfer(sender(), recipient(), 3);
+
+    let mut keys = array![];
+    let mut data = array![];
+    starknet::Event::append_keys_and_data(@transfer_event(3), ref keys, ref data);
+    let events = spy.get_events();
+    assert_eq!(events.len(), 2);
+    assert_eq!(
+        events[1].clone(),
+        EmittedEvent { from_address: token.contract_address, keys: keys.span(), data: data.span() },
+    );
+}
+
+#[test]
+fn test_assert_emitted() {
+    let token = deploy_token();
+    let spy = testing::spy_events();
+    token.transfer(sender(), recipient(), 100);
+
+    spy.assert_emitted(token.contract_address, @transfer_event(100));
+    spy.assert_not_emitted(token.contract_address, @transfer_event(50));
+}
+
+#[test]
+fn test_events_before_spy_are_ignored() {
+    let token = deploy_token();
+    token.transfer(sender(), recipient(), 100);
+    let spy = testing::spy_events();
+
+    spy.assert_not_emitted(token.contract_address, @transfer_event(100));
+}
+
+#[test]
+fn test_reverted_events_are_not_spied() {
+    let token = deploy_token();
+    let spy = testing::spy_events();
+
+    assert!(!token.try_transfer(sender(), recipient(), 0));
+    assert!(spy.get_events().is_empty());
+}
+
+#[test]
+#[should_panic(
+    expected: "Event `Transfer { from: 0x1, to: 0x2, amount: 50 }` was not emitted by contract 0x1234. Emitted events: [Transfer { from: 0x1, to: 0x2, amount: 100 }].",
+)]
+fn test_assert_emitted_failure() {
+    let token = deploy_token();
+    let spy = testing::spy_events();
+    token.transfer(sender(), recipient(), 100);
+
+    spy.assert_emitted(token.contract_address, @transfer_event(50));
+}
+
+#[test]
+#[should_panic(
+    expected: "Event `Transfer { from: 0x1, to: 0x2, amount: 100 }` was emitted by contract 0x1234.",
+)]
+fn test_assert_not_emitted_failure() {
+    let token = deploy_token();
+    let spy = testing::spy_events();
+    token.transfer(sender(), recipient(), 100);
+
+    spy.assert_not_emitted(token.contract_address, @transfer_event(100));
+}
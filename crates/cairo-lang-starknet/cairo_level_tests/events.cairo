@@ -65,6 +65,7 @@ use contract_with_event::{Event, IncrementalEvent, StaticEvent, FlatEvent};
 fn test_events() {
     core::internal::revoke_ap_tracking();
     // Set up.
+    starknet::testing::declare(contract_with_event::TEST_CLASS_HASH.try_into().unwrap()).unwrap();
     let (contract_address, _) = deploy_syscall(
         contract_with_event::TEST_CLASS_HASH.try_into().unwrap(), 0, [].span(), false,
     )
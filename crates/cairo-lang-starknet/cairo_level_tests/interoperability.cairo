@@ -1,5 +1,5 @@
 use starknet::syscalls::{deploy_syscall, get_block_hash_syscall};
-use starknet::SyscallResultTrait;
+use starknet::{SyscallResultTrait, testing};
 
 #[starknet::interface]
 trait IContract<T> {
@@ -45,6 +45,7 @@ fn test_internal_func() {
 #[test]
 fn test_flow() {
     // Set up.
+    testing::declare(contract_a::TEST_CLASS_HASH.try_into().unwrap()).unwrap();
     let (address0, _) = deploy_syscall(
         contract_a::TEST_CLASS_HASH.try_into().unwrap(), 0, [100].span(), false,
     )
@@ -73,6 +74,7 @@ fn test_flow() {
 #[feature("safe_dispatcher")]
 fn test_flow_safe_dispatcher() {
     // Set up.
+    testing::declare(contract_a::TEST_CLASS_HASH.try_into().unwrap()).unwrap();
     let (contract_address, _) = deploy_syscall(
         contract_a::TEST_CLASS_HASH.try_into().unwrap(), 0, [100].span(), false,
     )
@@ -102,8 +104,64 @@ fn test_flow_out_of_gas() {
 #[test]
 fn test_class_hash_not_found() {
     assert_eq!(
-        deploy_syscall(5.try_into().unwrap(), 0, [100].span(), false),
-        Result::Err(array!['CLASS_HASH_NOT_FOUND']),
+        testing::declare(5.try_into().unwrap()), Result::Err(array!['CLASS_HASH_NOT_FOUND']),
+    );
+}
+
+#[test]
+fn test_class_hash_not_declared() {
+    assert_eq!(
+        deploy_syscall(contract_a::TEST_CLASS_HASH.try_into().unwrap(), 0, [100].span(), false),
+        Result::Err(array!['CLASS_HASH_NOT_DECLARED']),
+    );
+}
+
+#[test]
+fn test_class_already_declared() {
+    let class_hash = contract_a::TEST_CLASS_HASH.try_into().unwrap();
+    testing::declare(class_hash).unwrap();
+    assert_eq!(testing::declare(class_hash), Result::Err(array!['CLASS_ALREADY_DECLARED']));
+}
+
+#[test]
+fn test_deploy_at() {
+    let class_hash = contract_a::TEST_CLASS_HASH.try_into().unwrap();
+    let contract_address = starknet::contract_address_const::<0x1234>();
+    let storage = [(selector!("value"), 100)].span();
+    assert_eq!(
+        testing::deploy_at(class_hash, contract_address, storage),
+        Result::Err(array!['CLASS_HASH_NOT_DECLARED']),
+    );
+    testing::declare(class_hash).unwrap();
+    testing::deploy_at(class_hash, contract_address, storage).unwrap();
+    assert_eq!(
+        testing::deploy_at(class_hash, contract_address, storage),
+        Result::Err(array!['CONTRACT_ALREADY_DEPLOYED']),
+    );
+    assert_eq!(
+        starknet::syscalls::get_class_hash_at_syscall(contract_address), Result::Ok(class_hash),
+    );
+
+    let mut contract = IContractDispatcher { contract_address };
+    assert_eq!(contract.foo(300), 100);
+    assert_eq!(contract.foo(400), 300);
+}
+
+#[test]
+fn test_deploy_salt_and_deploy_from_zero() {
+    let class_hash = contract_a::TEST_CLASS_HASH.try_into().unwrap();
+    testing::declare(class_hash).unwrap();
+    // Make the deployer address differ from the zero address.
+    testing::set_contract_address(starknet::contract_address_const::<0x1234>());
+    let (address0, _) = deploy_syscall(class_hash, 0, [100].span(), false).unwrap();
+    let (address1, _) = deploy_syscall(class_hash, 1, [100].span(), false).unwrap();
+    let (address2, _) = deploy_syscall(class_hash, 0, [100].span(), true).unwrap();
+    assert_ne!(address0, address1);
+    assert_ne!(address0, address2);
+    assert_ne!(address1, address2);
+    assert_eq!(
+        deploy_syscall(class_hash, 0, [100].span(), true),
+        Result::Err(array!['CONTRACT_ALREADY_DEPLOYED']),
     );
 }
 
@@ -128,6 +186,7 @@ mod contract_failed_constructor {
 #[test]
 fn test_failed_constructor() {
     // Set up.
+    testing::declare(contract_failed_constructor::TEST_CLASS_HASH.try_into().unwrap()).unwrap();
     let mut calldata = array![];
     calldata.append(100);
     let mut err = deploy_syscall(
@@ -151,6 +210,7 @@ mod contract_failed_entrypoint {
 
 #[test]
 fn test_non_empty_calldata_nonexistent_constructor() {
+    testing::declare(contract_failed_entrypoint::TEST_CLASS_HASH.try_into().unwrap()).unwrap();
     let mut err = deploy_syscall(
         contract_failed_entrypoint::TEST_CLASS_HASH.try_into().unwrap(),
         0,
@@ -164,6 +224,7 @@ fn test_non_empty_calldata_nonexistent_constructor() {
 #[test]
 #[should_panic(expected: ('Failure', 'ENTRYPOINT_FAILED'))]
 fn test_entrypoint_failed() {
+    testing::declare(contract_failed_entrypoint::TEST_CLASS_HASH.try_into().unwrap()).unwrap();
     let (address0, _) = deploy_syscall(
         contract_failed_entrypoint::TEST_CLASS_HASH.try_into().unwrap(), 0, array![].span(), false,
     )
@@ -7,6 +7,8 @@ mod contract_tests;
 #[cfg(test)]
 mod deployment;
 #[cfg(test)]
+mod event_spy_test;
+#[cfg(test)]
 mod events;
 #[cfg(test)]
 mod erc20_test;
@@ -17,12 +19,18 @@ mod keccak;
 #[cfg(test)]
 mod l2_to_l1_messages;
 #[cfg(test)]
+mod mock_call_test;
+#[cfg(test)]
 mod multi_component_test;
 #[cfg(test)]
 mod replace_class_test;
 #[cfg(test)]
+mod revert_test;
+#[cfg(test)]
 mod storage_access;
 #[cfg(test)]
+mod storage_cheatcodes_test;
+#[cfg(test)]
 mod contract_address_test;
 mod utils;
 #[cfg(test)]
@@ -0,0 +1,131 @@
+use starknet::{ContractAddress, testing};
+
+#[starknet::interface]
+trait IOracle<T> {
+    fn price(self: @T, token: felt252) -> u128;
+}
+
+#[starknet::interface]
+trait IConsumer<T> {
+    fn value_of(self: @T, token: felt252, amount: u128) -> u128;
+}
+
+#[starknet::contract]
+mod consumer {
+    use starknet::ContractAddress;
+    use starknet::storage::StoragePointerReadAccess;
+    use super::{IOracleDispatcher, IOracleDispatcherTrait};
+
+    #[storage]
+    struct Storage {
+        oracle: ContractAddress,
+    }
+
+    #[abi(embed_v0)]
+    impl ConsumerImpl of super::IConsumer<ContractState> {
+        fn value_of(self: @ContractState, token: felt252, amount: u128) -> u128 {
+            IOracleDispatcher { contract_address: self.oracle.read() }.price(token) * amount
+        }
+    }
+}
+
+#[starknet::interface]
+trait IMocker<T> {
+    fn mock_and_fail(self: @T);
+}
+
+#[starknet::contract]
+mod mocker {
+    use starknet::testing;
+
+    #[storage]
+    struct Storage {}
+
+    #[abi(embed_v0)]
+    impl MockerImpl of super::IMocker<ContractState> {
+        fn mock_and_fail(self: @ContractState) {
+            testing::mock_call(super::oracle_address(), selector!("price"), [10].span(), 1);
+            testing::expect_call(super::oracle_address(), selector!("price"), ['ETH'].span());
+            core::panic_with_felt252('MOCKER_FAILED');
+        }
+    }
+}
+
+fn oracle_address() -> ContractAddress {
+    starknet::contract_address_const::<0x1234>()
+}
+
+/// Places a `consumer` contract using the oracle at `oracle_address`.
+fn deploy_consumer() -> IConsumerDispatcher {
+    let class_hash = consumer::TEST_CLASS_HASH.try_into().unwrap();
+    let contract_address = starknet::contract_address_const::<0x5678>();
+    testing::declare(class_hash).unwrap();
+    testing::deploy_at(class_hash, contract_address, [(selector!("oracle"), 0x1234)].span())
+        .unwrap();
+    IConsumerDispatcher { contract_address }
+}
+
+#[test]
+fn test_mock_call() {
+    testing::mock_call(oracle_address(), selector!("price"), [10].span(), 1);
+    assert_eq!(IOracleDispatcher { contract_address: oracle_address() }.price('ETH'), 10);
+}
+
+#[test]
+fn test_mock_call_from_contract() {
+    let consumer = deploy_consumer();
+    testing::mock_call(oracle_address(), selector!("price"), [10].span(), 2);
+    assert_eq!(consumer.value_of('ETH', 3), 30);
+    testing::mock_call(oracle_address(), selector!("price"), [20].span(), 1);
+    assert_eq!(consumer.value_of('ETH', 3), 60);
+}
+
+#[test]
+#[should_panic(expected: ('CONTRACT_NOT_DEPLOYED', 'ENTRYPOINT_FAILED'))]
+fn test_mock_call_times() {
+    let oracle = IOracleDispatcher { contract_address: oracle_address() };
+    testing::mock_call(oracle_address(), selector!("price"), [10].span(), 2);
+    assert_eq!(oracle.price('ETH'), 10);
+    assert_eq!(oracle.price('ETH'), 10);
+    oracle.price('ETH');
+}
+
+#[test]
+#[should_panic(expected: ('CONTRACT_NOT_DEPLOYED', 'ENTRYPOINT_FAILED'))]
+fn test_clear_mock_call() {
+    let oracle = IOracleDispatcher { contract_address: oracle_address() };
+    testing::mock_call(oracle_address(), selector!("price"), [10].span(), 5);
+    assert_eq!(oracle.price('ETH'), 10);
+    testing::clear_mock_call(oracle_address(), selector!("price"));
+    oracle.price('ETH');
+}
+
+#[test]
+fn test_expect_call() {
+    let consumer = deploy_consumer();
+    testing::mock_call(oracle_address(), selector!("price"), [10].span(), 1);
+    testing::expect_call(oracle_address(), selector!("price"), ['ETH'].span());
+    assert_eq!(consumer.value_of('ETH', 3), 30);
+}
+
+#[test]
+fn test_mocks_and_expectations_of_reverted_call() {
+    let class_hash = mocker::TEST_CLASS_HASH.try_into().unwrap();
+    let mocker_address = starknet::contract_address_const::<0x9abc>();
+    testing::declare(class_hash).unwrap();
+    testing::deploy_at(class_hash, mocker_address, [].span()).unwrap();
+    assert_eq!(
+        starknet::syscalls::call_contract_syscall(
+            mocker_address, selector!("mock_and_fail"), [].span(),
+        ),
+        Result::Err(array!['MOCKER_FAILED', 'ENTRYPOINT_FAILED']),
+    );
+    // The mock and the expectation are reverted with the failed call, so the oracle is not mocked,
+    // and the test passes although the expected call is not made.
+    assert_eq!(
+        starknet::syscalls::call_contract_syscall(
+            oracle_address(), selector!("price"), ['BTC'].span(),
+        ),
+        Result::Err(array!['CONTRACT_NOT_DEPLOYED', 'ENTRYPOINT_FAILED']),
+    );
+}
@@ -8,6 +8,8 @@ use crate::contracts::multi_component::{
 fn test_flow() {
     // Set up.
     let recipient = starknet::contract_address_const::<0x1337>();
+    starknet::testing::declare(contract_with_4_components::TEST_CLASS_HASH.try_into().unwrap())
+        .unwrap();
     let (contract_address, _) = starknet::syscalls::deploy_syscall(
         contract_with_4_components::TEST_CLASS_HASH.try_into().unwrap(),
         0,
@@ -1,6 +1,7 @@
 use starknet::syscalls::{deploy_syscall, get_class_hash_at_syscall};
 use starknet::class_hash::{class_hash_const, ClassHash};
 use starknet::contract_address::contract_address_const;
+use starknet::testing::declare;
 
 #[starknet::interface]
 trait IWithReplace<TContractState> {
@@ -52,6 +53,8 @@ mod contract_b {
 
 #[test]
 fn test_replace_flow() {
+    declare(contract_a::TEST_CLASS_HASH.try_into().unwrap()).unwrap();
+    declare(contract_b::TEST_CLASS_HASH.try_into().unwrap()).unwrap();
     // Deploy ContractA with 100 in the storage.
     let (address0, _) = deploy_syscall(
         class_hash: contract_a::TEST_CLASS_HASH.try_into().unwrap(),
@@ -72,8 +75,9 @@ fn test_replace_flow() {
 
 #[test]
 #[available_gas(30000000)]
-#[should_panic(expected: ('CLASS_HASH_NOT_FOUND', 'ENTRYPOINT_FAILED'))]
+#[should_panic(expected: ('CLASS_HASH_NOT_DECLARED', 'ENTRYPOINT_FAILED'))]
 fn test_cannot_replace_with_non_existing_class_hash() {
+    declare(contract_a::TEST_CLASS_HASH.try_into().unwrap()).unwrap();
     // Deploy ContractA with 100 in the storage.
     let (address0, _) = deploy_syscall(
         class_hash: contract_a::TEST_CLASS_HASH.try_into().unwrap(),
@@ -91,6 +95,7 @@ fn test_cannot_replace_with_non_existing_class_hash() {
 #[test]
 fn test_class_hash_at_syscall() {
     let a_class_hash = class_hash_const::<contract_a::TEST_CLASS_HASH>();
+    declare(a_class_hash).unwrap();
     // Deploy ContractA with 100 in the storage.
     let (address0, _) = deploy_syscall(
         class_hash: a_class_hash,
@@ -103,10 +108,41 @@ fn test_class_hash_at_syscall() {
     assert_eq!(get_class_hash_at_syscall(address0), Result::Ok(a_class_hash));
     // Replace its class hash to Class B.
     let b_class_hash = class_hash_const::<contract_b::TEST_CLASS_HASH>();
+    declare(b_class_hash).unwrap();
     IWithReplaceDispatcher { contract_address: address0 }.replace(b_class_hash);
     assert_eq!(get_class_hash_at_syscall(address0), Result::Ok(b_class_hash));
 }
 
+#[test]
+fn test_failed_redeploy_keeps_replaced_class() {
+    let a_class_hash = class_hash_const::<contract_a::TEST_CLASS_HASH>();
+    let b_class_hash = class_hash_const::<contract_b::TEST_CLASS_HASH>();
+    declare(a_class_hash).unwrap();
+    declare(b_class_hash).unwrap();
+    let (address0, _) = deploy_syscall(
+        class_hash: a_class_hash,
+        contract_address_salt: 0,
+        calldata: [100].span(),
+        deploy_from_zero: false,
+    )
+        .unwrap();
+    IWithReplaceDispatcher { contract_address: address0 }.replace(b_class_hash);
+
+    // Deploying ContractA again with the same salt targets the same address, and must fail without
+    // changing the class of the deployed contract.
+    assert_eq!(
+        deploy_syscall(
+            class_hash: a_class_hash,
+            contract_address_salt: 0,
+            calldata: [100].span(),
+            deploy_from_zero: false,
+        ),
+        Result::Err(array!['CONTRACT_ALREADY_DEPLOYED']),
+    );
+    assert_eq!(get_class_hash_at_syscall(address0), Result::Ok(b_class_hash));
+    assert_eq!(IWithFooDispatcher { contract_address: address0 }.foo(), 100);
+}
+
 #[test]
 fn test_class_hash_at_syscall_undeployed_contract() {
     assert_eq!(
@@ -0,0 +1,203 @@
+use starknet::syscalls::{call_contract_syscall, deploy_syscall};
+use starknet::{ClassHash, ContractAddress, SyscallResultTrait, testing};
+
+#[starknet::interface]
+trait IRevertible<T> {
+    fn get_value(self: @T) -> felt252;
+    fn set_and_call(ref self: T, value: felt252, callees: Span<ContractAddress>, fail: bool);
+    fn try_set_and_call(
+        ref self: T, value: felt252, callees: Span<ContractAddress>, fail: bool,
+    ) -> bool;
+    fn deploy(ref self: T, class_hash: ClassHash, fail: bool) -> ContractAddress;
+}
+
+#[starknet::contract]
+mod revertible {
+    use starknet::storage::{StoragePointerReadAccess, StoragePointerWriteAccess};
+    use starknet::syscalls::{call_contract_syscall, deploy_syscall, send_message_to_l1_syscall};
+    use starknet::{ClassHash, ContractAddress, SyscallResultTrait};
+
+    #[storage]
+    struct Storage {
+        value: felt252,
+    }
+
+    #[derive(Copy, Drop, Debug, PartialEq, starknet::Event)]
+    pub struct ValueSet {
+        pub value: felt252,
+    }
+
+    #[event]
+    #[derive(Copy, Drop, Debug, PartialEq, starknet::Event)]
+    pub enum Event {
+        ValueSet: ValueSet,
+    }
+
+    #[generate_trait]
+    impl InternalImpl of InternalTrait {
+        /// Writes the value to storage, emits an event and sends an L2 to L1 message about it.
+        fn record_value(ref self: ContractState, value: felt252) {
+            self.value.write(value);
+            self.emit(Event::ValueSet(ValueSet { value }));
+            send_message_to_l1_syscall(value, [value].span()).unwrap_syscall();
+        }
+    }
+
+    #[abi(embed_v0)]
+    impl RevertibleImpl of super::IRevertible<ContractState> {
+        fn get_value(self: @ContractState) -> felt252 {
+            self.value.read()
+        }
+
+        fn set_and_call(
+            ref self: ContractState, value: felt252, mut callees: Span<ContractAddress>, fail: bool,
+        ) {
+            self.record_value(value);
+            match callees.pop_front() {
+                Option::Some(next) => super::IRevertibleDispatcher { contract_address: *next }
+                    .set_and_call(value, callees, fail),
+                Option::None => {
+                    if fail {
+                        core::panic_with_felt252('BOTTOM_FAILED');
+                    }
+                },
+            }
+        }
+
+        fn try_set_and_call(
+            ref self: ContractState, value: felt252, mut callees: Span<ContractAddress>, fail: bool,
+        ) -> bool {
+            self.record_value(value);
+            let Option::Some(next) = callees.pop_front() else {
+                return true;
+            };
+            let mut calldata = array![];
+            value.serialize(ref calldata);
+            callees.serialize(ref calldata);
+            fail.serialize(ref calldata);
+            call_contract_syscall(*next, selector!("set_and_call"), calldata.span()).is_ok()
+        }
+
+        fn deploy(ref self: ContractState, class_hash: ClassHash, fail: bool) -> ContractAddress {
+            let (address, _) = deploy_syscall(class_hash, 0, [].span(), false).unwrap_syscall();
+            if fail {
+                core::panic_with_felt252('DEPLOY_FAILED');
+            }
+            address
+        }
+    }
+}
+
+use revertible::{Event, ValueSet};
+
+/// Declares the `revertible` class and deploys three contracts of it.
+fn deploy_revertibles() -> (IRevertibleDispatcher, IRevertibleDispatcher, IRevertibleDispatcher) {
+    let class_hash = revertible::TEST_CLASS_HASH.try_into().unwrap();
+    testing::declare(class_hash).unwrap_syscall();
+    let (a, _) = deploy_syscall(class_hash, 1, [].span(), false).unwrap_syscall();
+    let (b, _) = deploy_syscall(class_hash, 2, [].span(), false).unwrap_syscall();
+    let (c, _) = deploy_syscall(class_hash, 3, [].span(), false).unwrap_syscall();
+    (
+        IRevertibleDispatcher { contract_address: a },
+        IRevertibleDispatcher { contract_address: b },
+        IRevertibleDispatcher { contract_address: c },
+    )
+}
+
+/// Asserts that the contract recorded exactly the given values, in order.
+fn assert_recorded(contract: IRevertibleDispatcher, values: Span<felt252>) {
+    let address = contract.contract_address;
+    for value in values {
+        let value = *value;
+        assert_eq!(testing::pop_log(address), Option::Some(Event::ValueSet(ValueSet { value })));
+        assert_eq!(testing::pop_l2_to_l1_message(address), Option::Some((value, [value].span())));
+    };
+    assert!(testing::pop_log_raw(address).is_none());
+    assert!(testing::pop_l2_to_l1_message(address).is_none());
+}
+
+#[test]
+fn test_nested_calls_success() {
+    let (a, b, c) = deploy_revertibles();
+
+    assert!(a.try_set_and_call(1, [b.contract_address, c.contract_address].span(), false));
+
+    assert_eq!(a.get_value(), 1);
+    assert_eq!(b.get_value(), 1);
+    assert_eq!(c.get_value(), 1);
+    assert_recorded(a, [1].span());
+    assert_recorded(b, [1].span());
+    assert_recorded(c, [1].span());
+}
+
+#[test]
+fn test_nested_revert_three_levels() {
+    let (a, b, c) = deploy_revertibles();
+
+    // `c` panics, reverting both `c` and `b`, while `a` catches the failure and keeps its changes.
+    assert!(!a.try_set_and_call(1, [b.contract_address, c.contract_address].span(), true));
+
+    assert_eq!(a.get_value(), 1);
+    assert_eq!(b.get_value(), 0);
+    assert_eq!(c.get_value(), 0);
+    assert_recorded(a, [1].span());
+    assert_recorded(b, [].span());
+    assert_recorded(c, [].span());
+}
+
+#[test]
+fn test_nested_revert_restores_previous_values() {
+    let (a, b, c) = deploy_revertibles();
+    let callees = [b.contract_address, c.contract_address].span();
+
+    assert!(a.try_set_and_call(1, callees, false));
+    assert!(!a.try_set_and_call(2, callees, true));
+
+    assert_eq!(a.get_value(), 2);
+    assert_eq!(b.get_value(), 1);
+    assert_eq!(c.get_value(), 1);
+    assert_recorded(a, [1, 2].span());
+    assert_recorded(b, [1].span());
+    assert_recorded(c, [1].span());
+}
+
+#[test]
+fn test_reverted_call_from_test() {
+    let (a, b, c) = deploy_revertibles();
+
+    let value: felt252 = 1;
+    let mut calldata = array![];
+    value.serialize(ref calldata);
+    [b.contract_address, c.contract_address].span().serialize(ref calldata);
+    true.serialize(ref calldata);
+    assert_eq!(
+        call_contract_syscall(a.contract_address, selector!("set_and_call"), calldata.span()),
+        Result::Err(
+            array!['BOTTOM_FAILED', 'ENTRYPOINT_FAILED', 'ENTRYPOINT_FAILED', 'ENTRYPOINT_FAILED'],
+        ),
+    );
+
+    assert_eq!(a.get_value(), 0);
+    assert_eq!(b.get_value(), 0);
+    assert_eq!(c.get_value(), 0);
+    assert_recorded(a, [].span());
+    assert_recorded(b, [].span());
+    assert_recorded(c, [].span());
+}
+
+#[test]
+fn test_reverted_deploy() {
+    let (a, _, _) = deploy_revertibles();
+    let class_hash: ClassHash = revertible::TEST_CLASS_HASH.try_into().unwrap();
+
+    let mut calldata = array![];
+    class_hash.serialize(ref calldata);
+    true.serialize(ref calldata);
+    assert_eq!(
+        call_contract_syscall(a.contract_address, selector!("deploy"), calldata.span()),
+        Result::Err(array!['DEPLOY_FAILED', 'ENTRYPOINT_FAILED']),
+    );
+
+    // Deploying to the same address succeeds, as the previous deployment was reverted.
+    a.deploy(class_hash, false);
+}
@@ -0,0 +1,60 @@
+use starknet::testing;
+
+#[starknet::interface]
+trait IWithStorage<T> {
+    fn get_value(self: @T) -> u256;
+    fn set_value(ref self: T, value: u256);
+}
+
+#[starknet::contract]
+mod with_storage {
+    use starknet::storage::{StoragePointerReadAccess, StoragePointerWriteAccess};
+
+    #[storage]
+    struct Storage {
+        value: u256,
+    }
+
+    #[abi(embed_v0)]
+    impl WithStorageImpl of super::IWithStorage<ContractState> {
+        fn get_value(self: @ContractState) -> u256 {
+            self.value.read()
+        }
+
+        fn set_value(ref self: ContractState, value: u256) {
+            self.value.write(value);
+        }
+    }
+}
+
+/// Places a `with_storage` contract at a fixed address.
+fn deploy_with_storage() -> IWithStorageDispatcher {
+    let class_hash = with_storage::TEST_CLASS_HASH.try_into().unwrap();
+    let contract_address = starknet::contract_address_const::<0x1234>();
+    testing::declare(class_hash).unwrap();
+    testing::deploy_at(class_hash, contract_address, [].span()).unwrap();
+    IWithStorageDispatcher { contract_address }
+}
+
+#[test]
+fn test_store() {
+    let contract = deploy_with_storage();
+    testing::store(contract.contract_address, selector!("value"), [1, 2].span());
+    assert_eq!(contract.get_value(), u256 { low: 1, high: 2 });
+}
+
+#[test]
+fn test_load() {
+    let contract = deploy_with_storage();
+    assert_eq!(testing::load(contract.contract_address, selector!("value"), 2), [0, 0].span());
+    contract.set_value(u256 { low: 3, high: 4 });
+    assert_eq!(testing::load(contract.contract_address, selector!("value"), 2), [3, 4].span());
+    assert_eq!(testing::load(contract.contract_address, selector!("value"), 0), [].span());
+}
+
+#[test]
+fn test_store_undeployed_contract() {
+    let contract_address = starknet::contract_address_const::<0x5678>();
+    testing::store(contract_address, 10, [5, 6, 7].span());
+    assert_eq!(testing::load(contract_address, 9, 5), [0, 5, 6, 7, 0].span());
+}
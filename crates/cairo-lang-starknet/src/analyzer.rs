@@ -1,5 +1,6 @@
 use cairo_lang_defs::ids::{EnumId, ModuleId, StructId};
 use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::items::attribute::SemanticQueryAttrs;
 use cairo_lang_semantic::items::structure::Member;
@@ -51,11 +52,14 @@ fn add_non_starknet_interface_embeddable_diagnostics(
         }
         let Ok(impl_trait) = db.impl_def_trait(*id) else { continue };
         if !impl_trait.has_attr(db.upcast(), STARKNET_INTERFACE_ATTR).unwrap_or(true) {
-            diagnostics.push(PluginDiagnostic::warning(
-                item.stable_ptr().untyped(),
-                "Impls with the embeddable attribute must implement a starknet interface trait."
-                    .to_string(),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::warning(
+                    item.stable_ptr().untyped(),
+                    "Impls with the embeddable attribute must implement a starknet interface trait."
+                        .to_string(),
+                )
+                .with_error_code(error_code!(E3018)),
+            );
         }
     }
 }
@@ -76,11 +80,14 @@ fn add_abi_diagnostics(
     };
     for err in abi_builder.errors() {
         if !matches!(err, ABIError::SemanticError) {
-            diagnostics.push(PluginDiagnostic::warning(
-                err.location(db)
-                    .unwrap_or_else(|| contract.submodule_id.stable_ptr(db.upcast()).untyped()),
-                format!("Failed to generate ABI: {err}"),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::warning(
+                    err.location(db)
+                        .unwrap_or_else(|| contract.submodule_id.stable_ptr(db.upcast()).untyped()),
+                    format!("Failed to generate ABI: {err}"),
+                )
+                .with_error_code(error_code!(E3019)),
+            );
         }
     }
 }
@@ -173,14 +180,17 @@ impl StorageStructMembers {
         diagnostics: &mut Vec<PluginDiagnostic>,
     ) {
         if let Some(existing_path) = self.name_to_paths.get(&member_name) {
-            diagnostics.push(PluginDiagnostic::warning(
-                pointer_to_code,
-                format!(
-                    "The path `{}` collides with existing path `{}`.",
-                    path_to_member.join("."),
-                    existing_path.join(".")
-                ),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::warning(
+                    pointer_to_code,
+                    format!(
+                        "The path `{}` collides with existing path `{}`.",
+                        path_to_member.join("."),
+                        existing_path.join(".")
+                    ),
+                )
+                .with_error_code(error_code!(E3016)),
+            );
         } else {
             self.name_to_paths.insert(member_name.clone(), path_to_member);
         }
@@ -251,12 +261,15 @@ fn add_derive_store_enum_diags(
     if !variants.iter().any(|(_, variant_id)| {
         variant_id.stable_ptr(db.upcast()).lookup(db.upcast()).has_attr(db.upcast(), "default")
     }) {
-        diagnostics.push(PluginDiagnostic::warning(
-            id.stable_ptr(db.upcast()).untyped(),
-            format!(
-                "Enum with `#[derive({STORE_TRAIT})] has no default variant. Either add one, or \
-                 add `#[allow({ALLOW_NO_DEFAULT_VARIANT_ATTR})]`"
-            ),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::warning(
+                id.stable_ptr(db.upcast()).untyped(),
+                format!(
+                    "Enum with `#[derive({STORE_TRAIT})] has no default variant. Either add one, \
+                     or add `#[allow({ALLOW_NO_DEFAULT_VARIANT_ATTR})]`"
+                ),
+            )
+            .with_error_code(error_code!(E3016)),
+        );
     }
 }
@@ -19,6 +19,7 @@ use cairo_lang_semantic::substitution::SemanticRewriter;
 use cairo_lang_sierra::ids::FunctionId;
 use cairo_lang_sierra_generator::db::SierraGenGroup;
 use cairo_lang_sierra_generator::replace_ids::SierraIdReplacer;
+use cairo_lang_starknet_classes::abi::Contract;
 use cairo_lang_starknet_classes::keccak::starknet_keccak;
 use cairo_lang_syntax::node::helpers::{GetIdentifier, PathSegmentEx, QueryAttrs};
 use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
@@ -31,6 +32,7 @@ use serde::{Deserialize, Serialize};
 use starknet_types_core::felt::Felt as Felt252;
 use {cairo_lang_lowering as lowering, cairo_lang_semantic as semantic};
 
+use crate::abi::AbiBuilder;
 use crate::aliased::Aliased;
 use crate::compile::{SemanticEntryPoints, extract_semantic_entrypoints};
 use crate::plugin::aux_data::StarkNetContractAuxData;
@@ -289,6 +291,9 @@ pub struct ContractInfo {
         deserialize_with = "deserialize_ordered_hashmap_vec"
     )]
     pub l1_handlers: OrderedHashMap<Felt252, FunctionId>,
+    /// The ABI of the contract, if it could be built.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub abi: Option<Contract>,
 }
 
 /// Returns the list of functions in a given module.
@@ -333,10 +338,15 @@ fn analyze_contract<T: SierraIdReplacer>(
         .map(|f| get_selector_and_sierra_function(db, &f, replacer))
         .collect();
 
+    let abi = AbiBuilder::from_submodule(db.upcast(), contract.submodule_id, Default::default())
+        .ok()
+        .and_then(|builder| builder.finalize().ok());
+
     let contract_info = ContractInfo {
         externals,
         l1_handlers,
         constructor: constructors.into_iter().next().map(|x| x.1),
+        abi,
     };
     Ok((class_hash, contract_info))
 }
@@ -4,6 +4,7 @@ use cairo_lang_defs::plugin::{
     InlineMacroExprPlugin, InlinePluginResult, MacroPluginMetadata, NamedPlugin, PluginDiagnostic,
     PluginGeneratedFile,
 };
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode, ast};
 use cairo_lang_utils::extract_matches;
@@ -65,13 +66,16 @@ fn get_dep_component_generate_code_helper(
         if !matches!(&contract_arg_modifiers[..], &[ast::Modifier::Ref(_)]) {
             // TODO(Gil): The generated diagnostics points to the whole inline macro, it should
             // point to the arg.
-            let diagnostics = vec![PluginDiagnostic::error(
-                contract_arg.stable_ptr().untyped(),
-                format!(
-                    "The first argument of `{}` macro must have only a `ref` modifier.",
-                    GetDepComponentMutMacro::NAME
-                ),
-            )];
+            let diagnostics = vec![
+                PluginDiagnostic::error(
+                    contract_arg.stable_ptr().untyped(),
+                    format!(
+                        "The first argument of `{}` macro must have only a `ref` modifier.",
+                        GetDepComponentMutMacro::NAME
+                    ),
+                )
+                .with_error_code(error_code!(E3005)),
+            ];
             return InlinePluginResult { code: None, diagnostics };
         };
     }
@@ -3,6 +3,7 @@ use cairo_lang_defs::plugin::{
     InlineMacroExprPlugin, InlinePluginResult, MacroPluginMetadata, NamedPlugin, PluginDiagnostic,
     PluginGeneratedFile,
 };
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_starknet_classes::keccak::starknet_keccak;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode, ast};
@@ -27,10 +28,13 @@ impl InlineMacroExprPlugin for SelectorMacro {
         );
 
         let ast::Expr::String(input_string) = arg else {
-            let diagnostics = vec![PluginDiagnostic::error(
-                syntax.stable_ptr().untyped(),
-                format!("`{}` macro argument must be a string", SelectorMacro::NAME),
-            )];
+            let diagnostics = vec![
+                PluginDiagnostic::error(
+                    syntax.stable_ptr().untyped(),
+                    format!("`{}` macro argument must be a string", SelectorMacro::NAME),
+                )
+                .with_error_code(error_code!(E3005)),
+            ];
             return InlinePluginResult { code: None, diagnostics };
         };
         let selector_string = input_string.string_value(db).unwrap();
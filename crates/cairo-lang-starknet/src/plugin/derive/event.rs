@@ -1,5 +1,6 @@
 use cairo_lang_defs::patcher::{ModifiedNode, RewriteNode};
 use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_starknet_classes::abi::EventFieldKind;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::helpers::QueryAttrs;
@@ -35,10 +36,13 @@ fn handle_struct(
     // TODO(spapini): Support generics.
     let generic_params = struct_ast.generic_params(db);
     let ast::OptionWrappedGenericParamList::Empty(_) = generic_params else {
-        diagnostics.push(PluginDiagnostic::error(
-            generic_params.stable_ptr().untyped(),
-            format!("{EVENT_TYPE_NAME} structs with generic arguments are unsupported"),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                generic_params.stable_ptr().untyped(),
+                format!("{EVENT_TYPE_NAME} structs with generic arguments are unsupported"),
+            )
+            .with_error_code(error_code!(E3017)),
+        );
         return None;
     };
 
@@ -116,17 +120,23 @@ fn get_field_kind_for_member(
 
     // Currently, nested fields are unsupported.
     if is_nested {
-        diagnostics.push(PluginDiagnostic::error(
-            member.stable_ptr().untyped(),
-            "Nested event fields are currently unsupported".to_string(),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                member.stable_ptr().untyped(),
+                "Nested event fields are currently unsupported".to_string(),
+            )
+            .with_error_code(error_code!(E3017)),
+        );
     }
     // Currently, serde fields are unsupported.
     if is_serde {
-        diagnostics.push(PluginDiagnostic::error(
-            member.stable_ptr().untyped(),
-            "Serde event fields are currently unsupported".to_string(),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                member.stable_ptr().untyped(),
+                "Serde event fields are currently unsupported".to_string(),
+            )
+            .with_error_code(error_code!(E3017)),
+        );
     }
 
     if is_key {
@@ -151,10 +161,13 @@ fn get_field_kind_for_variant(
 
     // Currently, nested fields are unsupported.
     if is_nested {
-        diagnostics.push(PluginDiagnostic::error(
-            variant.stable_ptr().untyped(),
-            "Nested event fields are currently unsupported".to_string(),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                variant.stable_ptr().untyped(),
+                "Nested event fields are currently unsupported".to_string(),
+            )
+            .with_error_code(error_code!(E3017)),
+        );
     }
 
     if is_flat {
@@ -163,10 +176,13 @@ fn get_field_kind_for_variant(
 
     // Currently, serde fields are unsupported.
     if is_serde {
-        diagnostics.push(PluginDiagnostic::error(
-            variant.stable_ptr().untyped(),
-            "Serde event fields are currently unsupported".to_string(),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                variant.stable_ptr().untyped(),
+                "Serde event fields are currently unsupported".to_string(),
+            )
+            .with_error_code(error_code!(E3017)),
+        );
     }
 
     if is_key {
@@ -187,10 +203,13 @@ fn handle_enum(
     // TODO(spapini): Support generics.
     let generic_params = enum_ast.generic_params(db);
     let ast::OptionWrappedGenericParamList::Empty(_) = generic_params else {
-        diagnostics.push(PluginDiagnostic::error(
-            generic_params.stable_ptr().untyped(),
-            format!("{EVENT_TYPE_NAME} enums with generic arguments are unsupported"),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                generic_params.stable_ptr().untyped(),
+                format!("{EVENT_TYPE_NAME} enums with generic arguments are unsupported"),
+            )
+            .with_error_code(error_code!(E3017)),
+        );
         return None;
     };
 
@@ -1,5 +1,6 @@
 use cairo_lang_defs::patcher::RewriteNode;
 use cairo_lang_defs::plugin::{MacroPluginMetadata, PluginDiagnostic};
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::helpers::QueryAttrs;
 use cairo_lang_syntax::node::{Terminal, TypedStablePtr, TypedSyntaxNode, ast};
@@ -192,10 +193,13 @@ fn handle_enum(
     for (i, variant) in enum_ast.variants(db).elements(db).iter().enumerate() {
         let indicator = if variant.attributes(db).has_attr(db, "default") {
             if default_index.is_some() {
-                diagnostics.push(PluginDiagnostic::error(
-                    variant.stable_ptr().untyped(),
-                    "Multiple variants annotated with `#[default]`".to_string(),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        variant.stable_ptr().untyped(),
+                        "Multiple variants annotated with `#[default]`".to_string(),
+                    )
+                    .with_error_code(error_code!(E3006)),
+                );
                 return None;
             }
             default_index = Some(i);
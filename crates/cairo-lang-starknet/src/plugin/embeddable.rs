@@ -1,5 +1,6 @@
 use cairo_lang_defs::patcher::{PatchBuilder, RewriteNode};
 use cairo_lang_defs::plugin::{PluginDiagnostic, PluginGeneratedFile, PluginResult};
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::helpers::{BodyItems, GenericParamEx};
 use cairo_lang_syntax::node::{Terminal, TypedStablePtr, TypedSyntaxNode, ast};
@@ -20,10 +21,13 @@ pub fn handle_embeddable(db: &dyn SyntaxGroup, item_impl: ast::ItemImpl) -> Plug
     let ast::MaybeImplBody::Some(body) = item_impl.body(db) else {
         return PluginResult {
             code: None,
-            diagnostics: vec![PluginDiagnostic::error(
-                item_impl.stable_ptr().untyped(),
-                "Making empty impls embeddable is disallowed.".to_string(),
-            )],
+            diagnostics: vec![
+                PluginDiagnostic::error(
+                    item_impl.stable_ptr().untyped(),
+                    "Making empty impls embeddable is disallowed.".to_string(),
+                )
+                .with_error_code(error_code!(E3018)),
+            ],
             remove_original_item: false,
         };
     };
@@ -46,14 +50,17 @@ pub fn handle_embeddable(db: &dyn SyntaxGroup, item_impl: ast::ItemImpl) -> Plug
                 if param.is_impl_of(db, "Destruct", GENERIC_CONTRACT_STATE_NAME)
                     || param.is_impl_of(db, "PanicDestruct", GENERIC_CONTRACT_STATE_NAME)
                 {
-                    diagnostics.push(PluginDiagnostic::error(
-                        param.stable_ptr().untyped(),
-                        format!(
-                            "`embeddable` impls can't have impl generic parameters of \
+                    diagnostics.push(
+                        PluginDiagnostic::error(
+                            param.stable_ptr().untyped(),
+                            format!(
+                                "`embeddable` impls can't have impl generic parameters of \
                              `Destruct<{GENERIC_CONTRACT_STATE_NAME}>` or \
                              `PanicDestruct<{GENERIC_CONTRACT_STATE_NAME}>`."
-                        ),
-                    ));
+                            ),
+                        )
+                        .with_error_code(error_code!(E3018)),
+                    );
                 }
             }
             let mut elements = elements.into_iter();
@@ -101,13 +108,16 @@ pub fn handle_embeddable(db: &dyn SyntaxGroup, item_impl: ast::ItemImpl) -> Plug
         }
     };
     if !is_valid_params {
-        diagnostics.push(PluginDiagnostic::error(
-            generic_params.stable_ptr().untyped(),
-            format!(
-                "First generic parameter of an embeddable impl should be \
+        diagnostics.push(
+            PluginDiagnostic::error(
+                generic_params.stable_ptr().untyped(),
+                format!(
+                    "First generic parameter of an embeddable impl should be \
                  `{GENERIC_CONTRACT_STATE_NAME}`."
-            ),
-        ));
+                ),
+            )
+            .with_error_code(error_code!(E3018)),
+        );
         return PluginResult { code: None, diagnostics, remove_original_item: false };
     };
     let mut data = EntryPointsGenerationData::default();
@@ -1,5 +1,6 @@
 use cairo_lang_defs::patcher::RewriteNode;
 use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_syntax::attribute::consts::IMPLICIT_PRECEDENCE_ATTR;
 use cairo_lang_syntax::node::ast::{
     self, FunctionWithBody, OptionReturnTypeClause, OptionTypeClause, OptionWrappedGenericParamList,
@@ -144,19 +145,25 @@ pub fn handle_entry_point(
     let declaration = item_function.declaration(db);
     let name_node = declaration.name(db);
     if entry_point_kind == EntryPointKind::Constructor && name_node.text(db) != CONSTRUCTOR_NAME {
-        diagnostics.push(PluginDiagnostic::error(
-            name_node.stable_ptr().untyped(),
-            format!("The constructor function must be called `{CONSTRUCTOR_NAME}`."),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                name_node.stable_ptr().untyped(),
+                format!("The constructor function must be called `{CONSTRUCTOR_NAME}`."),
+            )
+            .with_error_code(error_code!(E3015)),
+        );
     }
 
     if let OptionWrappedGenericParamList::WrappedGenericParamList(generic_params) =
         declaration.generic_params(db)
     {
-        diagnostics.push(PluginDiagnostic::error(
-            generic_params.stable_ptr().untyped(),
-            "Contract entry points cannot have generic arguments".to_string(),
-        ))
+        diagnostics.push(
+            PluginDiagnostic::error(
+                generic_params.stable_ptr().untyped(),
+                "Contract entry points cannot have generic arguments".to_string(),
+            )
+            .with_error_code(error_code!(E3015)),
+        )
     }
 
     let mut declaration_node = RewriteNode::new_trimmed(declaration.as_syntax_node());
@@ -230,16 +237,22 @@ fn generate_entry_point_wrapper(
     let mut ref_appends = Vec::new();
 
     let Some((0, first_param)) = params.next() else {
-        return Err(vec![PluginDiagnostic::error(
-            sig.stable_ptr().untyped(),
-            "The first parameter of an entry point must be `self`.".into(),
-        )]);
+        return Err(vec![
+            PluginDiagnostic::error(
+                sig.stable_ptr().untyped(),
+                "The first parameter of an entry point must be `self`.".into(),
+            )
+            .with_error_code(error_code!(E3015)),
+        ]);
     };
     if first_param.name(db).text(db) != "self" {
-        return Err(vec![PluginDiagnostic::error(
-            first_param.stable_ptr().untyped(),
-            "The first parameter of an entry point must be `self`.".into(),
-        )]);
+        return Err(vec![
+            PluginDiagnostic::error(
+                first_param.stable_ptr().untyped(),
+                "The first parameter of an entry point must be `self`.".into(),
+            )
+            .with_error_code(error_code!(E3015)),
+        ]);
     };
     let is_snapshot = matches!(
         extract_matches!(first_param.type_clause(db), OptionTypeClause::TypeClause).ty(db),
@@ -256,10 +269,13 @@ fn generate_entry_point_wrapper(
 
         let is_ref = param.is_ref_param(db);
         if raw_output && is_ref {
-            diagnostics.push(PluginDiagnostic::error(
-                param.modifiers(db).stable_ptr().untyped(),
-                format!("`{RAW_OUTPUT_ATTR}` functions cannot have `ref` parameters."),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    param.modifiers(db).stable_ptr().untyped(),
+                    format!("`{RAW_OUTPUT_ATTR}` functions cannot have `ref` parameters."),
+                )
+                .with_error_code(error_code!(E3015)),
+            );
         }
         let ref_modifier = if is_ref { "ref " } else { "" };
         arg_names.push(format!("{ref_modifier}{arg_name}"));
@@ -301,10 +317,13 @@ fn generate_entry_point_wrapper(
     };
 
     if raw_output && !return_ty_is_felt252_span {
-        diagnostics.push(PluginDiagnostic::error(
-            ret_type_ptr,
-            format!("`{RAW_OUTPUT_ATTR}` functions must return `Span::<felt252>`."),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                ret_type_ptr,
+                format!("`{RAW_OUTPUT_ATTR}` functions must return `Span::<felt252>`."),
+            )
+            .with_error_code(error_code!(E3015)),
+        );
     }
 
     if !diagnostics.is_empty() {
@@ -380,25 +399,35 @@ fn validate_l1_handler_first_parameter(
             .ty(db)
             .is_felt252(db)
         {
-            diagnostics.push(PluginDiagnostic::error(
-                first_param.stable_ptr().untyped(),
-                "The second parameter of an L1 handler must be of type `felt252`.".to_string(),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    first_param.stable_ptr().untyped(),
+                    "The second parameter of an L1 handler must be of type `felt252`.".to_string(),
+                )
+                .with_error_code(error_code!(E3015)),
+            );
         }
 
         // Validate name
         if maybe_strip_underscore(first_param.name(db).text(db).as_str())
             != L1_HANDLER_FIRST_PARAM_NAME
         {
-            diagnostics.push(PluginDiagnostic::error(
-                first_param.stable_ptr().untyped(),
-                "The second parameter of an L1 handler must be named 'from_address'.".to_string(),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    first_param.stable_ptr().untyped(),
+                    "The second parameter of an L1 handler must be named 'from_address'."
+                        .to_string(),
+                )
+                .with_error_code(error_code!(E3015)),
+            );
         }
     } else {
-        diagnostics.push(PluginDiagnostic::error(
-            params.stable_ptr().untyped(),
-            "An L1 handler must have the 'from_address' as its second parameter.".to_string(),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                params.stable_ptr().untyped(),
+                "An L1 handler must have the 'from_address' as its second parameter.".to_string(),
+            )
+            .with_error_code(error_code!(E3015)),
+        );
     };
 }
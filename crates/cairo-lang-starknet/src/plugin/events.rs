@@ -1,5 +1,6 @@
 use cairo_lang_defs::db::get_all_path_leaves;
 use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_starknet_classes::abi::EventFieldKind;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::helpers::{GetIdentifier, QueryAttrs};
@@ -52,14 +53,17 @@ pub fn get_starknet_event_variants(
                 let stable_ptr = &leaf.stable_ptr();
                 if stable_ptr.identifier(db) == EVENT_TYPE_NAME {
                     if !item.has_attr(db, EVENT_ATTR) {
-                        diagnostics.push(PluginDiagnostic::error(
-                            stable_ptr.untyped(),
-                            format!(
-                                "{} type that is named `{EVENT_TYPE_NAME}` must be marked with \
-                                 #[{EVENT_ATTR}].",
-                                module_kind.to_str_capital()
-                            ),
-                        ));
+                        diagnostics.push(
+                            PluginDiagnostic::error(
+                                stable_ptr.untyped(),
+                                format!(
+                                    "{} type that is named `{EVENT_TYPE_NAME}` must be marked \
+                                         with #[{EVENT_ATTR}].",
+                                    module_kind.to_str_capital()
+                                ),
+                            )
+                            .with_error_code(error_code!(E3017)),
+                        );
                     }
                     return Some(vec![]);
                 }
@@ -72,25 +76,31 @@ pub fn get_starknet_event_variants(
 
     match (has_event_attr, has_event_name) {
         (true, false) => {
-            diagnostics.push(PluginDiagnostic::error(
-                stable_ptr,
-                format!(
-                    "{} type that is marked with #[{EVENT_ATTR}] must be named \
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    stable_ptr,
+                    format!(
+                        "{} type that is marked with #[{EVENT_ATTR}] must be named \
                      `{EVENT_TYPE_NAME}`.",
-                    module_kind.to_str_capital()
-                ),
-            ));
+                        module_kind.to_str_capital()
+                    ),
+                )
+                .with_error_code(error_code!(E3017)),
+            );
             None
         }
         (false, true) => {
-            diagnostics.push(PluginDiagnostic::error(
-                stable_ptr,
-                format!(
-                    "{} type that is named `{EVENT_TYPE_NAME}` must be marked with \
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    stable_ptr,
+                    format!(
+                        "{} type that is named `{EVENT_TYPE_NAME}` must be marked with \
                      #[{EVENT_ATTR}].",
-                    module_kind.to_str_capital()
-                ),
-            ));
+                        module_kind.to_str_capital()
+                    ),
+                )
+                .with_error_code(error_code!(E3017)),
+            );
             // The attribute is missing, but this counts as a event - we can't create another
             // (empty) event.
             Some(variants)
@@ -1,5 +1,6 @@
 use cairo_lang_defs::patcher::RewriteNode;
 use cairo_lang_defs::plugin::{MacroPluginMetadata, PluginDiagnostic, PluginResult};
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_plugins::plugins::HasItemsInCfgEx;
 use cairo_lang_starknet_classes::keccak::starknet_keccak;
 use cairo_lang_syntax::node::db::SyntaxGroup;
@@ -143,34 +144,40 @@ impl ComponentsGenerationData {
 
         let storage_name_syntax_node = storage_name.as_syntax_node();
         if !self.substorage_members.contains(&storage_name_syntax_node.get_text(db)) {
-            diagnostics.push(PluginDiagnostic::error(
-                storage_name.stable_ptr().untyped(),
-                format!(
-                    "`{0}` is not a substorage member in the contract's \
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    storage_name.stable_ptr().untyped(),
+                    format!(
+                        "`{0}` is not a substorage member in the contract's \
                      `{STORAGE_STRUCT_NAME}`.\nConsider adding to \
                      `{STORAGE_STRUCT_NAME}`:\n```\n#[{SUBSTORAGE_ATTR}(v0)]\n{0}: \
                      path::to::component::{STORAGE_STRUCT_NAME},\n````",
-                    storage_name_syntax_node.get_text_without_trivia(db)
+                        storage_name_syntax_node.get_text_without_trivia(db)
+                    )
+                    .to_string(),
                 )
-                .to_string(),
-            ));
+                .with_error_code(error_code!(E3013)),
+            );
             is_valid = false;
         }
 
         let event_name_str = event_name.as_syntax_node().get_text_without_trivia(db);
         if !self.nested_event_variants.contains(&event_name_str.clone().into()) {
-            diagnostics.push(PluginDiagnostic::error(
-                event_name.stable_ptr().untyped(),
-                format!(
-                    "`{event_name_str}` is not a nested event in the contract's \
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    event_name.stable_ptr().untyped(),
+                    format!(
+                        "`{event_name_str}` is not a nested event in the contract's \
                      `{EVENT_TYPE_NAME}` enum.\nConsider adding to the `{EVENT_TYPE_NAME}` \
                      enum:\n```\n{event_name_str}: \
                      path::to::component::{EVENT_TYPE_NAME},\n```\nNote: currently with \
                      components, only an enum {EVENT_TYPE_NAME} directly in the contract is \
                      supported.",
+                    )
+                    .to_string(),
                 )
-                .to_string(),
-            ));
+                .with_error_code(error_code!(E3013)),
+            );
             is_valid = false;
         }
 
@@ -294,13 +301,16 @@ fn handle_contract_item(
                     &mut data.specific.entry_points_code,
                 );
             } else {
-                diagnostics.push(PluginDiagnostic::error(
-                    alias_ast.stable_ptr().untyped(),
-                    format!(
-                        "The '{ABI_ATTR}' attribute for impl aliases only supports the \
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        alias_ast.stable_ptr().untyped(),
+                        format!(
+                            "The '{ABI_ATTR}' attribute for impl aliases only supports the \
                          '{ABI_ATTR_EMBED_V0_ARG}' argument.",
-                    ),
-                ));
+                        ),
+                    )
+                    .with_error_code(error_code!(E3013)),
+                );
             }
         }
         ast::ModuleItem::InlineMacro(inline_macro_ast)
@@ -480,13 +490,16 @@ fn impl_abi_config(
         } else if is_single_arg_attr(db, &abi_attr, ABI_ATTR_EMBED_V0_ARG) {
             ImplAbiConfig::Embed
         } else {
-            diagnostics.push(PluginDiagnostic::error(
-                abi_attr.stable_ptr().untyped(),
-                format!(
-                    "The '{ABI_ATTR}' attribute for impls only supports the \
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    abi_attr.stable_ptr().untyped(),
+                    format!(
+                        "The '{ABI_ATTR}' attribute for impls only supports the \
                      '{ABI_ATTR_PER_ITEM_ARG}' or '{ABI_ATTR_EMBED_V0_ARG}' argument.",
-                ),
-            ));
+                    ),
+                )
+                .with_error_code(error_code!(E3013)),
+            );
             ImplAbiConfig::None
         }
     } else if has_v0_attribute_ex(db, diagnostics, imp, EXTERNAL_ATTR, || {
@@ -515,13 +528,16 @@ fn handle_embed_impl_alias(
         }
     };
     if has_generic_params {
-        diagnostics.push(PluginDiagnostic::error(
-            alias_ast.stable_ptr().untyped(),
-            format!(
-                "Generic parameters are not supported in impl aliases with \
+        diagnostics.push(
+            PluginDiagnostic::error(
+                alias_ast.stable_ptr().untyped(),
+                format!(
+                    "Generic parameters are not supported in impl aliases with \
                  `#[{ABI_ATTR}({ABI_ATTR_EMBED_V0_ARG})]`."
-            ),
-        ));
+                ),
+            )
+            .with_error_code(error_code!(E3013)),
+        );
         return;
     }
     let elements = alias_ast.impl_path(db).elements(db);
@@ -530,13 +546,16 @@ fn handle_embed_impl_alias(
     };
 
     if !is_first_generic_arg_contract_state(db, impl_final_part) {
-        diagnostics.push(PluginDiagnostic::error(
-            alias_ast.stable_ptr().untyped(),
-            format!(
-                "First generic argument of impl alias with \
+        diagnostics.push(
+            PluginDiagnostic::error(
+                alias_ast.stable_ptr().untyped(),
+                format!(
+                    "First generic argument of impl alias with \
                  `#[{ABI_ATTR}({ABI_ATTR_EMBED_V0_ARG})]` must be `{CONTRACT_STATE_NAME}`."
-            ),
-        ));
+                ),
+            )
+            .with_error_code(error_code!(E3013)),
+        );
         return;
     }
     let impl_name = impl_final_part.identifier_ast(db);
@@ -613,6 +632,7 @@ fn invalid_macro_diagnostic(component_macro_ast: &ast::ItemInlineMacro) -> Plugi
              \"<component_name>\", storage: \"<storage_name>\", event: \"<event_name>\");`"
         ),
     )
+    .with_error_code(error_code!(E3013))
 }
 
 /// Remove a `component!` inline macro from the original code if it's inside a starknet::contract.
@@ -672,33 +692,42 @@ fn try_extract_named_macro_argument(
                     if elements.len() != 1
                         || !matches!(elements.last().unwrap(), ast::PathSegment::Simple(_))
                     {
-                        diagnostics.push(PluginDiagnostic::error(
-                            path.stable_ptr().untyped(),
-                            format!(
-                                "Component macro argument `{arg_name}` must be a simple \
+                        diagnostics.push(
+                            PluginDiagnostic::error(
+                                path.stable_ptr().untyped(),
+                                format!(
+                                    "Component macro argument `{arg_name}` must be a simple \
                                  identifier.",
-                            ),
-                        ));
+                                ),
+                            )
+                            .with_error_code(error_code!(E3013)),
+                        );
                         return None;
                     }
                     Some(path)
                 }
                 value => {
-                    diagnostics.push(PluginDiagnostic::error(
-                        value.stable_ptr().untyped(),
-                        format!(
-                            "Component macro argument `{arg_name}` must be a path expression.",
-                        ),
-                    ));
+                    diagnostics.push(
+                        PluginDiagnostic::error(
+                            value.stable_ptr().untyped(),
+                            format!(
+                                "Component macro argument `{arg_name}` must be a path expression.",
+                            ),
+                        )
+                        .with_error_code(error_code!(E3013)),
+                    );
                     None
                 }
             }
         }
         _ => {
-            diagnostics.push(PluginDiagnostic::error(
-                arg_ast.stable_ptr().untyped(),
-                format!("Invalid component macro argument. Expected `{0}: <value>`", arg_name),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    arg_ast.stable_ptr().untyped(),
+                    format!("Invalid component macro argument. Expected `{0}: <value>`", arg_name),
+                )
+                .with_error_code(error_code!(E3013)),
+            );
             None
         }
     }
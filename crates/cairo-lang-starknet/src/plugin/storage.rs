@@ -1,5 +1,6 @@
 use cairo_lang_defs::patcher::RewriteNode;
 use cairo_lang_defs::plugin::{MacroPluginMetadata, PluginDiagnostic};
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::helpers::QueryAttrs;
 use cairo_lang_syntax::node::{Terminal, TypedSyntaxNode, ast};
@@ -39,13 +40,16 @@ pub fn handle_storage_struct(
                 substorage_members_struct_code.push(struct_code);
                 substorage_members_init_code.push(init_code);
             } else {
-                diagnostics.push(PluginDiagnostic::error(
-                    member.stable_ptr(),
-                    format!(
-                        "`{SUBSTORAGE_ATTR}` attribute is only allowed for members of type \
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        member.stable_ptr(),
+                        format!(
+                            "`{SUBSTORAGE_ATTR}` attribute is only allowed for members of type \
                          [some_path::]{STORAGE_STRUCT_NAME}`"
-                    ),
-                ));
+                        ),
+                    )
+                    .with_error_code(error_code!(E3016)),
+                );
             }
         }
         let SimpleMemberGeneratedCode { struct_code, struct_code_mut, init_code, storage_member } =
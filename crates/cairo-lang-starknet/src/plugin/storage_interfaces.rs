@@ -3,6 +3,7 @@ use cairo_lang_defs::plugin::{
     MacroPlugin, MacroPluginMetadata, PluginDiagnostic, PluginGeneratedFile, PluginResult,
 };
 use cairo_lang_defs::plugin_utils::extract_single_unnamed_arg;
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_filesystem::ids::CodeMapping;
 use cairo_lang_syntax::node::ast::OptionArgListParenthesized;
 use cairo_lang_syntax::node::db::SyntaxGroup;
@@ -69,18 +70,24 @@ impl MacroPlugin for StorageInterfacesPlugin {
                     return PluginResult::default();
                 }
                 if storage_node_attrs.len() > 1 {
-                    diagnostics.push(PluginDiagnostic::error(
-                        struct_ast.as_syntax_node().stable_ptr(),
-                        "Multiple storage node attributes are not allowed.".to_string(),
-                    ));
+                    diagnostics.push(
+                        PluginDiagnostic::error(
+                            struct_ast.as_syntax_node().stable_ptr(),
+                            "Multiple storage node attributes are not allowed.".to_string(),
+                        )
+                        .with_error_code(error_code!(E3016)),
+                    );
                 }
                 if has_derive(&struct_ast, db, STORE_TRAIT).is_some() {
-                    diagnostics.push(PluginDiagnostic::error(
-                        struct_ast.as_syntax_node().stable_ptr(),
-                        "Storage node and storage sub pointers attributes cannot be used
+                    diagnostics.push(
+                        PluginDiagnostic::error(
+                            struct_ast.as_syntax_node().stable_ptr(),
+                            "Storage node and storage sub pointers attributes cannot be used
                 together."
-                            .to_string(),
-                    ));
+                                .to_string(),
+                        )
+                        .with_error_code(error_code!(E3016)),
+                    );
                 }
                 if !diagnostics.is_empty() {
                     return PluginResult { code: None, diagnostics, remove_original_item: false };
@@ -105,27 +112,36 @@ impl MacroPlugin for StorageInterfacesPlugin {
                     return PluginResult::default();
                 }
                 if queryable_variants_attrs.len() > 1 {
-                    diagnostics.push(PluginDiagnostic::error(
-                        enum_ast.as_syntax_node().stable_ptr(),
-                        "Multiple query variants attributes are not allowed.".to_string(),
-                    ));
+                    diagnostics.push(
+                        PluginDiagnostic::error(
+                            enum_ast.as_syntax_node().stable_ptr(),
+                            "Multiple query variants attributes are not allowed.".to_string(),
+                        )
+                        .with_error_code(error_code!(E3016)),
+                    );
                 }
                 if let OptionArgListParenthesized::ArgListParenthesized(arguments) =
                     queryable_variants_attrs[0].arguments(db)
                 {
                     if extract_single_unnamed_arg(db, arguments.arguments(db)).is_none() {
-                        diagnostics.push(PluginDiagnostic::error(
+                        diagnostics.push(
+                            PluginDiagnostic::error(
+                                enum_ast.as_syntax_node().stable_ptr(),
+                                "Query variants attribute must have exactly one unnamed argument."
+                                    .to_string(),
+                            )
+                            .with_error_code(error_code!(E3016)),
+                        );
+                    }
+                } else {
+                    diagnostics.push(
+                        PluginDiagnostic::error(
                             enum_ast.as_syntax_node().stable_ptr(),
                             "Query variants attribute must have exactly one unnamed argument."
                                 .to_string(),
-                        ));
-                    }
-                } else {
-                    diagnostics.push(PluginDiagnostic::error(
-                        enum_ast.as_syntax_node().stable_ptr(),
-                        "Query variants attribute must have exactly one unnamed argument."
-                            .to_string(),
-                    ));
+                        )
+                        .with_error_code(error_code!(E3016)),
+                    );
                 }
                 if !diagnostics.is_empty() {
                     return PluginResult { code: None, diagnostics, remove_original_item: false };
@@ -144,10 +160,13 @@ impl MacroPlugin for StorageInterfacesPlugin {
             }
             _ => {
                 if item_ast.has_attr(db, STORAGE_NODE_ATTR) {
-                    let diagnostics = vec![PluginDiagnostic::error(
-                        item_ast.as_syntax_node().stable_ptr(),
-                        "#[starknet::storage_node] can only be applied to structs.".to_string(),
-                    )];
+                    let diagnostics = vec![
+                        PluginDiagnostic::error(
+                            item_ast.as_syntax_node().stable_ptr(),
+                            "#[starknet::storage_node] can only be applied to structs.".to_string(),
+                        )
+                        .with_error_code(error_code!(E3016)),
+                    ];
                     PluginResult { code: None, diagnostics, remove_original_item: false }
                 } else {
                     PluginResult::default()
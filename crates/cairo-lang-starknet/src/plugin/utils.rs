@@ -1,4 +1,5 @@
 use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_syntax::attribute::structured::{
     AttributeArg, AttributeArgVariant, AttributeStructurize,
 };
@@ -219,7 +220,10 @@ pub fn has_v0_attribute_ex(
     };
     validate_v0(db, diagnostics, &attr, attr_name);
     if let Some(deprecated) = deprecated() {
-        diagnostics.push(PluginDiagnostic::warning(attr.stable_ptr().untyped(), deprecated));
+        diagnostics.push(
+            PluginDiagnostic::warning(attr.stable_ptr().untyped(), deprecated)
+                .with_error_code(error_code!(E3015)),
+        );
     }
     true
 }
@@ -232,10 +236,13 @@ fn validate_v0(
     name: &str,
 ) {
     if !is_single_arg_attr(db, attr, "v0") {
-        diagnostics.push(PluginDiagnostic::error(
-            attr.stable_ptr().untyped(),
-            format!("Only #[{name}(v0)] is supported."),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                attr.stable_ptr().untyped(),
+                format!("Only #[{name}(v0)] is supported."),
+            )
+            .with_error_code(error_code!(E3015)),
+        );
     }
 }
 
@@ -260,13 +267,16 @@ pub fn forbid_attribute_in_impl(
     embedded_impl_attr: &str,
 ) {
     if let Some(attr) = impl_item.find_attr(db, attr_name) {
-        diagnostics.push(PluginDiagnostic::error(
-            attr.stable_ptr().untyped(),
-            format!(
-                "The `{attr_name}` attribute is not allowed inside an impl marked as \
+        diagnostics.push(
+            PluginDiagnostic::error(
+                attr.stable_ptr().untyped(),
+                format!(
+                    "The `{attr_name}` attribute is not allowed inside an impl marked as \
                  `{embedded_impl_attr}`."
-            ),
-        ));
+                ),
+            )
+            .with_error_code(error_code!(E3015)),
+        );
     }
 }
 
@@ -26,6 +26,12 @@ pub const INTERNAL_ATTR: &str = "internal";
 /// An attribute to allow code that would normally result in a warning.
 pub const ALLOW_ATTR: &str = "allow";
 
+/// An attribute to report the warnings of a lint as warnings, overriding an outer lint level.
+pub const WARN_ATTR: &str = "warn";
+
+/// An attribute to report the warnings of a lint as errors.
+pub const DENY_ATTR: &str = "deny";
+
 /// An attribute to allow usage of a feature under a statement.
 pub const FEATURE_ATTR: &str = "feature";
 
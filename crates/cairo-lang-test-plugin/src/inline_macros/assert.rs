@@ -6,6 +6,7 @@ use cairo_lang_defs::plugin::{
 use cairo_lang_defs::plugin_utils::{
     escape_node, try_extract_unnamed_arg, unsupported_bracket_diagnostic,
 };
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_filesystem::cfg::Cfg;
 use cairo_lang_syntax::node::ast::WrappedArgList;
 use cairo_lang_syntax::node::db::SyntaxGroup;
@@ -29,10 +30,13 @@ trait CompareAssertionPlugin: NamedPlugin {
         if arguments.len() < 2 {
             return InlinePluginResult {
                 code: None,
-                diagnostics: vec![PluginDiagnostic::error(
-                    &arguments_syntax.lparen(db),
-                    format!("Macro `{}` requires at least 2 arguments.", Self::NAME),
-                )],
+                diagnostics: vec![
+                    PluginDiagnostic::error(
+                        &arguments_syntax.lparen(db),
+                        format!("Macro `{}` requires at least 2 arguments.", Self::NAME),
+                    )
+                    .with_error_code(error_code!(E3005)),
+                ],
             };
         }
         let (lhs, rest) = arguments.split_first().unwrap();
@@ -40,19 +44,31 @@ trait CompareAssertionPlugin: NamedPlugin {
         let Some(lhs) = try_extract_unnamed_arg(db, lhs) else {
             return InlinePluginResult {
                 code: None,
-                diagnostics: vec![PluginDiagnostic::error(
-                    lhs,
-                    format!("Macro `{}` requires the first argument to be unnamed.", Self::NAME),
-                )],
+                diagnostics: vec![
+                    PluginDiagnostic::error(
+                        lhs,
+                        format!(
+                            "Macro `{}` requires the first argument to be unnamed.",
+                            Self::NAME
+                        ),
+                    )
+                    .with_error_code(error_code!(E3005)),
+                ],
             };
         };
         let Some(rhs) = try_extract_unnamed_arg(db, rhs) else {
             return InlinePluginResult {
                 code: None,
-                diagnostics: vec![PluginDiagnostic::error(
-                    rhs,
-                    format!("Macro `{}` requires the second argument to be unnamed.", Self::NAME),
-                )],
+                diagnostics: vec![
+                    PluginDiagnostic::error(
+                        rhs,
+                        format!(
+                            "Macro `{}` requires the second argument to be unnamed.",
+                            Self::NAME
+                        ),
+                    )
+                    .with_error_code(error_code!(E3005)),
+                ],
             };
         };
         let f = format!("__formatter_for_{}_macro_", Self::NAME);
@@ -1,12 +1,14 @@
 use std::default::Default;
 use std::sync::Arc;
 
-use anyhow::{Result, ensure};
+use anyhow::{Result, bail, ensure};
 use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
 use cairo_lang_compiler::get_sierra_program_for_functions;
 use cairo_lang_debug::DebugWithDb;
-use cairo_lang_defs::ids::{FreeFunctionId, FunctionWithBodyId, ModuleItemId};
+use cairo_lang_defs::ids::{
+    FreeFunctionId, FunctionWithBodyId, ModuleItemId, TopLevelLanguageElementId,
+};
 use cairo_lang_filesystem::db::FilesGroup;
 use cairo_lang_filesystem::ids::CrateId;
 use cairo_lang_lowering::ids::ConcreteFunctionWithBodyId;
@@ -36,17 +38,25 @@ use itertools::{Itertools, chain};
 pub use plugin::TestPlugin;
 use serde::{Deserialize, Serialize};
 use starknet_types_core::felt::Felt as Felt252;
+use test_config::{FuzzedParam, FuzzedType};
 pub use test_config::{TestConfig, try_extract_test_config};
 
 mod inline_macros;
 pub mod plugin;
 pub mod test_config;
 
-const TEST_ATTR: &str = "test";
+#[cfg(test)]
+mod test;
+
+/// The attribute marking a free function as a test.
+pub const TEST_ATTR: &str = "test";
 const SHOULD_PANIC_ATTR: &str = "should_panic";
 const IGNORE_ATTR: &str = "ignore";
 const AVAILABLE_GAS_ATTR: &str = "available_gas";
 const STATIC_GAS_ARG: &str = "static";
+const FUZZER_ATTR: &str = "fuzzer";
+/// The number of runs of a fuzzed test, if not set in the `fuzzer` attribute.
+const DEFAULT_FUZZER_RUNS: usize = 256;
 
 /// Configuration for test compilation.
 #[derive(Clone)]
@@ -130,7 +140,7 @@ pub fn compile_test_prepared_db(
         db,
         tests_compilation_config.executable_crate_ids.unwrap_or_else(|| test_crate_ids.clone()),
     );
-    let all_tests = find_all_tests(db, test_crate_ids.clone());
+    let all_tests = find_all_tests(db, test_crate_ids.clone())?;
 
     let func_ids = chain!(
         executable_functions.clone().into_keys(),
@@ -248,7 +258,7 @@ pub struct TestCompilationMetadata {
 fn find_all_tests(
     db: &dyn SemanticGroup,
     main_crates: Vec<CrateId>,
-) -> Vec<(FreeFunctionId, TestConfig)> {
+) -> Result<Vec<(FreeFunctionId, TestConfig)>> {
     let mut tests = vec![];
     for crate_id in main_crates {
         let modules = db.crate_modules(crate_id);
@@ -256,18 +266,51 @@ fn find_all_tests(
             let Ok(module_items) = db.module_items(*module_id) else {
                 continue;
             };
-            tests.extend(module_items.iter().filter_map(|item| {
-                let ModuleItemId::FreeFunction(func_id) = item else { return None };
+            for item in module_items.iter() {
+                let ModuleItemId::FreeFunction(func_id) = item else { continue };
                 let Ok(attrs) =
                     db.function_with_body_attributes(FunctionWithBodyId::Free(*func_id))
                 else {
-                    return None;
+                    continue;
+                };
+                let Ok(Some(mut test)) = try_extract_test_config(db.upcast(), attrs) else {
+                    continue;
                 };
-                Some((*func_id, try_extract_test_config(db.upcast(), attrs).ok()??))
-            }));
+                if let Some(fuzzer) = &mut test.fuzzer {
+                    fuzzer.params = extract_fuzzed_params(db, *func_id)?;
+                }
+                tests.push((*func_id, test));
+            }
         }
     }
-    tests
+    Ok(tests)
+}
+
+/// Extracts the parameters of a fuzzed test.
+/// Fails if a parameter has a type the fuzzer cannot generate arguments for.
+fn extract_fuzzed_params(
+    db: &dyn SemanticGroup,
+    func_id: FreeFunctionId,
+) -> Result<Vec<FuzzedParam>> {
+    let Ok(signature) = db.free_function_signature(func_id) else {
+        return Ok(vec![]);
+    };
+    signature
+        .params
+        .iter()
+        .map(|param| {
+            let ty_name = param.ty.format(db);
+            let Some(ty) = FuzzedType::from_type_name(&ty_name) else {
+                bail!(
+                    "Parameter `{}` of fuzzed test `{}` has type `{ty_name}`, which is not \
+                     supported by the fuzzer.",
+                    param.name,
+                    func_id.full_path(db.upcast())
+                );
+            };
+            Ok(FuzzedParam { name: param.name.to_string(), ty })
+        })
+        .collect()
 }
 
 /// The suite of plugins that implements assert macros for tests.
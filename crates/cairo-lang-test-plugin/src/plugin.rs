@@ -1,9 +1,10 @@
-use cairo_lang_defs::plugin::{MacroPlugin, MacroPluginMetadata, PluginResult};
+use cairo_lang_defs::plugin::{MacroPlugin, MacroPluginMetadata, PluginDiagnostic, PluginResult};
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_syntax::attribute::structured::AttributeListStructurize;
-use cairo_lang_syntax::node::ast;
 use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode, ast};
 
-use super::{AVAILABLE_GAS_ATTR, IGNORE_ATTR, SHOULD_PANIC_ATTR, TEST_ATTR};
+use super::{AVAILABLE_GAS_ATTR, FUZZER_ATTR, IGNORE_ATTR, SHOULD_PANIC_ATTR, TEST_ATTR};
 use crate::test_config::try_extract_test_config;
 
 /// Plugin to create diagnostics for tests attributes.
@@ -21,11 +22,30 @@ impl MacroPlugin for TestPlugin {
         PluginResult {
             code: None,
             diagnostics: if let ast::ModuleItem::FreeFunction(free_func_ast) = item_ast {
-                try_extract_test_config(db, free_func_ast.attributes(db).structurize(db)).err()
+                match try_extract_test_config(db, free_func_ast.attributes(db).structurize(db)) {
+                    Ok(Some(config)) if config.fuzzer.is_none() => {
+                        let params = free_func_ast.declaration(db).signature(db).parameters(db);
+                        if params.elements(db).is_empty() {
+                            vec![]
+                        } else {
+                            vec![
+                                PluginDiagnostic::error(
+                                    params.stable_ptr().untyped(),
+                                    format!(
+                                        "Tests with parameters must have the `{FUZZER_ATTR}` \
+                                     attribute."
+                                    ),
+                                )
+                                .with_error_code(error_code!(E3011)),
+                            ]
+                        }
+                    }
+                    Ok(_) => vec![],
+                    Err(diagnostics) => diagnostics,
+                }
             } else {
-                None
-            }
-            .unwrap_or_default(),
+                vec![]
+            },
             remove_original_item: false,
         }
     }
@@ -36,6 +56,7 @@ impl MacroPlugin for TestPlugin {
             AVAILABLE_GAS_ATTR.to_string(),
             SHOULD_PANIC_ATTR.to_string(),
             IGNORE_ATTR.to_string(),
+            FUZZER_ATTR.to_string(),
         ]
     }
 }
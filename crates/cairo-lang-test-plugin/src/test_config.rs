@@ -1,4 +1,5 @@
 use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_syntax::attribute::structured::{Attribute, AttributeArg, AttributeArgVariant};
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode, ast};
@@ -10,7 +11,10 @@ use num_traits::ToPrimitive;
 use serde::{Deserialize, Serialize};
 use starknet_types_core::felt::Felt as Felt252;
 
-use super::{AVAILABLE_GAS_ATTR, IGNORE_ATTR, SHOULD_PANIC_ATTR, STATIC_GAS_ARG, TEST_ATTR};
+use super::{
+    AVAILABLE_GAS_ATTR, DEFAULT_FUZZER_RUNS, FUZZER_ATTR, IGNORE_ATTR, SHOULD_PANIC_ATTR,
+    STATIC_GAS_ARG, TEST_ATTR,
+};
 
 /// Expectation for a panic case.
 #[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
@@ -39,6 +43,75 @@ pub struct TestConfig {
     pub expectation: TestExpectation,
     /// Should the test be ignored.
     pub ignored: bool,
+    /// The fuzzer configuration, if the test is fuzzed.
+    #[serde(default)]
+    pub fuzzer: Option<FuzzerConfig>,
+}
+
+/// The configuration for fuzzing a test with generated arguments.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct FuzzerConfig {
+    /// The number of runs with generated arguments.
+    pub runs: usize,
+    /// The seed for generating the arguments. A random seed is used if not set.
+    pub seed: Option<u64>,
+    /// The parameters of the test. Empty until the signature of the test is resolved.
+    pub params: Vec<FuzzedParam>,
+}
+
+/// A parameter of a fuzzed test.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct FuzzedParam {
+    /// The name of the parameter.
+    pub name: String,
+    /// The type of the parameter.
+    pub ty: FuzzedType,
+}
+
+/// A type of a parameter the fuzzer can generate arguments for.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub enum FuzzedType {
+    Felt252,
+    Bool,
+    /// An integer type with the given number of bits.
+    Integer {
+        bits: u32,
+        signed: bool,
+    },
+    ByteArray,
+    /// An `Array` or a `Span` of the given type.
+    Array(Box<FuzzedType>),
+}
+impl FuzzedType {
+    /// Returns the fuzzed type for the given full type name, or `None` if the type is not
+    /// supported by the fuzzer.
+    pub fn from_type_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "core::felt252" => Self::Felt252,
+            "core::bool" => Self::Bool,
+            "core::byte_array::ByteArray" => Self::ByteArray,
+            "core::integer::u256" => Self::Integer { bits: 256, signed: false },
+            _ => {
+                if let Some(inner) = name
+                    .strip_prefix("core::array::Array::<")
+                    .or_else(|| name.strip_prefix("core::array::Span::<"))
+                {
+                    return Some(Self::Array(Box::new(Self::from_type_name(
+                        inner.strip_suffix('>')?,
+                    )?)));
+                }
+                let int_name = name.strip_prefix("core::integer::")?;
+                let (signed, bits) = if let Some(bits) = int_name.strip_prefix('u') {
+                    (false, bits)
+                } else {
+                    (true, int_name.strip_prefix('i')?)
+                };
+                let bits = bits.parse().ok()?;
+                require(matches!(bits, 8 | 16 | 32 | 64 | 128))?;
+                Self::Integer { bits, signed }
+            }
+        })
+    }
 }
 
 /// Extracts the configuration of a tests from attributes, or returns the diagnostics if the
@@ -51,34 +124,47 @@ pub fn try_extract_test_config(
     let ignore_attr = attrs.iter().find(|attr| attr.id.as_str() == IGNORE_ATTR);
     let available_gas_attr = attrs.iter().find(|attr| attr.id.as_str() == AVAILABLE_GAS_ATTR);
     let should_panic_attr = attrs.iter().find(|attr| attr.id.as_str() == SHOULD_PANIC_ATTR);
+    let fuzzer_attr = attrs.iter().find(|attr| attr.id.as_str() == FUZZER_ATTR);
     let mut diagnostics = vec![];
     if let Some(attr) = test_attr {
         if !attr.args.is_empty() {
-            diagnostics.push(PluginDiagnostic::error(
-                attr.id_stable_ptr.untyped(),
-                "Attribute should not have arguments.".into(),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    attr.id_stable_ptr.untyped(),
+                    "Attribute should not have arguments.".into(),
+                )
+                .with_error_code(error_code!(E3011)),
+            );
         }
     } else {
-        for attr in [ignore_attr, available_gas_attr, should_panic_attr].into_iter().flatten() {
-            diagnostics.push(PluginDiagnostic::error(
-                attr.id_stable_ptr.untyped(),
-                "Attribute should only appear on tests.".into(),
-            ));
+        for attr in
+            [ignore_attr, available_gas_attr, should_panic_attr, fuzzer_attr].into_iter().flatten()
+        {
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    attr.id_stable_ptr.untyped(),
+                    "Attribute should only appear on tests.".into(),
+                )
+                .with_error_code(error_code!(E3011)),
+            );
         }
     }
     let ignored = if let Some(attr) = ignore_attr {
         if !attr.args.is_empty() {
-            diagnostics.push(PluginDiagnostic::error(
-                attr.id_stable_ptr.untyped(),
-                "Attribute should not have arguments.".into(),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    attr.id_stable_ptr.untyped(),
+                    "Attribute should not have arguments.".into(),
+                )
+                .with_error_code(error_code!(E3011)),
+            );
         }
         true
     } else {
         false
     };
     let available_gas = extract_available_gas(available_gas_attr, db, &mut diagnostics);
+    let fuzzer = fuzzer_attr.and_then(|attr| extract_fuzzer_config(attr, db, &mut diagnostics));
     let (should_panic, expected_panic_felts) = if let Some(attr) = should_panic_attr {
         if attr.args.is_empty() {
             (true, None)
@@ -86,12 +172,16 @@ pub fn try_extract_test_config(
             (
                 true,
                 extract_panic_bytes(db, attr).on_none(|| {
-                    diagnostics.push(PluginDiagnostic::error(
-                        attr.args_stable_ptr.untyped(),
-                        "Expected panic must be of the form `expected: <tuple of felt252s and \
-                         strings>` or `expected: \"some string\"` or `expected: <some felt252>`."
-                            .into(),
-                    ));
+                    diagnostics.push(
+                        PluginDiagnostic::error(
+                            attr.args_stable_ptr.untyped(),
+                            "Expected panic must be of the form `expected: <tuple of felt252s \
+                             and strings>` or `expected: \"some string\"` or `expected: <some \
+                             felt252>`."
+                                .into(),
+                        )
+                        .with_error_code(error_code!(E3011)),
+                    );
                 }),
             )
         }
@@ -116,10 +206,78 @@ pub fn try_extract_test_config(
                 TestExpectation::Success
             },
             ignored,
+            fuzzer,
         })
     })
 }
 
+/// Extracts the fuzzer configuration from the attribute.
+/// Adds a diagnostic if the attribute is malformed.
+fn extract_fuzzer_config(
+    attr: &Attribute,
+    db: &dyn SyntaxGroup,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) -> Option<FuzzerConfig> {
+    let mut config = FuzzerConfig { runs: DEFAULT_FUZZER_RUNS, seed: None, params: vec![] };
+    for arg in &attr.args {
+        let AttributeArg {
+            variant: AttributeArgVariant::Named { name, value: ast::Expr::Literal(value), .. },
+            ..
+        } = arg
+        else {
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    arg.arg.stable_ptr().untyped(),
+                    "Expected an argument of the form `runs: <positive integer>` or `seed: <u64>`."
+                        .into(),
+                )
+                .with_error_code(error_code!(E3011)),
+            );
+            return None;
+        };
+        let value = value.numeric_value(db);
+        match name.text.as_str() {
+            "runs" => match value.and_then(|v| v.to_usize()) {
+                Some(runs) if runs > 0 => config.runs = runs,
+                _ => {
+                    diagnostics.push(
+                        PluginDiagnostic::error(
+                            arg.arg.stable_ptr().untyped(),
+                            "`runs` should be a positive integer.".into(),
+                        )
+                        .with_error_code(error_code!(E3011)),
+                    );
+                    return None;
+                }
+            },
+            "seed" => match value.and_then(|v| v.to_u64()) {
+                Some(seed) => config.seed = Some(seed),
+                None => {
+                    diagnostics.push(
+                        PluginDiagnostic::error(
+                            arg.arg.stable_ptr().untyped(),
+                            "`seed` should be an integer in `u64` range.".into(),
+                        )
+                        .with_error_code(error_code!(E3011)),
+                    );
+                    return None;
+                }
+            },
+            _ => {
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        name.stable_ptr.untyped(),
+                        format!("Unknown `{FUZZER_ATTR}` argument `{}`.", name.text),
+                    )
+                    .with_error_code(error_code!(E3011)),
+                );
+                return None;
+            }
+        }
+    }
+    Some(config)
+}
+
 /// Extract the available gas from the attribute.
 /// Adds a diagnostic if the attribute is malformed.
 /// Returns `None` if the attribute is "static", or the attribute is malformed.
@@ -148,13 +306,16 @@ fn extract_available_gas(
         _ => None,
     }
     .on_none(|| {
-        diagnostics.push(PluginDiagnostic::error(
-            attr.args_stable_ptr.untyped(),
-            format!(
-                "Attribute should have a single non-negative literal in `i64` range or \
+        diagnostics.push(
+            PluginDiagnostic::error(
+                attr.args_stable_ptr.untyped(),
+                format!(
+                    "Attribute should have a single non-negative literal in `i64` range or \
                  `{STATIC_GAS_ARG}`."
-            ),
-        ))
+                ),
+            )
+            .with_error_code(error_code!(E3011)),
+        )
     })
 }
 
@@ -0,0 +1,82 @@
+//! Code coverage of test runs, reported in the LCOV format.
+//!
+//! Only whether each line was executed is reported, so the execution count of an executed line is
+//! always 1. The profiler counts the steps executed for each statement rather than the number of
+//! times it was executed, and these steps do not translate into line execution counts.
+
+use std::collections::BTreeMap;
+use std::io::Write;
+
+use anyhow::Result;
+use cairo_lang_runner::profiling::ProfilingInfo;
+use cairo_lang_sierra::program::StatementIdx;
+use cairo_lang_sierra_generator::statements_code_locations::StatementsSourceCodeLocations;
+use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
+
+#[cfg(test)]
+#[path = "coverage_test.rs"]
+mod test;
+
+/// The executed Sierra statements of the test runs, merged across all the tests.
+#[derive(Clone, Debug, Default)]
+pub struct StatementsCoverage {
+    /// The number of steps executed for each statement, summed over all the runs.
+    weights: UnorderedHashMap<StatementIdx, usize>,
+}
+impl StatementsCoverage {
+    /// Adds the statements executed in a run.
+    pub fn add(&mut self, profiling_info: &ProfilingInfo) {
+        self.weights.merge(&profiling_info.sierra_statement_weights, |mut entry, weight| {
+            *entry.get_mut() += weight;
+        });
+    }
+}
+
+/// The line coverage of Cairo source files.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct LineCoverage {
+    /// Whether each line (1 based) of each file was executed, by the file full path. Only lines
+    /// that generated statements are present.
+    files: BTreeMap<String, BTreeMap<usize, bool>>,
+}
+impl LineCoverage {
+    /// Maps the executed statements to the lines of the source code that generated them.
+    ///
+    /// A statement is attributed to the line of each of its locations - so code inlined into a
+    /// function is attributed to the inlined code as well as to the call sites it was inlined
+    /// into. The locations are expected to be user locations, so plugin-generated code is
+    /// attributed to the user code it was generated from.
+    pub fn new(
+        coverage: &StatementsCoverage,
+        statements_locations: &StatementsSourceCodeLocations,
+    ) -> Self {
+        let mut files = BTreeMap::<String, BTreeMap<usize, bool>>::new();
+        for (statement_idx, locations) in &statements_locations.statements_to_code_location_map {
+            let executed = coverage.weights.get(statement_idx).is_some_and(|weight| *weight > 0);
+            for (file, span) in locations {
+                *files
+                    .entry(file.0.clone())
+                    .or_default()
+                    .entry(span.start.line + 1)
+                    .or_default() |= executed;
+            }
+        }
+        Self { files }
+    }
+
+    /// Writes the coverage as an LCOV tracefile, with an execution count of 1 for executed lines
+    /// and 0 for the others.
+    pub fn write_lcov(&self, writer: &mut impl Write) -> Result<()> {
+        for (file, lines) in &self.files {
+            writeln!(writer, "TN:")?;
+            writeln!(writer, "SF:{file}")?;
+            for (line, executed) in lines {
+                writeln!(writer, "DA:{line},{}", usize::from(*executed))?;
+            }
+            writeln!(writer, "LF:{}", lines.len())?;
+            writeln!(writer, "LH:{}", lines.values().filter(|executed| **executed).count())?;
+            writeln!(writer, "end_of_record")?;
+        }
+        Ok(writer.flush()?)
+    }
+}
@@ -0,0 +1,63 @@
+use cairo_lang_runner::profiling::ProfilingInfo;
+use cairo_lang_sierra::program::StatementIdx;
+use cairo_lang_sierra_generator::statements_code_locations::{
+    SourceCodeLocation, SourceCodeSpan, SourceFileFullPath, StatementsSourceCodeLocations,
+};
+use indoc::indoc;
+
+use super::{LineCoverage, StatementsCoverage};
+
+/// Returns a location in the given file, starting at the given 0 based line.
+fn location(file: &str, line: usize) -> (SourceFileFullPath, SourceCodeSpan) {
+    (SourceFileFullPath(file.into()), SourceCodeSpan {
+        start: SourceCodeLocation { line, col: 4 },
+        end: SourceCodeLocation { line, col: 10 },
+    })
+}
+
+/// Returns profiling info of a run executing the given statements with the given weights.
+fn profiling_info(weights: &[(usize, usize)]) -> ProfilingInfo {
+    ProfilingInfo {
+        sierra_statement_weights: weights
+            .iter()
+            .map(|(idx, weight)| (StatementIdx(*idx), *weight))
+            .collect(),
+        stack_trace_weights: Default::default(),
+    }
+}
+
+#[test]
+fn test_lcov() {
+    let mut coverage = StatementsCoverage::default();
+    coverage.add(&profiling_info(&[(0, 2), (1, 3)]));
+    coverage.add(&profiling_info(&[(0, 5)]));
+    let locations = StatementsSourceCodeLocations {
+        statements_to_code_location_map: [
+            (StatementIdx(0), vec![location("/src/lib.cairo", 1)]),
+            // An inlined statement, attributed to both the inlined code and the call site.
+            (StatementIdx(1), vec![location("/core/lib.cairo", 7), location("/src/lib.cairo", 2)]),
+            (StatementIdx(2), vec![location("/src/lib.cairo", 3)]),
+            (StatementIdx(3), vec![location("/src/lib.cairo", 2)]),
+        ]
+        .into_iter()
+        .collect(),
+    };
+    let mut buffer = vec![];
+    LineCoverage::new(&coverage, &locations).write_lcov(&mut buffer).unwrap();
+    assert_eq!(String::from_utf8(buffer).unwrap(), indoc! {"
+            TN:
+            SF:/core/lib.cairo
+            DA:8,1
+            LF:1
+            LH:1
+            end_of_record
+            TN:
+            SF:/src/lib.cairo
+            DA:2,1
+            DA:3,1
+            DA:4,0
+            LF:3
+            LH:2
+            end_of_record
+        "});
+}
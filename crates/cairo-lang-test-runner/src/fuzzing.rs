@@ -0,0 +1,350 @@
+//! Generation of arguments for fuzzed tests, and shrinking of failing arguments.
+
+use std::fmt::Display;
+
+use anyhow::Result;
+use cairo_lang_runner::Arg;
+use cairo_lang_test_plugin::test_config::{FuzzedParam, FuzzedType, FuzzerConfig};
+use cairo_lang_utils::byte_array::BYTES_IN_WORD;
+use itertools::Itertools;
+use num_bigint::{BigInt, BigUint, Sign};
+use num_traits::{One, Signed, Zero};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use starknet_types_core::felt::Felt as Felt252;
+
+#[cfg(test)]
+#[path = "fuzzing_test.rs"]
+mod test;
+
+/// The maximal number of runs spent on shrinking failing arguments.
+const MAX_SHRINK_RUNS: usize = 1000;
+/// The maximal length of generated arrays and byte arrays.
+const MAX_GENERATED_LEN: usize = 16;
+
+/// The result of fuzzing a test.
+#[derive(Clone, Debug)]
+pub struct FuzzingReport {
+    /// The number of runs with generated arguments, excluding shrinking runs.
+    pub runs: usize,
+    /// The seed used for generating the arguments.
+    pub seed: u64,
+    /// The minimal failing arguments found, if any run failed.
+    pub counterexample: Option<Vec<(String, String)>>,
+}
+impl Display for FuzzingReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(counterexample) = &self.counterexample {
+            let args =
+                counterexample.iter().map(|(name, value)| format!("{name} = {value}")).join(", ");
+            write!(f, "Counterexample: {args}. ")?;
+        }
+        write!(f, "Reproduce with `#[fuzzer(runs: {}, seed: {})]`.", self.runs, self.seed)
+    }
+}
+
+/// Runs a test with generated arguments, shrinking the arguments of the first failing run.
+///
+/// `run` runs the test with the given arguments, and `is_failure` checks whether a run failed.
+/// Returns the result of the last run - which is the run with the minimal failing arguments if any
+/// run failed, along with the fuzzing report.
+pub fn fuzz<R>(
+    config: &FuzzerConfig,
+    mut run: impl FnMut(Vec<Arg>) -> Result<R>,
+    is_failure: impl Fn(&R) -> bool,
+) -> Result<(R, FuzzingReport)> {
+    let seed = config.seed.unwrap_or_else(rand::random);
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut report = FuzzingReport { runs: config.runs, seed, counterexample: None };
+    let mut result = None;
+    for _ in 0..config.runs {
+        let values = config.params.iter().map(|param| generate(&param.ty, &mut rng)).collect_vec();
+        let curr = run(to_args(&config.params, &values))?;
+        if is_failure(&curr) {
+            let (values, curr) = shrink(&config.params, values, curr, &mut run, &is_failure)?;
+            report.counterexample = Some(
+                config
+                    .params
+                    .iter()
+                    .zip(&values)
+                    .map(|(param, value)| (param.name.clone(), value.to_string()))
+                    .collect(),
+            );
+            return Ok((curr, report));
+        }
+        result = Some(curr);
+    }
+    Ok((result.expect("The fuzzer config should have at least one run."), report))
+}
+
+/// A generated argument of a fuzzed parameter.
+#[derive(Clone, Debug, PartialEq)]
+enum FuzzValue {
+    Felt252(Felt252),
+    Bool(bool),
+    Integer(BigInt),
+    ByteArray(Vec<u8>),
+    Array(Vec<FuzzValue>),
+}
+impl Display for FuzzValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FuzzValue::Felt252(value) => write!(f, "{:#x}", value.to_biguint()),
+            FuzzValue::Bool(value) => write!(f, "{value}"),
+            FuzzValue::Integer(value) => write!(f, "{value}"),
+            FuzzValue::ByteArray(bytes) => write!(f, "{:?}", String::from_utf8_lossy(bytes)),
+            FuzzValue::Array(values) => write!(f, "[{}]", values.iter().join(", ")),
+        }
+    }
+}
+impl FuzzValue {
+    /// Returns the distance of a numeric value from zero, or `None` for non-numeric values.
+    fn magnitude(&self) -> Option<BigInt> {
+        match self {
+            FuzzValue::Felt252(value) => Some(value.to_biguint().into()),
+            FuzzValue::Integer(value) => Some(value.abs()),
+            _ => None,
+        }
+    }
+
+    /// Returns the numeric value with the given magnitude and the sign of this value.
+    fn with_magnitude(&self, magnitude: BigInt) -> FuzzValue {
+        match self {
+            FuzzValue::Felt252(_) => FuzzValue::Felt252(Felt252::from(magnitude)),
+            FuzzValue::Integer(value) => FuzzValue::Integer(magnitude * value.signum()),
+            _ => unreachable!("Only numeric values have a magnitude."),
+        }
+    }
+
+    /// Returns smaller values to try instead of this value when shrinking, smallest first.
+    fn shrink_candidates(&self) -> Vec<FuzzValue> {
+        match self {
+            FuzzValue::Felt252(value) if *value != Felt252::ZERO => {
+                let half = Felt252::from(value.to_biguint() / 2u32);
+                [Felt252::ZERO, half].into_iter().dedup().map(FuzzValue::Felt252).collect()
+            }
+            FuzzValue::Bool(true) => vec![FuzzValue::Bool(false)],
+            FuzzValue::Integer(value) if !value.is_zero() => {
+                let towards_zero = value - value.signum();
+                [BigInt::zero(), value / 2, towards_zero]
+                    .into_iter()
+                    .dedup()
+                    .map(FuzzValue::Integer)
+                    .collect()
+            }
+            FuzzValue::ByteArray(bytes) if !bytes.is_empty() => {
+                shrink_sequence(bytes, |byte| if *byte != b'a' { vec![b'a'] } else { vec![] })
+                    .into_iter()
+                    .map(FuzzValue::ByteArray)
+                    .collect()
+            }
+            FuzzValue::Array(values) if !values.is_empty() => {
+                shrink_sequence(values, FuzzValue::shrink_candidates)
+                    .into_iter()
+                    .map(FuzzValue::Array)
+                    .collect()
+            }
+            _ => vec![],
+        }
+    }
+}
+
+/// Returns smaller sequences to try instead of a non-empty sequence when shrinking - the empty
+/// sequence, its first half, the sequence without one of its elements, and the sequence with one
+/// of its elements shrunk.
+fn shrink_sequence<T: Clone + PartialEq>(
+    items: &[T],
+    shrink_item: impl Fn(&T) -> Vec<T>,
+) -> Vec<Vec<T>> {
+    let mut candidates = vec![vec![], items[..items.len() / 2].to_vec()];
+    for i in 0..items.len() {
+        candidates.push([&items[..i], &items[i + 1..]].concat());
+    }
+    for (i, item) in items.iter().enumerate() {
+        for smaller in shrink_item(item) {
+            let mut candidate = items.to_vec();
+            candidate[i] = smaller;
+            candidates.push(candidate);
+        }
+    }
+    candidates.into_iter().dedup().filter(|candidate| candidate != items).collect()
+}
+
+/// Generates a random value of the given type, preferring edge cases.
+fn generate(ty: &FuzzedType, rng: &mut StdRng) -> FuzzValue {
+    match ty {
+        FuzzedType::Felt252 => FuzzValue::Felt252(if rng.gen_ratio(1, 4) {
+            [Felt252::ZERO, Felt252::ONE, Felt252::MAX][rng.gen_range(0..3)]
+        } else {
+            Felt252::from_bytes_be(&rng.gen())
+        }),
+        FuzzedType::Bool => FuzzValue::Bool(rng.gen()),
+        FuzzedType::Integer { bits, signed } => {
+            let (min, max) = integer_range(*bits, *signed);
+            FuzzValue::Integer(if rng.gen_ratio(1, 4) {
+                let edge_cases = [min.clone(), max, BigInt::zero(), BigInt::from(1)];
+                edge_cases[rng.gen_range(0..edge_cases.len())].clone()
+            } else {
+                let bytes = (0..bits / 8).map(|_| rng.gen::<u8>()).collect_vec();
+                BigInt::from_bytes_be(Sign::Plus, &bytes) + min
+            })
+        }
+        FuzzedType::ByteArray => FuzzValue::ByteArray(
+            (0..rng.gen_range(0..=MAX_GENERATED_LEN)).map(|_| rng.gen_range(b' '..=b'~')).collect(),
+        ),
+        FuzzedType::Array(inner) => FuzzValue::Array(
+            (0..rng.gen_range(0..=MAX_GENERATED_LEN)).map(|_| generate(inner, rng)).collect(),
+        ),
+    }
+}
+
+/// Returns the inclusive range of values of an integer type.
+fn integer_range(bits: u32, signed: bool) -> (BigInt, BigInt) {
+    if signed {
+        let half = BigInt::from(1) << (bits - 1);
+        (-half.clone(), half - 1)
+    } else {
+        (BigInt::zero(), (BigInt::from(1) << bits) - 1)
+    }
+}
+
+/// Shrinks failing arguments, returning the minimal failing arguments found and the result of
+/// running with them.
+///
+/// Numeric values are shrunk by a binary search for the failing value closest to zero, and other
+/// values by trying smaller candidates, until no value can be shrunk further.
+fn shrink<R>(
+    params: &[FuzzedParam],
+    values: Vec<FuzzValue>,
+    result: R,
+    run: &mut impl FnMut(Vec<Arg>) -> Result<R>,
+    is_failure: &impl Fn(&R) -> bool,
+) -> Result<(Vec<FuzzValue>, R)> {
+    let mut shrinker = Shrinker { params, values, result, run, is_failure, runs: 0 };
+    loop {
+        let mut shrunk = false;
+        for i in 0..shrinker.values.len() {
+            shrunk |= match shrinker.values[i].magnitude() {
+                Some(magnitude) => shrinker.bisect(i, magnitude)?,
+                None => shrinker.try_candidates(i)?,
+            };
+        }
+        if !shrunk {
+            return Ok((shrinker.values, shrinker.result));
+        }
+    }
+}
+
+/// The state of shrinking failing arguments.
+struct Shrinker<'a, R, Run, IsFailure> {
+    params: &'a [FuzzedParam],
+    /// The minimal failing arguments found so far.
+    values: Vec<FuzzValue>,
+    /// The result of running with `values`.
+    result: R,
+    run: &'a mut Run,
+    is_failure: &'a IsFailure,
+    /// The number of runs spent on shrinking so far.
+    runs: usize,
+}
+impl<R, Run: FnMut(Vec<Arg>) -> Result<R>, IsFailure: Fn(&R) -> bool>
+    Shrinker<'_, R, Run, IsFailure>
+{
+    /// Runs with the value of parameter `i` replaced by `candidate`, and keeps the candidate if the
+    /// run fails. Returns whether the candidate was kept, which it never is once the shrinking runs
+    /// are exhausted.
+    fn try_candidate(&mut self, i: usize, candidate: FuzzValue) -> Result<bool> {
+        if self.runs == MAX_SHRINK_RUNS {
+            return Ok(false);
+        }
+        self.runs += 1;
+        let mut values = self.values.clone();
+        values[i] = candidate;
+        let result = (self.run)(to_args(self.params, &values))?;
+        if !(self.is_failure)(&result) {
+            return Ok(false);
+        }
+        self.values = values;
+        self.result = result;
+        Ok(true)
+    }
+
+    /// Shrinks the numeric value of parameter `i`, of the given magnitude, by a binary search for
+    /// the failing value of the same sign closest to zero. Returns whether the value was shrunk.
+    fn bisect(&mut self, i: usize, magnitude: BigInt) -> Result<bool> {
+        if magnitude.is_zero() {
+            return Ok(false);
+        }
+        let value = self.values[i].clone();
+        if self.try_candidate(i, value.with_magnitude(BigInt::zero()))? {
+            return Ok(true);
+        }
+        // Values of magnitude `passing` pass, and values of magnitude `failing` fail.
+        let (mut passing, mut failing) = (BigInt::zero(), magnitude.clone());
+        while &failing - &passing > BigInt::one() {
+            let middle: BigInt = (&passing + &failing) / 2;
+            if self.try_candidate(i, value.with_magnitude(middle.clone()))? {
+                failing = middle;
+            } else {
+                passing = middle;
+            }
+        }
+        Ok(failing != magnitude)
+    }
+
+    /// Tries the shrink candidates of the value of parameter `i`, smallest first, and keeps the
+    /// first failing one. Returns whether the value was shrunk.
+    fn try_candidates(&mut self, i: usize) -> Result<bool> {
+        for candidate in self.values[i].shrink_candidates() {
+            if self.try_candidate(i, candidate)? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
+
+/// Converts the generated values of the parameters to runner arguments.
+fn to_args(params: &[FuzzedParam], values: &[FuzzValue]) -> Vec<Arg> {
+    params
+        .iter()
+        .zip_eq(values)
+        .flat_map(|(param, value)| value_to_args(&param.ty, value))
+        .collect()
+}
+
+/// Converts a generated value to the runner arguments representing it.
+fn value_to_args(ty: &FuzzedType, value: &FuzzValue) -> Vec<Arg> {
+    match (ty, value) {
+        (FuzzedType::Felt252, FuzzValue::Felt252(value)) => vec![Arg::Value(*value)],
+        (FuzzedType::Bool, FuzzValue::Bool(value)) => {
+            vec![Arg::Value(if *value { Felt252::ONE } else { Felt252::ZERO })]
+        }
+        (FuzzedType::Integer { bits: 256, .. }, FuzzValue::Integer(value)) => {
+            let value = value.magnitude();
+            let low_mask = (BigUint::from(1u32) << 128) - 1u32;
+            vec![
+                Arg::Value(Felt252::from(value & low_mask)),
+                Arg::Value(Felt252::from(value >> 128)),
+            ]
+        }
+        (FuzzedType::Integer { .. }, FuzzValue::Integer(value)) => {
+            vec![Arg::Value(Felt252::from(value.clone()))]
+        }
+        (FuzzedType::ByteArray, FuzzValue::ByteArray(bytes)) => {
+            let chunks = bytes.chunks_exact(BYTES_IN_WORD);
+            let pending_word = Felt252::from_bytes_be_slice(chunks.remainder());
+            let pending_word_len = Felt252::from(chunks.remainder().len());
+            let full_words = chunks.map(|chunk| Arg::Value(Felt252::from_bytes_be_slice(chunk)));
+            vec![
+                Arg::Array(full_words.collect()),
+                Arg::Value(pending_word),
+                Arg::Value(pending_word_len),
+            ]
+        }
+        (FuzzedType::Array(inner), FuzzValue::Array(values)) => {
+            vec![Arg::Array(values.iter().flat_map(|value| value_to_args(inner, value)).collect())]
+        }
+        _ => unreachable!("Generated values always match their types."),
+    }
+}
@@ -0,0 +1,107 @@
+use cairo_lang_runner::Arg;
+use cairo_lang_test_plugin::test_config::{FuzzedParam, FuzzedType, FuzzerConfig};
+use num_bigint::BigUint;
+use num_traits::ToPrimitive;
+use starknet_types_core::felt::Felt as Felt252;
+
+use super::fuzz;
+
+/// Returns a fuzzer config with the given parameters and a fixed seed.
+fn config(params: Vec<(&str, FuzzedType)>) -> FuzzerConfig {
+    FuzzerConfig {
+        runs: 256,
+        seed: Some(42),
+        params: params
+            .into_iter()
+            .map(|(name, ty)| FuzzedParam { name: name.into(), ty })
+            .collect(),
+    }
+}
+
+/// Returns the value of a single felt argument.
+fn as_value(arg: &Arg) -> &Felt252 {
+    let Arg::Value(value) = arg else { panic!("Expected a value argument.") };
+    value
+}
+
+#[test]
+fn test_fuzz_success() {
+    let config = config(vec![("x", FuzzedType::Integer { bits: 8, signed: false })]);
+    let mut runs = 0;
+    let (_, report) = fuzz(
+        &config,
+        |args| {
+            runs += 1;
+            assert!(as_value(&args[0]).to_u8().is_some());
+            Ok(false)
+        },
+        |failed| *failed,
+    )
+    .unwrap();
+    assert_eq!(runs, 256);
+    assert_eq!(report.counterexample, None);
+}
+
+#[test]
+fn test_fuzz_shrinks_integers() {
+    let config = config(vec![
+        ("a", FuzzedType::Integer { bits: 64, signed: false }),
+        ("b", FuzzedType::Bool),
+    ]);
+    let (failed, report) =
+        fuzz(&config, |args| Ok(as_value(&args[0]).to_u64().unwrap() >= 1234567), |failed| *failed)
+            .unwrap();
+    assert!(failed);
+    assert_eq!(
+        report.counterexample,
+        Some(vec![("a".into(), "1234567".into()), ("b".into(), "false".into())])
+    );
+    assert_eq!(
+        report.to_string(),
+        "Counterexample: a = 1234567, b = false. Reproduce with `#[fuzzer(runs: 256, seed: 42)]`."
+    );
+}
+
+#[test]
+fn test_fuzz_shrinks_felts() {
+    let config = config(vec![("x", FuzzedType::Felt252)]);
+    let threshold = BigUint::from(1u32) << 200;
+    let (_, report) =
+        fuzz(&config, |args| Ok(as_value(&args[0]).to_biguint() >= threshold), |failed| *failed)
+            .unwrap();
+    assert_eq!(report.counterexample, Some(vec![("x".into(), format!("{threshold:#x}"))]));
+}
+
+#[test]
+fn test_fuzz_shrinks_arrays() {
+    let config = config(vec![("values", FuzzedType::Array(Box::new(FuzzedType::Felt252)))]);
+    let (_, report) = fuzz(
+        &config,
+        |args| {
+            let [Arg::Array(values)] = &args[..] else { panic!("Expected a single array.") };
+            Ok(values.len() >= 2)
+        },
+        |failed| *failed,
+    )
+    .unwrap();
+    assert_eq!(report.counterexample, Some(vec![("values".into(), "[0x0, 0x0]".into())]));
+}
+
+#[test]
+fn test_fuzz_byte_array_args() {
+    let config = config(vec![("s", FuzzedType::ByteArray)]);
+    let (_, report) = fuzz(
+        &config,
+        |args| {
+            let [Arg::Array(full_words), pending_word, pending_word_len] = &args[..] else {
+                panic!("Expected a byte array.")
+            };
+            let len = full_words.len() * 31 + as_value(pending_word_len).to_usize().unwrap();
+            assert!(as_value(pending_word).bits() <= 8 * 31);
+            Ok(len >= 3)
+        },
+        |failed| *failed,
+    )
+    .unwrap();
+    assert_eq!(report.counterexample, Some(vec![("s".into(), "\"aaa\"".into())]));
+}
@@ -0,0 +1,261 @@
+//! Per-test gas snapshots, and checking test runs against them for regressions.
+
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use cairo_lang_test_plugin::TestConfig;
+use colored::Colorize;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use crate::report::{TestOutcome, TestReport};
+
+#[cfg(test)]
+#[path = "gas_snapshot_test.rs"]
+mod test;
+
+/// Configuration of gas snapshots of a test run.
+#[derive(Clone, Debug)]
+pub struct GasSnapshotConfig {
+    /// The path of the snapshot file.
+    pub path: PathBuf,
+    /// Whether to write the snapshot of the run, or to check the run against the snapshot.
+    pub mode: GasSnapshotMode,
+}
+
+/// The mode of gas snapshots of a test run.
+#[derive(Clone, Debug, PartialEq)]
+pub enum GasSnapshotMode {
+    /// Write the gas and step counts of the passed tests to the snapshot file, keeping the counts
+    /// of the tests that were not run.
+    Write,
+    /// Compare the gas and step counts of the passed tests against the snapshot file, ignoring the
+    /// tests that were not run.
+    Check {
+        /// The allowed increase of the gas or step count of a test, in percents, before it is
+        /// considered a regression.
+        threshold_percent: f64,
+        /// Whether regressions only warn, instead of failing the run.
+        allow_regressions: bool,
+    },
+}
+
+/// The snapshotted usage of a single test.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestUsage {
+    /// The gas usage of the test, if relevant.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gas: Option<i64>,
+    /// The number of steps of the test run.
+    pub steps: usize,
+}
+
+/// The snapshotted usages of tests, by test name.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct GasSnapshot {
+    pub tests: BTreeMap<String, TestUsage>,
+}
+impl GasSnapshot {
+    /// Creates a snapshot of the passed tests of the given reports.
+    pub fn from_reports(reports: &[TestReport]) -> Self {
+        Self {
+            tests: reports
+                .iter()
+                .filter(|report| report.outcome == TestOutcome::Passed)
+                .map(|report| {
+                    let steps = report
+                        .used_resources
+                        .as_ref()
+                        .map_or(0, |resources| resources.basic_resources.n_steps);
+                    (report.name.clone(), TestUsage { gas: report.gas_usage, steps })
+                })
+                .collect(),
+        }
+    }
+
+    /// Keeps only the tests with the given names.
+    pub fn retain_tests(&mut self, names: &HashSet<String>) {
+        self.tests.retain(|name, _| names.contains(name));
+    }
+
+    /// Merges the snapshot of a run into this snapshot.
+    ///
+    /// `run_tests` are the snapshotted tests that were run, and `all_tests` are all the snapshotted
+    /// tests. The usages of the tests that were run are replaced, the usages of the tests that were
+    /// not run, such as filtered out tests, are kept, and the usages of tests that are no longer
+    /// snapshotted are removed.
+    pub fn merge(
+        &mut self,
+        run: GasSnapshot,
+        run_tests: &HashSet<String>,
+        all_tests: &HashSet<String>,
+    ) {
+        self.tests.retain(|name, _| all_tests.contains(name) && !run_tests.contains(name));
+        self.tests.extend(run.tests);
+    }
+
+    /// Reads a snapshot from the file at the given path.
+    pub fn read(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read gas snapshot `{}`.", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse gas snapshot `{}`.", path.display()))
+    }
+
+    /// Writes the snapshot to the file at the given path.
+    pub fn write(&self, path: &Path) -> Result<()> {
+        let content = serde_json::to_string_pretty(self)? + "\n";
+        std::fs::write(path, content)
+            .with_context(|| format!("Failed to write gas snapshot `{}`.", path.display()))
+    }
+
+    /// Compares the snapshot of a run against this snapshot.
+    pub fn compare(&self, current: &GasSnapshot, threshold_percent: f64) -> GasSnapshotDiff {
+        let mut diff = GasSnapshotDiff::default();
+        for (name, usage) in &current.tests {
+            let Some(expected) = self.tests.get(name) else {
+                diff.added.push(name.clone());
+                continue;
+            };
+            if expected == usage {
+                continue;
+            }
+            let gas_change = expected.gas.zip(usage.gas).map(|(old, new)| Change::new(old, new));
+            let steps_change = Change::new(expected.steps as i64, usage.steps as i64);
+            let regressed = [gas_change, Some(steps_change)]
+                .into_iter()
+                .flatten()
+                .any(|change| change.exceeds(threshold_percent));
+            diff.changed.push(TestUsageDiff {
+                name: name.clone(),
+                gas: gas_change,
+                steps: steps_change,
+                regressed,
+            });
+        }
+        diff.removed =
+            self.tests.keys().filter(|name| !current.tests.contains_key(*name)).cloned().collect();
+        // Sorted from the largest relative increase to the largest relative decrease.
+        diff.changed.sort_by(|a, b| {
+            b.sort_key().total_cmp(&a.sort_key()).then_with(|| a.name.cmp(&b.name))
+        });
+        diff
+    }
+}
+
+/// Returns the names of the given tests that are snapshotted - the tests that are not fuzzed, as
+/// their usage depends on the generated arguments.
+pub fn snapshotted_tests<'a>(
+    tests: impl IntoIterator<Item = &'a (String, TestConfig)>,
+) -> HashSet<String> {
+    tests
+        .into_iter()
+        .filter(|(_, test)| test.fuzzer.is_none())
+        .map(|(name, _)| name.clone())
+        .collect()
+}
+
+/// A change of a usage count between two runs.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Change {
+    pub old: i64,
+    pub new: i64,
+}
+impl Change {
+    fn new(old: i64, new: i64) -> Self {
+        Self { old, new }
+    }
+
+    /// The relative change, in percents.
+    fn percent(&self) -> f64 {
+        if self.old == 0 {
+            if self.new == 0 { 0.0 } else { f64::INFINITY * self.new.signum() as f64 }
+        } else {
+            (self.new - self.old) as f64 * 100.0 / self.old.abs() as f64
+        }
+    }
+
+    /// Whether the change is an increase larger than the given threshold.
+    fn exceeds(&self, threshold_percent: f64) -> bool {
+        self.new > self.old && self.percent() > threshold_percent
+    }
+}
+impl Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} -> {} ({:+.2}%)", self.old, self.new, self.percent())
+    }
+}
+
+/// The difference of the usage of a single test between two runs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TestUsageDiff {
+    pub name: String,
+    /// The change in gas usage, if both runs had one.
+    pub gas: Option<Change>,
+    /// The change in the number of steps.
+    pub steps: Change,
+    /// Whether the change is a regression beyond the threshold.
+    pub regressed: bool,
+}
+impl TestUsageDiff {
+    /// The key to sort diffs by - the relative gas change, or the steps change if gas is not used.
+    fn sort_key(&self) -> f64 {
+        self.gas.unwrap_or(self.steps).percent()
+    }
+}
+
+/// The difference between a snapshot and a run.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GasSnapshotDiff {
+    /// The tests with changed usage, sorted from the largest relative increase.
+    pub changed: Vec<TestUsageDiff>,
+    /// Tests in the run that are missing from the snapshot.
+    pub added: Vec<String>,
+    /// Tests in the snapshot that are missing from the run.
+    pub removed: Vec<String>,
+}
+impl GasSnapshotDiff {
+    /// Returns the names of the tests that regressed beyond the threshold.
+    pub fn regressions(&self) -> Vec<&str> {
+        self.changed.iter().filter(|diff| diff.regressed).map(|diff| diff.name.as_str()).collect()
+    }
+}
+impl Display for GasSnapshotDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.changed.is_empty() && self.added.is_empty() && self.removed.is_empty() {
+            return Ok(());
+        }
+        writeln!(f, "gas snapshot changes:")?;
+        if !self.changed.is_empty() {
+            let rows = self
+                .changed
+                .iter()
+                .map(|diff| {
+                    let gas = diff.gas.map_or_else(|| "-".into(), |change| change.to_string());
+                    (diff, gas, diff.steps.to_string())
+                })
+                .collect_vec();
+            let name_width = rows.iter().map(|(diff, ..)| diff.name.len()).max().unwrap_or(0);
+            let gas_width = rows.iter().map(|(_, gas, _)| gas.len()).max().unwrap_or(0);
+            for (diff, gas, steps) in rows {
+                let line =
+                    format!("  {:name_width$}  gas: {gas:gas_width$}  steps: {steps}", diff.name);
+                if diff.regressed {
+                    writeln!(f, "{}", line.bright_red())?;
+                } else {
+                    writeln!(f, "{line}")?;
+                }
+            }
+        }
+        for name in &self.added {
+            writeln!(f, "  {name}: not in gas snapshot.")?;
+        }
+        for name in &self.removed {
+            writeln!(f, "  {name}: in gas snapshot but not run.")?;
+        }
+        Ok(())
+    }
+}
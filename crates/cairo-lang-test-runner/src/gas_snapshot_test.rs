@@ -0,0 +1,119 @@
+use std::collections::HashSet;
+
+use cairo_lang_test_plugin::TestConfig;
+use cairo_lang_test_plugin::test_config::{FuzzerConfig, TestExpectation};
+use indoc::indoc;
+
+use super::{Change, GasSnapshot, TestUsage, snapshotted_tests};
+
+/// Returns a snapshot of the given tests, with their gas and steps.
+fn snapshot(tests: &[(&str, Option<i64>, usize)]) -> GasSnapshot {
+    GasSnapshot {
+        tests: tests
+            .iter()
+            .map(|(name, gas, steps)| (name.to_string(), TestUsage { gas: *gas, steps: *steps }))
+            .collect(),
+    }
+}
+
+#[test]
+fn test_serialization() {
+    let snapshot = snapshot(&[("b::test", Some(1200), 30), ("a::test", None, 7)]);
+    let serialized = serde_json::to_string_pretty(&snapshot).unwrap();
+    assert_eq!(serialized, indoc! {r#"
+            {
+              "a::test": {
+                "steps": 7
+              },
+              "b::test": {
+                "gas": 1200,
+                "steps": 30
+              }
+            }"#});
+    assert_eq!(serde_json::from_str::<GasSnapshot>(&serialized).unwrap(), snapshot);
+}
+
+#[test]
+fn test_compare() {
+    colored::control::set_override(false);
+    let expected = snapshot(&[
+        ("unchanged", Some(100), 10),
+        ("improved", Some(1000), 100),
+        ("small_regression", Some(1000), 100),
+        ("regression", Some(1000), 100),
+        ("steps_regression", None, 100),
+        ("removed", Some(100), 10),
+    ]);
+    let current = snapshot(&[
+        ("unchanged", Some(100), 10),
+        ("improved", Some(800), 90),
+        ("small_regression", Some(1040), 100),
+        ("regression", Some(1200), 110),
+        ("steps_regression", None, 150),
+        ("added", Some(100), 10),
+    ]);
+    let diff = expected.compare(&current, 5.0);
+    assert_eq!(diff.regressions(), ["steps_regression", "regression"]);
+    assert_eq!(diff.added, ["added"]);
+    assert_eq!(diff.removed, ["removed"]);
+    assert_eq!(diff.changed[2].gas, Some(Change { old: 1000, new: 1040 }));
+    assert_eq!(diff.to_string(), indoc! {"
+            gas snapshot changes:
+              steps_regression  gas: -                       steps: 100 -> 150 (+50.00%)
+              regression        gas: 1000 -> 1200 (+20.00%)  steps: 100 -> 110 (+10.00%)
+              small_regression  gas: 1000 -> 1040 (+4.00%)   steps: 100 -> 100 (+0.00%)
+              improved          gas: 1000 -> 800 (-20.00%)   steps: 100 -> 90 (-10.00%)
+              added: not in gas snapshot.
+              removed: in gas snapshot but not run.
+        "});
+}
+
+/// Returns the set of the given test names.
+fn names(names: &[&str]) -> HashSet<String> {
+    names.iter().map(|name| name.to_string()).collect()
+}
+
+#[test]
+fn test_snapshotted_tests() {
+    let config = |fuzzer| TestConfig {
+        available_gas: None,
+        expectation: TestExpectation::Success,
+        ignored: false,
+        fuzzer,
+    };
+    let fuzzer = FuzzerConfig { runs: 10, seed: None, params: vec![] };
+    let tests = [("plain".to_string(), config(None)), ("fuzzed".to_string(), config(Some(fuzzer)))];
+    assert_eq!(snapshotted_tests(&tests), names(&["plain"]));
+}
+
+#[test]
+fn test_merge_filtered_run() {
+    let mut merged = snapshot(&[
+        ("run", Some(100), 10),
+        ("failed", Some(100), 10),
+        ("filtered_out", Some(200), 20),
+        ("deleted", Some(300), 30),
+    ]);
+    let run = snapshot(&[("run", Some(150), 15), ("added", Some(50), 5)]);
+    merged.merge(
+        run,
+        &names(&["run", "failed", "added"]),
+        &names(&["run", "failed", "filtered_out", "added"]),
+    );
+    assert_eq!(
+        merged,
+        snapshot(&[
+            ("added", Some(50), 5),
+            ("filtered_out", Some(200), 20),
+            ("run", Some(150), 15)
+        ])
+    );
+}
+
+#[test]
+fn test_compare_filtered_run() {
+    let mut expected = snapshot(&[("run", Some(100), 10), ("filtered_out", Some(200), 20)]);
+    expected.retain_tests(&names(&["run"]));
+    let diff = expected.compare(&snapshot(&[("run", Some(100), 10)]), 0.0);
+    assert_eq!(diff, Default::default());
+}
@@ -1,10 +1,13 @@
-use std::path::Path;
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
 use std::sync::Mutex;
+use std::time::{Duration, Instant};
 use std::vec::IntoIter;
 
 use anyhow::{Context, Result, bail};
 use cairo_lang_compiler::db::RootDatabase;
-use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
+use cairo_lang_compiler::diagnostics::{DiagnosticsReporter, MessageFormat};
 use cairo_lang_compiler::project::setup_project;
 use cairo_lang_filesystem::cfg::{Cfg, CfgSet};
 use cairo_lang_filesystem::ids::CrateId;
@@ -12,12 +15,14 @@ use cairo_lang_runner::casm_run::format_next_item;
 use cairo_lang_runner::profiling::{
     ProfilingInfo, ProfilingInfoProcessor, ProfilingInfoProcessorParams,
 };
+use cairo_lang_runner::profiling_export::ProfileExportFormat;
 use cairo_lang_runner::{
-    ProfilingInfoCollectionConfig, RunResultValue, SierraCasmRunner, StarknetExecutionResources,
+    Arg, ProfilingInfoCollectionConfig, RunResultValue, SierraCasmRunner,
+    StarknetExecutionResources,
 };
 use cairo_lang_sierra::extensions::gas::CostTokenType;
 use cairo_lang_sierra::ids::FunctionId;
-use cairo_lang_sierra::program::{Program, StatementIdx};
+use cairo_lang_sierra::program::{Function, Program, StatementIdx};
 use cairo_lang_sierra_generator::db::SierraGenGroup;
 use cairo_lang_sierra_to_casm::metadata::MetadataComputationConfig;
 use cairo_lang_starknet::contract::ContractInfo;
@@ -31,11 +36,21 @@ use cairo_lang_utils::casts::IntoOrPanic;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
 use colored::Colorize;
+use coverage::{LineCoverage, StatementsCoverage};
+use fuzzing::{FuzzingReport, fuzz};
+use gas_snapshot::{GasSnapshot, snapshotted_tests};
+pub use gas_snapshot::{GasSnapshotConfig, GasSnapshotMode};
 use itertools::Itertools;
 use num_traits::ToPrimitive;
 use rayon::prelude::{IntoParallelIterator, ParallelIterator};
+use report::{JsonLinesWriter, write_junit};
+pub use report::{TestOutcome, TestReport, TestReportConfig, TestReportFormat};
 use starknet_types_core::felt::Felt as Felt252;
 
+mod coverage;
+mod fuzzing;
+mod gas_snapshot;
+mod report;
 #[cfg(test)]
 mod test;
 
@@ -61,7 +76,7 @@ impl TestRunner {
         allow_warnings: bool,
         config: TestRunConfig,
     ) -> Result<Self> {
-        let compiler = TestCompiler::try_new(
+        let mut compiler = TestCompiler::try_new(
             path,
             allow_warnings,
             config.gas_enabled,
@@ -74,6 +89,7 @@ impl TestRunner {
                 executable_crate_ids: None,
             },
         )?;
+        compiler.message_format = config.message_format;
         Ok(Self { compiler, config })
     }
 
@@ -102,19 +118,33 @@ impl CompiledTestRunner {
 
     /// Execute preconfigured test execution.
     pub fn run(self, db: Option<&RootDatabase>) -> Result<Option<TestsSummary>> {
+        let all_snapshotted_tests = snapshotted_tests(&self.compiled.metadata.named_tests);
         let (compiled, filtered_out) = filter_test_cases(
             self.compiled,
             self.config.include_ignored,
             self.config.ignored,
             &self.config.filter,
+            self.config.exact,
+        );
+        let run_snapshotted_tests = snapshotted_tests(
+            compiled.metadata.named_tests.iter().filter(|(_, test)| !test.ignored),
         );
 
-        let TestsSummary { passed, failed, ignored, failed_run_results } = run_tests(
+        let statements_locations = compiled.metadata.statements_locations;
+        let TestsSummary {
+            passed,
+            failed,
+            ignored,
+            failed_run_results,
+            fuzzing_failures,
+            coverage,
+            reports,
+            ..
+        } = run_tests(
             if self.config.run_profiler == RunProfilerConfig::Cairo {
                 let db = db.expect("db must be passed when profiling.");
-                let statements_locations = compiled
-                    .metadata
-                    .statements_locations
+                let statements_locations = statements_locations
+                    .as_ref()
                     .expect("statements locations must be present when profiling.");
                 Some(PorfilingAuxData {
                     db,
@@ -131,6 +161,44 @@ impl CompiledTestRunner {
             &self.config,
         )?;
 
+        if let (Some(path), Some(coverage)) = (&self.config.coverage, coverage) {
+            let db = db.expect("db must be passed when collecting coverage.");
+            let statements_locations = statements_locations
+                .as_ref()
+                .expect("statements locations must be present when collecting coverage.");
+            let line_coverage = LineCoverage::new(
+                &coverage,
+                &statements_locations.extract_statements_source_code_locations(db),
+            );
+            let file = File::create(path)
+                .with_context(|| format!("Failed to create coverage file `{}`.", path.display()))?;
+            line_coverage.write_lcov(&mut BufWriter::new(file))?;
+        }
+
+        let mut gas_regressions = vec![];
+        if let Some(GasSnapshotConfig { path, mode }) = &self.config.gas_snapshot {
+            let mut snapshot = GasSnapshot::from_reports(&reports);
+            snapshot.retain_tests(&run_snapshotted_tests);
+            match mode {
+                GasSnapshotMode::Write => {
+                    let mut merged =
+                        if path.exists() { GasSnapshot::read(path)? } else { Default::default() };
+                    merged.merge(snapshot, &run_snapshotted_tests, &all_snapshotted_tests);
+                    merged.write(path)?;
+                }
+                GasSnapshotMode::Check { threshold_percent, allow_regressions } => {
+                    let mut expected = GasSnapshot::read(path)?;
+                    expected.retain_tests(&run_snapshotted_tests);
+                    let diff = expected.compare(&snapshot, *threshold_percent);
+                    print!("{diff}");
+                    if !allow_regressions {
+                        gas_regressions =
+                            diff.regressions().into_iter().map(String::from).collect();
+                    }
+                }
+            }
+        }
+
         if failed.is_empty() {
             println!(
                 "test result: {}. {} passed; {} failed; {} ignored; {filtered_out} filtered out;",
@@ -139,18 +207,21 @@ impl CompiledTestRunner {
                 failed.len(),
                 ignored.len()
             );
+            if !gas_regressions.is_empty() {
+                bail!(
+                    "gas snapshot check: {}. {} tests regressed: {}",
+                    "FAILED".bright_red(),
+                    gas_regressions.len(),
+                    gas_regressions.join(", ")
+                );
+            }
             Ok(None)
         } else {
             println!("failures:");
             for (failure, run_result) in failed.iter().zip_eq(failed_run_results) {
-                print!("   {failure} - ");
-                match run_result {
-                    RunResultValue::Success(_) => {
-                        println!("expected panic but finished successfully.");
-                    }
-                    RunResultValue::Panic(values) => {
-                        println!("{}", format_for_panic(values.into_iter()));
-                    }
+                println!("   {failure} - {}", format_failure(run_result));
+                if let Some(report) = fuzzing_failures.get(failure) {
+                    println!("      {report}");
                 }
             }
             println!();
@@ -165,12 +236,26 @@ impl CompiledTestRunner {
     }
 }
 
-/// Formats the given felts as a panic string.
-fn format_for_panic(mut felts: IntoIter<Felt252>) -> String {
+/// Formats the run result of a failed test as a description of the failure.
+fn format_failure(run_result: RunResultValue) -> String {
+    match run_result {
+        RunResultValue::Success(_) => "expected panic but finished successfully.".into(),
+        RunResultValue::Panic(values) => format_for_panic(values.into_iter()),
+    }
+}
+
+/// Decodes the given panic felts into formatted items.
+fn decode_panic_data(mut felts: IntoIter<Felt252>) -> Vec<String> {
     let mut items = Vec::new();
     while let Some(item) = format_next_item(&mut felts) {
         items.push(item.quote_if_string());
     }
+    items
+}
+
+/// Formats the given felts as a panic string.
+fn format_for_panic(felts: IntoIter<Felt252>) -> String {
+    let items = decode_panic_data(felts);
     let panic_values_string =
         if let [item] = &items[..] { item.clone() } else { format!("({})", items.join(", ")) };
     format!("Panicked with {panic_values_string}.")
@@ -193,6 +278,8 @@ pub enum RunProfilerConfig {
 #[derive(Clone, Debug)]
 pub struct TestRunConfig {
     pub filter: String,
+    /// Whether the filter must match the full name of a test, instead of a part of it.
+    pub exact: bool,
     pub include_ignored: bool,
     pub ignored: bool,
     /// Whether to run the profiler and how.
@@ -201,6 +288,28 @@ pub struct TestRunConfig {
     pub gas_enabled: bool,
     /// Whether to print used resources after each test.
     pub print_resource_usage: bool,
+    /// The machine-readable report to write, if any.
+    pub report: Option<TestReportConfig>,
+    /// The path to write an LCOV code coverage report of the tests to, if any. The report only
+    /// marks whether each line was executed.
+    pub coverage: Option<PathBuf>,
+    /// Where to export the profiled stack traces of all the tests to, if any. Requires running
+    /// the Cairo profiler.
+    pub profile_export: Option<ProfileExportConfig>,
+    /// The gas snapshot to write or check against, if any.
+    pub gas_snapshot: Option<GasSnapshotConfig>,
+    /// The format in which compilation diagnostics are printed.
+    pub message_format: MessageFormat,
+}
+
+/// Configuration of exporting the profiled stack traces of the tests, for opening in standard
+/// profile viewers.
+#[derive(Clone, Debug)]
+pub struct ProfileExportConfig {
+    /// The format to export the stack traces in.
+    pub format: ProfileExportFormat,
+    /// The path of the file to write the stack traces to.
+    pub path: PathBuf,
 }
 
 /// The test cases compiler.
@@ -210,6 +319,8 @@ pub struct TestCompiler {
     pub test_crate_ids: Vec<CrateId>,
     pub allow_warnings: bool,
     pub config: TestsCompilationConfig,
+    /// The format in which compilation diagnostics are printed.
+    pub message_format: MessageFormat,
 }
 
 impl TestCompiler {
@@ -249,13 +360,14 @@ impl TestCompiler {
             main_crate_ids,
             allow_warnings,
             config,
+            message_format: MessageFormat::default(),
         })
     }
 
     /// Build the tests and collect metadata.
     pub fn build(&self) -> Result<TestCompilation> {
-        let mut diag_reporter =
-            DiagnosticsReporter::stderr().with_crates(&self.main_crate_ids.clone());
+        let mut diag_reporter = DiagnosticsReporter::stderr_with_format(self.message_format)
+            .with_crates(&self.main_crate_ids.clone());
         if self.allow_warnings {
             diag_reporter = diag_reporter.allow_warnings();
         }
@@ -276,6 +388,7 @@ impl TestCompiler {
 /// * `include_ignored` - Include ignored tests as well.
 /// * `ignored` - Run ignored tests only.l
 /// * `filter` - Include only tests containing the filter string.
+/// * `exact` - Include only tests whose full name is the filter string instead.
 /// # Returns
 /// * (`TestCompilation`, `usize`) - The filtered test cases and the number of filtered out cases.
 pub fn filter_test_cases(
@@ -283,6 +396,7 @@ pub fn filter_test_cases(
     include_ignored: bool,
     ignored: bool,
     filter: &str,
+    exact: bool,
 ) -> (TestCompilation, usize) {
     let total_tests_count = compiled.metadata.named_tests.len();
     let named_tests = compiled
@@ -298,7 +412,7 @@ pub fn filter_test_cases(
             }
             (func, test)
         })
-        .filter(|(name, _)| name.contains(filter))
+        .filter(|(name, _)| if exact { name == filter } else { name.contains(filter) })
         .collect_vec();
     let filtered_out = total_tests_count - named_tests.len();
     let tests = TestCompilation {
@@ -322,8 +436,12 @@ struct TestResult {
     gas_usage: Option<i64>,
     /// The used resources of the run.
     used_resources: StarknetExecutionResources,
+    /// The wall-clock duration of the run.
+    duration: Duration,
     /// The profiling info of the run, if requested.
     profiling_info: Option<ProfilingInfo>,
+    /// The fuzzing report, if the test is fuzzed.
+    fuzzing: Option<FuzzingReport>,
 }
 
 /// Summary data of the ran tests.
@@ -332,6 +450,20 @@ pub struct TestsSummary {
     failed: Vec<String>,
     ignored: Vec<String>,
     failed_run_results: Vec<RunResultValue>,
+    /// The fuzzing reports of the failed fuzzed tests, by test name.
+    fuzzing_failures: UnorderedHashMap<String, FuzzingReport>,
+    /// The reports of all the tests, in the order they finished.
+    reports: Vec<TestReport>,
+    /// The executed statements of all the tests, if collecting coverage.
+    coverage: Option<StatementsCoverage>,
+    /// The profiled stack trace weights of all the tests, if exporting them.
+    profile_stack_traces: Option<OrderedHashMap<Vec<String>, usize>>,
+}
+impl TestsSummary {
+    /// Returns the reports of all the tests, in the order they finished.
+    pub fn reports(&self) -> &[TestReport] {
+        &self.reports
+    }
 }
 
 /// Auxiliary data that is required when running tests with profiling.
@@ -364,8 +496,8 @@ pub fn run_tests(
         },
         contracts_info,
         match config.run_profiler {
-            RunProfilerConfig::None => None,
-            RunProfilerConfig::Cairo | RunProfilerConfig::Sierra => {
+            RunProfilerConfig::None if config.coverage.is_none() => None,
+            RunProfilerConfig::None | RunProfilerConfig::Cairo | RunProfilerConfig::Sierra => {
                 Some(ProfilingInfoCollectionConfig::default())
             }
         },
@@ -378,17 +510,28 @@ pub fn run_tests(
         failed: vec![],
         ignored: vec![],
         failed_run_results: vec![],
+        fuzzing_failures: Default::default(),
+        reports: vec![],
+        coverage: config.coverage.as_ref().map(|_| StatementsCoverage::default()),
+        profile_stack_traces: config.profile_export.as_ref().map(|_| Default::default()),
     }));
+    let events_writer = match &config.report {
+        Some(TestReportConfig { format: TestReportFormat::JsonLines, path }) => {
+            Some(JsonLinesWriter::create(path)?)
+        }
+        _ => None,
+    };
 
     // Run in parallel if possible. If running with db, parallelism is impossible.
     if profiler_data.is_none() {
         named_tests
             .into_par_iter()
-            .map(|(name, test)| run_single_test(test, name, &runner))
+            .map(|(name, test)| run_single_test(test, name, &runner, events_writer.as_ref()))
             .for_each(|res| {
                 update_summary(
                     &wrapped_summary,
                     res,
+                    events_writer.as_ref(),
                     &None,
                     &sierra_program,
                     &ProfilingInfoProcessorParams {
@@ -396,27 +539,40 @@ pub fn run_tests(
                         process_by_cairo_function: false,
                         ..ProfilingInfoProcessorParams::default()
                     },
-                    config.print_resource_usage,
+                    config,
                 );
             });
     } else {
         eprintln!("Note: Tests don't run in parallel when running with profiling.");
         named_tests
             .into_iter()
-            .map(move |(name, test)| run_single_test(test, name, &runner))
+            .map(|(name, test)| run_single_test(test, name, &runner, events_writer.as_ref()))
             .for_each(|test_result| {
                 update_summary(
                     &wrapped_summary,
                     test_result,
+                    events_writer.as_ref(),
                     &profiler_data,
                     &sierra_program,
                     &ProfilingInfoProcessorParams::default(),
-                    config.print_resource_usage,
+                    config,
                 );
             });
     }
 
-    wrapped_summary.into_inner().unwrap()
+    let summary = wrapped_summary.into_inner().unwrap()?;
+    if let Some(TestReportConfig { format: TestReportFormat::JUnit, path }) = &config.report {
+        let file = File::create(path)
+            .with_context(|| format!("Failed to create report file `{}`.", path.display()))?;
+        write_junit(&summary.reports, &mut BufWriter::new(file))?;
+    }
+    if let (Some(ProfileExportConfig { format, path }), Some(stack_trace_weights)) =
+        (&config.profile_export, &summary.profile_stack_traces)
+    {
+        std::fs::write(path, format.export(stack_trace_weights))
+            .with_context(|| format!("Failed to write the profile to `{}`.", path.display()))?;
+    }
+    Ok(summary)
 }
 
 /// Runs a single test and returns a tuple of its name and result.
@@ -424,55 +580,89 @@ fn run_single_test(
     test: TestConfig,
     name: String,
     runner: &SierraCasmRunner,
+    events_writer: Option<&JsonLinesWriter>,
 ) -> anyhow::Result<(String, Option<TestResult>)> {
     if test.ignored {
         return Ok((name, None));
     }
+    if let Some(events_writer) = events_writer {
+        events_writer.started(&name)?;
+    }
+    let start = Instant::now();
     let func = runner.find_function(name.as_str())?;
+    let run = |args| run_test_case(&test, &name, func, args, runner);
+    let result = match &test.fuzzer {
+        Some(fuzzer) => {
+            let (result, report) =
+                fuzz(fuzzer, run, |result| matches!(result.status, TestStatus::Fail(_)))?;
+            TestResult { fuzzing: Some(report), ..result }
+        }
+        None => run(vec![])?,
+    };
+    let result = TestResult { duration: start.elapsed(), ..result };
+    Ok((name, Some(result)))
+}
+
+/// Runs a single test with the given arguments.
+fn run_test_case(
+    test: &TestConfig,
+    name: &str,
+    func: &Function,
+    args: Vec<Arg>,
+    runner: &SierraCasmRunner,
+) -> anyhow::Result<TestResult> {
     let result = runner
-        .run_function_with_starknet_context(func, vec![], test.available_gas, Default::default())
-        .with_context(|| format!("Failed to run the function `{}`.", name.as_str()))?;
-    Ok((
-        name,
-        Some(TestResult {
-            status: match &result.value {
-                RunResultValue::Success(_) => match test.expectation {
-                    TestExpectation::Success => TestStatus::Success,
-                    TestExpectation::Panics(_) => TestStatus::Fail(result.value),
-                },
-                RunResultValue::Panic(value) => match test.expectation {
-                    TestExpectation::Success => TestStatus::Fail(result.value),
-                    TestExpectation::Panics(panic_expectation) => match panic_expectation {
-                        PanicExpectation::Exact(expected) if value != &expected => {
-                            TestStatus::Fail(result.value)
-                        }
-                        _ => TestStatus::Success,
-                    },
+        .run_function_with_starknet_context(func, args, test.available_gas, Default::default())
+        .with_context(|| format!("Failed to run the function `{name}`."))?;
+    Ok(TestResult {
+        status: match &result.value {
+            RunResultValue::Success(_) => match test.expectation {
+                TestExpectation::Success => {
+                    match result.starknet_state.unfulfilled_expected_calls().first() {
+                        // Fail the test as if it panicked, reporting the first call not made.
+                        Some(expected_call) => TestStatus::Fail(RunResultValue::Panic(vec![
+                            Felt252::from_bytes_be_slice(b"EXPECTED_CALL_NOT_MADE"),
+                            expected_call.contract_address,
+                            expected_call.selector,
+                        ])),
+                        None => TestStatus::Success,
+                    }
+                }
+                TestExpectation::Panics(_) => TestStatus::Fail(result.value),
+            },
+            RunResultValue::Panic(value) => match &test.expectation {
+                TestExpectation::Success => TestStatus::Fail(result.value),
+                TestExpectation::Panics(panic_expectation) => match panic_expectation {
+                    PanicExpectation::Exact(expected) if value != expected => {
+                        TestStatus::Fail(result.value)
+                    }
+                    _ => TestStatus::Success,
                 },
             },
-            gas_usage: test
-                .available_gas
-                .zip(result.gas_counter)
-                .map(|(before, after)| {
-                    before.into_or_panic::<i64>() - after.to_bigint().to_i64().unwrap()
-                })
-                .or_else(|| {
-                    runner.initial_required_gas(func).map(|gas| gas.into_or_panic::<i64>())
-                }),
-            used_resources: result.used_resources,
-            profiling_info: result.profiling_info,
-        }),
-    ))
+        },
+        gas_usage: test
+            .available_gas
+            .zip(result.gas_counter)
+            .map(|(before, after)| {
+                before.into_or_panic::<i64>() - after.to_bigint().to_i64().unwrap()
+            })
+            .or_else(|| runner.initial_required_gas(func).map(|gas| gas.into_or_panic::<i64>())),
+        used_resources: result.used_resources,
+        duration: Duration::ZERO,
+        profiling_info: result.profiling_info,
+        fuzzing: None,
+    })
 }
 
 /// Updates the test summary with the given test result.
 fn update_summary(
     wrapped_summary: &Mutex<std::prelude::v1::Result<TestsSummary, anyhow::Error>>,
     test_result: std::prelude::v1::Result<(String, Option<TestResult>), anyhow::Error>,
+    events_writer: Option<&JsonLinesWriter>,
     profiler_data: &Option<PorfilingAuxData<'_>>,
     sierra_program: &Program,
     profiling_params: &ProfilingInfoProcessorParams,
-    print_resource_usage: bool,
+    config: &TestRunConfig,
 ) {
     let mut wrapped_summary = wrapped_summary.lock().unwrap();
     if wrapped_summary.is_err() {
@@ -486,29 +676,63 @@ fn update_summary(
         }
     };
     let summary = wrapped_summary.as_mut().unwrap();
-    let (res_type, status_str, gas_usage, used_resources, profiling_info) =
-        if let Some(result) = opt_result {
-            let (res_type, status_str) = match result.status {
-                TestStatus::Success => (&mut summary.passed, "ok".bright_green()),
-                TestStatus::Fail(run_result) => {
-                    summary.failed_run_results.push(run_result);
-                    (&mut summary.failed, "fail".bright_red())
+    let (res_type, status_str, report, profiling_info, fuzzing) = if let Some(result) = opt_result {
+        let (res_type, status_str, outcome) = match result.status {
+            TestStatus::Success => (&mut summary.passed, "ok".bright_green(), TestOutcome::Passed),
+            TestStatus::Fail(run_result) => {
+                let mut message = format_failure(run_result.clone());
+                if let Some(report) = &result.fuzzing {
+                    message = format!("{message} {report}");
+                    summary.fuzzing_failures.insert(name.clone(), report.clone());
                 }
-            };
-            (
-                res_type,
-                status_str,
-                result.gas_usage,
-                print_resource_usage.then_some(result.used_resources),
-                result.profiling_info,
-            )
-        } else {
-            (&mut summary.ignored, "ignored".bright_yellow(), None, None, None)
+                let panic_data = match &run_result {
+                    RunResultValue::Success(_) => None,
+                    RunResultValue::Panic(values) => {
+                        Some(decode_panic_data(values.clone().into_iter()))
+                    }
+                };
+                summary.failed_run_results.push(run_result);
+                (&mut summary.failed, "fail".bright_red(), TestOutcome::Failed {
+                    message,
+                    panic_data,
+                })
+            }
+        };
+        let report = TestReport {
+            name: name.clone(),
+            outcome,
+            duration: result.duration,
+            gas_usage: result.gas_usage,
+            used_resources: Some(result.used_resources),
         };
-    if let Some(gas_usage) = gas_usage {
-        println!("test {name} ... {status_str} (gas usage est.: {gas_usage})");
+        (res_type, status_str, report, result.profiling_info, result.fuzzing)
     } else {
+        let report = TestReport {
+            name: name.clone(),
+            outcome: TestOutcome::Ignored,
+            duration: Duration::ZERO,
+            gas_usage: None,
+            used_resources: None,
+        };
+        (&mut summary.ignored, "ignored".bright_yellow(), report, None, None)
+    };
+    if let Some(events_writer) = events_writer {
+        if let Err(err) = events_writer.finished(&report) {
+            *wrapped_summary = Err(err);
+            return;
+        }
+    }
+    let gas_usage = report.gas_usage;
+    let used_resources = report.used_resources.clone().filter(|_| config.print_resource_usage);
+    let details = gas_usage
+        .map(|gas_usage| format!("gas usage est.: {gas_usage}"))
+        .into_iter()
+        .chain(fuzzing.map(|report| format!("fuzzer runs: {}, seed: {}", report.runs, report.seed)))
+        .collect_vec();
+    if details.is_empty() {
         println!("test {name} ... {status_str}");
+    } else {
+        println!("test {name} ... {status_str} ({})", details.join(", "));
     }
     if let Some(used_resources) = used_resources {
         let filtered = used_resources.basic_resources.filter_unused_builtins();
@@ -534,7 +758,12 @@ fn update_summary(
         );
         print_resource_map(used_resources.syscalls.into_iter(), "syscalls");
     }
-    if let Some(profiling_info) = profiling_info {
+    if let (Some(profiling_info), Some(coverage)) = (&profiling_info, &mut summary.coverage) {
+        coverage.add(profiling_info);
+    }
+    if let Some(profiling_info) =
+        profiling_info.filter(|_| config.run_profiler != RunProfilerConfig::None)
+    {
         let Some(PorfilingAuxData { db, statements_functions }) = profiler_data else {
             panic!("profiler_data is None");
         };
@@ -547,8 +776,19 @@ fn update_summary(
         let processed_profiling_info =
             profiling_processor.process_ex(&profiling_info, profiling_params);
         println!("Profiling info:\n{processed_profiling_info}");
+        if let (Some(profile_stack_traces), Some(stack_trace_weights)) = (
+            &mut summary.profile_stack_traces,
+            processed_profiling_info.stack_trace_weights.exportable(),
+        ) {
+            // The stack traces of each test start with the test function, so merging them keeps
+            // the tests apart.
+            for (stack_trace, weight) in stack_trace_weights.iter() {
+                *profile_stack_traces.entry(stack_trace.clone()).or_default() += weight;
+            }
+        }
     }
     res_type.push(name);
+    summary.reports.push(report);
 }
 
 /// Given an iterator of (String, usize) pairs, prints a usage map. E.g.:
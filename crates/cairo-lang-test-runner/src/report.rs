@@ -0,0 +1,208 @@
+//! Machine-readable reports of test runs - a JSON-lines event stream and JUnit XML.
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use cairo_lang_runner::StarknetExecutionResources;
+use itertools::Itertools;
+use serde::Serialize;
+
+#[cfg(test)]
+#[path = "report_test.rs"]
+mod test;
+
+/// The format of a machine-readable test report.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TestReportFormat {
+    /// A JSON object per line for every test event, written as the tests run.
+    JsonLines,
+    /// A JUnit XML document, written once all the tests ran.
+    JUnit,
+}
+
+/// Configuration of a machine-readable test report.
+#[derive(Clone, Debug)]
+pub struct TestReportConfig {
+    /// The format of the report.
+    pub format: TestReportFormat,
+    /// The path of the file to write the report to.
+    pub path: PathBuf,
+}
+
+/// The outcome of a single test.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TestOutcome {
+    Passed,
+    Failed {
+        /// A human readable description of the failure.
+        message: String,
+        /// The decoded items of the panic data, if the test panicked.
+        panic_data: Option<Vec<String>>,
+    },
+    Ignored,
+}
+
+/// The report of a single test.
+#[derive(Clone, Debug)]
+pub struct TestReport {
+    /// The full name of the test.
+    pub name: String,
+    /// The outcome of the test.
+    pub outcome: TestOutcome,
+    /// The wall-clock duration of the test run, including fuzzing runs.
+    pub duration: Duration,
+    /// The gas usage of the test, if relevant.
+    pub gas_usage: Option<i64>,
+    /// The resources used by the test. `None` for ignored tests.
+    pub used_resources: Option<StarknetExecutionResources>,
+}
+
+/// An event of the JSON-lines test report.
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(tag = "event", rename_all = "snake_case")]
+enum TestEvent<'a> {
+    Started {
+        name: &'a str,
+    },
+    Passed {
+        name: &'a str,
+        duration_ms: f64,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        gas_usage: Option<i64>,
+        steps: usize,
+    },
+    Failed {
+        name: &'a str,
+        duration_ms: f64,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        gas_usage: Option<i64>,
+        steps: usize,
+        message: &'a str,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        panic_data: Option<&'a [String]>,
+    },
+    Ignored {
+        name: &'a str,
+    },
+}
+impl<'a> From<&'a TestReport> for TestEvent<'a> {
+    fn from(report: &'a TestReport) -> Self {
+        let name = report.name.as_str();
+        let duration_ms = report.duration.as_secs_f64() * 1000.0;
+        let gas_usage = report.gas_usage;
+        let steps = report.used_resources.as_ref().map_or(0, |r| r.basic_resources.n_steps);
+        match &report.outcome {
+            TestOutcome::Passed => TestEvent::Passed { name, duration_ms, gas_usage, steps },
+            TestOutcome::Failed { message, panic_data } => TestEvent::Failed {
+                name,
+                duration_ms,
+                gas_usage,
+                steps,
+                message,
+                panic_data: panic_data.as_deref(),
+            },
+            TestOutcome::Ignored => TestEvent::Ignored { name },
+        }
+    }
+}
+
+/// Writes test events as JSON lines, as the tests run.
+pub struct JsonLinesWriter {
+    writer: Mutex<Box<dyn Write + Send>>,
+}
+impl JsonLinesWriter {
+    /// Creates a writer of the events into the file at the given path.
+    pub fn create(path: &Path) -> Result<Self> {
+        let file = File::create(path)
+            .with_context(|| format!("Failed to create report file `{}`.", path.display()))?;
+        Ok(Self::new(Box::new(BufWriter::new(file))))
+    }
+
+    /// Creates a writer of the events into the given writer.
+    pub fn new(writer: Box<dyn Write + Send>) -> Self {
+        Self { writer: Mutex::new(writer) }
+    }
+
+    /// Writes the event of a test starting to run.
+    pub fn started(&self, name: &str) -> Result<()> {
+        self.write_event(&TestEvent::Started { name })
+    }
+
+    /// Writes the event of a test finishing its run, or being ignored.
+    pub fn finished(&self, report: &TestReport) -> Result<()> {
+        self.write_event(&report.into())
+    }
+
+    fn write_event(&self, event: &TestEvent<'_>) -> Result<()> {
+        let mut writer = self.writer.lock().unwrap();
+        serde_json::to_writer(&mut *writer, event)?;
+        writeln!(writer)?;
+        Ok(writer.flush()?)
+    }
+}
+
+/// Writes a JUnit XML document of the given test reports.
+pub fn write_junit(reports: &[TestReport], writer: &mut impl Write) -> Result<()> {
+    let failures =
+        reports.iter().filter(|r| matches!(r.outcome, TestOutcome::Failed { .. })).count();
+    let skipped = reports.iter().filter(|r| r.outcome == TestOutcome::Ignored).count();
+    let time: f64 = reports.iter().map(|r| r.duration.as_secs_f64()).sum();
+    writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        writer,
+        r#"<testsuites name="cairo-test" tests="{}" failures="{failures}" skipped="{skipped}" time="{time:.6}">"#,
+        reports.len(),
+    )?;
+    writeln!(
+        writer,
+        r#"  <testsuite name="cairo-test" tests="{}" failures="{failures}" skipped="{skipped}" time="{time:.6}">"#,
+        reports.len(),
+    )?;
+    for report in reports.iter().sorted_by(|a, b| a.name.cmp(&b.name)) {
+        let (classname, name) = report.name.rsplit_once("::").unwrap_or(("", &report.name));
+        write!(
+            writer,
+            r#"    <testcase name="{}" classname="{}" time="{:.6}""#,
+            xml_escape(name),
+            xml_escape(classname),
+            report.duration.as_secs_f64(),
+        )?;
+        match &report.outcome {
+            TestOutcome::Passed => writeln!(writer, "/>")?,
+            TestOutcome::Failed { message, .. } => {
+                writeln!(writer, ">")?;
+                writeln!(writer, r#"      <failure message="{}"/>"#, xml_escape(message))?;
+                writeln!(writer, "    </testcase>")?;
+            }
+            TestOutcome::Ignored => {
+                writeln!(writer, ">")?;
+                writeln!(writer, "      <skipped/>")?;
+                writeln!(writer, "    </testcase>")?;
+            }
+        }
+    }
+    writeln!(writer, "  </testsuite>")?;
+    writeln!(writer, "</testsuites>")?;
+    Ok(writer.flush()?)
+}
+
+/// Escapes a string to be used as an XML attribute value.
+fn xml_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' => escaped.push_str("&#10;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,89 @@
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use cairo_lang_runner::StarknetExecutionResources;
+use indoc::indoc;
+
+use super::{JsonLinesWriter, TestOutcome, TestReport, write_junit};
+
+/// A writer into a shared buffer, for inspecting the output of a [JsonLinesWriter].
+#[derive(Clone, Default)]
+struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Returns reports of a passed, a failed and an ignored test.
+fn reports() -> Vec<TestReport> {
+    let mut used_resources = StarknetExecutionResources::default();
+    used_resources.basic_resources.n_steps = 42;
+    vec![
+        TestReport {
+            name: "crate::tests::test_ok".into(),
+            outcome: TestOutcome::Passed,
+            duration: Duration::from_millis(2),
+            gas_usage: Some(1000),
+            used_resources: Some(used_resources.clone()),
+        },
+        TestReport {
+            name: "crate::tests::test_fail".into(),
+            outcome: TestOutcome::Failed {
+                message: "Panicked with \"a < b\".".into(),
+                panic_data: Some(vec!["\"a < b\"".into()]),
+            },
+            duration: Duration::from_millis(1),
+            gas_usage: None,
+            used_resources: Some(used_resources),
+        },
+        TestReport {
+            name: "crate::test_ignored".into(),
+            outcome: TestOutcome::Ignored,
+            duration: Duration::ZERO,
+            gas_usage: None,
+            used_resources: None,
+        },
+    ]
+}
+
+#[test]
+fn test_json_lines() {
+    let buffer = SharedBuffer::default();
+    let writer = JsonLinesWriter::new(Box::new(buffer.clone()));
+    writer.started("crate::tests::test_ok").unwrap();
+    for report in reports() {
+        writer.finished(&report).unwrap();
+    }
+    assert_eq!(String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap(), indoc! {r#"
+            {"event":"started","name":"crate::tests::test_ok"}
+            {"event":"passed","name":"crate::tests::test_ok","duration_ms":2.0,"gas_usage":1000,"steps":42}
+            {"event":"failed","name":"crate::tests::test_fail","duration_ms":1.0,"steps":42,"message":"Panicked with \"a < b\".","panic_data":["\"a < b\""]}
+            {"event":"ignored","name":"crate::test_ignored"}
+        "#});
+}
+
+#[test]
+fn test_junit() {
+    let mut buffer = vec![];
+    write_junit(&reports(), &mut buffer).unwrap();
+    assert_eq!(String::from_utf8(buffer).unwrap(), indoc! {r#"
+            <?xml version="1.0" encoding="UTF-8"?>
+            <testsuites name="cairo-test" tests="3" failures="1" skipped="1" time="0.003000">
+              <testsuite name="cairo-test" tests="3" failures="1" skipped="1" time="0.003000">
+                <testcase name="test_ignored" classname="crate" time="0.000000">
+                  <skipped/>
+                </testcase>
+                <testcase name="test_fail" classname="crate::tests" time="0.001000">
+                  <failure message="Panicked with &quot;a &lt; b&quot;."/>
+                </testcase>
+                <testcase name="test_ok" classname="crate::tests" time="0.002000"/>
+              </testsuite>
+            </testsuites>
+        "#});
+}
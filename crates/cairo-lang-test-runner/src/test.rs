@@ -1,18 +1,23 @@
+use std::path::PathBuf;
+
+use cairo_lang_runner::RunResultValue;
 use cairo_lang_sierra::program::{Program, ProgramArtifact};
+use cairo_lang_starknet_classes::keccak::starknet_keccak;
 use cairo_lang_test_plugin::test_config::TestExpectation;
 use cairo_lang_test_plugin::{TestCompilationMetadata, TestConfig, TestsCompilationConfig};
 use cairo_lang_utils::byte_array::BYTE_ARRAY_MAGIC;
 use itertools::Itertools;
 use starknet_types_core::felt::Felt as Felt252;
 
-use crate::{TestCompilation, TestCompiler, filter_test_cases, format_for_panic};
+use crate::{
+    RunProfilerConfig, TestCompilation, TestCompiler, TestRunConfig, TestsSummary,
+    filter_test_cases, format_for_panic, run_tests,
+};
 
-#[test]
-fn test_compiled_serialization() {
-    use std::path::PathBuf;
+/// Returns a compiler of the tests in `test_data`.
+fn test_data_compiler(gas_enabled: bool) -> TestCompiler {
     let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("test_data");
-
-    let compiler = TestCompiler::try_new(&path, true, false, TestsCompilationConfig {
+    TestCompiler::try_new(&path, true, gas_enabled, TestsCompilationConfig {
         starknet: true,
         add_statements_functions: false,
         add_statements_code_locations: false,
@@ -20,8 +25,45 @@ fn test_compiled_serialization() {
         contract_crate_ids: None,
         executable_crate_ids: None,
     })
-    .unwrap();
-    let compiled = compiler.build().unwrap();
+    .unwrap()
+}
+
+/// Returns the default configuration for running the tests in `test_data`.
+fn test_run_config() -> TestRunConfig {
+    TestRunConfig {
+        filter: String::new(),
+        exact: false,
+        include_ignored: false,
+        ignored: false,
+        run_profiler: RunProfilerConfig::None,
+        gas_enabled: true,
+        print_resource_usage: false,
+        report: None,
+        coverage: None,
+        profile_export: None,
+        gas_snapshot: None,
+        message_format: Default::default(),
+    }
+}
+
+/// Compiles the tests in `test_data` and runs the ones whose name contains `filter`.
+fn run_test_data(filter: &str) -> TestsSummary {
+    let (compiled, _) =
+        filter_test_cases(test_data_compiler(true).build().unwrap(), false, false, filter, false);
+    run_tests(
+        None,
+        compiled.metadata.named_tests,
+        compiled.sierra_program.program,
+        compiled.metadata.function_set_costs,
+        compiled.metadata.contracts_info,
+        &test_run_config(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_compiled_serialization() {
+    let compiled = test_data_compiler(false).build().unwrap();
     let serialized = serde_json::to_string_pretty(&compiled).unwrap();
     let deserialized: TestCompilation = serde_json::from_str(&serialized).unwrap();
 
@@ -34,6 +76,34 @@ fn test_compiled_serialization() {
     );
 }
 
+#[test]
+fn test_expected_call_not_made() {
+    let summary = run_test_data("test_expected_call_not_made");
+    assert_eq!(summary.failed, ["contracts::failing_tests::test_expected_call_not_made"]);
+    let [RunResultValue::Panic(panic_data)] = summary.failed_run_results.as_slice() else {
+        panic!("Expected the test to fail as if it panicked.");
+    };
+    // The panic data is followed by the contract address and the selector of the expected call.
+    assert_eq!(panic_data, &[
+        Felt252::from_bytes_be_slice(b"EXPECTED_CALL_NOT_MADE"),
+        Felt252::from(0x1234),
+        Felt252::from(starknet_keccak(b"price")),
+    ]);
+}
+
+#[test]
+fn test_fuzzed_tests() {
+    let summary = run_test_data("test_fuzzed_");
+    assert_eq!(summary.passed, ["contracts::fuzzing_tests::test_fuzzed_addition_commutes"]);
+    assert_eq!(summary.failed, ["contracts::failing_tests::test_fuzzed_bound"]);
+    let report =
+        summary.fuzzing_failures.get("contracts::failing_tests::test_fuzzed_bound").unwrap();
+    assert_eq!(
+        report.counterexample,
+        Some(vec![("x".into(), "1000".into()), ("flag".into(), "true".into())])
+    );
+}
+
 #[test]
 fn test_format_for_panic() {
     // Valid short string.
@@ -230,6 +300,7 @@ fn to_named_test(test: &(&str, bool)) -> (String, TestConfig) {
         available_gas: None,
         expectation: TestExpectation::Success,
         ignored: test.1,
+        fuzzer: None,
     })
 }
 
@@ -258,7 +329,8 @@ fn test_filter_test_cases() {
             to_test_compilation(&[("test1", false), ("test2", true), ("test3", false)]),
             false,
             false,
-            "test"
+            "test",
+            false
         ),
         (to_test_compilation(&[("test1", false), ("test2", true), ("test3", false)]), 0)
     );
@@ -271,7 +343,8 @@ fn test_filter_test_cases_include_ignored() {
             to_test_compilation(&[("test1", false), ("test2", true), ("test3", false)]),
             true,
             false,
-            "test"
+            "test",
+            false
         ),
         (to_test_compilation(&[("test1", false), ("test2", false), ("test3", false)]), 0)
     );
@@ -284,7 +357,8 @@ fn test_filter_test_cases_ignored() {
             to_test_compilation(&[("test1", false), ("test2", true), ("test3", false)]),
             false,
             true,
-            "test"
+            "test",
+            false
         ),
         (to_test_compilation(&[("test2", false)]), 2)
     );
@@ -297,8 +371,23 @@ fn test_filter_test_cases_include_ignored_and_ignored() {
             to_test_compilation(&[("test1", false), ("test2", true), ("test3", false)]),
             true,
             true,
-            "test"
+            "test",
+            false
         ),
         (to_test_compilation(&[("test1", false), ("test2", false), ("test3", false)]), 0)
     );
 }
+
+#[test]
+fn test_filter_test_cases_exact() {
+    assert_eq!(
+        filter_test_cases(
+            to_test_compilation(&[("test1", false), ("test10", false), ("other::test1", false)]),
+            false,
+            false,
+            "test1",
+            true
+        ),
+        (to_test_compilation(&[("test1", false)]), 2)
+    );
+}
@@ -37,6 +37,7 @@ mod tests {
 
     #[test]
     fn test_flow() {
+        starknet::testing::declare(Balance::TEST_CLASS_HASH.try_into().unwrap()).unwrap();
         let (address0, _) = deploy_syscall(
             Balance::TEST_CLASS_HASH.try_into().unwrap(), 0, [100].span(), false,
         )
@@ -56,3 +57,32 @@ mod tests {
         assert_eq!(contract1.get(), 400);
     }
 }
+
+#[cfg(test)]
+mod fuzzing_tests {
+    #[test]
+    #[fuzzer(runs: 64, seed: 7)]
+    fn test_fuzzed_addition_commutes(a: u64, b: u64) {
+        let (a, b): (u128, u128) = (a.into(), b.into());
+        assert_eq!(a + b, b + a);
+    }
+}
+
+/// Tests that are expected to fail, checked by the tests of the test runner.
+#[cfg(test)]
+mod failing_tests {
+    use starknet::testing;
+
+    #[test]
+    fn test_expected_call_not_made() {
+        testing::expect_call(
+            starknet::contract_address_const::<0x1234>(), selector!("price"), ['ETH'].span(),
+        );
+    }
+
+    #[test]
+    #[fuzzer(seed: 42)]
+    fn test_fuzzed_bound(x: u32, flag: bool) {
+        assert!(x < 1000 || !flag);
+    }
+}
@@ -52,7 +52,8 @@ pub fn verify_diagnostics_expectation(
     let expect_diagnostics = expect_diagnostics_input_input(expect_diagnostics);
     let has_diagnostics = !diagnostics.trim().is_empty();
     // TODO(Gil): This is a bit of a hack, try and get the original diagnostics from the test.
-    let has_errors = diagnostics.lines().any(|line| line.starts_with("error: "));
+    let has_errors =
+        diagnostics.lines().any(|line| line.starts_with("error: ") || line.starts_with("error["));
     match expect_diagnostics {
         ExpectDiagnostics::Any => {
             if !has_diagnostics {
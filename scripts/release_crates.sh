@@ -46,7 +46,9 @@ CRATES_TO_PUBLISH=(
     cairo-language-server
     cairo-run
     cairo-test
+    casm-disasm
     sierra-compile
+    sierra-verify
     starknet-compile
     starknet-sierra-compile
 )
@@ -2,7 +2,7 @@
 
 set -ex
 
-NAMES="cairo-compile cairo-format cairo-language-server cairo-run cairo-test sierra-compile starknet-compile starknet-sierra-compile"
+NAMES="cairo-compile cairo-format cairo-language-server cairo-run cairo-test casm-disasm sierra-compile sierra-verify starknet-compile starknet-sierra-compile"
 TARGET=$1
 rustup target add $TARGET
 cargo build --release --target $TARGET